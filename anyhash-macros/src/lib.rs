@@ -1,107 +1,482 @@
 #![doc = include_str!("../README.md")]
 
 use proc_macro::TokenStream as TokenStream1;
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 use quote::{format_ident, quote, ToTokens};
 use syn::{
+    meta::ParseNestedMeta,
+    parenthesized,
     parse::{Parse, ParseStream},
     parse_macro_input,
     punctuated::Punctuated,
-    ConstParam, Data, DeriveInput, Error, Fields, GenericArgument, Generics, Index, Lifetime,
-    LifetimeParam, Token, TypeParam, WhereClause,
+    Attribute, ConstParam, Data, DeriveInput, Error, Expr, Fields, GenericParam, Generics, Index,
+    LifetimeParam, LitInt, LitStr, Member, Path, Token, Type, TypeParam, Visibility, WhereClause,
+    WherePredicate,
 };
 
-fn crate_root() -> TokenStream {
-    quote!(::anyhash)
+mod kw {
+    syn::custom_keyword!(via);
 }
 
-#[proc_macro_derive(Hash)]
-#[allow(non_snake_case)]
+fn crate_root(path: Option<&Path>) -> TokenStream {
+    match path {
+        Some(path) => path.to_token_stream(),
+        None => quote!(::anyhash),
+    }
+}
+
+// parses the value of `crate = "path"` in a `hash` or `build_hasher` attribute
+fn parse_crate_path(meta: &ParseNestedMeta) -> syn::Result<Path> {
+    meta.value()?.parse::<LitStr>()?.parse()
+}
+
+// parses `#[name(crate = "path")]` attributes on the type for derives other than `Hash`
+fn parse_crate_attr(attrs: &[Attribute], name: &str) -> syn::Result<Option<Path>> {
+    let mut crate_path = None;
+    for attr in attrs.iter().filter(|a| a.path().is_ident(name)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                crate_path = Some(parse_crate_path(&meta)?);
+                Ok(())
+            } else {
+                Err(meta.error(format!("unrecognized `{name}` attribute")))
+            }
+        })?;
+    }
+    Ok(crate_path)
+}
+
+// parses `#[hash(crate = "path")]` attributes at the start of the input of a function-like macro
+fn parse_crate_attrs(input: ParseStream) -> syn::Result<Option<Path>> {
+    let mut crate_path = None;
+    for attr in Attribute::parse_outer(input)? {
+        if !attr.path().is_ident("hash") {
+            return Err(Error::new_spanned(attr, "expected `#[hash(...)]`"));
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                crate_path = Some(parse_crate_path(&meta)?);
+                Ok(())
+            } else {
+                Err(meta.error("unrecognized `hash` attribute"))
+            }
+        })?;
+    }
+    Ok(crate_path)
+}
+
+#[derive(Default)]
+struct ContainerAttrs {
+    bound: Option<Punctuated<WherePredicate, Token![,]>>,
+    core: bool,
+    crate_path: Option<Path>,
+    order: Option<Punctuated<Member, Token![,]>>,
+    pod: bool,
+    sort_fields: bool,
+    stable_discriminant: bool,
+    tag: Option<LitStr>,
+    tag_fields: bool,
+    transparent: bool,
+    use_discriminant: bool,
+    version: Option<u64>,
+}
+
+impl ContainerAttrs {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut this = Self::default();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("hash")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("bound") {
+                    let bound = meta.value()?.parse::<LitStr>()?;
+                    this.bound = Some(bound.parse_with(Punctuated::parse_terminated)?);
+                    Ok(())
+                } else if meta.path.is_ident("core") {
+                    this.core = true;
+                    Ok(())
+                } else if meta.path.is_ident("crate") {
+                    this.crate_path = Some(parse_crate_path(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("order") {
+                    let content;
+                    parenthesized!(content in meta.input);
+                    this.order = Some(Punctuated::parse_terminated(&content)?);
+                    Ok(())
+                } else if meta.path.is_ident("pod") {
+                    this.pod = true;
+                    Ok(())
+                } else if meta.path.is_ident("sort_fields") {
+                    this.sort_fields = true;
+                    Ok(())
+                } else if meta.path.is_ident("stable_discriminant") {
+                    this.stable_discriminant = true;
+                    Ok(())
+                } else if meta.path.is_ident("tag") {
+                    this.tag = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("tag_fields") {
+                    this.tag_fields = true;
+                    Ok(())
+                } else if meta.path.is_ident("transparent") {
+                    this.transparent = true;
+                    Ok(())
+                } else if meta.path.is_ident("use_discriminant") {
+                    this.use_discriminant = true;
+                    Ok(())
+                } else if meta.path.is_ident("version") {
+                    this.version = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unrecognized `hash` attribute"))
+                }
+            })?;
+        }
+        Ok(this)
+    }
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    skip: bool,
+}
+
+impl FieldAttrs {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut this = Self::default();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("hash")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    this.skip = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unrecognized `hash` field attribute"))
+                }
+            })?;
+        }
+        Ok(this)
+    }
+}
+
+// applies `order` or `sort_fields` to the hashed fields of a struct
+fn order_fields(
+    mut fields: Vec<Member>,
+    attrs: &ContainerAttrs,
+    ident: &Ident,
+) -> syn::Result<Vec<Member>> {
+    if let Some(order) = &attrs.order {
+        let mut ordered = Vec::new();
+        for member in order.iter() {
+            if !fields.contains(member) {
+                return Err(Error::new_spanned(
+                    member,
+                    "not a hashed field of this struct",
+                ));
+            }
+            if ordered.contains(member) {
+                return Err(Error::new_spanned(member, "field is listed more than once"));
+            }
+            ordered.push(member.clone());
+        }
+        if ordered.len() != fields.len() {
+            return Err(Error::new(
+                ident.span(),
+                "`order` must list all hashed fields",
+            ));
+        }
+        Ok(ordered)
+    } else {
+        if attrs.sort_fields {
+            let mut names = Vec::new();
+            for member in fields.iter() {
+                let Member::Named(name) = member else {
+                    return Err(Error::new(
+                        ident.span(),
+                        "`sort_fields` is only supported for structs with named fields",
+                    ));
+                };
+                let name = name.to_string();
+                names.push(name.strip_prefix("r#").map(String::from).unwrap_or(name));
+            }
+            let mut sorted: Vec<_> = names.into_iter().zip(fields).collect();
+            sorted.sort_by(|a, b| a.0.cmp(&b.0));
+            fields = sorted.into_iter().map(|(_, member)| member).collect();
+        }
+        Ok(fields)
+    }
+}
+
+#[proc_macro_derive(Hash, attributes(hash))]
 pub fn derive_anyhash(input: TokenStream1) -> TokenStream1 {
-    let root = crate_root();
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_hash(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[allow(non_snake_case)]
+fn derive_hash(mut input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident = input.ident;
+    let attrs = ContainerAttrs::parse(&input.attrs)?;
+    let packed = has_repr(&input.attrs, "packed")?;
+
+    let root = crate_root(attrs.crate_path.as_ref());
     let hash = quote!(#root::Hash);
     let hasher_write = quote!(#root::HasherWrite);
 
-    let mut input = parse_macro_input!(input as DeriveInput);
-    let ident = input.ident;
+    // writes the field name before the field value if `tag_fields` is set
+    let tag = |name: &Ident| {
+        attrs.tag_fields.then(|| {
+            let name = name.to_string();
+            let name = name.strip_prefix("r#").unwrap_or(&name);
+            quote!(#hasher_write::write_str(state, #name);)
+        })
+    };
 
     let mut tokens = TokenStream::new();
     let mut types = Vec::new();
+    let mut predicates = Vec::new();
 
-    match input.data {
-        Data::Struct(x) => match x.fields {
-            Fields::Named(x) => {
-                let fields = x.named.iter().map(|x| {
-                    types.push(x.ty.clone());
-                    x.ident.as_ref().unwrap()
-                });
-                quote! {
-                    #( #hash::hash(&self.#fields, state); )*
+    // `hash_slice` is only overridden for `pod` types, which can hash a slice with one write,
+    // and `transparent` types, which hash slices like the field type does
+    let mut hash_slice = None;
+
+    if attrs.pod {
+        if let Data::Enum(_) = &input.data {
+            return Err(Error::new(
+                ident.span(),
+                "`pod` is only supported for structs and unions",
+            ));
+        }
+        if attrs.tag.is_some()
+            || attrs.tag_fields
+            || attrs.version.is_some()
+            || attrs.transparent
+            || attrs.order.is_some()
+            || attrs.sort_fields
+        {
+            return Err(Error::new(
+                ident.span(),
+                "`pod` can't be used with `order`, `sort_fields`, `tag`, `tag_fields`, \
+                `transparent` or `version`",
+            ));
+        }
+        if let Data::Struct(x) = &input.data {
+            for x in x.fields.iter() {
+                if FieldAttrs::parse(&x.attrs)?.skip {
+                    return Err(Error::new_spanned(
+                        x,
+                        "`pod` types can't have skipped fields",
+                    ));
                 }
-                .to_tokens(&mut tokens)
             }
+        }
+        predicates.push(quote!(Self: #root::internal::NoUninit));
+        quote!(#root::internal::hash_pod(self, state);).to_tokens(&mut tokens);
+        hash_slice = Some(quote! {
+            #[inline]
+            fn hash_slice<H: #hasher_write>(data: &[Self], state: &mut H) {
+                #root::internal::hash_pod_slice(data, state);
+            }
+        });
+    }
 
-            Fields::Unnamed(x) => {
-                let fields = x.unnamed.iter().enumerate().map(|(i, x)| {
-                    types.push(x.ty.clone());
-                    Index::from(i)
-                });
-                quote! {
-                    #( #hash::hash(&self.#fields, state); )*
+    if attrs.transparent {
+        if attrs.tag.is_some() || attrs.tag_fields || attrs.version.is_some() {
+            return Err(Error::new(
+                ident.span(),
+                "`transparent` can't be used with `tag`, `tag_fields` or `version`",
+            ));
+        }
+        let mut hashed_fields = Vec::new();
+        if let Data::Struct(x) = &input.data {
+            for x in x.fields.iter() {
+                if !FieldAttrs::parse(&x.attrs)?.skip {
+                    hashed_fields.push(x.ty.clone());
                 }
-                .to_tokens(&mut tokens)
             }
+        }
+        let [inner] = &hashed_fields[..] else {
+            return Err(Error::new(
+                ident.span(),
+                "`transparent` requires a struct with exactly one hashed field",
+            ));
+        };
+        if !has_repr(&input.attrs, "transparent")? {
+            return Err(Error::new(
+                ident.span(),
+                "`transparent` requires `#[repr(transparent)]`",
+            ));
+        }
+        hash_slice = Some(quote! {
+            #[inline]
+            fn hash_slice<H: #hasher_write>(data: &[Self], state: &mut H) {
+                // SAFETY: `Self` is `repr(transparent)`, so the hashed field either has the same
+                // layout as `Self`, or is zero sized with an alignment of 1
+                let data = unsafe {
+                    ::core::slice::from_raw_parts(data.as_ptr().cast::<#inner>(), data.len())
+                };
+                <#inner as #hash>::hash_slice(data, state);
+            }
+        });
+    }
+
+    if let Some(type_tag) = &attrs.tag {
+        quote!(#root::HasherWriteExt::write_domain(state, #type_tag);).to_tokens(&mut tokens);
+    }
+
+    if let Some(version) = attrs.version {
+        quote!(#hasher_write::write_u64(state, #version);).to_tokens(&mut tokens);
+    }
 
-            Fields::Unit => (),
-        },
+    if (attrs.order.is_some() || attrs.sort_fields) && !matches!(input.data, Data::Struct(_)) {
+        return Err(Error::new(
+            ident.span(),
+            "`order` and `sort_fields` are only supported for structs",
+        ));
+    }
+    if attrs.order.is_some() && attrs.sort_fields {
+        return Err(Error::new(
+            ident.span(),
+            "`order` and `sort_fields` can't be used together",
+        ));
+    }
+
+    match input.data {
+        _ if attrs.pod => (),
+
+        Data::Struct(x) => {
+            let mut fields = Vec::new();
+            for (i, x) in x.fields.iter().enumerate() {
+                if !FieldAttrs::parse(&x.attrs)?.skip {
+                    types.push(x.ty.clone());
+                    fields.push(match &x.ident {
+                        Some(name) => Member::Named(name.clone()),
+                        None => Member::Unnamed(Index::from(i)),
+                    });
+                }
+            }
+            let fields = order_fields(fields, &attrs, &ident)?;
+            let tags = fields.iter().map(|member| match member {
+                Member::Named(name) => tag(name),
+                Member::Unnamed(_) => None,
+            });
+            // fields of packed structs may be unaligned, so they're copied before being hashed
+            let values = fields.iter().map(|member| {
+                if packed {
+                    quote!(&{ self.#member })
+                } else {
+                    quote!(&self.#member)
+                }
+            });
+            quote! {
+                #( #tags #hash::hash(#values, state); )*
+            }
+            .to_tokens(&mut tokens)
+        }
 
         Data::Enum(x) => {
             let mut variant_tokens = TokenStream::new();
 
-            for x in x.variants.iter() {
+            let repr = if attrs.use_discriminant {
+                if attrs.stable_discriminant {
+                    return Err(Error::new(
+                        ident.span(),
+                        "`stable_discriminant` and `use_discriminant` can't be used together",
+                    ));
+                }
+                Some(enum_repr(&input.attrs)?)
+            } else {
+                None
+            };
+
+            // last explicit discriminant and the number of variants after it
+            let mut explicit: Option<&Expr> = None;
+            let mut offset = 0_u64;
+
+            for (index, x) in x.variants.iter().enumerate() {
                 let var = &x.ident;
 
+                if let Some((_, expr)) = &x.discriminant {
+                    explicit = Some(expr);
+                    offset = 0;
+                } else {
+                    offset += 1;
+                }
+
+                // the discriminant is hashed in each arm if it isn't hashed before the match
+                let disc = if let Some(repr) = &repr {
+                    let value = match explicit {
+                        Some(expr) if offset == 0 => quote!(#expr),
+                        Some(expr) => {
+                            let offset = LitInt::new(&offset.to_string(), Span::call_site());
+                            quote!((#expr) + #offset)
+                        }
+                        None => {
+                            LitInt::new(&index.to_string(), Span::call_site()).into_token_stream()
+                        }
+                    };
+                    Some(quote! {
+                        #hash::hash(&{ let disc: #repr = #value; disc }, state);
+                    })
+                } else {
+                    attrs.stable_discriminant.then(|| {
+                        let index = index as u32;
+                        quote!(#hasher_write::write_u32(state, #index);)
+                    })
+                };
+
                 match &x.fields {
                     Fields::Named(x) => {
-                        let fields: Vec<_> = x
-                            .named
-                            .iter()
-                            .map(|x| {
+                        let mut fields = Vec::new();
+                        for x in x.named.iter() {
+                            if !FieldAttrs::parse(&x.attrs)?.skip {
                                 types.push(x.ty.clone());
-                                x.ident.as_ref().unwrap()
-                            })
-                            .collect();
+                                fields.push(x.ident.as_ref().unwrap());
+                            }
+                        }
+                        let tags = fields.iter().copied().map(tag);
                         quote! {
-                            Self::#var { #(#fields),* } => { #( #hash::hash(#fields, state); )* }
+                            Self::#var { #(#fields,)* .. } => {
+                                #disc
+                                #( #tags #hash::hash(#fields, state); )*
+                            }
                         }
                         .to_tokens(&mut variant_tokens);
                     }
 
                     Fields::Unnamed(x) => {
-                        let fields: Vec<_> = x
-                            .unnamed
-                            .iter()
-                            .enumerate()
-                            .map(|(i, x)| {
+                        let mut fields = Vec::new();
+                        let mut pats = Vec::new();
+                        for (i, x) in x.unnamed.iter().enumerate() {
+                            if FieldAttrs::parse(&x.attrs)?.skip {
+                                pats.push(quote!(_));
+                            } else {
                                 types.push(x.ty.clone());
-                                format_ident!("_{i}")
-                            })
-                            .collect();
+                                let field = format_ident!("_{i}");
+                                pats.push(field.to_token_stream());
+                                fields.push(field);
+                            }
+                        }
                         quote! {
-                            Self::#var(#(#fields),*) => { #( #hash::hash(#fields, state); )* }
+                            Self::#var(#(#pats),*) => {
+                                #disc
+                                #( #hash::hash(#fields, state); )*
+                            }
                         }
                         .to_tokens(&mut variant_tokens);
                     }
 
                     Fields::Unit => quote! {
-                        Self::#var => (),
+                        Self::#var => { #disc }
                     }
                     .to_tokens(&mut variant_tokens),
                 }
             }
 
+            let per_variant = attrs.stable_discriminant || attrs.use_discriminant;
+            let disc =
+                (!per_variant).then(|| quote!(#hash::hash(&core::mem::discriminant(self), state);));
             quote! {
-                #hash::hash(&core::mem::discriminant(self), state);
+                #disc
                 match self {
                     #variant_tokens
                 }
@@ -110,55 +485,507 @@ pub fn derive_anyhash(input: TokenStream1) -> TokenStream1 {
         }
 
         Data::Union(_) => {
-            return Error::new(ident.span(), "can't derive `Hash` for union")
-                .to_compile_error()
-                .into()
+            return Err(Error::new(
+                ident.span(),
+                "can't derive `Hash` for union without `#[hash(pod)]`",
+            ));
         }
     }
 
     input.generics.make_where_clause();
     let wc = input.generics.where_clause.as_mut().unwrap();
     let where_ = fix_where(Some(wc));
+
+    // bridging `core::hash::Hash` impl for `#[hash(core)]`
+    let core_impl = attrs.core.then(|| {
+        let SplitGenerics {
+            lti,
+            tpi,
+            cpi,
+            args,
+            wc,
+        } = split_generics(&input.generics);
+        quote! {
+            impl<#(#lti,)* #(#tpi,)* #(#cpi,)*> ::core::hash::Hash
+                for #ident<#(#args),*> #where_ #wc
+                Self: #hash,
+            {
+                #[inline]
+                fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    <Self as #hash>::hash(
+                        self, &mut #root::internal::WrapCoreForHasherU64::new(state)
+                    )
+                }
+            }
+        }
+    });
+
     let SplitGenerics {
         lti,
-        ltt,
         tpi,
-        tpt,
         cpi,
-        cpt,
+        args,
         wc,
     } = split_generics(&input.generics);
-    quote! {
-        impl<#(#lti,)* #(#tpi,)* #(#cpi,)*> #hash for #ident<#(#ltt,)* #(#tpt,)* #(#cpt),*> #where_ #wc
-            #( #types: #hash ),*
+    let bounds = match attrs.bound {
+        Some(bound) => bound.into_token_stream(),
+        None => {
+            let types = bounded_types(types, &input.generics);
+            quote!(#( #types: #hash, )* #( #predicates, )*)
+        }
+    };
+    Ok(quote! {
+        impl<#(#lti,)* #(#tpi,)* #(#cpi,)*> #hash for #ident<#(#args),*> #where_ #wc
+            #bounds
         {
             #[inline]
             fn hash<H: #hasher_write>(&self, state: &mut H) {
                 #tokens
             }
+
+            #hash_slice
         }
+
+        #core_impl
+    })
+}
+
+#[proc_macro_derive(HasherForward, attributes(hasher_forward))]
+pub fn derive_hasher_forward(input: TokenStream1) -> TokenStream1 {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_hasher_forward_impl(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn derive_hasher_forward_impl(mut input: DeriveInput) -> syn::Result<TokenStream> {
+    let crate_path = parse_crate_attr(&input.attrs, "hasher_forward")?;
+    let root = crate_root(crate_path.as_ref());
+    let hasher_t = quote!(#root::Hasher);
+    let hasher_write = quote!(#root::HasherWrite);
+
+    let ident = input.ident;
+    let Data::Struct(data) = input.data else {
+        return Err(Error::new(
+            ident.span(),
+            "`HasherForward` can only be derived for structs",
+        ));
+    };
+
+    // the field marked with `#[hasher_forward]`, or the only field
+    let mut inner = None;
+    for (i, field) in data.fields.iter().enumerate() {
+        if field
+            .attrs
+            .iter()
+            .any(|a| a.path().is_ident("hasher_forward"))
+        {
+            if inner.is_some() {
+                return Err(Error::new_spanned(
+                    field,
+                    "only one field can be marked with `#[hasher_forward]`",
+                ));
+            }
+            inner = Some((i, field));
+        }
+    }
+    let (i, field) = match inner {
+        Some(inner) => inner,
+        None if data.fields.len() == 1 => (0, data.fields.iter().next().unwrap()),
+        None => {
+            return Err(Error::new(
+                ident.span(),
+                "mark the field with the inner hasher with `#[hasher_forward]`",
+            ))
+        }
+    };
+    let member = match &field.ident {
+        Some(ident) => ident.to_token_stream(),
+        None => Index::from(i).to_token_stream(),
+    };
+    let ty = &field.ty;
+
+    let mut writes = TokenStream::new();
+    for t in [
+        quote!(u8),
+        quote!(u16),
+        quote!(u32),
+        quote!(u64),
+        quote!(u128),
+        quote!(usize),
+        quote!(i8),
+        quote!(i16),
+        quote!(i32),
+        quote!(i64),
+        quote!(i128),
+        quote!(isize),
+        quote!(f32),
+        quote!(f64),
+    ] {
+        let wid = format_ident!("write_{t}");
+        quote! {
+            #[inline]
+            fn #wid(&mut self, i: #t) {
+                <#ty as #hasher_write>::#wid(&mut self.#member, i);
+            }
+        }
+        .to_tokens(&mut writes);
+    }
+
+    input.generics.make_where_clause();
+    let wc = input.generics.where_clause.as_mut().unwrap();
+    let where_ = fix_where(Some(wc));
+    let SplitGenerics {
+        lti,
+        tpi,
+        cpi,
+        args,
+        wc,
+    } = split_generics(&input.generics);
+    let lti: Vec<_> = lti.collect();
+    let tpi: Vec<_> = tpi.collect();
+    let cpi: Vec<_> = cpi.collect();
+    let args: Vec<_> = args.collect();
+
+    Ok(quote! {
+        impl<#(#lti,)* #(#tpi,)* #(#cpi,)*> #hasher_write for #ident<#(#args),*> #where_ #wc
+            #ty: #hasher_write,
+        {
+            #[inline]
+            fn write(&mut self, bytes: &[u8]) {
+                <#ty as #hasher_write>::write(&mut self.#member, bytes);
+            }
+
+            #writes
+
+            #[inline]
+            fn write_length_prefix(&mut self, len: usize) {
+                <#ty as #hasher_write>::write_length_prefix(&mut self.#member, len);
+            }
+
+            #[inline]
+            fn write_str(&mut self, s: &str) {
+                <#ty as #hasher_write>::write_str(&mut self.#member, s);
+            }
+        }
+
+        impl<#(#lti,)* __AnyhashT, #(#tpi,)* #(#cpi,)*> #hasher_t<__AnyhashT> for #ident<#(#args),*> #where_ #wc
+            #ty: #hasher_t<__AnyhashT>,
+        {
+            #[inline]
+            fn finish(&self) -> __AnyhashT {
+                <#ty as #hasher_t<__AnyhashT>>::finish(&self.#member)
+            }
+
+            #[inline]
+            fn finish_once(self) -> __AnyhashT {
+                <#ty as #hasher_t<__AnyhashT>>::finish_once(self.#member)
+            }
+        }
+    })
+}
+
+#[derive(Default)]
+struct BuildHasherAttrs {
+    hasher: Option<Type>,
+    hash: Vec<Type>,
+    hash_map: Option<Ident>,
+    hash_set: Option<Ident>,
+    crate_path: Option<Path>,
+    new: bool,
+}
+
+impl BuildHasherAttrs {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut this = Self::default();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("build_hasher")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("hasher") {
+                    this.hasher = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("hash") {
+                    let hash = meta.value()?.parse::<LitStr>()?;
+                    let hash = hash.parse_with(Punctuated::<Type, Token![,]>::parse_terminated)?;
+                    this.hash.extend(hash);
+                    Ok(())
+                } else if meta.path.is_ident("hash_map") {
+                    this.hash_map = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("hash_set") {
+                    this.hash_set = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("crate") {
+                    this.crate_path = Some(parse_crate_path(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("new") {
+                    this.new = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unrecognized `build_hasher` attribute"))
+                }
+            })?;
+        }
+        Ok(this)
+    }
+}
+
+#[proc_macro_derive(BuildHasher, attributes(build_hasher))]
+pub fn derive_build_hasher(input: TokenStream1) -> TokenStream1 {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_build_hasher_impl(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn derive_build_hasher_impl(mut input: DeriveInput) -> syn::Result<TokenStream> {
+    let attrs = BuildHasherAttrs::parse(&input.attrs)?;
+    let root = crate_root(attrs.crate_path.as_ref());
+    let build_hasher_t = quote!(#root::BuildHasher);
+
+    let ident = input.ident;
+    let vis = input.vis;
+    let Some(hasher) = attrs.hasher else {
+        return Err(Error::new(
+            ident.span(),
+            "missing `#[build_hasher(hasher = \"...\")]` attribute",
+        ));
+    };
+    let hash_ts = if attrs.hash.is_empty() {
+        vec![syn::parse_quote!(u64)]
+    } else {
+        attrs.hash
+    };
+    let Data::Struct(data) = input.data else {
+        return Err(Error::new(
+            ident.span(),
+            "`BuildHasher` can only be derived for structs",
+        ));
+    };
+
+    // the seed fields, and the names of the parameters of `with_seed` for them
+    let mut members = Vec::new();
+    let mut params = Vec::new();
+    let mut types = Vec::new();
+    for (i, field) in data.fields.iter().enumerate() {
+        match &field.ident {
+            Some(ident) => {
+                members.push(ident.to_token_stream());
+                params.push(ident.clone());
+            }
+            None => {
+                members.push(Index::from(i).to_token_stream());
+                params.push(if data.fields.len() == 1 {
+                    format_ident!("seed")
+                } else {
+                    format_ident!("seed{i}")
+                });
+            }
+        }
+        types.push(&field.ty);
+    }
+    let construct = match &data.fields {
+        Fields::Named(_) => quote!(Self { #(#params),* }),
+        Fields::Unnamed(_) => quote!(Self(#(#params),*)),
+        Fields::Unit => quote!(Self),
+    };
+    let build = if members.is_empty() {
+        quote!(<#hasher as ::core::default::Default>::default())
+    } else {
+        quote!(<#hasher>::with_seed(#(::core::clone::Clone::clone(&self.#members)),*))
+    };
+
+    let generic = input.generics.type_params().next().is_some()
+        || input.generics.lifetimes().next().is_some()
+        || input.generics.const_params().next().is_some();
+    if generic && (attrs.hash_map.is_some() || attrs.hash_set.is_some()) {
+        return Err(Error::new(
+            ident.span(),
+            "`hash_map` and `hash_set` aren't supported for generic types",
+        ));
+    }
+    // the aliases need `core::hash::BuildHasher` to be usable, so implement it with them
+    let core_build_hasher = if attrs.hash_map.is_some() || attrs.hash_set.is_some() {
+        if !hash_ts
+            .iter()
+            .any(|t| t.to_token_stream().to_string() == "u64")
+        {
+            return Err(Error::new(
+                ident.span(),
+                "`hash_map` and `hash_set` require `BuildHasher<u64>`",
+            ));
+        }
+        Some(quote! {
+            impl ::core::hash::BuildHasher for #ident {
+                type Hasher = #root::internal::WrapHasherU64ForCore<#hasher>;
+
+                #[inline]
+                fn build_hasher(&self) -> Self::Hasher {
+                    Self::Hasher::new(<Self as #build_hasher_t<u64>>::build_hasher(self))
+                }
+            }
+        })
+    } else {
+        None
+    };
+    let hash_map = attrs.hash_map.map(|name| {
+        quote! {
+            #[doc = concat!("`HashMap` using [`", stringify!(#ident), "`].")]
+            #vis type #name<K, V> = ::std::collections::HashMap<K, V, #ident>;
+        }
+    });
+    let hash_set = attrs.hash_set.map(|name| {
+        quote! {
+            #[doc = concat!("`HashSet` using [`", stringify!(#ident), "`].")]
+            #vis type #name<T> = ::std::collections::HashSet<T, #ident>;
+        }
+    });
+
+    input.generics.make_where_clause();
+    let wc = input.generics.where_clause.as_mut().unwrap();
+    let where_ = fix_where(Some(wc));
+    let SplitGenerics {
+        lti,
+        tpi,
+        cpi,
+        args,
+        wc,
+    } = split_generics(&input.generics);
+    let lti: Vec<_> = lti.collect();
+    let tpi: Vec<_> = tpi.collect();
+    let cpi: Vec<_> = cpi.collect();
+    let args: Vec<_> = args.collect();
+
+    let mut impls = TokenStream::new();
+    for hash_t in hash_ts {
+        quote! {
+            impl<#(#lti,)* #(#tpi,)* #(#cpi,)*> #build_hasher_t<#hash_t> for #ident<#(#args),*> #where_ #wc
+                #hasher: #root::Hasher<#hash_t>,
+            {
+                type Hasher = #hasher;
+
+                #[inline]
+                fn build_hasher(&self) -> Self::Hasher {
+                    #build
+                }
+            }
+        }
+        .to_tokens(&mut impls);
+    }
+
+    // opt-in, because the `Default` bounds are errors for concrete types that don't implement it
+    let new = attrs.new.then(|| {
+        quote! {
+            #[doc = concat!("Create a new `", stringify!(#ident), "` using the default seed.")]
+            #[inline]
+            #vis fn new() -> Self
+            where
+                #( #types: ::core::default::Default, )*
+            {
+                Self::with_seed(#(<#types as ::core::default::Default>::default()),*)
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl<#(#lti,)* #(#tpi,)* #(#cpi,)*> #ident<#(#args),*> #where_ #wc {
+            #new
+
+            #[doc = concat!("Create a new `", stringify!(#ident), "` with a custom seed.")]
+            #[inline]
+            #vis const fn with_seed(#(#params: #types),*) -> Self {
+                #construct
+            }
+        }
+
+        #impls
+
+        #core_build_hasher
+        #hash_map
+        #hash_set
+    })
+}
+
+struct HashCollections {
+    crate_path: Option<Path>,
+    vis: Visibility,
+    prefix: Ident,
+    build_hasher: Type,
+}
+
+impl Parse for HashCollections {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let crate_path = parse_crate_attrs(input)?;
+        let vis = Visibility::parse(input)?;
+        let prefix = Ident::parse(input)?;
+        <Token![=]>::parse(input)?;
+        let build_hasher = Type::parse(input)?;
+        Option::<Token![;]>::parse(input)?;
+        Ok(Self {
+            crate_path,
+            vis,
+            prefix,
+            build_hasher,
+        })
     }
-    .into()
+}
+
+#[proc_macro]
+pub fn define_hash_collections(input: TokenStream1) -> TokenStream1 {
+    let HashCollections {
+        crate_path,
+        vis,
+        prefix,
+        build_hasher,
+    } = parse_macro_input!(input as HashCollections);
+    let root = crate_root(crate_path.as_ref());
+
+    let mut output = TokenStream::new();
+    for (mac, map, set) in [
+        ("__define_std_hash_collections", "HashMap", "HashSet"),
+        (
+            "__define_hashbrown_collections",
+            "HashBrownMap",
+            "HashBrownSet",
+        ),
+        ("__define_indexmap_collections", "IndexMap", "IndexSet"),
+        (
+            "__define_heapless_collections",
+            "HeaplessIndexMap",
+            "HeaplessIndexSet",
+        ),
+    ] {
+        let mac = format_ident!("{mac}");
+        let map = format_ident!("{prefix}{map}");
+        let set = format_ident!("{prefix}{set}");
+        quote! {
+            #root::#mac!(#vis #map, #set = #build_hasher);
+        }
+        .to_tokens(&mut output);
+    }
+    output.into()
 }
 
 #[proc_macro]
 pub fn impl_core_hash(input: TokenStream1) -> TokenStream1 {
-    let root = crate_root();
+    let input = parse_macro_input!(input as TypesWithGenerics);
+    let root = crate_root(input.crate_path.as_ref());
     let hash = quote!(#root::Hash);
 
-    let input = parse_macro_input!(input as IdentsWithGenerics);
     let mut output = TokenStream::new();
 
-    for IdentWithGenerics {
+    for TypeWithGenerics {
         impl_generics,
-        ident,
-        use_generics,
+        ty,
+        via,
         mut where_clause,
     } in input.punctuated
     {
+        if let Some(via) = via {
+            return via.unsupported().into();
+        }
         let where_ = fix_where(where_clause.as_mut());
         quote! {
-            impl #impl_generics ::core::hash::Hash for #ident #use_generics #where_ #where_clause
+            impl #impl_generics ::core::hash::Hash for #ty #where_ #where_clause
                 Self: #hash,
             {
                 #[inline]
@@ -176,24 +1003,44 @@ pub fn impl_core_hash(input: TokenStream1) -> TokenStream1 {
 
 #[proc_macro]
 pub fn impl_core_hasher(input: TokenStream1) -> TokenStream1 {
-    let root = crate_root();
+    let input = parse_macro_input!(input as TypesWithGenerics);
+    let root = crate_root(input.crate_path.as_ref());
     let hasher_t = quote!(#root::Hasher);
     let hasher_write = quote!(#root::HasherWrite);
 
-    let input = parse_macro_input!(input as IdentsWithGenerics);
     let mut output = TokenStream::new();
 
-    for IdentWithGenerics {
+    for TypeWithGenerics {
         impl_generics,
-        ident,
-        use_generics,
+        ty,
+        via,
         mut where_clause,
     } in input.punctuated
     {
+        let (hash_t, finish) = if let Some(Via {
+            hash_t, strategy, ..
+        }) = via
+        {
+            let strategy = strategy_path(&root, strategy);
+            (
+                hash_t.to_token_stream(),
+                quote! {
+                    <#strategy as #root::internal::Via<#hash_t>>::to_u64(
+                        <Self as #hasher_t::<#hash_t>>::finish(self)
+                    )
+                },
+            )
+        } else {
+            (
+                quote!(u64),
+                quote!(<Self as #hasher_t::<u64>>::finish(self)),
+            )
+        };
+
         let mut body = quote! {
             #[inline(always)]
             fn finish(&self) -> u64 {
-                <Self as #hasher_t::<u64>>::finish(self)
+                #finish
             }
 
             #[inline(always)]
@@ -228,8 +1075,8 @@ pub fn impl_core_hasher(input: TokenStream1) -> TokenStream1 {
 
         let where_ = fix_where(where_clause.as_mut());
         quote! {
-            impl #impl_generics ::core::hash::Hasher for #ident #use_generics #where_ #where_clause
-                Self: #hasher_t<u64>,
+            impl #impl_generics ::core::hash::Hasher for #ty #where_ #where_clause
+                Self: #hasher_t<#hash_t>,
             {
                 #body
             }
@@ -241,29 +1088,49 @@ pub fn impl_core_hasher(input: TokenStream1) -> TokenStream1 {
 
 #[proc_macro]
 pub fn impl_core_build_hasher(input: TokenStream1) -> TokenStream1 {
-    let root = crate_root();
+    let input = parse_macro_input!(input as TypesWithGenerics);
+    let root = crate_root(input.crate_path.as_ref());
     let build_hasher_t = quote!(#root::BuildHasher);
 
-    let input = parse_macro_input!(input as IdentsWithGenerics);
     let mut output = TokenStream::new();
 
-    for IdentWithGenerics {
+    for TypeWithGenerics {
         impl_generics,
-        ident,
-        use_generics,
+        ty,
+        via,
         mut where_clause,
     } in input.punctuated
     {
+        let (hash_t, wrapper) = if let Some(Via {
+            hash_t, strategy, ..
+        }) = via
+        {
+            let strategy = strategy_path(&root, strategy);
+            (
+                hash_t.to_token_stream(),
+                quote! {
+                    #root::internal::WrapHasherViaForCore<
+                        <Self as #build_hasher_t::<#hash_t>>::Hasher, #hash_t, #strategy
+                    >
+                },
+            )
+        } else {
+            (
+                quote!(u64),
+                quote!(#root::internal::WrapHasherU64ForCore<<Self as #build_hasher_t::<u64>>::Hasher>),
+            )
+        };
+
         let where_ = fix_where(where_clause.as_mut());
         quote! {
-            impl #impl_generics ::core::hash::BuildHasher for #ident #use_generics #where_ #where_clause
-                Self: #build_hasher_t<u64>,
+            impl #impl_generics ::core::hash::BuildHasher for #ty #where_ #where_clause
+                Self: #build_hasher_t<#hash_t>,
             {
-                type Hasher = #root::internal::WrapHasherU64ForCore<<Self as #build_hasher_t::<u64>>::Hasher>;
+                type Hasher = #wrapper;
 
                 #[inline]
                 fn build_hasher(&self) -> Self::Hasher {
-                    Self::Hasher::new(<Self as #build_hasher_t::<u64>>::build_hasher(self))
+                    Self::Hasher::new(<Self as #build_hasher_t::<#hash_t>>::build_hasher(self))
                 }
             }
         }
@@ -275,33 +1142,34 @@ pub fn impl_core_build_hasher(input: TokenStream1) -> TokenStream1 {
 #[proc_macro]
 #[allow(non_snake_case)]
 pub fn impl_hash(input: TokenStream1) -> TokenStream1 {
-    let root = crate_root();
+    let input = parse_macro_input!(input as TypesWithGenerics);
+    let root = crate_root(input.crate_path.as_ref());
     let hash = quote!(#root::Hash);
     let hasher_write = quote!(#root::HasherWrite);
 
-    let input = parse_macro_input!(input as IdentsWithGenerics);
     let mut output = TokenStream::new();
 
-    for IdentWithGenerics {
+    for TypeWithGenerics {
         impl_generics,
-        ident,
-        use_generics,
+        ty,
+        via,
         mut where_clause,
     } in input.punctuated
     {
+        if let Some(via) = via {
+            return via.unsupported().into();
+        }
         let SplitGenerics {
             lti,
-            ltt: _,
             tpi,
-            tpt: _,
             cpi,
-            cpt: _,
+            args: _,
             wc: _,
         } = split_generics(&impl_generics);
         let where_ = fix_where(where_clause.as_mut());
 
         quote! {
-            impl<#(#lti,)* #(#tpi,)* #(#cpi,)*> #hash for #ident #use_generics #where_ #where_clause {
+            impl<#(#lti,)* #(#tpi,)* #(#cpi,)*> #hash for #ty #where_ #where_clause {
                 #[inline]
                 fn hash<H: #hasher_write>(&self, state: &mut H) {
                     <Self as ::core::hash::Hash>::hash(
@@ -315,6 +1183,96 @@ pub fn impl_hash(input: TokenStream1) -> TokenStream1 {
     output.into()
 }
 
+// filters the field types that need a `Hash` bound in the derive. Only types that mention a type
+// parameter are bounded, except `PhantomData`, which is always `Hash`. Duplicates are removed.
+fn bounded_types(types: Vec<Type>, generics: &Generics) -> Vec<Type> {
+    let params: Vec<&Ident> = generics.type_params().map(|t| &t.ident).collect();
+    let mut seen = Vec::new();
+    types
+        .into_iter()
+        .filter(|ty| {
+            let tokens = ty.to_token_stream();
+            if is_phantom_data(ty) || !mentions_any(tokens.clone(), &params) {
+                return false;
+            }
+            let key = tokens.to_string();
+            if seen.contains(&key) {
+                false
+            } else {
+                seen.push(key);
+                true
+            }
+        })
+        .collect()
+}
+
+fn is_phantom_data(ty: &Type) -> bool {
+    match ty {
+        Type::Path(ty) => {
+            ty.qself.is_none()
+                && ty
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|s| s.ident == "PhantomData")
+        }
+        _ => false,
+    }
+}
+
+fn mentions_any(tokens: TokenStream, idents: &[&Ident]) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Ident(ident) => idents.iter().any(|i| **i == ident),
+        TokenTree::Group(group) => mentions_any(group.stream(), idents),
+        _ => false,
+    })
+}
+
+// gets the integer type from the `repr` attribute of an enum, or `isize` if there isn't one
+fn enum_repr(attrs: &[Attribute]) -> syn::Result<Ident> {
+    const INTS: [&str; 12] = [
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ];
+    let mut repr = None;
+    for attr in attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                if INTS.iter().any(|int| ident == int) {
+                    repr = Some(ident.clone());
+                }
+            }
+            // skip arguments like in `align(8)`
+            if meta.input.peek(syn::token::Paren) {
+                let content;
+                parenthesized!(content in meta.input);
+                content.parse::<TokenStream>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(repr.unwrap_or_else(|| format_ident!("isize")))
+}
+
+// checks if the `repr` attributes contain `name`, like `packed` or `transparent`
+fn has_repr(attrs: &[Attribute], name: &str) -> syn::Result<bool> {
+    let mut found = false;
+    for attr in attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(name) {
+                found = true;
+            }
+            // skip arguments like in `packed(2)`
+            if meta.input.peek(syn::token::Paren) {
+                let content;
+                parenthesized!(content in meta.input);
+                content.parse::<TokenStream>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(found)
+}
+
 fn fix_where(wc: Option<&mut WhereClause>) -> Option<Token![where]> {
     if let Some(wc) = wc {
         if wc.predicates.is_empty() {
@@ -330,73 +1288,85 @@ fn fix_where(wc: Option<&mut WhereClause>) -> Option<Token![where]> {
     }
 }
 
+// splits generics into the parameters for `impl<...>`, without defaults, and the arguments for
+// `Type<...>`, in the order they're declared in
 struct SplitGenerics<
     'a,
     LTI: Iterator<Item = &'a LifetimeParam>,
-    LTT: Iterator<Item = &'a Lifetime>,
-    TPI: Iterator<Item = &'a TypeParam>,
-    TPT: Iterator<Item = &'a Ident>,
-    CPI: Iterator<Item = &'a ConstParam>,
-    CPT: Iterator<Item = &'a Ident>,
+    TPI: Iterator<Item = TypeParam>,
+    CPI: Iterator<Item = ConstParam>,
+    ARGS: Iterator<Item = TokenStream>,
 > {
     lti: LTI,
-    ltt: LTT,
     tpi: TPI,
-    tpt: TPT,
     cpi: CPI,
-    cpt: CPT,
+    args: ARGS,
     wc: &'a Option<WhereClause>,
 }
 
 fn split_generics(
     generics: &Generics,
 ) -> SplitGenerics<
+    '_,
     impl Iterator<Item = &LifetimeParam>,
-    impl Iterator<Item = &Lifetime>,
-    impl Iterator<Item = &TypeParam>,
-    impl Iterator<Item = &Ident>,
-    impl Iterator<Item = &ConstParam>,
-    impl Iterator<Item = &Ident>,
+    impl Iterator<Item = TypeParam> + '_,
+    impl Iterator<Item = ConstParam> + '_,
+    impl Iterator<Item = TokenStream> + '_,
 > {
     SplitGenerics {
         lti: generics.lifetimes(),
-        ltt: generics.lifetimes().map(|l| &l.lifetime),
-        tpi: generics.type_params(),
-        tpt: generics.type_params().map(|t| &t.ident),
-        cpi: generics.const_params(),
-        cpt: generics.const_params().map(|c| &c.ident),
+        tpi: generics.type_params().map(|t| TypeParam {
+            eq_token: None,
+            default: None,
+            ..t.clone()
+        }),
+        cpi: generics.const_params().map(|c| ConstParam {
+            eq_token: None,
+            default: None,
+            ..c.clone()
+        }),
+        args: generics.params.iter().map(|param| match param {
+            GenericParam::Lifetime(l) => l.lifetime.to_token_stream(),
+            GenericParam::Type(t) => t.ident.to_token_stream(),
+            GenericParam::Const(c) => c.ident.to_token_stream(),
+        }),
         wc: &generics.where_clause,
     }
 }
 
-struct IdentsWithGenerics {
-    punctuated: Punctuated<IdentWithGenerics, Token![;]>,
+struct TypesWithGenerics {
+    crate_path: Option<Path>,
+    punctuated: Punctuated<TypeWithGenerics, Token![;]>,
 }
 
-impl Parse for IdentsWithGenerics {
+impl Parse for TypesWithGenerics {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let crate_path = parse_crate_attrs(input)?;
         let punctuated = Punctuated::parse_terminated(input)?;
-        Ok(Self { punctuated })
+        Ok(Self {
+            crate_path,
+            punctuated,
+        })
     }
 }
 
-struct IdentWithGenerics {
+struct TypeWithGenerics {
     impl_generics: Generics,
-    ident: Ident,
-    use_generics: Option<GenericArguments>,
+    ty: Type,
+    via: Option<Via>,
     where_clause: Option<WhereClause>,
 }
 
-impl Parse for IdentWithGenerics {
+impl Parse for TypeWithGenerics {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let impl_generics = if Option::<Token![impl]>::parse(input)?.is_some() {
             Generics::parse(input)?
         } else {
             Generics::default()
         };
-        let ident = Ident::parse(input)?;
-        let use_generics = if input.peek(Token![<]) {
-            Some(GenericArguments::parse(input)?)
+        let ty = Type::parse(input)?;
+        let via = if input.peek(kw::via) {
+            Some(Via::parse(input)?)
         } else {
             None
         };
@@ -404,47 +1374,47 @@ impl Parse for IdentWithGenerics {
 
         Ok(Self {
             impl_generics,
-            ident,
-            use_generics,
+            ty,
+            via,
             where_clause,
         })
     }
 }
 
-struct GenericArguments {
-    lt_token: Token![<],
-    args: Punctuated<GenericArgument, Token![,]>,
-    rt_token: Token![>],
+struct Via {
+    via_token: kw::via,
+    hash_t: Type,
+    strategy: Option<Ident>,
 }
 
-impl Parse for GenericArguments {
-    fn parse(input: ParseStream) -> syn::Result<Self> {
-        let lt_token = <Token![<]>::parse(input)?;
-
-        let mut args = Punctuated::new();
-        while let Ok(arg) = GenericArgument::parse(input) {
-            args.push(arg);
-            if let Ok(comma) = <Token![,]>::parse(input) {
-                args.push_punct(comma);
-            } else {
-                break;
-            }
-        }
-
-        let rt_token = <Token![>]>::parse(input)?;
+impl Via {
+    fn unsupported(&self) -> TokenStream {
+        Error::new_spanned(
+            self.via_token,
+            "`via` is only supported by `impl_core_hasher!` and `impl_core_build_hasher!`",
+        )
+        .into_compile_error()
+    }
+}
 
+impl Parse for Via {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let via_token = kw::via::parse(input)?;
+        let hash_t = Type::parse(input)?;
+        let strategy = if input.peek(syn::Ident) {
+            Some(Ident::parse(input)?)
+        } else {
+            None
+        };
         Ok(Self {
-            lt_token,
-            args,
-            rt_token,
+            via_token,
+            hash_t,
+            strategy,
         })
     }
 }
 
-impl ToTokens for GenericArguments {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        self.lt_token.to_tokens(tokens);
-        self.args.to_tokens(tokens);
-        self.rt_token.to_tokens(tokens);
-    }
+fn strategy_path(root: &TokenStream, strategy: Option<Ident>) -> TokenStream {
+    let strategy = strategy.unwrap_or_else(|| format_ident!("default"));
+    quote!(#root::internal::via::#strategy)
 }
@@ -0,0 +1,226 @@
+//! Hasher using the Adler-32 checksum algorithm.
+
+// based on rfc 1950 and the combine function from zlib
+
+use crate::{
+    BuildHasher, BuildHasherDefault, Checksum, EndianIndependentAlgorithm, Hasher, HasherWrite,
+    SeedableBuildHasher,
+};
+
+const BASE: u32 = 65521;
+
+// largest n such that 255n(n+1)/2 + (n+1)(BASE-1) fits in a u32
+const NMAX: usize = 5552;
+
+/// [`BuildHasher`] implementation for the [`Adler32`] hasher.
+/// If you don't need support for using custom seeds, use the zero sized
+/// [`Adler32BuildHasherDefault`] instead.
+#[derive(Clone, Debug)]
+pub struct Adler32BuildHasher(u32);
+
+impl Adler32BuildHasher {
+    /// Create a [`BuildHasher`] for [`Adler32`] using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(1)
+    }
+
+    /// Create a [`BuildHasher`] for [`Adler32`] with a custom seed.
+    /// See [`Adler32::with_seed`].
+    #[inline]
+    pub const fn with_seed(seed: u32) -> Self {
+        Self(seed)
+    }
+}
+
+impl BuildHasher<u32> for Adler32BuildHasher {
+    type Hasher = Adler32;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seed(self.0)
+    }
+}
+
+impl SeedableBuildHasher for Adler32BuildHasher {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seed(seed as u32)
+    }
+}
+
+impl Default for Adler32BuildHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// [`BuildHasher`] implementation for the [`Adler32`] hasher using the default seed (zero sized).
+pub type Adler32BuildHasherDefault = BuildHasherDefault<Adler32>;
+
+/// Hasher using the Adler-32 checksum algorithm.
+///
+/// Adler-32 is a fast checksum, but it's a poor hash function for short inputs,
+/// so this hasher doesn't have collection type aliases.
+#[derive(Clone, Debug)]
+pub struct Adler32 {
+    a: u32,
+    b: u32,
+}
+
+impl Adler32 {
+    /// Create a new `Adler32` hasher using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seed(1)
+    }
+
+    /// Create a new `Adler32` hasher with a custom `seed`.
+    ///
+    /// The seed is the checksum of any data that came before the data that will be written
+    /// to the hasher, so hashing can be resumed from a previously calculated checksum.
+    /// The default seed is `1`, which is the checksum of no data.
+    #[inline]
+    pub const fn with_seed(seed: u32) -> Self {
+        Self {
+            a: (seed & 0xffff) % BASE,
+            b: (seed >> 16) % BASE,
+        }
+    }
+}
+
+impl Default for Adler32 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EndianIndependentAlgorithm for Adler32 {}
+
+impl HasherWrite for Adler32 {
+    fn write(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(NMAX) {
+            for &byte in chunk {
+                self.a += byte as u32;
+                self.b += self.a;
+            }
+            self.a %= BASE;
+            self.b %= BASE;
+        }
+    }
+}
+
+impl Hasher<u32> for Adler32 {
+    #[inline]
+    fn finish(&self) -> u32 {
+        self.a | (self.b << 16)
+    }
+}
+
+impl Checksum<u32> for Adler32 {
+    fn combine(a: u32, b: u32, len_b: u64) -> u32 {
+        let rem = (len_b % BASE as u64) as u32;
+        let a1 = (a & 0xffff) % BASE;
+        let b1 = (a >> 16) % BASE;
+        let a2 = (b & 0xffff) % BASE;
+        let b2 = (b >> 16) % BASE;
+
+        // the 1 from the initial value of `a` is counted twice, so subtract it once
+        let sum_a = (a1 + a2 + BASE - 1) % BASE;
+        let sum_b = ((rem as u64 * a1 as u64 + b1 as u64 + b2 as u64 + (BASE - rem) as u64)
+            % BASE as u64) as u32;
+        sum_a | (sum_b << 16)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::RawBytes, Hash};
+
+    fn default_seed<T: Hash>(x: T) -> u32 {
+        Adler32BuildHasherDefault::new().hash_one(x)
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(default_seed(()), 1);
+    }
+
+    #[test]
+    fn long() {
+        assert_eq!(default_seed(RawBytes(&[b'a'; 10000])), 0x9fbbcde3);
+    }
+
+    #[test]
+    fn resume() {
+        let seed = default_seed(RawBytes(b"abcdefghijklm"));
+        assert_eq!(
+            Adler32BuildHasher::with_seed(seed).hash_one(RawBytes(b"nopqrstuvwxyz0123456789")),
+            default_seed(RawBytes(b"abcdefghijklmnopqrstuvwxyz0123456789"))
+        );
+    }
+
+    #[test]
+    fn combine() {
+        let data = [
+            b"abcdefghijklmnopqrstuvwxyz0123456789".as_slice(),
+            &[0xff; 10000],
+        ];
+        for data in data {
+            for i in (0..=data.len()).step_by(7) {
+                let (a, b) = data.split_at(i);
+                assert_eq!(
+                    Adler32::combine(
+                        default_seed(RawBytes(a)),
+                        default_seed(RawBytes(b)),
+                        b.len() as u64
+                    ),
+                    default_seed(RawBytes(data))
+                );
+            }
+        }
+    }
+
+    hash_test_vectors! {
+        [default_seed]
+        a: 0x620062,
+        ab: 0x12600c4,
+        abc: 0x24d0127,
+        abcd: 0x3d8018b,
+        abcde: 0x5c801f0,
+        abcdef: 0x81e0256,
+        abcdefg: 0xadb02bd,
+        abcdefgh: 0xe000325,
+        abcdefghi: 0x118e038e,
+        abcdefghij: 0x158603f8,
+        abcdefghijk: 0x19e90463,
+        abcdefghijkl: 0x1eb804cf,
+        abcdefghijklm: 0x23f4053c,
+        abcdefghijklmn: 0x299e05aa,
+        abcdefghijklmno: 0x2fb70619,
+        abcdefghijklmnop: 0x36400689,
+        abcdefghijklmnopq: 0x3d3a06fa,
+        abcdefghijklmnopqr: 0x44a6076c,
+        abcdefghijklmnopqrs: 0x4c8507df,
+        abcdefghijklmnopqrst: 0x54d80853,
+        abcdefghijklmnopqrstu: 0x5da008c8,
+        abcdefghijklmnopqrstuv: 0x66de093e,
+        abcdefghijklmnopqrstuvw: 0x709309b5,
+        abcdefghijklmnopqrstuvwx: 0x7ac00a2d,
+        abcdefghijklmnopqrstuvwxy: 0x85660aa6,
+        abcdefghijklmnopqrstuvwxyz: 0x90860b20,
+        abcdefghijklmnopqrstuvwxyz0: 0x9bd60b50,
+        abcdefghijklmnopqrstuvwxyz01: 0xa7570b81,
+        abcdefghijklmnopqrstuvwxyz012: 0xb30a0bb3,
+        abcdefghijklmnopqrstuvwxyz0123: 0xbef00be6,
+        abcdefghijklmnopqrstuvwxyz01234: 0xcb0a0c1a,
+        abcdefghijklmnopqrstuvwxyz012345: 0xd7590c4f,
+        abcdefghijklmnopqrstuvwxyz0123456: 0xe3de0c85,
+        abcdefghijklmnopqrstuvwxyz01234567: 0xf09a0cbc,
+        abcdefghijklmnopqrstuvwxyz012345678: 0xfd8e0cf4,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0xaca0d2d,
+    }
+}
@@ -0,0 +1,492 @@
+//! Hasher and collections using an AES based algorithm in the style of aHash.
+
+// inspired by the AES variant of aHash at https://github.com/tkaitchuck/aHash, but only uses
+// the AES encryption round so the software fallback gives the same hashes as the hardware
+
+use crate::{
+    aes_round::{dispatch, AesFn, AesRound},
+    impl_core_build_hasher, impl_core_hasher,
+    internal::fmix64,
+    random::ambient_seed,
+    BuildHasher, BuildHasherDefault, EndianIndependentAlgorithm, Hasher, HasherWrite,
+    SeedableBuildHasher,
+};
+
+impl_core_build_hasher!(AesBuildHasher);
+impl_core_hasher!(AesHasher);
+
+// digits of pi
+const KEYS: [u128; 2] = [
+    0x13198a2e03707344_243f6a8885a308d3,
+    0x082efa98ec4e6c89_a4093822299f31d0,
+];
+
+#[inline(always)]
+fn r2(bytes: &[u8], i: usize) -> u64 {
+    u16::from_le_bytes(bytes[i..i + 2].try_into().unwrap()) as u64
+}
+
+#[inline(always)]
+fn r4(bytes: &[u8], i: usize) -> u64 {
+    u32::from_le_bytes(bytes[i..i + 4].try_into().unwrap()) as u64
+}
+
+#[inline(always)]
+fn r8(bytes: &[u8], i: usize) -> u64 {
+    u64::from_le_bytes(bytes[i..i + 8].try_into().unwrap())
+}
+
+#[inline(always)]
+fn r16(bytes: &[u8], i: usize) -> u128 {
+    u128::from_le_bytes(bytes[i..i + 16].try_into().unwrap())
+}
+
+#[inline(always)]
+const fn add_by_64s(a: u128, b: u128) -> u128 {
+    let lo = (a as u64).wrapping_add(b as u64);
+    let hi = ((a >> 64) as u64).wrapping_add((b >> 64) as u64);
+    lo as u128 | (hi as u128) << 64
+}
+
+#[inline(always)]
+const fn shuffle_and_add(base: u128, value: u128) -> u128 {
+    // move the bytes with the most entropy from the multiplications in aesenc to where
+    // they'll affect the most bits in the addition
+    add_by_64s(base.swap_bytes(), value)
+}
+
+/// [`BuildHasher`] implementation for the [`AesHasher`] hasher.
+///
+/// [`AesBuildHasher::new`] and `Default` use random keys, so each `AesBuildHasher` calculates
+/// different hashes, which gives some resistance against HashDoS attacks. The keys are seeded
+/// with [`ambient_seed`]. If you need the same hashes every time, use
+/// [`AesBuildHasher::with_seed`], [`AesBuildHasher::with_keys`] or the zero sized
+/// [`AesBuildHasherDefault`] instead.
+#[derive(Clone, Debug)]
+pub struct AesBuildHasher([u128; 2]);
+
+impl AesBuildHasher {
+    /// Create a [`BuildHasher`] for [`AesHasher`] using random keys.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_seed(ambient_seed())
+    }
+
+    /// Create a [`BuildHasher`] for [`AesHasher`] with keys derived from `seed`.
+    #[inline]
+    pub const fn with_seed(seed: u128) -> Self {
+        let lo = fmix64(seed as u64 ^ KEYS[1] as u64);
+        let hi = fmix64((seed >> 64) as u64 ^ lo);
+        Self::with_keys(seed, lo as u128 | (hi as u128) << 64)
+    }
+
+    /// Create a [`BuildHasher`] for [`AesHasher`] with custom keys.
+    #[inline]
+    pub const fn with_keys(key1: u128, key2: u128) -> Self {
+        Self([key1, key2])
+    }
+}
+
+impl BuildHasher<u64> for AesBuildHasher {
+    type Hasher = AesHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_keys(self.0[0], self.0[1])
+    }
+}
+
+impl SeedableBuildHasher for AesBuildHasher {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seed(seed)
+    }
+}
+
+impl Default for AesBuildHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for AesBuildHasher {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for AesBuildHasher {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for AesBuildHasher {}
+
+/// [`BuildHasher`] implementation for the [`AesHasher`] hasher using the default keys (zero sized).
+pub type AesBuildHasherDefault = BuildHasherDefault<AesHasher>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`AesHasher`] hasher with random keys.
+pub type AesHashMap<K, V> = std::collections::HashMap<K, V, AesBuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`AesHasher`] hasher with the default keys.
+pub type AesHashMapDefault<K, V> = std::collections::HashMap<K, V, AesBuildHasherDefault>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`AesHasher`] hasher with random keys.
+pub type AesHashSet<T> = std::collections::HashSet<T, AesBuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`AesHasher`] hasher with the default keys.
+pub type AesHashSetDefault<T> = std::collections::HashSet<T, AesBuildHasherDefault>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`AesHasher`] hasher with random keys.
+pub type AesHeaplessIndexMap<K, V, const N: usize> = heapless::IndexMap<K, V, AesBuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`AesHasher`] hasher with the default keys.
+pub type AesHeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, AesBuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`AesHasher`] hasher with random keys.
+pub type AesHeaplessIndexSet<T, const N: usize> = heapless::IndexSet<T, AesBuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`AesHasher`] hasher with the default keys.
+pub type AesHeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, AesBuildHasherDefault, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`AesHasher`] hasher with random keys.
+pub type AesHashBrownMap<K, V> = hashbrown::HashMap<K, V, AesBuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`AesHasher`] hasher with the default keys.
+pub type AesHashBrownMapDefault<K, V> = hashbrown::HashMap<K, V, AesBuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`AesHasher`] hasher with random keys.
+pub type AesHashBrownSet<T> = hashbrown::HashSet<T, AesBuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`AesHasher`] hasher with the default keys.
+pub type AesHashBrownSetDefault<T> = hashbrown::HashSet<T, AesBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`AesHasher`] hasher with random keys.
+pub type AesIndexMap<K, V> = indexmap::IndexMap<K, V, AesBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`AesHasher`] hasher with the default keys.
+pub type AesIndexMapDefault<K, V> = indexmap::IndexMap<K, V, AesBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`AesHasher`] hasher with random keys.
+pub type AesIndexSet<T> = indexmap::IndexSet<T, AesBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`AesHasher`] hasher with the default keys.
+pub type AesIndexSetDefault<T> = indexmap::IndexSet<T, AesBuildHasherDefault>;
+
+/// Hasher using an AES based algorithm in the style of aHash.
+///
+/// This uses AES-NI on x86 and the crypto extensions on ARMv8 when they're available. With
+/// the `std` feature, support is detected at runtime; otherwise the hardware is only used if
+/// the `aes` target feature is enabled at compile time. Without hardware support, a portable
+/// implementation of the AES round that calculates the same hashes is used.
+///
+/// Like aHash, each call to `write` is processed separately, so the hash of a byte stream
+/// depends on how it was split up into writes. This is not a cryptographic hash.
+#[derive(Clone)]
+pub struct AesHasher {
+    enc: u128,
+    sum: u128,
+    key: u128,
+}
+
+impl AesHasher {
+    /// Create a new `AesHasher` using the default keys.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_keys(0, 0)
+    }
+
+    /// Create a new `AesHasher` with custom keys.
+    #[inline]
+    pub const fn with_keys(key1: u128, key2: u128) -> Self {
+        let key1 = key1 ^ KEYS[0];
+        let key2 = key2 ^ KEYS[1];
+        Self {
+            enc: key1,
+            sum: key2,
+            key: key1 ^ key2,
+        }
+    }
+
+    #[inline(always)]
+    fn hash_in<A: AesRound>(&mut self, value: u128) {
+        self.enc = A::aesenc(self.enc, value);
+        self.sum = shuffle_and_add(self.sum, value);
+    }
+
+    #[inline(always)]
+    fn write_with<A: AesRound>(&mut self, bytes: &[u8]) {
+        let len = bytes.len();
+        self.enc = add_by_64s(self.enc, len as u128);
+
+        if len <= 8 {
+            let value = if len >= 4 {
+                r4(bytes, 0) as u128 | (r4(bytes, len - 4) as u128) << 64
+            } else if len >= 2 {
+                r2(bytes, 0) as u128 | (bytes[len - 1] as u128) << 64
+            } else if len != 0 {
+                bytes[0] as u128 | (bytes[0] as u128) << 64
+            } else {
+                0
+            };
+            self.hash_in::<A>(value);
+        } else if len <= 16 {
+            self.hash_in::<A>(r8(bytes, 0) as u128 | (r8(bytes, len - 8) as u128) << 64);
+        } else if len <= 32 {
+            self.hash_in::<A>(r16(bytes, 0));
+            self.hash_in::<A>(r16(bytes, len - 16));
+        } else if len <= 64 {
+            self.hash_in::<A>(r16(bytes, 0));
+            self.hash_in::<A>(r16(bytes, 16));
+            self.hash_in::<A>(r16(bytes, len - 32));
+            self.hash_in::<A>(r16(bytes, len - 16));
+        } else {
+            // the last 64 bytes seed the lanes, and the loop covers the rest of the data
+            let tail: [u128; 4] = core::array::from_fn(|i| r16(bytes, len - 64 + i * 16));
+            let mut current = tail.map(|t| A::aesenc(self.key, t));
+            let mut sum = [
+                add_by_64s(self.key, tail[0]),
+                add_by_64s(!self.key, tail[1]),
+            ];
+            sum[0] = shuffle_and_add(sum[0], tail[2]);
+            sum[1] = shuffle_and_add(sum[1], tail[3]);
+
+            let mut data = bytes;
+            while data.len() > 64 {
+                let block: [u128; 4] = core::array::from_fn(|i| r16(data, i * 16));
+                for (current, block) in current.iter_mut().zip(block) {
+                    *current = A::aesenc(*current, block);
+                }
+                sum[0] = shuffle_and_add(sum[0], block[0]);
+                sum[1] = shuffle_and_add(sum[1], block[1]);
+                sum[0] = shuffle_and_add(sum[0], block[2]);
+                sum[1] = shuffle_and_add(sum[1], block[3]);
+                data = &data[64..];
+            }
+
+            for value in current.into_iter().chain(sum) {
+                self.hash_in::<A>(value);
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn finish_with<A: AesRound>(&self) -> u64 {
+        let combined = A::aesenc(self.sum, self.enc);
+        A::aesenc(A::aesenc(combined, self.key), combined) as u64
+    }
+}
+
+impl EndianIndependentAlgorithm for AesHasher {}
+
+impl Default for AesHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for AesHasher {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.enc.zeroize();
+        self.sum.zeroize();
+        self.key.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for AesHasher {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for AesHasher {}
+
+impl HasherWrite for AesHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        struct Write<'a>(&'a mut AesHasher, &'a [u8]);
+
+        impl AesFn for Write<'_> {
+            type Output = ();
+
+            #[inline(always)]
+            fn call<A: AesRound>(self) {
+                self.0.write_with::<A>(self.1)
+            }
+        }
+
+        dispatch(Write(self, bytes))
+    }
+}
+
+impl Hasher<u64> for AesHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        struct Finish<'a>(&'a AesHasher);
+
+        impl AesFn for Finish<'_> {
+            type Output = u64;
+
+            #[inline(always)]
+            fn call<A: AesRound>(self) -> u64 {
+                self.0.finish_with::<A>()
+            }
+        }
+
+        dispatch(Finish(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aes_round::Soft, tests::RawBytes, Hash};
+
+    fn default_keys<T: Hash>(x: T) -> u64 {
+        AesBuildHasherDefault::new().hash_one(x)
+    }
+
+    fn data() -> [u8; 300] {
+        let mut data = [0; 300];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = (i * 7 + 1) as u8;
+        }
+        data
+    }
+
+    fn hash_soft(hasher: &mut AesHasher, bytes: &[u8]) -> u64 {
+        hasher.write_with::<Soft>(bytes);
+        hasher.finish_with::<Soft>()
+    }
+
+    #[test]
+    fn lengths() {
+        let data = data();
+        let bh = AesBuildHasher::with_seed(0x1234);
+        for (len, expected) in [
+            (0, 0xbe755ecf0eaa3cb9),
+            (1, 0x99131e838cf4ec68),
+            (2, 0xf11ac2b33f8f4118),
+            (3, 0x9db9c38c32b27a27),
+            (4, 0xca5c191c8c4ab24f),
+            (7, 0x2a58bc6055d4b114),
+            (8, 0xabc9644ede68dc66),
+            (9, 0x4d6812690db7c4a1),
+            (16, 0xb00111e6d57f6880),
+            (17, 0x72802948d9230fb8),
+            (32, 0x8c279c65c49ca497),
+            (33, 0x12cd34e4148340ec),
+            (64, 0x8d9ec6a18fbabcf5),
+            (65, 0x910887d1717bc676),
+            (128, 0x2866b9203c0f3dac),
+            (129, 0xd40006e8468c8fcc),
+            (300, 0xb4919259d01e002f),
+        ] {
+            let mut hasher = bh.build_hasher();
+            hasher.write(&data[..len]);
+            assert_eq!(hasher.finish(), expected, "len {len}");
+            assert_eq!(hash_soft(&mut bh.build_hasher(), &data[..len]), expected);
+        }
+    }
+
+    #[test]
+    fn software_fallback() {
+        let data = data();
+        for len in 0..=data.len() {
+            let mut hasher = AesHasher::with_keys(len as u128, !(len as u128));
+            let mut soft = hasher.clone();
+            hasher.write(&data[..len]);
+            hasher.write(&data[len / 2..]);
+            soft.write_with::<Soft>(&data[..len]);
+            soft.write_with::<Soft>(&data[len / 2..]);
+            assert_eq!(hasher.finish(), soft.finish_with::<Soft>(), "len {len}");
+        }
+    }
+
+    #[test]
+    fn keys() {
+        let a: u64 = AesBuildHasher::with_seed(1).hash_one(RawBytes(b"a"));
+        assert_ne!(a, AesBuildHasher::with_seed(2).hash_one(RawBytes(b"a")));
+        assert_ne!(
+            a,
+            AesBuildHasher::with_seed(1 << 64).hash_one(RawBytes(b"a"))
+        );
+        assert_ne!(a, default_keys(RawBytes(b"a")));
+
+        let random = AesBuildHasher::new();
+        let b: u64 = random.hash_one(RawBytes(b"a"));
+        assert_eq!(b, random.clone().hash_one(RawBytes(b"a")));
+        assert_ne!(b, AesBuildHasher::new().hash_one(RawBytes(b"a")));
+    }
+
+    hash_test_vectors! {
+        [default_keys]
+        a: 0xaf425fed5e0a8626,
+        ab: 0xfa787da117a8548c,
+        abc: 0xe7111bf35bf0ba14,
+        abcd: 0xf9c6ce7fe9adac1a,
+        abcde: 0x3ecb43ffc30105fc,
+        abcdef: 0x8d74815ccd204a8b,
+        abcdefg: 0x686d542990489513,
+        abcdefgh: 0xe81d63c1fadccac8,
+        abcdefghi: 0x65897bc923bbe8ad,
+        abcdefghij: 0xbabfec1a40a6526e,
+        abcdefghijk: 0x8b79af5a9b859fcb,
+        abcdefghijkl: 0x5cd23797654d57bc,
+        abcdefghijklm: 0x4488dcb2391d8fde,
+        abcdefghijklmn: 0x25762a5b748d800a,
+        abcdefghijklmno: 0xbbce11f431636687,
+        abcdefghijklmnop: 0x193b7bb2ce63b52f,
+        abcdefghijklmnopq: 0x17529edf0a60a6ce,
+        abcdefghijklmnopqr: 0x3719bc87c5d8e5cc,
+        abcdefghijklmnopqrs: 0xa4af684739dfcfd6,
+        abcdefghijklmnopqrst: 0x933492463487f8f1,
+        abcdefghijklmnopqrstu: 0x4cc4568a29df6104,
+        abcdefghijklmnopqrstuv: 0xe2f57076eca4292d,
+        abcdefghijklmnopqrstuvw: 0x99e9a059ce034572,
+        abcdefghijklmnopqrstuvwx: 0xf1df827c206970e4,
+        abcdefghijklmnopqrstuvwxy: 0x64a4d0b441b0606f,
+        abcdefghijklmnopqrstuvwxyz: 0x7b4aaa49b9352ce0,
+        abcdefghijklmnopqrstuvwxyz0: 0x154118e514b4543e,
+        abcdefghijklmnopqrstuvwxyz01: 0x68cbbec87d60b04e,
+        abcdefghijklmnopqrstuvwxyz012: 0x2112f9abfbb7f770,
+        abcdefghijklmnopqrstuvwxyz0123: 0x261d2d164d6c6c24,
+        abcdefghijklmnopqrstuvwxyz01234: 0x5b78908f586e5038,
+        abcdefghijklmnopqrstuvwxyz012345: 0x9acb94266c7cbdbf,
+        abcdefghijklmnopqrstuvwxyz0123456: 0xbfa09a86d1534da3,
+        abcdefghijklmnopqrstuvwxyz01234567: 0xa2ad73d67175f42a,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x8824399b5277a47b,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x538b7da354d033f6,
+    }
+}
@@ -0,0 +1,277 @@
+//! Shared implementation of the AES round function for the AES based hashers.
+//!
+//! The hardware implementations are used when the cpu supports them, and give the same
+//! results as the portable implementation.
+
+#[rustfmt::skip]
+const SBOX: [u8; 256] = [
+    0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
+    0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
+    0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
+    0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a, 0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
+    0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
+    0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
+    0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
+    0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
+    0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
+    0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
+    0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c, 0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
+    0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
+    0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
+    0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
+    0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
+    0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
+];
+
+/// Single rounds of AES encryption.
+pub(crate) trait AesRound {
+    /// A middle round, like the `aesenc` instruction on x86.
+    fn aesenc(state: u128, key: u128) -> u128;
+
+    /// The last round without `MixColumns`, like the `aesenclast` instruction on x86.
+    #[cfg(feature = "gxhash")]
+    fn aesenclast(state: u128, key: u128) -> u128;
+}
+
+/// Portable implementation of the AES rounds.
+pub(crate) struct Soft;
+
+impl AesRound for Soft {
+    #[inline(always)]
+    fn aesenc(state: u128, key: u128) -> u128 {
+        #[inline(always)]
+        const fn xtime(x: u8) -> u8 {
+            (x << 1) ^ ((x >> 7) * 0x1b)
+        }
+
+        let s = state.to_le_bytes();
+        let mut out = [0; 16];
+        for c in 0..4 {
+            // SubBytes and ShiftRows
+            let a: [u8; 4] = core::array::from_fn(|r| SBOX[s[r + 4 * ((c + r) % 4)] as usize]);
+            // MixColumns
+            let all = a[0] ^ a[1] ^ a[2] ^ a[3];
+            for r in 0..4 {
+                out[r + 4 * c] = a[r] ^ all ^ xtime(a[r] ^ a[(r + 1) % 4]);
+            }
+        }
+        u128::from_le_bytes(out) ^ key
+    }
+
+    #[cfg(feature = "gxhash")]
+    #[inline(always)]
+    fn aesenclast(state: u128, key: u128) -> u128 {
+        let s = state.to_le_bytes();
+        // SubBytes and ShiftRows
+        let out: [u8; 16] = core::array::from_fn(|i| {
+            let (r, c) = (i % 4, i / 4);
+            SBOX[s[r + 4 * ((c + r) % 4)] as usize]
+        });
+        u128::from_le_bytes(out) ^ key
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+/// AES rounds using AES-NI. Only use this from functions with the `aes` target feature.
+pub(crate) struct AesNi;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl AesRound for AesNi {
+    #[inline(always)]
+    fn aesenc(state: u128, key: u128) -> u128 {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::{__m128i, _mm_aesenc_si128};
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::{__m128i, _mm_aesenc_si128};
+        use core::mem::transmute;
+
+        #[inline]
+        #[target_feature(enable = "aes")]
+        unsafe fn aesenc(state: u128, key: u128) -> u128 {
+            // SAFETY: `__m128i` and `u128` are both 16 bytes of plain data
+            unsafe {
+                transmute::<__m128i, u128>(_mm_aesenc_si128(
+                    transmute::<u128, __m128i>(state),
+                    transmute::<u128, __m128i>(key),
+                ))
+            }
+        }
+
+        // SAFETY: this is only used from functions that enable the `aes` target feature
+        unsafe { aesenc(state, key) }
+    }
+
+    #[cfg(feature = "gxhash")]
+    #[inline(always)]
+    fn aesenclast(state: u128, key: u128) -> u128 {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::{__m128i, _mm_aesenclast_si128};
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::{__m128i, _mm_aesenclast_si128};
+        use core::mem::transmute;
+
+        #[inline]
+        #[target_feature(enable = "aes")]
+        unsafe fn aesenclast(state: u128, key: u128) -> u128 {
+            // SAFETY: `__m128i` and `u128` are both 16 bytes of plain data
+            unsafe {
+                transmute::<__m128i, u128>(_mm_aesenclast_si128(
+                    transmute::<u128, __m128i>(state),
+                    transmute::<u128, __m128i>(key),
+                ))
+            }
+        }
+
+        // SAFETY: this is only used from functions that enable the `aes` target feature
+        unsafe { aesenclast(state, key) }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_endian = "little"))]
+/// AES rounds using the ARMv8 crypto extensions. Only use this from functions with the `aes`
+/// target feature.
+pub(crate) struct Neon;
+
+#[cfg(all(target_arch = "aarch64", target_endian = "little"))]
+impl AesRound for Neon {
+    #[inline(always)]
+    fn aesenc(state: u128, key: u128) -> u128 {
+        use core::arch::aarch64::{uint8x16_t, vaeseq_u8, vaesmcq_u8, vdupq_n_u8};
+        use core::mem::transmute;
+
+        #[inline]
+        #[target_feature(enable = "aes")]
+        unsafe fn aesenc(state: u128, key: u128) -> u128 {
+            // aese adds the round key before SubBytes and ShiftRows, so use a zero key there
+            // and add the real key after MixColumns, like aesenc does
+            // SAFETY: `uint8x16_t` and `u128` are both 16 bytes of plain data
+            unsafe {
+                let state = vaesmcq_u8(vaeseq_u8(
+                    transmute::<u128, uint8x16_t>(state),
+                    vdupq_n_u8(0),
+                ));
+                transmute::<uint8x16_t, u128>(state) ^ key
+            }
+        }
+
+        // SAFETY: this is only used from functions that enable the `aes` target feature
+        unsafe { aesenc(state, key) }
+    }
+
+    #[cfg(feature = "gxhash")]
+    #[inline(always)]
+    fn aesenclast(state: u128, key: u128) -> u128 {
+        use core::arch::aarch64::{uint8x16_t, vaeseq_u8, vdupq_n_u8};
+        use core::mem::transmute;
+
+        #[inline]
+        #[target_feature(enable = "aes")]
+        unsafe fn aesenclast(state: u128, key: u128) -> u128 {
+            // SAFETY: `uint8x16_t` and `u128` are both 16 bytes of plain data
+            unsafe {
+                let state = vaeseq_u8(transmute::<u128, uint8x16_t>(state), vdupq_n_u8(0));
+                transmute::<uint8x16_t, u128>(state) ^ key
+            }
+        }
+
+        // SAFETY: this is only used from functions that enable the `aes` target feature
+        unsafe { aesenclast(state, key) }
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline(always)]
+fn has_aes() -> bool {
+    #[cfg(target_feature = "aes")]
+    return true;
+    #[cfg(all(not(target_feature = "aes"), feature = "std"))]
+    return std::is_x86_feature_detected!("aes");
+    #[cfg(all(not(target_feature = "aes"), not(feature = "std")))]
+    return false;
+}
+
+#[cfg(all(target_arch = "aarch64", target_endian = "little"))]
+#[inline(always)]
+fn has_aes() -> bool {
+    #[cfg(target_feature = "aes")]
+    return true;
+    #[cfg(all(not(target_feature = "aes"), feature = "std"))]
+    return std::arch::is_aarch64_feature_detected!("aes");
+    #[cfg(all(not(target_feature = "aes"), not(feature = "std")))]
+    return false;
+}
+
+/// Function that's generic over the implementation of the AES rounds. See [`dispatch`].
+pub(crate) trait AesFn {
+    type Output;
+
+    fn call<A: AesRound>(self) -> Self::Output;
+}
+
+/// Call `f` with the fastest implementation of the AES rounds supported by the cpu.
+#[inline(always)]
+pub(crate) fn dispatch<F: AesFn>(f: F) -> F::Output {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if has_aes() {
+        #[target_feature(enable = "aes")]
+        unsafe fn call<F: AesFn>(f: F) -> F::Output {
+            f.call::<AesNi>()
+        }
+        // SAFETY: the cpu supports aes
+        return unsafe { call(f) };
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_endian = "little"))]
+    if has_aes() {
+        #[target_feature(enable = "aes")]
+        unsafe fn call<F: AesFn>(f: F) -> F::Output {
+            f.call::<Neon>()
+        }
+        // SAFETY: the cpu supports aes
+        return unsafe { call(f) };
+    }
+
+    f.call::<Soft>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // example from Intel's AES-NI white paper
+    #[test]
+    fn aesenc() {
+        assert_eq!(
+            Soft::aesenc(
+                0x7b5b54657374566563746f725d53475d,
+                0x48692853686179295b477565726f6e5d
+            ),
+            0xa8311c2f9fdba3c58b104b58ded7e595
+        );
+    }
+
+    #[test]
+    fn hardware() {
+        struct Rounds(u128, u128);
+
+        impl AesFn for Rounds {
+            type Output = (u128, u128);
+
+            #[inline(always)]
+            fn call<A: AesRound>(self) -> (u128, u128) {
+                #[cfg(feature = "gxhash")]
+                let last = A::aesenclast(self.0, self.1);
+                #[cfg(not(feature = "gxhash"))]
+                let last = 0;
+                (A::aesenc(self.0, self.1), last)
+            }
+        }
+
+        let mut x = 0x0123456789abcdef_fedcba9876543210_u128;
+        for _ in 0..256 {
+            let key = x.rotate_left(47) ^ 0x5555;
+            assert_eq!(dispatch(Rounds(x, key)), Rounds(x, key).call::<Soft>());
+            x = Soft::aesenc(x, key);
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! Hasher and collections that pick a hashing algorithm based on the size of the input.
+
+use crate::{
+    impl_core_build_hasher, impl_core_hasher, xxh64::Xxh64, BuildHasher, BuildHasherDefault,
+    EndianIndependentAlgorithm, Hasher, HasherWrite, SeedableBuildHasher,
+};
+
+impl_core_build_hasher!(AutoBuildHasher);
+impl_core_hasher!(AutoHasher);
+
+/// Inputs up to this many bytes are hashed with the short input algorithm.
+const THRESHOLD: usize = 32;
+
+const P0: u64 = 0xa0761d6478bd642f;
+const P1: u64 = 0xe7037ed1a0b428db;
+const P2: u64 = 0x8ebc6af09c88c6e3;
+
+/// [`BuildHasher`] implementation for the [`AutoHasher`] hasher.
+/// If you don't need support for using custom seeds, use the zero sized
+/// [`AutoBuildHasherDefault`] instead.
+#[derive(Clone, Debug)]
+pub struct AutoBuildHasher(u64);
+
+impl AutoBuildHasher {
+    /// Create a [`BuildHasher`] for [`AutoHasher`] using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Create a [`BuildHasher`] for [`AutoHasher`] with a custom seed.
+    #[inline]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self(seed)
+    }
+}
+
+impl BuildHasher<u64> for AutoBuildHasher {
+    type Hasher = AutoHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seed(self.0)
+    }
+}
+
+impl SeedableBuildHasher for AutoBuildHasher {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seed(seed as u64)
+    }
+}
+
+impl Default for AutoBuildHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for AutoBuildHasher {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for AutoBuildHasher {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for AutoBuildHasher {}
+
+/// [`BuildHasher`] implementation for the [`AutoHasher`] hasher using the default seed (zero sized).
+pub type AutoBuildHasherDefault = BuildHasherDefault<AutoHasher>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`AutoHasher`] hasher.
+pub type AutoHashMap<K, V> = std::collections::HashMap<K, V, AutoBuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`AutoHasher`] hasher with the default seed.
+pub type AutoHashMapDefault<K, V> = std::collections::HashMap<K, V, AutoBuildHasherDefault>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`AutoHasher`] hasher.
+pub type AutoHashSet<T> = std::collections::HashSet<T, AutoBuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`AutoHasher`] hasher with the default seed.
+pub type AutoHashSetDefault<T> = std::collections::HashSet<T, AutoBuildHasherDefault>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`AutoHasher`] hasher.
+pub type AutoHeaplessIndexMap<K, V, const N: usize> = heapless::IndexMap<K, V, AutoBuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`AutoHasher`] hasher with the default seed.
+pub type AutoHeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, AutoBuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`AutoHasher`] hasher.
+pub type AutoHeaplessIndexSet<T, const N: usize> = heapless::IndexSet<T, AutoBuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`AutoHasher`] hasher with the default seed.
+pub type AutoHeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, AutoBuildHasherDefault, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`AutoHasher`] hasher.
+pub type AutoHashBrownMap<K, V> = hashbrown::HashMap<K, V, AutoBuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`AutoHasher`] hasher with the default seed.
+pub type AutoHashBrownMapDefault<K, V> = hashbrown::HashMap<K, V, AutoBuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`AutoHasher`] hasher.
+pub type AutoHashBrownSet<T> = hashbrown::HashSet<T, AutoBuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`AutoHasher`] hasher with the default seed.
+pub type AutoHashBrownSetDefault<T> = hashbrown::HashSet<T, AutoBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`AutoHasher`] hasher.
+pub type AutoIndexMap<K, V> = indexmap::IndexMap<K, V, AutoBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`AutoHasher`] hasher with the default seed.
+pub type AutoIndexMapDefault<K, V> = indexmap::IndexMap<K, V, AutoBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`AutoHasher`] hasher.
+pub type AutoIndexSet<T> = indexmap::IndexSet<T, AutoBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`AutoHasher`] hasher with the default seed.
+pub type AutoIndexSetDefault<T> = indexmap::IndexSet<T, AutoBuildHasherDefault>;
+
+/// Hasher that picks an algorithm based on the size of the input.
+///
+/// Up to 32 bytes of input are buffered and hashed with a fast multiply-and-fold mixer
+/// when the hash is finished. If more data is written, the hasher switches to streaming
+/// the data through [`Xxh64`], so long inputs hash the same as with `Xxh64` using the same
+/// seed. The choice only depends on the total length of the data, so the hash of a byte
+/// stream doesn't depend on how it was split up into writes.
+///
+/// This is useful for maps where most keys are small but some are large, where
+/// a single algorithm would either be slow for the small keys or weak for the large ones.
+#[derive(Clone)]
+pub struct AutoHasher {
+    seed: u64,
+    buffer: [u8; THRESHOLD],
+    buffer_len: usize,
+    bulk: Option<Xxh64>,
+}
+
+impl AutoHasher {
+    /// Create a new `AutoHasher` using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Create a new `AutoHasher` with a custom `seed`.
+    #[inline]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            buffer: [0; THRESHOLD],
+            buffer_len: 0,
+            bulk: None,
+        }
+    }
+
+    #[inline(always)]
+    const fn folded_multiply(a: u64, b: u64) -> u64 {
+        let r = a as u128 * b as u128;
+        r as u64 ^ (r >> 64) as u64
+    }
+
+    fn finish_short(&self) -> u64 {
+        let bytes = &self.buffer[..self.buffer_len];
+        let mut acc = self.seed ^ P0 ^ (self.buffer_len as u64).wrapping_mul(P1);
+        for chunk in bytes.chunks(8) {
+            let mut lane = [0; 8];
+            lane[..chunk.len()].copy_from_slice(chunk);
+            acc = Self::folded_multiply(acc ^ u64::from_le_bytes(lane), P1);
+        }
+        Self::folded_multiply(acc ^ P2, acc ^ P0)
+    }
+}
+
+impl EndianIndependentAlgorithm for AutoHasher {}
+
+impl Default for AutoHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for AutoHasher {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.seed.zeroize();
+        self.buffer.zeroize();
+        self.buffer_len.zeroize();
+        if let Some(bulk) = &mut self.bulk {
+            bulk.zeroize();
+        }
+        self.bulk = None;
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for AutoHasher {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for AutoHasher {}
+
+impl HasherWrite for AutoHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        if let Some(bulk) = &mut self.bulk {
+            bulk.write(bytes);
+        } else if self.buffer_len + bytes.len() <= THRESHOLD {
+            self.buffer[self.buffer_len..self.buffer_len + bytes.len()].copy_from_slice(bytes);
+            self.buffer_len += bytes.len();
+        } else {
+            let mut bulk = Xxh64::with_seed(self.seed);
+            bulk.write(&self.buffer[..self.buffer_len]);
+            bulk.write(bytes);
+            self.bulk = Some(bulk);
+        }
+    }
+}
+
+impl Hasher<u64> for AutoHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        if let Some(bulk) = &self.bulk {
+            bulk.finish()
+        } else {
+            self.finish_short()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::RawBytes, xxh64::Xxh64BuildHasher};
+
+    const DATA: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    fn hash_chunked(seed: u64, bytes: &[u8], chunk_size: usize) -> u64 {
+        let mut hasher = AutoHasher::with_seed(seed);
+        for chunk in bytes.chunks(chunk_size) {
+            hasher.write(chunk);
+        }
+        hasher.finish()
+    }
+
+    #[test]
+    fn split_independent() {
+        for len in 0..=DATA.len() {
+            let expected = hash_chunked(0, &DATA[..len], DATA.len());
+            for chunk_size in 1..8 {
+                assert_eq!(hash_chunked(0, &DATA[..len], chunk_size), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn short() {
+        let bh = AutoBuildHasher::with_seed(0x55555555_55555555);
+        let mut hashes = [0; THRESHOLD + 1];
+        for (len, hash) in hashes.iter_mut().enumerate() {
+            *hash = bh.hash_one(RawBytes(&DATA[..len]));
+        }
+        for (i, a) in hashes.iter().enumerate() {
+            for b in &hashes[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+        assert_ne!(
+            bh.hash_one(RawBytes(b"a")),
+            AutoBuildHasher::new().hash_one(RawBytes(b"a"))
+        );
+        assert_ne!(bh.hash_one(RawBytes(b"\0")), bh.hash_one(RawBytes(b"")));
+    }
+
+    #[test]
+    fn long() {
+        for seed in [0, 0x55555555_55555555] {
+            for len in THRESHOLD + 1..=DATA.len() {
+                assert_eq!(
+                    AutoBuildHasher::with_seed(seed).hash_one(RawBytes(&DATA[..len])),
+                    Xxh64BuildHasher::with_seed(seed).hash_one(RawBytes(&DATA[..len]))
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,304 @@
+//! Adapter for the BLAKE3 cryptographic hash function from the `blake3` crate.
+
+use crate::{
+    impl_core_build_hasher, impl_core_hasher, BuildHasher, BuildHasherDefault,
+    EndianIndependentAlgorithm, Hasher, HasherWrite, KeyedBuildHasher, SeedableBuildHasher,
+};
+
+impl_core_build_hasher!(Blake3BuildHasher);
+impl_core_hasher!(Blake3);
+
+/// [`BuildHasher`] implementation for the [`Blake3`] hasher.
+/// If you don't need support for using keys, use the zero sized
+/// [`Blake3BuildHasherDefault`] instead.
+#[derive(Clone, Debug)]
+pub struct Blake3BuildHasher(Option<[u8; 32]>);
+
+impl Blake3BuildHasher {
+    /// Create a [`BuildHasher`] for [`Blake3`] in the default hashing mode.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(None)
+    }
+
+    /// Create a [`BuildHasher`] for [`Blake3`] in the keyed hashing mode.
+    /// See [`Blake3::new_keyed`].
+    #[inline]
+    pub const fn with_key(key: &[u8; 32]) -> Self {
+        Self(Some(*key))
+    }
+}
+
+impl BuildHasher<[u8; 32]> for Blake3BuildHasher {
+    type Hasher = Blake3;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        match &self.0 {
+            Some(key) => Self::Hasher::new_keyed(key),
+            None => Self::Hasher::new(),
+        }
+    }
+}
+
+impl BuildHasher<u128> for Blake3BuildHasher {
+    type Hasher = Blake3;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        BuildHasher::<[u8; 32]>::build_hasher(self)
+    }
+}
+
+impl BuildHasher<u64> for Blake3BuildHasher {
+    type Hasher = Blake3;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        BuildHasher::<[u8; 32]>::build_hasher(self)
+    }
+}
+
+impl SeedableBuildHasher for Blake3BuildHasher {
+    /// Create a keyed [`BuildHasher`] using the little endian bytes of `seed`
+    /// followed by zeroes as the key.
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        let mut key = [0; 32];
+        key[..16].copy_from_slice(&seed.to_le_bytes());
+        Self::with_key(&key)
+    }
+}
+
+impl KeyedBuildHasher for Blake3BuildHasher {
+    const KEY_LEN: usize = 32;
+
+    /// Create a [`BuildHasher`] in the keyed hashing mode.
+    #[inline]
+    fn from_key(key: &[u8]) -> Self {
+        Self::with_key(key.try_into().expect("key must be 32 bytes"))
+    }
+}
+
+impl Default for Blake3BuildHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Blake3BuildHasher {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Blake3BuildHasher {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Blake3BuildHasher {}
+
+/// [`BuildHasher`] implementation for the [`Blake3`] hasher in the default hashing mode
+/// (zero sized).
+pub type Blake3BuildHasherDefault = BuildHasherDefault<Blake3>;
+
+/// Hasher using the BLAKE3 cryptographic hash function.
+///
+/// This implements [`Hasher<[u8; 32]>`](Hasher) for the full 256-bit hash, and [`Hasher<u128>`]
+/// and [`Hasher<u64>`] for the hash truncated to its first 16 or 8 bytes, read as little endian.
+///
+/// BLAKE3 is much slower than the non-cryptographic hashers, so this hasher doesn't
+/// have collection type aliases.
+#[derive(Clone, Debug)]
+pub struct Blake3(blake3::Hasher);
+
+impl Blake3 {
+    /// Create a new `Blake3` hasher in the default hashing mode.
+    #[inline]
+    pub fn new() -> Self {
+        Self(blake3::Hasher::new())
+    }
+
+    /// Create a new `Blake3` hasher in the keyed hashing mode, for use as a MAC or PRF.
+    #[inline]
+    pub fn new_keyed(key: &[u8; 32]) -> Self {
+        Self(blake3::Hasher::new_keyed(key))
+    }
+
+    /// Create a new `Blake3` hasher in the key derivation mode. The `context` string
+    /// should be hardcoded, globally unique and application specific.
+    #[inline]
+    pub fn new_derive_key(context: &str) -> Self {
+        Self(blake3::Hasher::new_derive_key(context))
+    }
+
+    /// Get a reference to the wrapped `blake3::Hasher`,
+    /// for example to get extended output.
+    #[inline]
+    pub fn inner(&self) -> &blake3::Hasher {
+        &self.0
+    }
+}
+
+impl From<blake3::Hasher> for Blake3 {
+    #[inline]
+    fn from(value: blake3::Hasher) -> Self {
+        Self(value)
+    }
+}
+
+impl EndianIndependentAlgorithm for Blake3 {}
+
+impl Default for Blake3 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Blake3 {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Blake3 {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Blake3 {}
+
+impl HasherWrite for Blake3 {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+}
+
+impl Hasher<[u8; 32]> for Blake3 {
+    #[inline]
+    fn finish(&self) -> [u8; 32] {
+        self.0.finalize().into()
+    }
+}
+
+impl Hasher<u128> for Blake3 {
+    #[inline]
+    fn finish(&self) -> u128 {
+        let hash: [u8; 32] = self.finish();
+        u128::from_le_bytes(hash[..16].try_into().unwrap())
+    }
+}
+
+impl Hasher<u64> for Blake3 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        let hash: [u8; 32] = self.finish();
+        u64::from_le_bytes(hash[..8].try_into().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::RawBytes, Hash};
+
+    fn default_mode<T: Hash>(x: T) -> u64 {
+        Blake3BuildHasherDefault::new().hash_one(x)
+    }
+
+    fn hex(s: &str) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    #[test]
+    fn reference() {
+        let hash: [u8; 32] = Blake3BuildHasher::new().hash_one(());
+        assert_eq!(
+            hash,
+            hex("af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262")
+        );
+
+        let hash: [u8; 32] = Blake3BuildHasher::new().hash_one(RawBytes(b"abc"));
+        assert_eq!(
+            hash,
+            hex("6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85")
+        );
+        let hash: u128 = Blake3BuildHasher::new().hash_one(RawBytes(b"abc"));
+        assert_eq!(hash, 0xb58d3a27753bb6ff33514638acb33764);
+
+        // from the official test vectors
+        let hash: [u8; 32] =
+            Blake3BuildHasher::with_key(b"whats the Elvish word for friend").hash_one(());
+        assert_eq!(
+            hash,
+            hex("92b2b75604ed3c761f9d6f62392c8a9227ad0ea3f09573e783f1498a4ed60d26")
+        );
+
+        let hash: [u8; 32] =
+            Blake3::new_derive_key("BLAKE3 2019-12-27 16:29:52 test vectors context").finish();
+        assert_eq!(
+            hash,
+            hex("2cc39783c223154fea8dfb7c1b1660f2ac2dcbd1c1de8277b0b0dd39b7e50d7d")
+        );
+    }
+
+    hash_test_vectors! {
+        [default_mode]
+        a: 0x53a469d9dd2f7617,
+        ab: 0x3fefaaa69999c92d,
+        abc: 0x33514638acb33764,
+        abcd: 0x841a5d8081989c8c,
+        abcde: 0x6dbbd95a3bc04806,
+        abcdef: 0x7ffd126707564bb3,
+        abcdefg: 0x5e7012db708dd1e2,
+        abcdefgh: 0x5965980ac32aaadd,
+        abcdefghi: 0x716e1e5667ad9e89,
+        abcdefghij: 0x4ad78f51cb2a0cd1,
+        abcdefghijk: 0x8bcbaab0bc936933,
+        abcdefghijkl: 0x7f95f9a12e544aa7,
+        abcdefghijklm: 0x2544013a9ddff7a1,
+        abcdefghijklmn: 0xfc8989f3cc9f977a,
+        abcdefghijklmno: 0x9ece0652611b50f3,
+        abcdefghijklmnop: 0x8bf4526d83439e00,
+        abcdefghijklmnopq: 0x6d41655712edc26,
+        abcdefghijklmnopqr: 0xed75f380d11732d1,
+        abcdefghijklmnopqrs: 0x6f1e9bde6ce85f24,
+        abcdefghijklmnopqrst: 0xe223d384ed4fc518,
+        abcdefghijklmnopqrstu: 0x46c41be95b684a61,
+        abcdefghijklmnopqrstuv: 0xc49312fe746f3722,
+        abcdefghijklmnopqrstuvw: 0xcbbc9cceccc165c9,
+        abcdefghijklmnopqrstuvwx: 0xd3ea93ab4236003a,
+        abcdefghijklmnopqrstuvwxy: 0x8ad5af5be071d9f7,
+        abcdefghijklmnopqrstuvwxyz: 0xb4cf4a89c8ee6824,
+        abcdefghijklmnopqrstuvwxyz0: 0xdb7bd2542cdec9d6,
+        abcdefghijklmnopqrstuvwxyz01: 0xc4ebc1b02f6f0c4d,
+        abcdefghijklmnopqrstuvwxyz012: 0x73b6f47a497ceb4c,
+        abcdefghijklmnopqrstuvwxyz0123: 0xc0b32e6ac94a4ced,
+        abcdefghijklmnopqrstuvwxyz01234: 0x560ddb641f16966a,
+        abcdefghijklmnopqrstuvwxyz012345: 0x5ccda925d65f5e35,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x21a14623b308de86,
+        abcdefghijklmnopqrstuvwxyz01234567: 0xcf822c9923cd75fa,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x65146d47d5fc962f,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0xfb3e5481782fb9b0,
+    }
+}
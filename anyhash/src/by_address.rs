@@ -0,0 +1,149 @@
+use core::{
+    cmp::Ordering,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{Hash, HasherWrite};
+
+/// Wrapper for pointers that hashes and compares them by the address they point to, instead of
+/// by the value.
+///
+/// This works with any pointer type that implements `Deref`, like references, `Box`, `Rc` and
+/// `Arc`. Two wrapped pointers are equal if they point to the same address. Metadata like the
+/// length of a slice isn't compared. The wrapper also implements `core::hash::Hash`, so it can
+/// be used as a key in collections from `std`.
+///
+/// ```
+/// # use anyhash::ByAddress;
+/// # use std::{collections::HashSet, rc::Rc};
+/// let a = Rc::new(1);
+/// let b = Rc::new(1);
+///
+/// let mut set = HashSet::new();
+/// set.insert(ByAddress(a.clone()));
+/// assert!(set.contains(&ByAddress(a)));
+/// assert!(!set.contains(&ByAddress(b)));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct ByAddress<T: ?Sized>(pub T);
+
+impl<T> ByAddress<T> {
+    /// Unwrap the wrapped pointer.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: ?Sized + Deref> ByAddress<T> {
+    /// Get the address the wrapped pointer points to.
+    #[inline]
+    pub fn addr(&self) -> *const () {
+        (&*self.0 as *const T::Target).cast()
+    }
+}
+
+impl<T: ?Sized + Deref> Hash for ByAddress<T> {
+    #[inline]
+    fn hash<H: HasherWrite>(&self, state: &mut H) {
+        self.addr().hash(state);
+    }
+}
+
+impl<T: ?Sized + Deref> core::hash::Hash for ByAddress<T> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::hash::Hash::hash(&self.addr(), state);
+    }
+}
+
+impl<T: ?Sized + Deref> PartialEq for ByAddress<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.addr() == other.addr()
+    }
+}
+
+impl<T: ?Sized + Deref> Eq for ByAddress<T> {}
+
+impl<T: ?Sized + Deref> PartialOrd for ByAddress<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: ?Sized + Deref> Ord for ByAddress<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.addr().cmp(&other.addr())
+    }
+}
+
+impl<T> From<T> for ByAddress<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: ?Sized> Deref for ByAddress<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> DerefMut for ByAddress<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Fnv(u64);
+
+    impl HasherWrite for Fnv {
+        fn write(&mut self, bytes: &[u8]) {
+            for &b in bytes {
+                self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3);
+            }
+        }
+    }
+
+    fn hash<T: Hash>(value: T) -> u64 {
+        let mut hasher = Fnv(0xcbf29ce484222325);
+        value.hash(&mut hasher);
+        hasher.0
+    }
+
+    #[test]
+    fn by_address() {
+        let values = [1_u32, 1];
+        let (a, b) = (&values[0], &values[1]);
+        assert_eq!(ByAddress(a), ByAddress(&values[0]));
+        assert_eq!(hash(ByAddress(a)), hash(ByAddress(&values[0])));
+        assert_ne!(ByAddress(a), ByAddress(b));
+        assert_ne!(hash(ByAddress(a)), hash(ByAddress(b)));
+        assert!(ByAddress(a) < ByAddress(b));
+        assert_eq!(ByAddress(&values[..1]), ByAddress(&values[..]));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn weak() {
+        use alloc::rc::Rc;
+
+        let a = Rc::new(1);
+        let b = Rc::new(1);
+        assert_eq!(hash(Rc::downgrade(&a)), hash(ByAddress(a.clone())));
+        assert_ne!(hash(Rc::downgrade(&a)), hash(Rc::downgrade(&b)));
+    }
+}
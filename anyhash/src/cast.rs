@@ -0,0 +1,328 @@
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+use crate::{
+    impl_core_build_hasher, impl_core_hasher, BuildHasher, EndianIndependentAlgorithm,
+    EndianIndependentWrites, Hasher, HasherWrite,
+};
+
+/// Method used by [`HasherCast`] to convert a hash to a different type.
+pub trait CastMethod<From, To> {
+    /// Convert the hash `value`.
+    fn cast(value: From) -> To;
+}
+
+/// [`CastMethod`] that truncates hashes to narrower types, keeping the low bits, and
+/// zero-extends hashes to wider types.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Resize;
+
+/// [`CastMethod`] that folds hashes to narrower types by xoring together all the parts of
+/// the narrower type's width, and zero-extends hashes to wider types.
+///
+/// Unlike [`Resize`], this uses all the bits of the hash when narrowing, which is better
+/// for hashers whose low bits are weak.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FoldXor;
+
+macro_rules! impl_cast_methods {
+    ($($from:ty => $($to:ty),*;)*) => { $($(
+        impl CastMethod<$from, $to> for Resize {
+            #[inline(always)]
+            fn cast(value: $from) -> $to {
+                value as $to
+            }
+        }
+
+        impl CastMethod<$from, $to> for FoldXor {
+            #[inline(always)]
+            fn cast(value: $from) -> $to {
+                if <$to>::BITS >= <$from>::BITS {
+                    return value as $to;
+                }
+                let mut value = value as u128;
+                let mut folded = 0;
+                while value != 0 {
+                    folded ^= value as $to;
+                    value = value.checked_shr(<$to>::BITS).unwrap_or(0);
+                }
+                folded
+            }
+        }
+    )*)* };
+}
+
+impl_cast_methods! {
+    u8 => u8, u16, u32, u64, u128;
+    u16 => u8, u16, u32, u64, u128;
+    u32 => u8, u16, u32, u64, u128;
+    u64 => u8, u16, u32, u64, u128;
+    u128 => u8, u16, u32, u64, u128;
+}
+
+type Marker<From, To, M> = PhantomData<fn() -> (From, To, M)>;
+
+macro_rules! impl_cast_writes {
+    ($($t:ty: $fn:ident),* $(,)?) => { $(
+        #[inline]
+        fn $fn(&mut self, i: $t) {
+            self.hasher.$fn(i);
+        }
+    )* };
+}
+
+/// Wrapper for a [`Hasher<From>`] that makes it a [`Hasher<To>`], converting the hash with
+/// the [`CastMethod`] `M`.
+///
+/// This makes it possible to use a hasher where a different hash type is expected, like using
+/// a hasher that only implements `Hasher<u128>` as a `Hasher<u64>`. All writes are forwarded
+/// to the wrapped hasher.
+///
+/// ```
+/// # use anyhash::{FoldXor, Hasher, HasherCast, HasherWrite};
+/// # #[derive(Default)]
+/// # struct Wide(u128);
+/// # impl HasherWrite for Wide {
+/// #     fn write(&mut self, _: &[u8]) {}
+/// #     fn write_u128(&mut self, i: u128) {
+/// #         self.0 = i;
+/// #     }
+/// # }
+/// # impl Hasher<u128> for Wide {
+/// #     fn finish(&self) -> u128 {
+/// #         self.0
+/// #     }
+/// # }
+/// // `Wide` only implements `Hasher<u128>`
+/// let mut hasher = HasherCast::<u128, u64, _, FoldXor>::new(Wide::default());
+/// hasher.write_u128(0x0123_4567_89ab_cdef_0000_0000_0000_0001);
+/// let hash: u64 = hasher.finish();
+/// assert_eq!(hash, 0x0123_4567_89ab_cdee);
+/// ```
+pub struct HasherCast<From, To, H, M = Resize> {
+    hasher: H,
+    _pd: Marker<From, To, M>,
+}
+
+impl_core_hasher!(
+    impl<From, To, H: Hasher<From>, M: CastMethod<From, To>> HasherCast<From, To, H, M>
+);
+
+impl<From, To, H, M> HasherCast<From, To, H, M> {
+    /// Create a new `HasherCast` wrapping `hasher`.
+    #[inline]
+    pub const fn new(hasher: H) -> Self {
+        Self {
+            hasher,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Get a reference to the wrapped hasher.
+    #[inline]
+    pub const fn get_ref(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Unwrap the wrapped hasher.
+    #[inline]
+    pub fn into_inner(self) -> H {
+        self.hasher
+    }
+}
+
+impl<From, To, H: Hasher<From>, M: CastMethod<From, To>> Hasher<To> for HasherCast<From, To, H, M> {
+    #[inline]
+    fn finish(&self) -> To {
+        M::cast(self.hasher.finish())
+    }
+
+    #[inline]
+    fn finish_once(self) -> To {
+        M::cast(self.hasher.finish_once())
+    }
+}
+
+impl<From, To, H: HasherWrite, M> HasherWrite for HasherCast<From, To, H, M> {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.hasher.write(bytes);
+    }
+
+    impl_cast_writes! {
+        u8: write_u8,
+        u16: write_u16,
+        u32: write_u32,
+        u64: write_u64,
+        u128: write_u128,
+        usize: write_usize,
+        i8: write_i8,
+        i16: write_i16,
+        i32: write_i32,
+        i64: write_i64,
+        i128: write_i128,
+        isize: write_isize,
+        f32: write_f32,
+        f64: write_f64,
+        &str: write_str,
+    }
+
+    #[inline]
+    fn write_length_prefix(&mut self, len: usize) {
+        self.hasher.write_length_prefix(len);
+    }
+}
+
+impl<From, To, H: EndianIndependentAlgorithm, M> EndianIndependentAlgorithm
+    for HasherCast<From, To, H, M>
+{
+}
+
+impl<From, To, H: EndianIndependentWrites, M> EndianIndependentWrites
+    for HasherCast<From, To, H, M>
+{
+}
+
+impl<From, To, H: Debug, M> Debug for HasherCast<From, To, H, M> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("HasherCast").field(&self.hasher).finish()
+    }
+}
+
+impl<From, To, H: Clone, M> Clone for HasherCast<From, To, H, M> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.hasher.clone())
+    }
+}
+
+impl<From, To, H: Default, M> Default for HasherCast<From, To, H, M> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(H::default())
+    }
+}
+
+/// `BuildHasher` for making [`HasherCast`] hashers, turning a [`BuildHasher<From>`] into a
+/// [`BuildHasher<To>`].
+pub struct BuildHasherCast<From, To, BH, M = Resize> {
+    build_hasher: BH,
+    _pd: Marker<From, To, M>,
+}
+
+impl_core_build_hasher!(
+    impl<From, To, BH: BuildHasher<From>, M: CastMethod<From, To>>
+        BuildHasherCast<From, To, BH, M>
+);
+
+impl<From, To, BH, M> BuildHasherCast<From, To, BH, M> {
+    /// Create a new `BuildHasherCast` wrapping `build_hasher`.
+    #[inline]
+    pub const fn new(build_hasher: BH) -> Self {
+        Self {
+            build_hasher,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Get a reference to the wrapped `BuildHasher`.
+    #[inline]
+    pub const fn get_ref(&self) -> &BH {
+        &self.build_hasher
+    }
+}
+
+impl<From, To, BH: BuildHasher<From>, M: CastMethod<From, To>> BuildHasher<To>
+    for BuildHasherCast<From, To, BH, M>
+{
+    type Hasher = HasherCast<From, To, BH::Hasher, M>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        HasherCast::new(self.build_hasher.build_hasher())
+    }
+}
+
+impl<From, To, BH: Debug, M> Debug for BuildHasherCast<From, To, BH, M> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BuildHasherCast")
+            .field(&self.build_hasher)
+            .finish()
+    }
+}
+
+impl<From, To, BH: Clone, M> Clone for BuildHasherCast<From, To, BH, M> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.build_hasher.clone())
+    }
+}
+
+impl<From, To, BH: Default, M> Default for BuildHasherCast<From, To, BH, M> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(BH::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BuildHasherDefault;
+
+    #[derive(Default)]
+    struct Last(u128);
+
+    impl HasherWrite for Last {
+        fn write(&mut self, _: &[u8]) {}
+
+        fn write_u128(&mut self, i: u128) {
+            self.0 = i;
+        }
+    }
+
+    impl Hasher<u128> for Last {
+        fn finish(&self) -> u128 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn methods() {
+        assert_eq!(
+            <Resize as CastMethod<u128, u64>>::cast(0x1_0000_0000_0000_0002),
+            2
+        );
+        assert_eq!(
+            <Resize as CastMethod<u32, u64>>::cast(u32::MAX),
+            u32::MAX as u64
+        );
+        assert_eq!(
+            <FoldXor as CastMethod<u128, u64>>::cast(0x3_0000_0000_0000_0005),
+            6
+        );
+        assert_eq!(
+            <FoldXor as CastMethod<u128, u32>>::cast(0x1_0000_0002_0000_0004_0000_0008),
+            15
+        );
+        assert_eq!(<FoldXor as CastMethod<u16, u8>>::cast(0xff0f), 0xf0);
+        assert_eq!(
+            <FoldXor as CastMethod<u32, u128>>::cast(u32::MAX),
+            u32::MAX as u128
+        );
+    }
+
+    #[test]
+    fn build_hasher() {
+        let bh = BuildHasherCast::<u128, u64, BuildHasherDefault<Last>, FoldXor>::default();
+        let hash: u64 = bh.hash_one(0x1_0000_0000_0000_0001_u128);
+        assert_eq!(hash, 0);
+        let bh = BuildHasherCast::<u128, u32, BuildHasherDefault<Last>>::default();
+        let hash: u32 = bh.hash_one(0x1_0000_0000_0000_0001_u128);
+        assert_eq!(hash, 1);
+    }
+}
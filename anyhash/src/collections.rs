@@ -0,0 +1,165 @@
+//! Helpers for hash collections that use custom build hashers.
+//!
+//! The `new` and `with_capacity` constructors of the collections in `std` are only available
+//! with the default hasher. [`HashMapExt`] and [`HashSetExt`] add them for collections with any
+//! build hasher that implements `Default`. Use
+//! [`define_hash_collections`](crate::define_hash_collections) to define type aliases for
+//! collections using a build hasher.
+
+/// Constructors for hash maps with build hashers that implement `Default`.
+///
+/// This is implemented for `HashMap` from `std` and `hashbrown`, and for `IndexMap`, if the
+/// corresponding features are enabled.
+pub trait HashMapExt {
+    /// Create an empty map.
+    fn new() -> Self;
+
+    /// Create an empty map with space for at least `capacity` elements.
+    fn with_capacity(capacity: usize) -> Self;
+}
+
+/// Constructors for hash sets with build hashers that implement `Default`.
+///
+/// This is implemented for `HashSet` from `std` and `hashbrown`, and for `IndexSet`, if the
+/// corresponding features are enabled.
+pub trait HashSetExt {
+    /// Create an empty set.
+    fn new() -> Self;
+
+    /// Create an empty set with space for at least `capacity` elements.
+    fn with_capacity(capacity: usize) -> Self;
+}
+
+macro_rules! impl_collection_ext {
+    ($($(#[$attr:meta])* $trait:ident for $t:ident<$($p:ident),*>;)*) => { $(
+        $(#[$attr])*
+        impl<$($p,)* S: Default> $trait for $t<$($p,)* S> {
+            #[inline]
+            fn new() -> Self {
+                Self::with_hasher(S::default())
+            }
+
+            #[inline]
+            fn with_capacity(capacity: usize) -> Self {
+                Self::with_capacity_and_hasher(capacity, S::default())
+            }
+        }
+    )* };
+}
+
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+impl_collection_ext! {
+    #[cfg(feature = "std")]
+    HashMapExt for HashMap<K, V>;
+    #[cfg(feature = "std")]
+    HashSetExt for HashSet<T>;
+}
+
+#[cfg(feature = "hashbrown")]
+mod hashbrown_impls {
+    use super::*;
+    use hashbrown::{HashMap, HashSet};
+
+    impl_collection_ext! {
+        HashMapExt for HashMap<K, V>;
+        HashSetExt for HashSet<T>;
+    }
+}
+
+#[cfg(feature = "indexmap")]
+mod indexmap_impls {
+    use super::*;
+    use indexmap::{IndexMap, IndexSet};
+
+    impl_collection_ext! {
+        HashMapExt for IndexMap<K, V>;
+        HashSetExt for IndexSet<T>;
+    }
+}
+
+// The macros below are used by `define_hash_collections!`. Each has a version that defines the
+// aliases if the feature is enabled, and one that does nothing if it isn't.
+
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_std_hash_collections {
+    ($vis:vis $map:ident, $set:ident = $bh:ty) => {
+        #[doc = concat!("`HashMap` from `std` using [`", stringify!($bh), "`].")]
+        $vis type $map<K, V> = $crate::internal::collections::StdHashMap<K, V, $bh>;
+
+        #[doc = concat!("`HashSet` from `std` using [`", stringify!($bh), "`].")]
+        $vis type $set<T> = $crate::internal::collections::StdHashSet<T, $bh>;
+    };
+}
+
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_std_hash_collections {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "hashbrown")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_hashbrown_collections {
+    ($vis:vis $map:ident, $set:ident = $bh:ty) => {
+        #[doc = concat!("`HashMap` from `hashbrown` using [`", stringify!($bh), "`].")]
+        $vis type $map<K, V> = $crate::internal::collections::hashbrown::HashMap<K, V, $bh>;
+
+        #[doc = concat!("`HashSet` from `hashbrown` using [`", stringify!($bh), "`].")]
+        $vis type $set<T> = $crate::internal::collections::hashbrown::HashSet<T, $bh>;
+    };
+}
+
+#[cfg(not(feature = "hashbrown"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_hashbrown_collections {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "indexmap")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_indexmap_collections {
+    ($vis:vis $map:ident, $set:ident = $bh:ty) => {
+        #[doc = concat!("`IndexMap` using [`", stringify!($bh), "`].")]
+        $vis type $map<K, V> = $crate::internal::collections::indexmap::IndexMap<K, V, $bh>;
+
+        #[doc = concat!("`IndexSet` using [`", stringify!($bh), "`].")]
+        $vis type $set<T> = $crate::internal::collections::indexmap::IndexSet<T, $bh>;
+    };
+}
+
+#[cfg(not(feature = "indexmap"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_indexmap_collections {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "heapless")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_heapless_collections {
+    ($vis:vis $map:ident, $set:ident = $bh:ty) => {
+        #[doc = concat!("`IndexMap` from `heapless` using [`", stringify!($bh), "`].")]
+        $vis type $map<K, V, const N: usize> =
+            $crate::internal::collections::heapless::IndexMap<K, V, $bh, N>;
+
+        #[doc = concat!("`IndexSet` from `heapless` using [`", stringify!($bh), "`].")]
+        $vis type $set<T, const N: usize> =
+            $crate::internal::collections::heapless::IndexSet<T, $bh, N>;
+    };
+}
+
+#[cfg(not(feature = "heapless"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_heapless_collections {
+    ($($tt:tt)*) => {};
+}
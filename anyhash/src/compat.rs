@@ -0,0 +1,247 @@
+//! Adapters between the hasher traits in this crate and the ones in `core::hash`.
+//!
+//! Most types can be bridged at definition time with [`impl_core_hash`](crate::impl_core_hash),
+//! [`impl_core_hasher`](crate::impl_core_hasher) and
+//! [`impl_core_build_hasher`](crate::impl_core_build_hasher). The wrappers in this module do the
+//! same for values, for use in generic code or with types from other crates.
+
+use core::fmt::{self, Debug};
+
+use crate::{
+    internal::{impl_hasher_core_fwd, impl_hasher_core_fwd_writes},
+    BuildHasher, Hasher, HasherWrite,
+};
+
+/// Wrapper that makes a `core::hash::Hasher` usable as a [`Hasher<u64>`].
+///
+/// All writes are forwarded to the wrapped hasher. This also works with a mutable reference to
+/// a hasher, e.g. to hash an anyhash [`Hash`](crate::Hash) type in an implementation of
+/// `core::hash::Hash`.
+///
+/// ```
+/// # use anyhash::{compat::CoreAsAnyHasher, Hash, Hasher};
+/// use std::collections::hash_map::DefaultHasher;
+///
+/// let mut hasher = CoreAsAnyHasher::new(DefaultHasher::new());
+/// "hello".hash(&mut hasher);
+/// let hash: u64 = hasher.finish();
+/// # let _ = hash;
+/// ```
+#[derive(Clone, Default)]
+#[repr(transparent)]
+pub struct CoreAsAnyHasher<H>(H);
+
+impl<H> CoreAsAnyHasher<H> {
+    /// Create a new `CoreAsAnyHasher` wrapping `hasher`.
+    #[inline(always)]
+    pub const fn new(hasher: H) -> Self {
+        Self(hasher)
+    }
+
+    /// Get a reference to the wrapped hasher.
+    #[inline]
+    pub const fn get_ref(&self) -> &H {
+        &self.0
+    }
+
+    /// Unwrap the wrapped hasher.
+    #[inline]
+    pub fn into_inner(self) -> H {
+        self.0
+    }
+}
+
+impl<H: core::hash::Hasher> Hasher<u64> for CoreAsAnyHasher<H> {
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        H::finish(&self.0)
+    }
+}
+
+impl<H: core::hash::Hasher> HasherWrite for CoreAsAnyHasher<H> {
+    impl_hasher_core_fwd!(&mut);
+}
+
+impl<H: Debug> Debug for CoreAsAnyHasher<H> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CoreAsAnyHasher").field(&self.0).finish()
+    }
+}
+
+/// Wrapper that makes a [`Hasher<u64>`] usable as a `core::hash::Hasher`.
+///
+/// All writes are forwarded to the wrapped hasher. This also works with a mutable reference to
+/// a hasher.
+///
+/// ```
+/// # use anyhash::{compat::AnyAsCoreHasher, Hasher, HasherWrite};
+/// # #[derive(Default)]
+/// # struct MyHasher(u64);
+/// # impl HasherWrite for MyHasher {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3); }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for MyHasher {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+/// use core::hash::Hash;
+///
+/// let mut hasher = AnyAsCoreHasher::new(MyHasher::default());
+/// "hello".hash(&mut hasher);
+/// let hash = core::hash::Hasher::finish(&hasher);
+/// # let _ = hash;
+/// ```
+#[derive(Clone, Default)]
+#[repr(transparent)]
+pub struct AnyAsCoreHasher<H>(H);
+
+impl<H> AnyAsCoreHasher<H> {
+    /// Create a new `AnyAsCoreHasher` wrapping `hasher`.
+    #[inline(always)]
+    pub const fn new(hasher: H) -> Self {
+        Self(hasher)
+    }
+
+    /// Get a reference to the wrapped hasher.
+    #[inline]
+    pub const fn get_ref(&self) -> &H {
+        &self.0
+    }
+
+    /// Unwrap the wrapped hasher.
+    #[inline]
+    pub fn into_inner(self) -> H {
+        self.0
+    }
+}
+
+impl<H: Hasher<u64>> core::hash::Hasher for AnyAsCoreHasher<H> {
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        H::finish(&self.0)
+    }
+
+    impl_hasher_core_fwd!(&mut);
+}
+
+impl<H: Debug> Debug for AnyAsCoreHasher<H> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AnyAsCoreHasher").field(&self.0).finish()
+    }
+}
+
+/// Wrapper that makes a `core::hash::BuildHasher` usable as a [`BuildHasher<u64>`].
+///
+/// The hashers it makes are wrapped in [`CoreAsAnyHasher`].
+///
+/// ```
+/// # use anyhash::{compat::FromCoreBuildHasher, BuildHasher};
+/// use std::collections::hash_map::RandomState;
+///
+/// let build_hasher = FromCoreBuildHasher::new(RandomState::new());
+/// let hash: u64 = build_hasher.hash_one("hello");
+/// # let _ = hash;
+/// ```
+#[derive(Clone, Default)]
+pub struct FromCoreBuildHasher<BH>(BH);
+
+impl<BH> FromCoreBuildHasher<BH> {
+    /// Create a new `FromCoreBuildHasher` wrapping `build_hasher`.
+    #[inline]
+    pub const fn new(build_hasher: BH) -> Self {
+        Self(build_hasher)
+    }
+
+    /// Get a reference to the wrapped `BuildHasher`.
+    #[inline]
+    pub const fn get_ref(&self) -> &BH {
+        &self.0
+    }
+
+    /// Unwrap the wrapped `BuildHasher`.
+    #[inline]
+    pub fn into_inner(self) -> BH {
+        self.0
+    }
+}
+
+impl<BH: core::hash::BuildHasher> BuildHasher<u64> for FromCoreBuildHasher<BH> {
+    type Hasher = CoreAsAnyHasher<BH::Hasher>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        CoreAsAnyHasher::new(self.0.build_hasher())
+    }
+}
+
+impl<BH: Debug> Debug for FromCoreBuildHasher<BH> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FromCoreBuildHasher").field(&self.0).finish()
+    }
+}
+
+/// Wrapper that makes a [`BuildHasher<u64>`] usable as a `core::hash::BuildHasher`, e.g. for
+/// collections from `std`.
+///
+/// The hashers it makes are wrapped in [`AnyAsCoreHasher`].
+///
+/// ```
+/// # use anyhash::{compat::IntoCoreBuildHasher, BuildHasherDefault, Hasher, HasherWrite};
+/// # #[derive(Default)]
+/// # struct MyHasher(u64);
+/// # impl HasherWrite for MyHasher {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3); }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for MyHasher {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+/// use std::collections::HashMap;
+///
+/// let build_hasher = IntoCoreBuildHasher::new(BuildHasherDefault::<MyHasher>::new());
+/// let mut map = HashMap::with_hasher(build_hasher);
+/// map.insert("key", 1);
+/// ```
+#[derive(Clone, Default)]
+pub struct IntoCoreBuildHasher<BH>(BH);
+
+impl<BH> IntoCoreBuildHasher<BH> {
+    /// Create a new `IntoCoreBuildHasher` wrapping `build_hasher`.
+    #[inline]
+    pub const fn new(build_hasher: BH) -> Self {
+        Self(build_hasher)
+    }
+
+    /// Get a reference to the wrapped `BuildHasher`.
+    #[inline]
+    pub const fn get_ref(&self) -> &BH {
+        &self.0
+    }
+
+    /// Unwrap the wrapped `BuildHasher`.
+    #[inline]
+    pub fn into_inner(self) -> BH {
+        self.0
+    }
+}
+
+impl<BH: BuildHasher<u64>> core::hash::BuildHasher for IntoCoreBuildHasher<BH> {
+    type Hasher = AnyAsCoreHasher<BH::Hasher>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        AnyAsCoreHasher::new(self.0.build_hasher())
+    }
+}
+
+impl<BH: Debug> Debug for IntoCoreBuildHasher<BH> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoCoreBuildHasher").field(&self.0).finish()
+    }
+}
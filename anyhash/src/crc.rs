@@ -0,0 +1,563 @@
+//! Generic CRC hasher that can be parameterized to calculate any CRC of up to 64 bits.
+
+// byte-wise table driven implementation, with the combine function adapted from zlib.
+// parameters and check values for the presets are from the catalogue of parametrised
+// CRC algorithms at https://reveng.sourceforge.io/crc-catalogue/
+
+use core::{fmt::Debug, marker::PhantomData};
+
+use crate::{
+    BuildHasher, BuildHasherDefault, Checksum, EndianIndependentAlgorithm, Hasher, HasherWrite,
+    SeedableBuildHasher,
+};
+
+/// Parameters for a CRC algorithm, used with the [`Crc`] hasher.
+///
+/// Implement this to define your own CRC, or use one of the presets in this module.
+/// The parameters have the same meaning as in the usual catalogues of CRC algorithms.
+///
+/// ```
+/// # use anyhash::{crc::{Crc, CrcAlgorithm}, Hasher, HasherWrite};
+/// #[derive(Clone, Copy, Debug, Default)]
+/// struct Crc16Genibus;
+///
+/// impl CrcAlgorithm for Crc16Genibus {
+///     type Output = u16;
+///     const WIDTH: u32 = 16;
+///     const POLY: u64 = 0x1021;
+///     const INIT: u64 = 0xffff;
+///     const REFIN: bool = false;
+///     const REFOUT: bool = false;
+///     const XOROUT: u64 = 0xffff;
+/// }
+///
+/// let mut hasher = Crc::<Crc16Genibus>::new();
+/// hasher.write(b"123456789");
+/// assert_eq!(hasher.finish(), 0xd64e);
+/// ```
+pub trait CrcAlgorithm {
+    /// Type of the checksum. It must have at least `WIDTH` bits.
+    type Output: Type;
+
+    /// Width of the CRC in bits, from 1 to 64.
+    const WIDTH: u32;
+
+    /// The generator polynomial without the implicit top bit, not reflected.
+    const POLY: u64;
+
+    /// Initial value of the register, not reflected.
+    const INIT: u64;
+
+    /// Whether the bits of each input byte are processed from the least significant bit first.
+    const REFIN: bool;
+
+    /// Whether the register is reflected before the final xor.
+    const REFOUT: bool;
+
+    /// Value to xor with the register to get the checksum.
+    const XOROUT: u64;
+}
+
+use sealed::Type;
+mod sealed {
+    /// Output type for CRC algorithms.
+    pub trait Type: Copy {
+        /// Number of bits in the type.
+        const BITS: u32;
+
+        /// Truncate from `u64`.
+        fn from_u64(x: u64) -> Self;
+
+        /// Convert to `u64`.
+        fn to_u64(self) -> u64;
+    }
+}
+
+macro_rules! impl_type {
+    ($($t:ty),*) => { $(
+        impl Type for $t {
+            const BITS: u32 = <$t>::BITS;
+
+            #[inline]
+            fn from_u64(x: u64) -> Self {
+                x as Self
+            }
+
+            #[inline]
+            fn to_u64(self) -> u64 {
+                self as u64
+            }
+        }
+    )* };
+}
+
+impl_type!(u8, u16, u32, u64);
+
+const fn make_table(width: u32, poly: u64, reflected: bool) -> [u64; 256] {
+    let mut table = [0; 256];
+    let rpoly = poly.reverse_bits() >> (64 - width);
+    let lpoly = poly << (64 - width);
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = if reflected {
+            i as u64
+        } else {
+            (i as u64) << 56
+        };
+        let mut j = 0;
+        while j < 8 {
+            crc = if reflected {
+                (crc >> 1) ^ if crc & 1 != 0 { rpoly } else { 0 }
+            } else {
+                (crc << 1) ^ if crc >> 63 != 0 { lpoly } else { 0 }
+            };
+            j += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+// The register is kept in the low bits when the input is reflected, and in the high bits
+// otherwise, so the same byte-wise update works for all widths. Arithmetic for combining
+// checksums uses the unreflected value in the low bits.
+trait Engine: CrcAlgorithm {
+    const MASK: u64;
+    const TABLE: [u64; 256];
+
+    #[inline]
+    fn reflect(x: u64) -> u64 {
+        x.reverse_bits() >> (64 - Self::WIDTH)
+    }
+
+    #[inline]
+    fn to_register(x: u64) -> u64 {
+        if Self::REFIN {
+            Self::reflect(x)
+        } else {
+            x << (64 - Self::WIDTH)
+        }
+    }
+
+    #[inline]
+    fn from_register(register: u64) -> u64 {
+        if Self::REFIN {
+            Self::reflect(register)
+        } else {
+            register >> (64 - Self::WIDTH)
+        }
+    }
+
+    #[inline]
+    fn to_output(x: u64) -> u64 {
+        let x = if Self::REFOUT { Self::reflect(x) } else { x };
+        x ^ (Self::XOROUT & Self::MASK)
+    }
+
+    #[inline]
+    fn from_output(x: u64) -> u64 {
+        let x = (x ^ Self::XOROUT) & Self::MASK;
+        if Self::REFOUT {
+            Self::reflect(x)
+        } else {
+            x
+        }
+    }
+
+    #[inline]
+    fn multiply_by_x(x: u64) -> u64 {
+        if (x >> (Self::WIDTH - 1)) & 1 != 0 {
+            ((x << 1) ^ Self::POLY) & Self::MASK
+        } else {
+            x << 1
+        }
+    }
+
+    // multiply a and b modulo the polynomial
+    #[inline]
+    fn multiply_mod(a: u64, b: u64) -> u64 {
+        let mut p = 0;
+        for i in (0..Self::WIDTH).rev() {
+            p = Self::multiply_by_x(p);
+            if (a >> i) & 1 != 0 {
+                p ^= b;
+            }
+        }
+        p
+    }
+
+    // x^(8n) modulo the polynomial
+    #[inline]
+    fn x8n_mod(mut n: u64) -> u64 {
+        let mut x2k = 1;
+        for _ in 0..8 {
+            x2k = Self::multiply_by_x(x2k);
+        }
+        let mut p = 1;
+        while n != 0 {
+            if n & 1 != 0 {
+                p = Self::multiply_mod(x2k, p);
+            }
+            n >>= 1;
+            x2k = Self::multiply_mod(x2k, x2k);
+        }
+        p
+    }
+}
+
+impl<A: CrcAlgorithm> Engine for A {
+    const MASK: u64 = {
+        assert!(
+            A::WIDTH > 0 && A::WIDTH <= A::Output::BITS,
+            "CRC width must be between 1 and the number of bits in the output type"
+        );
+        u64::MAX >> (64 - A::WIDTH)
+    };
+    const TABLE: [u64; 256] = make_table(A::WIDTH, A::POLY & A::MASK, A::REFIN);
+}
+
+/// [`BuildHasher`] implementation for the [`Crc`] hasher.
+/// If you don't need support for using custom seeds, use the zero sized
+/// [`CrcBuildHasherDefault`] instead.
+#[derive(Clone, Debug)]
+pub struct CrcBuildHasher<A: CrcAlgorithm>(Option<A::Output>, PhantomData<fn() -> A>);
+
+impl<A: CrcAlgorithm> CrcBuildHasher<A> {
+    /// Create a [`BuildHasher`] for [`Crc`] using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(None, PhantomData)
+    }
+
+    /// Create a [`BuildHasher`] for [`Crc`] with a custom seed.
+    /// See [`Crc::with_seed`].
+    #[inline]
+    pub const fn with_seed(seed: A::Output) -> Self {
+        Self(Some(seed), PhantomData)
+    }
+}
+
+impl<A: CrcAlgorithm> BuildHasher<A::Output> for CrcBuildHasher<A> {
+    type Hasher = Crc<A>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        match self.0 {
+            Some(seed) => Self::Hasher::with_seed(seed),
+            None => Self::Hasher::new(),
+        }
+    }
+}
+
+impl<A: CrcAlgorithm> SeedableBuildHasher for CrcBuildHasher<A> {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seed(A::Output::from_u64(seed as u64))
+    }
+}
+
+impl<A: CrcAlgorithm> Default for CrcBuildHasher<A> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// [`BuildHasher`] implementation for the [`Crc`] hasher using the default seed (zero sized).
+pub type CrcBuildHasherDefault<A> = BuildHasherDefault<Crc<A>>;
+
+/// Hasher using a CRC algorithm with the parameters from `A`.
+///
+/// See [`CrcAlgorithm`] for how to define an algorithm, or use one of the presets
+/// in this module.
+///
+/// CRCs are good at detecting errors, but they're poor hash functions,
+/// so this hasher doesn't have collection type aliases.
+#[derive(Clone, Debug)]
+pub struct Crc<A: CrcAlgorithm>(u64, PhantomData<fn() -> A>);
+
+impl<A: CrcAlgorithm> Crc<A> {
+    /// Create a new `Crc` hasher using the default seed.
+    #[inline]
+    pub fn new() -> Self {
+        Self(A::to_register(A::INIT & A::MASK), PhantomData)
+    }
+
+    /// Create a new `Crc` hasher with a custom `seed`.
+    ///
+    /// The seed is the checksum of any data that came before the data that will be written
+    /// to the hasher, so hashing can be resumed from a previously calculated checksum.
+    /// The default seed is the checksum of no data.
+    #[inline]
+    pub fn with_seed(seed: A::Output) -> Self {
+        Self(A::to_register(A::from_output(seed.to_u64())), PhantomData)
+    }
+}
+
+impl<A: CrcAlgorithm> Default for Crc<A> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: CrcAlgorithm> EndianIndependentAlgorithm for Crc<A> {}
+
+impl<A: CrcAlgorithm> HasherWrite for Crc<A> {
+    fn write(&mut self, bytes: &[u8]) {
+        let table = &A::TABLE;
+        let mut crc = self.0;
+        if A::REFIN {
+            for &byte in bytes {
+                crc = (crc >> 8) ^ table[(crc as u8 ^ byte) as usize];
+            }
+        } else {
+            for &byte in bytes {
+                crc = (crc << 8) ^ table[((crc >> 56) as u8 ^ byte) as usize];
+            }
+        }
+        self.0 = crc;
+    }
+}
+
+impl<A: CrcAlgorithm> Hasher<A::Output> for Crc<A> {
+    #[inline]
+    fn finish(&self) -> A::Output {
+        A::Output::from_u64(A::to_output(A::from_register(self.0)))
+    }
+}
+
+impl<A: CrcAlgorithm> Checksum<A::Output> for Crc<A> {
+    #[inline]
+    fn combine(a: A::Output, b: A::Output, len_b: u64) -> A::Output {
+        // the initial value is included in both checksums, so it has to be cancelled out once
+        let a = A::from_output(a.to_u64()) ^ (A::INIT & A::MASK);
+        let b = A::from_output(b.to_u64());
+        A::Output::from_u64(A::to_output(A::multiply_mod(a, A::x8n_mod(len_b)) ^ b))
+    }
+}
+
+macro_rules! presets {
+    ($(
+        $name:ident($catalogue_name:literal): $t:ty =
+            $width:literal, $poly:literal, $init:literal,
+            $refin:literal, $refout:literal, $xorout:literal;
+    )*) => { $(
+        #[doc = concat!("Parameters for the ", $catalogue_name, " algorithm.")]
+        #[derive(Clone, Copy, Debug, Default)]
+        pub struct $name;
+
+        impl CrcAlgorithm for $name {
+            type Output = $t;
+            const WIDTH: u32 = $width;
+            const POLY: u64 = $poly;
+            const INIT: u64 = $init;
+            const REFIN: bool = $refin;
+            const REFOUT: bool = $refout;
+            const XOROUT: u64 = $xorout;
+        }
+    )* };
+}
+
+presets! {
+    Crc5Usb("CRC-5/USB"): u8 = 5, 0x05, 0x1f, true, true, 0x1f;
+    Crc8MaximDow("CRC-8/MAXIM-DOW"): u8 = 8, 0x31, 0, true, true, 0;
+    Crc8Smbus("CRC-8/SMBUS"): u8 = 8, 0x07, 0, false, false, 0;
+    Crc16Arc("CRC-16/ARC"): u16 = 16, 0x8005, 0, true, true, 0;
+    Crc16Ibm3740("CRC-16/IBM-3740 (CRC-16/CCITT-FALSE)"): u16 =
+        16, 0x1021, 0xffff, false, false, 0;
+    Crc16Kermit("CRC-16/KERMIT"): u16 = 16, 0x1021, 0, true, true, 0;
+    Crc16Modbus("CRC-16/MODBUS"): u16 = 16, 0x8005, 0xffff, true, true, 0;
+    Crc16Usb("CRC-16/USB"): u16 = 16, 0x8005, 0xffff, true, true, 0xffff;
+    Crc16Xmodem("CRC-16/XMODEM"): u16 = 16, 0x1021, 0, false, false, 0;
+    Crc24Openpgp("CRC-24/OPENPGP"): u32 = 24, 0x864cfb, 0xb704ce, false, false, 0;
+    Crc32Bzip2("CRC-32/BZIP2"): u32 = 32, 0x04c11db7, 0xffffffff, false, false, 0xffffffff;
+    Crc32Iscsi("CRC-32/ISCSI (CRC-32C)"): u32 =
+        32, 0x1edc6f41, 0xffffffff, true, true, 0xffffffff;
+    Crc32IsoHdlc("CRC-32/ISO-HDLC (CRC-32)"): u32 =
+        32, 0x04c11db7, 0xffffffff, true, true, 0xffffffff;
+    Crc32Mpeg2("CRC-32/MPEG-2"): u32 = 32, 0x04c11db7, 0xffffffff, false, false, 0;
+    Crc64Ecma182("CRC-64/ECMA-182"): u64 = 64, 0x42f0e1eba9ea3693, 0, false, false, 0;
+    Crc64GoIso("CRC-64/GO-ISO"): u64 =
+        64, 0x1b, 0xffffffffffffffff, true, true, 0xffffffffffffffff;
+    Crc64Xz("CRC-64/XZ"): u64 =
+        64, 0x42f0e1eba9ea3693, 0xffffffffffffffff, true, true, 0xffffffffffffffff;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::RawBytes, Hash};
+
+    fn crc<A: CrcAlgorithm, T: Hash>(x: T) -> A::Output {
+        CrcBuildHasherDefault::<A>::new().hash_one(x)
+    }
+
+    fn xmodem<T: Hash>(x: T) -> u16 {
+        crc::<Crc16Xmodem, T>(x)
+    }
+
+    fn usb<T: Hash>(x: T) -> u8 {
+        crc::<Crc5Usb, T>(x)
+    }
+
+    #[test]
+    fn check() {
+        fn check<A: CrcAlgorithm>(check: A::Output)
+        where
+            A::Output: PartialEq + Debug,
+        {
+            assert_eq!(crc::<A, _>(RawBytes(b"123456789")), check);
+        }
+        check::<Crc5Usb>(0x19);
+        check::<Crc8MaximDow>(0xa1);
+        check::<Crc8Smbus>(0xf4);
+        check::<Crc16Arc>(0xbb3d);
+        check::<Crc16Ibm3740>(0x29b1);
+        check::<Crc16Kermit>(0x2189);
+        check::<Crc16Modbus>(0x4b37);
+        check::<Crc16Usb>(0xb4c8);
+        check::<Crc16Xmodem>(0x31c3);
+        check::<Crc24Openpgp>(0x21cf02);
+        check::<Crc32Bzip2>(0xfc891918);
+        check::<Crc32Iscsi>(0xe3069283);
+        check::<Crc32IsoHdlc>(0xcbf43926);
+        check::<Crc32Mpeg2>(0x0376e6e7);
+        check::<Crc64Ecma182>(0x6c40df5f0b497347);
+        check::<Crc64GoIso>(0xb90956c775a41001);
+        check::<Crc64Xz>(0x995dc9bbdf1939fa);
+    }
+
+    #[test]
+    fn resume() {
+        fn resume<A: CrcAlgorithm>()
+        where
+            A::Output: PartialEq + Debug,
+        {
+            let seed = crc::<A, _>(RawBytes(b"abcdefghijklm"));
+            assert_eq!(
+                CrcBuildHasher::<A>::with_seed(seed).hash_one(RawBytes(b"nopqrstuvwxyz0123456789")),
+                crc::<A, _>(RawBytes(b"abcdefghijklmnopqrstuvwxyz0123456789"))
+            );
+        }
+        resume::<Crc5Usb>();
+        resume::<Crc16Ibm3740>();
+        resume::<Crc24Openpgp>();
+        resume::<Crc32Mpeg2>();
+        resume::<Crc64Xz>();
+    }
+
+    #[test]
+    fn combine() {
+        fn combine<A: CrcAlgorithm>()
+        where
+            A::Output: PartialEq + Debug,
+        {
+            let data = [
+                b"abcdefghijklmnopqrstuvwxyz0123456789".as_slice(),
+                &[0xff; 1000],
+            ];
+            for data in data {
+                for i in (0..=data.len()).step_by(7) {
+                    let (a, b) = data.split_at(i);
+                    assert_eq!(
+                        Crc::<A>::combine(
+                            crc::<A, _>(RawBytes(a)),
+                            crc::<A, _>(RawBytes(b)),
+                            b.len() as u64
+                        ),
+                        crc::<A, _>(RawBytes(data))
+                    );
+                }
+            }
+        }
+        combine::<Crc5Usb>();
+        combine::<Crc8Smbus>();
+        combine::<Crc16Ibm3740>();
+        combine::<Crc16Usb>();
+        combine::<Crc24Openpgp>();
+        combine::<Crc32Mpeg2>();
+        combine::<Crc32IsoHdlc>();
+        combine::<Crc64Ecma182>();
+        combine::<Crc64Xz>();
+    }
+
+    hash_test_vectors! {
+        [xmodem]
+        a: 0x7c87,
+        ab: 0x74ff,
+        abc: 0x9dd6,
+        abcd: 0xa836,
+        abcde: 0x3ee1,
+        abcdef: 0x3afd,
+        abcdefg: 0x7658,
+        abcdefgh: 0xabff,
+        abcdefghi: 0x60e,
+        abcdefghij: 0xa32a,
+        abcdefghijk: 0x7244,
+        abcdefghijkl: 0xb7ff,
+        abcdefghijklm: 0x9537,
+        abcdefghijklmn: 0x6974,
+        abcdefghijklmno: 0x14c6,
+        abcdefghijklmnop: 0xea22,
+        abcdefghijklmnopq: 0x10d2,
+        abcdefghijklmnopqr: 0x9ee4,
+        abcdefghijklmnopqrs: 0xc883,
+        abcdefghijklmnopqrst: 0xe557,
+        abcdefghijklmnopqrstu: 0xd4b9,
+        abcdefghijklmnopqrstuv: 0x2ca8,
+        abcdefghijklmnopqrstuvw: 0x439e,
+        abcdefghijklmnopqrstuvwx: 0x1938,
+        abcdefghijklmnopqrstuvwxy: 0x54a6,
+        abcdefghijklmnopqrstuvwxyz: 0x63ac,
+        abcdefghijklmnopqrstuvwxyz0: 0xc696,
+        abcdefghijklmnopqrstuvwxyz01: 0x9f8,
+        abcdefghijklmnopqrstuvwxyz012: 0x7f38,
+        abcdefghijklmnopqrstuvwxyz0123: 0xb148,
+        abcdefghijklmnopqrstuvwxyz01234: 0x892d,
+        abcdefghijklmnopqrstuvwxyz012345: 0x4b57,
+        abcdefghijklmnopqrstuvwxyz0123456: 0xf83a,
+        abcdefghijklmnopqrstuvwxyz01234567: 0x12a3,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x2628,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0xcbde,
+
+        [usb]
+        a: 0x0,
+        ab: 0x12,
+        abc: 0x16,
+        abcd: 0x4,
+        abcde: 0x0,
+        abcdef: 0x3,
+        abcdefg: 0x1f,
+        abcdefgh: 0x1b,
+        abcdefghi: 0x4,
+        abcdefghij: 0x8,
+        abcdefghijk: 0x1c,
+        abcdefghijkl: 0x18,
+        abcdefghijklm: 0x7,
+        abcdefghijklmn: 0xb,
+        abcdefghijklmno: 0x1f,
+        abcdefghijklmnop: 0x6,
+        abcdefghijklmnopq: 0x1b,
+        abcdefghijklmnopqr: 0xb,
+        abcdefghijklmnopqrs: 0x13,
+        abcdefghijklmnopqrst: 0xd,
+        abcdefghijklmnopqrstu: 0x13,
+        abcdefghijklmnopqrstuv: 0x11,
+        abcdefghijklmnopqrstuvw: 0x3,
+        abcdefghijklmnopqrstuvwx: 0x1,
+        abcdefghijklmnopqrstuvwxy: 0x13,
+        abcdefghijklmnopqrstuvwxyz: 0xb,
+        abcdefghijklmnopqrstuvwxyz0: 0xb,
+        abcdefghijklmnopqrstuvwxyz01: 0x5,
+        abcdefghijklmnopqrstuvwxyz012: 0x11,
+        abcdefghijklmnopqrstuvwxyz0123: 0x18,
+        abcdefghijklmnopqrstuvwxyz01234: 0x1e,
+        abcdefghijklmnopqrstuvwxyz012345: 0x1d,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x1d,
+        abcdefghijklmnopqrstuvwxyz01234567: 0x13,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x1d,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x15,
+    }
+}
@@ -0,0 +1,380 @@
+//! Hashers using the CRC-32 and CRC-32C checksum algorithms.
+
+// table driven implementation using slicing-by-8, with the combine function from zlib
+
+use core::{fmt::Debug, marker::PhantomData};
+
+use crate::{
+    BuildHasher, BuildHasherDefault, Checksum, EndianIndependentAlgorithm, Hasher, HasherWrite,
+    SeedableBuildHasher,
+};
+
+const fn make_table(poly: u32) -> [[u32; 256]; 8] {
+    let mut table = [[0; 256]; 8];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut j = 0;
+        while j < 8 {
+            crc = (crc >> 1) ^ if crc & 1 != 0 { poly } else { 0 };
+            j += 1;
+        }
+        table[0][i] = crc;
+        i += 1;
+    }
+    let mut k = 1;
+    while k < 8 {
+        let mut i = 0;
+        while i < 256 {
+            let crc = table[k - 1][i];
+            table[k][i] = (crc >> 8) ^ table[0][crc as usize & 0xff];
+            i += 1;
+        }
+        k += 1;
+    }
+    table
+}
+
+// multiply a and b modulo the polynomial, where the lowest power of x is in the top bit
+#[inline]
+fn multiply_mod<P: Polynomial>(a: u32, mut b: u32) -> u32 {
+    let mut m = 1 << 31;
+    let mut p = 0;
+    loop {
+        if a & m != 0 {
+            p ^= b;
+            if a & (m - 1) == 0 {
+                return p;
+            }
+        }
+        m >>= 1;
+        b = (b >> 1) ^ if b & 1 != 0 { P::POLY } else { 0 };
+    }
+}
+
+// x^(8n) modulo the polynomial
+#[inline]
+fn x8n_mod<P: Polynomial>(mut n: u64) -> u32 {
+    // x^8
+    let mut x2k = 1 << 23;
+    let mut p = 1 << 31;
+    while n != 0 {
+        if n & 1 != 0 {
+            p = multiply_mod::<P>(x2k, p);
+        }
+        n >>= 1;
+        x2k = multiply_mod::<P>(x2k, x2k);
+    }
+    p
+}
+
+use sealed::Polynomial;
+mod sealed {
+    /// Polynomial trait for CRC-32.
+    pub trait Polynomial: Clone + core::fmt::Debug + Default {
+        /// The reflected polynomial.
+        const POLY: u32;
+
+        /// Lookup tables for the polynomial.
+        const TABLE: [[u32; 256]; 8] = super::make_table(Self::POLY);
+    }
+}
+
+/// Selector for the IEEE polynomial used by CRC-32, as in zip, gzip and png.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Ieee;
+
+/// Selector for the Castagnoli polynomial used by CRC-32C, as in iSCSI, ext4 and btrfs.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Castagnoli;
+
+impl Polynomial for Ieee {
+    const POLY: u32 = 0xedb88320;
+}
+
+impl Polynomial for Castagnoli {
+    const POLY: u32 = 0x82f63b78;
+}
+
+/// [`BuildHasher`] implementation for the [`Crc32`] hasher.
+/// If you don't need support for using custom seeds, use the zero sized
+/// [`Crc32BuildHasherDefault`] instead.
+#[derive(Clone, Debug)]
+pub struct Crc32BuildHasher<P: Polynomial = Ieee>(u32, PhantomData<fn() -> P>);
+
+/// [`BuildHasher`] implementation for the [`Crc32c`] hasher.
+/// If you don't need support for using custom seeds, use the zero sized
+/// [`Crc32cBuildHasherDefault`] instead.
+pub type Crc32cBuildHasher = Crc32BuildHasher<Castagnoli>;
+
+impl<P: Polynomial> Crc32BuildHasher<P> {
+    /// Create a [`BuildHasher`] for [`Crc32`] using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Create a [`BuildHasher`] for [`Crc32`] with a custom seed.
+    /// See [`Crc32::with_seed`].
+    #[inline]
+    pub const fn with_seed(seed: u32) -> Self {
+        Self(seed, PhantomData)
+    }
+}
+
+impl<P: Polynomial> BuildHasher<u32> for Crc32BuildHasher<P> {
+    type Hasher = Crc32<P>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seed(self.0)
+    }
+}
+
+impl<P: Polynomial> SeedableBuildHasher for Crc32BuildHasher<P> {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seed(seed as u32)
+    }
+}
+
+impl<P: Polynomial> Default for Crc32BuildHasher<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// [`BuildHasher`] implementation for the [`Crc32`] hasher using the default seed (zero sized).
+pub type Crc32BuildHasherDefault<P = Ieee> = BuildHasherDefault<Crc32<P>>;
+
+/// [`BuildHasher`] implementation for the [`Crc32c`] hasher using the default seed (zero sized).
+pub type Crc32cBuildHasherDefault = BuildHasherDefault<Crc32c>;
+
+/// Hasher using the CRC-32C checksum algorithm.
+pub type Crc32c = Crc32<Castagnoli>;
+
+/// Hasher using the CRC-32 checksum algorithm.
+///
+/// The polynomial is selected with the `P` parameter, which is either [`Ieee`] for
+/// CRC-32 (the default) or [`Castagnoli`] for CRC-32C.
+///
+/// CRCs are good at detecting errors, but they're poor hash functions,
+/// so this hasher doesn't have collection type aliases.
+#[derive(Clone, Debug)]
+pub struct Crc32<P: Polynomial = Ieee>(u32, PhantomData<fn() -> P>);
+
+impl<P: Polynomial> Crc32<P> {
+    /// Create a new `Crc32` hasher using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Create a new `Crc32` hasher with a custom `seed`.
+    ///
+    /// The seed is the checksum of any data that came before the data that will be written
+    /// to the hasher, so hashing can be resumed from a previously calculated checksum.
+    /// The default seed is `0`, which is the checksum of no data.
+    #[inline]
+    pub const fn with_seed(seed: u32) -> Self {
+        Self(!seed, PhantomData)
+    }
+}
+
+impl<P: Polynomial> Default for Crc32<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Polynomial> EndianIndependentAlgorithm for Crc32<P> {}
+
+impl<P: Polynomial> HasherWrite for Crc32<P> {
+    fn write(&mut self, bytes: &[u8]) {
+        let table = &P::TABLE;
+        let mut crc = self.0;
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            let lo = u32::from_le_bytes(chunk[..4].try_into().unwrap()) ^ crc;
+            let hi = u32::from_le_bytes(chunk[4..].try_into().unwrap());
+            crc = table[7][lo as usize & 0xff]
+                ^ table[6][(lo >> 8) as usize & 0xff]
+                ^ table[5][(lo >> 16) as usize & 0xff]
+                ^ table[4][(lo >> 24) as usize]
+                ^ table[3][hi as usize & 0xff]
+                ^ table[2][(hi >> 8) as usize & 0xff]
+                ^ table[1][(hi >> 16) as usize & 0xff]
+                ^ table[0][(hi >> 24) as usize];
+        }
+        for &byte in chunks.remainder() {
+            crc = (crc >> 8) ^ table[0][(crc as u8 ^ byte) as usize];
+        }
+        self.0 = crc;
+    }
+}
+
+impl<P: Polynomial> Hasher<u32> for Crc32<P> {
+    #[inline]
+    fn finish(&self) -> u32 {
+        !self.0
+    }
+}
+
+impl<P: Polynomial> Checksum<u32> for Crc32<P> {
+    #[inline]
+    fn combine(a: u32, b: u32, len_b: u64) -> u32 {
+        multiply_mod::<P>(x8n_mod::<P>(len_b), a) ^ b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::RawBytes, Hash};
+
+    fn ieee<T: Hash>(x: T) -> u32 {
+        Crc32BuildHasherDefault::<Ieee>::new().hash_one(x)
+    }
+
+    fn castagnoli<T: Hash>(x: T) -> u32 {
+        Crc32cBuildHasherDefault::new().hash_one(x)
+    }
+
+    #[test]
+    fn check() {
+        assert_eq!(ieee(()), 0);
+        assert_eq!(castagnoli(()), 0);
+        assert_eq!(ieee(RawBytes(b"123456789")), 0xcbf43926);
+        assert_eq!(castagnoli(RawBytes(b"123456789")), 0xe3069283);
+    }
+
+    #[test]
+    fn long() {
+        let mut ieee = Crc32::<Ieee>::new();
+        let mut castagnoli = Crc32c::new();
+        ieee.write(&[0xff; 10000]);
+        castagnoli.write(&[0xff; 10000]);
+        assert_eq!(ieee.finish(), 0x133c790d);
+        assert_eq!(castagnoli.finish(), 0x31548795);
+    }
+
+    #[test]
+    fn resume() {
+        let seed = ieee(RawBytes(b"abcdefghijklm"));
+        assert_eq!(
+            Crc32BuildHasher::<Ieee>::with_seed(seed)
+                .hash_one(RawBytes(b"nopqrstuvwxyz0123456789")),
+            ieee(RawBytes(b"abcdefghijklmnopqrstuvwxyz0123456789"))
+        );
+        let seed = castagnoli(RawBytes(b"abcdefghijklm"));
+        assert_eq!(
+            Crc32cBuildHasher::with_seed(seed).hash_one(RawBytes(b"nopqrstuvwxyz0123456789")),
+            castagnoli(RawBytes(b"abcdefghijklmnopqrstuvwxyz0123456789"))
+        );
+    }
+
+    #[test]
+    fn combine() {
+        let data = [
+            b"abcdefghijklmnopqrstuvwxyz0123456789".as_slice(),
+            &[0xff; 10000],
+        ];
+        for data in data {
+            for i in (0..=data.len()).step_by(7) {
+                let (a, b) = data.split_at(i);
+                assert_eq!(
+                    Crc32::<Ieee>::combine(ieee(RawBytes(a)), ieee(RawBytes(b)), b.len() as u64),
+                    ieee(RawBytes(data))
+                );
+                assert_eq!(
+                    Crc32c::combine(
+                        castagnoli(RawBytes(a)),
+                        castagnoli(RawBytes(b)),
+                        b.len() as u64
+                    ),
+                    castagnoli(RawBytes(data))
+                );
+            }
+        }
+    }
+
+    hash_test_vectors! {
+        [ieee]
+        a: 0xe8b7be43,
+        ab: 0x9e83486d,
+        abc: 0x352441c2,
+        abcd: 0xed82cd11,
+        abcde: 0x8587d865,
+        abcdef: 0x4b8e39ef,
+        abcdefg: 0x312a6aa6,
+        abcdefgh: 0xaeef2a50,
+        abcdefghi: 0x8da988af,
+        abcdefghij: 0x3981703a,
+        abcdefghijk: 0xce570f9f,
+        abcdefghijkl: 0xf6781b24,
+        abcdefghijklm: 0xddf46ea2,
+        abcdefghijklmn: 0x400d9578,
+        abcdefghijklmno: 0x519167df,
+        abcdefghijklmnop: 0x943ac093,
+        abcdefghijklmnopq: 0x9c925619,
+        abcdefghijklmnopqr: 0x8fec50b,
+        abcdefghijklmnopqrs: 0x8cd4e846,
+        abcdefghijklmnopqrst: 0x1a596ae5,
+        abcdefghijklmnopqrstu: 0x221725a3,
+        abcdefghijklmnopqrstuv: 0x2499def3,
+        abcdefghijklmnopqrstuvw: 0x38f3316a,
+        abcdefghijklmnopqrstuvwx: 0x21836df4,
+        abcdefghijklmnopqrstuvwxy: 0x412a937d,
+        abcdefghijklmnopqrstuvwxyz: 0x4c2750bd,
+        abcdefghijklmnopqrstuvwxyz0: 0x41473740,
+        abcdefghijklmnopqrstuvwxyz01: 0xf541e910,
+        abcdefghijklmnopqrstuvwxyz012: 0x797ef80,
+        abcdefghijklmnopqrstuvwxyz0123: 0x806d9a54,
+        abcdefghijklmnopqrstuvwxyz01234: 0x9f30e34f,
+        abcdefghijklmnopqrstuvwxyz012345: 0x624d474c,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x62b03ae8,
+        abcdefghijklmnopqrstuvwxyz01234567: 0xc40c90f2,
+        abcdefghijklmnopqrstuvwxyz012345678: 0xa977c8b3,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0xdfc6f27b,
+
+        [castagnoli]
+        a: 0xc1d04330,
+        ab: 0xe2a22936,
+        abc: 0x364b3fb7,
+        abcd: 0x92c80a31,
+        abcde: 0xc450d697,
+        abcdef: 0x53bceff1,
+        abcdefg: 0xe627f441,
+        abcdefgh: 0xa9421b7,
+        abcdefghi: 0x2ddc99fc,
+        abcdefghij: 0xe6599437,
+        abcdefghijk: 0x4efd1fc6,
+        abcdefghijkl: 0x9b9a33d0,
+        abcdefghijklm: 0x5fdbf778,
+        abcdefghijklmn: 0x64dda821,
+        abcdefghijklmno: 0xbf1a2c62,
+        abcdefghijklmnop: 0xa3a7fee5,
+        abcdefghijklmnopq: 0x7ec9fa7,
+        abcdefghijklmnopqr: 0xb4584a79,
+        abcdefghijklmnopqrs: 0x392b2323,
+        abcdefghijklmnopqrst: 0xd7abc64a,
+        abcdefghijklmnopqrstu: 0x3c5a8d02,
+        abcdefghijklmnopqrstuv: 0xe443cace,
+        abcdefghijklmnopqrstuvw: 0x983eb99e,
+        abcdefghijklmnopqrstuvwx: 0x9774221a,
+        abcdefghijklmnopqrstuvwxy: 0x207c47e5,
+        abcdefghijklmnopqrstuvwxyz: 0x9ee6ef25,
+        abcdefghijklmnopqrstuvwxyz0: 0x774c66cd,
+        abcdefghijklmnopqrstuvwxyz01: 0xec27bf92,
+        abcdefghijklmnopqrstuvwxyz012: 0xf0dac148,
+        abcdefghijklmnopqrstuvwxyz0123: 0xba9c76a6,
+        abcdefghijklmnopqrstuvwxyz01234: 0x215443c7,
+        abcdefghijklmnopqrstuvwxyz012345: 0x40091890,
+        abcdefghijklmnopqrstuvwxyz0123456: 0xd6d70807,
+        abcdefghijklmnopqrstuvwxyz01234567: 0x6248cde8,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x81ccfa37,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0xfe84208c,
+    }
+}
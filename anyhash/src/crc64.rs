@@ -0,0 +1,411 @@
+//! Hashers using the CRC-64/ECMA-182 and CRC-64/XZ checksum algorithms.
+
+// table driven implementation using slicing-by-8, with the combine function adapted from zlib
+
+use core::{fmt::Debug, marker::PhantomData};
+
+use crate::{
+    BuildHasher, BuildHasherDefault, Checksum, EndianIndependentAlgorithm, Hasher, HasherWrite,
+    SeedableBuildHasher,
+};
+
+const fn make_table(poly: u64, reflected: bool) -> [[u64; 256]; 8] {
+    let mut table = [[0; 256]; 8];
+    let rpoly = poly.reverse_bits();
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = if reflected {
+            i as u64
+        } else {
+            (i as u64) << 56
+        };
+        let mut j = 0;
+        while j < 8 {
+            crc = if reflected {
+                (crc >> 1) ^ if crc & 1 != 0 { rpoly } else { 0 }
+            } else {
+                (crc << 1) ^ if crc >> 63 != 0 { poly } else { 0 }
+            };
+            j += 1;
+        }
+        table[0][i] = crc;
+        i += 1;
+    }
+    let mut k = 1;
+    while k < 8 {
+        let mut i = 0;
+        while i < 256 {
+            let crc = table[k - 1][i];
+            table[k][i] = if reflected {
+                (crc >> 8) ^ table[0][crc as usize & 0xff]
+            } else {
+                (crc << 8) ^ table[0][(crc >> 56) as usize]
+            };
+            i += 1;
+        }
+        k += 1;
+    }
+    table
+}
+
+// multiply a and b modulo the polynomial
+#[inline]
+fn multiply_mod<P: Polynomial>(a: u64, b: u64) -> u64 {
+    let mut p = 0;
+    for i in (0..64).rev() {
+        p = (p << 1) ^ if p >> 63 != 0 { P::POLY } else { 0 };
+        if (a >> i) & 1 != 0 {
+            p ^= b;
+        }
+    }
+    p
+}
+
+// x^(8n) modulo the polynomial
+#[inline]
+fn x8n_mod<P: Polynomial>(mut n: u64) -> u64 {
+    let mut x2k = 1 << 8;
+    let mut p = 1;
+    while n != 0 {
+        if n & 1 != 0 {
+            p = multiply_mod::<P>(x2k, p);
+        }
+        n >>= 1;
+        x2k = multiply_mod::<P>(x2k, x2k);
+    }
+    p
+}
+
+use sealed::Polynomial;
+mod sealed {
+    /// Polynomial trait for CRC-64.
+    pub trait Polynomial: Clone + core::fmt::Debug + Default {
+        /// The polynomial, without the implicit x^64 term.
+        const POLY: u64;
+
+        /// Whether the input and output are bit reflected.
+        const REFLECTED: bool;
+
+        /// Initial value and final xor.
+        const XOR: u64;
+
+        /// Lookup tables for the polynomial.
+        const TABLE: [[u64; 256]; 8] = super::make_table(Self::POLY, Self::REFLECTED);
+    }
+}
+
+/// Selector for CRC-64/ECMA-182, which isn't reflected and has zero initial value and final xor.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Ecma;
+
+/// Selector for CRC-64/XZ, which uses the ECMA-182 polynomial reflected, as in xz and Go's
+/// `hash/crc64` with the `ECMA` table.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Xz;
+
+impl Polynomial for Ecma {
+    const POLY: u64 = 0x42f0e1eba9ea3693;
+    const REFLECTED: bool = false;
+    const XOR: u64 = 0;
+}
+
+impl Polynomial for Xz {
+    const POLY: u64 = 0x42f0e1eba9ea3693;
+    const REFLECTED: bool = true;
+    const XOR: u64 = !0;
+}
+
+/// [`BuildHasher`] implementation for the [`Crc64`] hasher.
+/// If you don't need support for using custom seeds, use the zero sized
+/// [`Crc64BuildHasherDefault`] instead.
+#[derive(Clone, Debug)]
+pub struct Crc64BuildHasher<P: Polynomial = Xz>(u64, PhantomData<fn() -> P>);
+
+impl<P: Polynomial> Crc64BuildHasher<P> {
+    /// Create a [`BuildHasher`] for [`Crc64`] using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Create a [`BuildHasher`] for [`Crc64`] with a custom seed.
+    /// See [`Crc64::with_seed`].
+    #[inline]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self(seed, PhantomData)
+    }
+}
+
+impl<P: Polynomial> BuildHasher<u64> for Crc64BuildHasher<P> {
+    type Hasher = Crc64<P>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seed(self.0)
+    }
+}
+
+impl<P: Polynomial> SeedableBuildHasher for Crc64BuildHasher<P> {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seed(seed as u64)
+    }
+}
+
+impl<P: Polynomial> Default for Crc64BuildHasher<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// [`BuildHasher`] implementation for the [`Crc64`] hasher using the default seed (zero sized).
+pub type Crc64BuildHasherDefault<P = Xz> = BuildHasherDefault<Crc64<P>>;
+
+/// Hasher using the CRC-64/ECMA-182 checksum algorithm.
+pub type Crc64Ecma = Crc64<Ecma>;
+
+/// Hasher using the CRC-64/XZ checksum algorithm.
+pub type Crc64Xz = Crc64<Xz>;
+
+/// Hasher using a CRC-64 checksum algorithm.
+///
+/// The algorithm is selected with the `P` parameter, which is either [`Xz`] for
+/// CRC-64/XZ (the default) or [`Ecma`] for CRC-64/ECMA-182.
+///
+/// CRCs are good at detecting errors, but they're poor hash functions,
+/// so this hasher doesn't have collection type aliases.
+#[derive(Clone, Debug)]
+pub struct Crc64<P: Polynomial = Xz>(u64, PhantomData<fn() -> P>);
+
+impl<P: Polynomial> Crc64<P> {
+    /// Create a new `Crc64` hasher using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Create a new `Crc64` hasher with a custom `seed`.
+    ///
+    /// The seed is the checksum of any data that came before the data that will be written
+    /// to the hasher, so hashing can be resumed from a previously calculated checksum.
+    /// The default seed is `0`, which is the checksum of no data.
+    #[inline]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self(seed ^ P::XOR, PhantomData)
+    }
+}
+
+impl<P: Polynomial> Default for Crc64<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Polynomial> EndianIndependentAlgorithm for Crc64<P> {}
+
+impl<P: Polynomial> HasherWrite for Crc64<P> {
+    fn write(&mut self, bytes: &[u8]) {
+        let table = &P::TABLE;
+        let mut crc = self.0;
+        let mut chunks = bytes.chunks_exact(8);
+        if P::REFLECTED {
+            for chunk in &mut chunks {
+                let x = u64::from_le_bytes(chunk.try_into().unwrap()) ^ crc;
+                crc = table[7][x as usize & 0xff]
+                    ^ table[6][(x >> 8) as usize & 0xff]
+                    ^ table[5][(x >> 16) as usize & 0xff]
+                    ^ table[4][(x >> 24) as usize & 0xff]
+                    ^ table[3][(x >> 32) as usize & 0xff]
+                    ^ table[2][(x >> 40) as usize & 0xff]
+                    ^ table[1][(x >> 48) as usize & 0xff]
+                    ^ table[0][(x >> 56) as usize];
+            }
+            for &byte in chunks.remainder() {
+                crc = (crc >> 8) ^ table[0][(crc as u8 ^ byte) as usize];
+            }
+        } else {
+            for chunk in &mut chunks {
+                let x = u64::from_be_bytes(chunk.try_into().unwrap()) ^ crc;
+                crc = table[7][(x >> 56) as usize]
+                    ^ table[6][(x >> 48) as usize & 0xff]
+                    ^ table[5][(x >> 40) as usize & 0xff]
+                    ^ table[4][(x >> 32) as usize & 0xff]
+                    ^ table[3][(x >> 24) as usize & 0xff]
+                    ^ table[2][(x >> 16) as usize & 0xff]
+                    ^ table[1][(x >> 8) as usize & 0xff]
+                    ^ table[0][x as usize & 0xff];
+            }
+            for &byte in chunks.remainder() {
+                crc = (crc << 8) ^ table[0][((crc >> 56) as u8 ^ byte) as usize];
+            }
+        }
+        self.0 = crc;
+    }
+}
+
+impl<P: Polynomial> Hasher<u64> for Crc64<P> {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0 ^ P::XOR
+    }
+}
+
+impl<P: Polynomial> Checksum<u64> for Crc64<P> {
+    #[inline]
+    fn combine(a: u64, b: u64, len_b: u64) -> u64 {
+        let xn = x8n_mod::<P>(len_b);
+        if P::REFLECTED {
+            multiply_mod::<P>(xn, a.reverse_bits()).reverse_bits() ^ b
+        } else {
+            multiply_mod::<P>(xn, a) ^ b
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::RawBytes, Hash};
+
+    fn ecma<T: Hash>(x: T) -> u64 {
+        Crc64BuildHasherDefault::<Ecma>::new().hash_one(x)
+    }
+
+    fn xz<T: Hash>(x: T) -> u64 {
+        Crc64BuildHasherDefault::<Xz>::new().hash_one(x)
+    }
+
+    #[test]
+    fn check() {
+        assert_eq!(ecma(()), 0);
+        assert_eq!(xz(()), 0);
+        assert_eq!(ecma(RawBytes(b"123456789")), 0x6c40df5f0b497347);
+        assert_eq!(xz(RawBytes(b"123456789")), 0x995dc9bbdf1939fa);
+    }
+
+    #[test]
+    fn long() {
+        let mut ecma = Crc64Ecma::new();
+        let mut xz = Crc64Xz::new();
+        ecma.write(&[0xff; 10000]);
+        xz.write(&[0xff; 10000]);
+        assert_eq!(ecma.finish(), 0x3b8df8e02eecdefc);
+        assert_eq!(xz.finish(), 0x5b40aeb2c73e3a2f);
+    }
+
+    #[test]
+    fn resume() {
+        let seed = ecma(RawBytes(b"abcdefghijklm"));
+        assert_eq!(
+            Crc64BuildHasher::<Ecma>::with_seed(seed)
+                .hash_one(RawBytes(b"nopqrstuvwxyz0123456789")),
+            ecma(RawBytes(b"abcdefghijklmnopqrstuvwxyz0123456789"))
+        );
+        let seed = xz(RawBytes(b"abcdefghijklm"));
+        assert_eq!(
+            Crc64BuildHasher::<Xz>::with_seed(seed).hash_one(RawBytes(b"nopqrstuvwxyz0123456789")),
+            xz(RawBytes(b"abcdefghijklmnopqrstuvwxyz0123456789"))
+        );
+    }
+
+    #[test]
+    fn combine() {
+        let data = [
+            b"abcdefghijklmnopqrstuvwxyz0123456789".as_slice(),
+            &[0xff; 10000],
+        ];
+        for data in data {
+            for i in (0..=data.len()).step_by(7) {
+                let (a, b) = data.split_at(i);
+                assert_eq!(
+                    Crc64Ecma::combine(ecma(RawBytes(a)), ecma(RawBytes(b)), b.len() as u64),
+                    ecma(RawBytes(data))
+                );
+                assert_eq!(
+                    Crc64Xz::combine(xz(RawBytes(a)), xz(RawBytes(b)), b.len() as u64),
+                    xz(RawBytes(data))
+                );
+            }
+        }
+    }
+
+    hash_test_vectors! {
+        [ecma]
+        a: 0x548f120162451c62,
+        ab: 0xe9872df0a9a86dc8,
+        abc: 0x66501a349a0e0855,
+        abcd: 0xd5fbf74d5ddc3826,
+        abcde: 0xa74a219d1028dcfc,
+        abcdef: 0x242e9b2e16689f71,
+        abcdefg: 0x338f0e3f78cd0b9a,
+        abcdefgh: 0x6641ab24513dbccb,
+        abcdefghi: 0x5defaca2d545a5d4,
+        abcdefghij: 0xcbc96fac00fbed5b,
+        abcdefghijk: 0xf3efb83fa71c2413,
+        abcdefghijkl: 0x633b2a6b190e8cf6,
+        abcdefghijklm: 0x659e02014f9fae47,
+        abcdefghijklmn: 0xcb75eff97915c50b,
+        abcdefghijklmno: 0x65c8b03477698cc,
+        abcdefghijklmnop: 0xe01b2dc07040e2e7,
+        abcdefghijklmnopq: 0xc91abca404db20b1,
+        abcdefghijklmnopqr: 0x13762672f18bc9f7,
+        abcdefghijklmnopqrs: 0x6059811b4066ddf1,
+        abcdefghijklmnopqrst: 0x768f05faf87e9830,
+        abcdefghijklmnopqrstu: 0x4814d8c484a66bb5,
+        abcdefghijklmnopqrstuv: 0xe02b249c565a6376,
+        abcdefghijklmnopqrstuvw: 0x35c1451043cc3048,
+        abcdefghijklmnopqrstuvwx: 0x82e559729d716add,
+        abcdefghijklmnopqrstuvwxy: 0x3696f2feb1ad84d8,
+        abcdefghijklmnopqrstuvwxyz: 0x97a2566b552fcc4e,
+        abcdefghijklmnopqrstuvwxyz0: 0x16f43b1387418679,
+        abcdefghijklmnopqrstuvwxyz01: 0xb663a6019439c5b4,
+        abcdefghijklmnopqrstuvwxyz012: 0xdc6f82114ab52c12,
+        abcdefghijklmnopqrstuvwxyz0123: 0x93438f93eb4822e8,
+        abcdefghijklmnopqrstuvwxyz01234: 0xf72dc3ade0af2079,
+        abcdefghijklmnopqrstuvwxyz012345: 0x84dd89e26baa41c4,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x4d54d7c358553da,
+        abcdefghijklmnopqrstuvwxyz01234567: 0xb81bd709ce4f0f84,
+        abcdefghijklmnopqrstuvwxyz012345678: 0xed2dec0e323df0cd,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x2e1df75d340df1d2,
+
+        [xz]
+        a: 0x330284772e652b05,
+        ab: 0xbc6573200e84b046,
+        abc: 0x2cd8094a1a277627,
+        abcd: 0x3c9d28596e5960ba,
+        abcde: 0x40bdf58fb0895f2,
+        abcdef: 0xd08e9f8545a700f4,
+        abcdefg: 0xec20a3a8cc710e66,
+        abcdefgh: 0x67b4f30a647a0c59,
+        abcdefghi: 0x9966f6c89d56ef8e,
+        abcdefghij: 0x32093a2ecd5773f4,
+        abcdefghijk: 0x60b3608067681c40,
+        abcdefghijkl: 0x688b14ee46f77982,
+        abcdefghijklm: 0x82f32a2cbf759130,
+        abcdefghijklmn: 0x7ef7aa715af9e92e,
+        abcdefghijklmno: 0xc84b31adfd591e7e,
+        abcdefghijklmnop: 0x67909898614b2449,
+        abcdefghijklmnopq: 0x6ec755aaf04d62c0,
+        abcdefghijklmnopqr: 0xa487e4ac420577f9,
+        abcdefghijklmnopqrs: 0xd540c05c48fc2951,
+        abcdefghijklmnopqrst: 0x2cb12cf966610ebe,
+        abcdefghijklmnopqrstu: 0x25091e0f5bc95e6,
+        abcdefghijklmnopqrstuv: 0xab58074f0226eac7,
+        abcdefghijklmnopqrstuvw: 0x50c64da90fb7bc2a,
+        abcdefghijklmnopqrstuvwx: 0xf254db9bdfd8c956,
+        abcdefghijklmnopqrstuvwxy: 0x2fb389bcf6a83663,
+        abcdefghijklmnopqrstuvwxyz: 0x26967875751b122f,
+        abcdefghijklmnopqrstuvwxyz0: 0xa9cba857eb575f63,
+        abcdefghijklmnopqrstuvwxyz01: 0xf2add67e213c29b5,
+        abcdefghijklmnopqrstuvwxyz012: 0xea49e25d99a9ad35,
+        abcdefghijklmnopqrstuvwxyz0123: 0x90131d426e31689c,
+        abcdefghijklmnopqrstuvwxyz01234: 0xdac72082acf67187,
+        abcdefghijklmnopqrstuvwxyz012345: 0xa433e4d96a59ccea,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x43390b7dd0eb6a6a,
+        abcdefghijklmnopqrstuvwxyz01234567: 0x399c1ab9a07188e1,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x8b5a2bb94c094372,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0xcb9b2d726211f2e9,
+    }
+}
@@ -0,0 +1,7 @@
+//! Adapters for cryptographic hash functions.
+//!
+//! These are much slower than the non-cryptographic hashers, but they can be used to make
+//! cryptographic fingerprints of anything that implements [`Hash`](crate::Hash).
+
+#[cfg(feature = "sha2")]
+pub mod sha256;
@@ -0,0 +1,156 @@
+//! Adapter for the SHA-256 cryptographic hash function from the RustCrypto `sha2` crate.
+
+use sha2::Digest;
+
+use crate::{
+    impl_core_hasher, BuildHasherDefault, EndianIndependentAlgorithm, Hasher, HasherWrite,
+};
+
+impl_core_hasher!(Sha256);
+
+/// [`BuildHasher`](crate::BuildHasher) implementation for the [`Sha256`] hasher (zero sized).
+pub type Sha256BuildHasherDefault = BuildHasherDefault<Sha256>;
+
+/// Hasher using the SHA-256 cryptographic hash function.
+///
+/// This implements [`Hasher<[u8; 32]>`](Hasher) for the full 256-bit hash, and [`Hasher<u128>`]
+/// and [`Hasher<u64>`] for the hash truncated to its first 16 or 8 bytes, read as little endian.
+///
+/// SHA-256 is much slower than the non-cryptographic hashers, so this hasher doesn't
+/// have collection type aliases.
+#[derive(Clone, Debug, Default)]
+pub struct Sha256(sha2::Sha256);
+
+impl Sha256 {
+    /// Create a new `Sha256` hasher.
+    #[inline]
+    pub fn new() -> Self {
+        Self(sha2::Sha256::new())
+    }
+
+    /// Get a reference to the wrapped `sha2::Sha256`.
+    #[inline]
+    pub fn inner(&self) -> &sha2::Sha256 {
+        &self.0
+    }
+
+    /// Convert this into the wrapped `sha2::Sha256`.
+    #[inline]
+    pub fn into_inner(self) -> sha2::Sha256 {
+        self.0
+    }
+}
+
+impl From<sha2::Sha256> for Sha256 {
+    #[inline]
+    fn from(value: sha2::Sha256) -> Self {
+        Self(value)
+    }
+}
+
+impl EndianIndependentAlgorithm for Sha256 {}
+
+impl HasherWrite for Sha256 {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+}
+
+impl Hasher<[u8; 32]> for Sha256 {
+    #[inline]
+    fn finish(&self) -> [u8; 32] {
+        self.0.clone().finalize().into()
+    }
+}
+
+impl Hasher<u128> for Sha256 {
+    #[inline]
+    fn finish(&self) -> u128 {
+        let hash: [u8; 32] = self.finish();
+        u128::from_le_bytes(hash[..16].try_into().unwrap())
+    }
+}
+
+impl Hasher<u64> for Sha256 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        let hash: [u8; 32] = self.finish();
+        u64::from_le_bytes(hash[..8].try_into().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::RawBytes, BuildHasher, Hash};
+
+    fn default<T: Hash>(x: T) -> u64 {
+        Sha256BuildHasherDefault::new().hash_one(x)
+    }
+
+    fn hex(s: &str) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    #[test]
+    fn reference() {
+        let hash: [u8; 32] = Sha256BuildHasherDefault::new().hash_one(());
+        assert_eq!(
+            hash,
+            hex("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+        );
+
+        let hash: [u8; 32] = Sha256BuildHasherDefault::new().hash_one(RawBytes(b"abc"));
+        assert_eq!(
+            hash,
+            hex("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        );
+        let hash: u128 = Sha256BuildHasherDefault::new().hash_one(RawBytes(b"abc"));
+        assert_eq!(hash, 0x2322ae5dde404141eacf018fbf1678ba);
+    }
+
+    hash_test_vectors! {
+        [default]
+        a: 0xcabd1bca128197ca,
+        ab: 0x243f4c2efc208efb,
+        abc: 0xeacf018fbf1678ba,
+        abcd: 0x8d33e6d46f26d488,
+        abcde: 0xd14168d90ee5bb36,
+        abcdef: 0x406d3af5c77ef5be,
+        abcdefg: 0x225227b12541a7d,
+        abcdefgh: 0xbac374b351cc569c,
+        abcdefghi: 0xc53cf46df202cc19,
+        abcdefghij: 0x54776ada61933972,
+        abcdefghijk: 0x466e0cea69202fca,
+        abcdefghijkl: 0xc189d9a44ced82d6,
+        abcdefghijklm: 0x636f21a4f3010ff,
+        abcdefghijklmn: 0xd4aad792e9c75306,
+        abcdefghijklmno: 0x99deef500c76c741,
+        abcdefghijklmnop: 0x5e53baba6cac9df3,
+        abcdefghijklmnopq: 0x4ab5dfc44a958a91,
+        abcdefghijklmnopqr: 0x6ef5fa2a6922122d,
+        abcdefghijklmnopqrs: 0xba778b7286f850e2,
+        abcdefghijklmnopqrst: 0x94cb9d32a0ee65dd,
+        abcdefghijklmnopqrstu: 0xc64e413d5a2af625,
+        abcdefghijklmnopqrstuv: 0x44a5c9d1709b9ff6,
+        abcdefghijklmnopqrstuvw: 0x4489d0148e81077f,
+        abcdefghijklmnopqrstuvwx: 0xc5e06886bfcab093,
+        abcdefghijklmnopqrstuvwxy: 0x9645509d5480b969,
+        abcdefghijklmnopqrstuvwxyz: 0x2faed693df80c471,
+        abcdefghijklmnopqrstuvwxyz0: 0x365c6616d3ca1da1,
+        abcdefghijklmnopqrstuvwxyz01: 0x17f2a16088630d2d,
+        abcdefghijklmnopqrstuvwxyz012: 0x22b99aa4cd938ca8,
+        abcdefghijklmnopqrstuvwxyz0123: 0x3287bdabc745f20b,
+        abcdefghijklmnopqrstuvwxyz01234: 0x120c24f0ed286e30,
+        abcdefghijklmnopqrstuvwxyz012345: 0xcd8f825e24b13b65,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x9759f0fb9bf64fe4,
+        abcdefghijklmnopqrstuvwxyz01234567: 0x3aee6f20ab8f3d39,
+        abcdefghijklmnopqrstuvwxyz012345678: 0xde5325f0657429ab,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x51d2394e99c21f01,
+    }
+}
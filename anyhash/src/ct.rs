@@ -0,0 +1,175 @@
+use core::{
+    fmt::{self, Debug},
+    hint::black_box,
+};
+
+/// Types that can be compared for equality in constant time.
+///
+/// This is implemented for the primitive integer types and for arrays and slices of them,
+/// which covers the output types of the keyed hashers. Use [`ct_eq`] or [`CtHashValue`] to
+/// compare hashes that must not leak timing information, like MACs.
+pub trait ConstantTimeEq {
+    /// Returns a value that's zero if and only if `self` and `other` are equal.
+    /// Implementations must not branch on the contents of `self` or `other`.
+    fn ct_diff(&self, other: &Self) -> u128;
+
+    /// Check if `self` and `other` are equal in constant time.
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> bool {
+        let diff = black_box(self.ct_diff(other));
+        ((diff | diff.wrapping_neg()) >> 127) == 0
+    }
+}
+
+macro_rules! impl_constant_time_eq {
+    ($($t:ty),* $(,)?) => { $(
+        impl ConstantTimeEq for $t {
+            #[inline]
+            fn ct_diff(&self, other: &Self) -> u128 {
+                (*self ^ *other) as u128
+            }
+        }
+    )* };
+}
+
+impl_constant_time_eq!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<T: ConstantTimeEq> ConstantTimeEq for [T] {
+    // slices of different lengths compare unequal; the length isn't considered secret
+    #[inline]
+    fn ct_diff(&self, other: &Self) -> u128 {
+        if self.len() != other.len() {
+            return 1;
+        }
+        self.iter()
+            .zip(other)
+            .fold(0, |acc, (a, b)| acc | black_box(a.ct_diff(b)))
+    }
+}
+
+impl<T: ConstantTimeEq, const N: usize> ConstantTimeEq for [T; N] {
+    #[inline]
+    fn ct_diff(&self, other: &Self) -> u128 {
+        self[..].ct_diff(&other[..])
+    }
+}
+
+#[cfg(feature = "bnum")]
+mod bnum_impls {
+    use super::*;
+    use bnum::{BInt, BIntD16, BIntD32, BIntD8, BUint, BUintD16, BUintD32, BUintD8};
+
+    macro_rules! impl_buint {
+        ($($id:ident),*) => { $(
+            impl<const N: usize> ConstantTimeEq for $id<N> {
+                #[inline]
+                fn ct_diff(&self, other: &Self) -> u128 {
+                    self.digits().ct_diff(other.digits())
+                }
+            }
+        )* };
+    }
+
+    macro_rules! impl_bint {
+        ($($id:ident),*) => { $(
+            impl<const N: usize> ConstantTimeEq for $id<N> {
+                #[inline]
+                fn ct_diff(&self, other: &Self) -> u128 {
+                    self.to_bits().ct_diff(&other.to_bits())
+                }
+            }
+        )* };
+    }
+
+    impl_buint!(BUint, BUintD8, BUintD16, BUintD32);
+    impl_bint!(BInt, BIntD8, BIntD16, BIntD32);
+}
+
+/// Check if two hashes are equal in constant time.
+///
+/// See [`ConstantTimeEq`].
+#[inline]
+pub fn ct_eq<T: ?Sized + ConstantTimeEq>(a: &T, b: &T) -> bool {
+    a.ct_eq(b)
+}
+
+/// Wrapper for hash values that compares for equality in constant time.
+///
+/// Use this for the outputs of keyed hashes that are used for authentication, so comparing
+/// a received hash against the expected one doesn't leak how many bytes matched.
+///
+/// ```
+/// # use anyhash::{BuildHasher, CtHashValue, Hasher, HasherWrite};
+/// # #[derive(Default)]
+/// # struct Keyed(u64);
+/// # impl HasherWrite for Keyed {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = self.0.rotate_left(5) ^ b as u64; }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for Keyed {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+/// # let build_hasher = anyhash::BuildHasherDefault::<Keyed>::new();
+/// # let received_tag = build_hasher.hash_one("message");
+/// let expected: u64 = build_hasher.hash_one("message");
+/// assert!(CtHashValue(expected) == CtHashValue(received_tag));
+/// ```
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct CtHashValue<T>(pub T);
+
+impl<T> CtHashValue<T> {
+    /// Unwrap the hash value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: ConstantTimeEq> PartialEq for CtHashValue<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<T: ConstantTimeEq> Eq for CtHashValue<T> {}
+
+impl<T: ConstantTimeEq> PartialEq<T> for CtHashValue<T> {
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        self.0.ct_eq(other)
+    }
+}
+
+impl<T> From<T> for CtHashValue<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Debug> Debug for CtHashValue<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eq() {
+        assert!(ct_eq(&0_u64, &0));
+        assert!(!ct_eq(&0_u64, &(1 << 63)));
+        assert!(!ct_eq(&u128::MAX, &(u128::MAX - 1)));
+        assert!(ct_eq(&[1_u8, 2, 3], &[1, 2, 3]));
+        assert!(!ct_eq(&[1_u8, 2, 3], &[1, 2, 4]));
+        assert!(!ct_eq(&[1_u8, 2][..], &[1, 2, 3][..]));
+        assert_eq!(CtHashValue(-1_i32), CtHashValue(-1));
+        assert_ne!(CtHashValue(-1_i32), -2);
+    }
+}
@@ -0,0 +1,139 @@
+use core::fmt::{self, Debug};
+
+use crate::{impl_core_build_hasher, BuildHasher, HasherWrite};
+
+/// Extension trait for [`HasherWrite`] with helpers for framing data.
+///
+/// This is implemented for all types that implement `HasherWrite`.
+pub trait HasherWriteExt: HasherWrite {
+    /// Write a domain separation tag.
+    ///
+    /// The tag is written as its length as a `u64` followed by its bytes, so no tag is a prefix
+    /// of another. Writing different tags before the same data gives unrelated hashes.
+    #[inline]
+    fn write_domain(&mut self, tag: &str) {
+        self.write_u64(tag.len() as u64);
+        self.write(tag.as_bytes());
+    }
+}
+
+impl<H: ?Sized + HasherWrite> HasherWriteExt for H {}
+
+/// `BuildHasher` wrapper that writes a domain separation tag into every hasher before any other
+/// data, using [`HasherWriteExt::write_domain`].
+///
+/// Use this to get unrelated hashes of the same values for independent subsystems, like a cache
+/// and a Bloom filter, that use the same algorithm and seed.
+///
+/// ```
+/// # use anyhash::{BuildHasher, BuildHasherDefault, DomainSeparated, Hasher, HasherWrite};
+/// # #[derive(Default)]
+/// # struct MyHasher(u64);
+/// # impl HasherWrite for MyHasher {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3); }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for MyHasher {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+/// let cache = DomainSeparated::new(BuildHasherDefault::<MyHasher>::new(), "cache");
+/// let shards = DomainSeparated::new(BuildHasherDefault::<MyHasher>::new(), "shards");
+/// assert_ne!(cache.hash_one("key"), shards.hash_one("key"));
+/// ```
+pub struct DomainSeparated<BH, D = &'static str> {
+    build_hasher: BH,
+    domain: D,
+}
+
+impl_core_build_hasher!(impl<BH: BuildHasher<u64>, D: AsRef<str>> DomainSeparated<BH, D>);
+
+impl<BH, D> DomainSeparated<BH, D> {
+    /// Create a new `DomainSeparated` with the tag `domain`.
+    #[inline]
+    pub const fn new(build_hasher: BH, domain: D) -> Self {
+        Self {
+            build_hasher,
+            domain,
+        }
+    }
+
+    /// Get a reference to the wrapped `BuildHasher`.
+    #[inline]
+    pub const fn get_ref(&self) -> &BH {
+        &self.build_hasher
+    }
+
+    /// Get a reference to the domain separation tag.
+    #[inline]
+    pub const fn domain(&self) -> &D {
+        &self.domain
+    }
+}
+
+impl<T, BH: BuildHasher<T>, D: AsRef<str>> BuildHasher<T> for DomainSeparated<BH, D> {
+    type Hasher = BH::Hasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = self.build_hasher.build_hasher();
+        hasher.write_domain(self.domain.as_ref());
+        hasher
+    }
+}
+
+impl<BH: Debug, D: Debug> Debug for DomainSeparated<BH, D> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DomainSeparated")
+            .field("build_hasher", &self.build_hasher)
+            .field("domain", &self.domain)
+            .finish()
+    }
+}
+
+impl<BH: Clone, D: Clone> Clone for DomainSeparated<BH, D> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.build_hasher.clone(), self.domain.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BuildHasherDefault, Hasher};
+
+    #[derive(Default)]
+    struct Fnv(u64);
+
+    impl HasherWrite for Fnv {
+        fn write(&mut self, bytes: &[u8]) {
+            for &b in bytes {
+                self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3);
+            }
+        }
+    }
+
+    impl Hasher<u64> for Fnv {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn prefix_free() {
+        let hash = |domain: &str, data: &[u8]| {
+            let mut hasher = Fnv::default();
+            hasher.write_domain(domain);
+            hasher.write(data);
+            hasher.finish()
+        };
+        assert_ne!(hash("ab", b"c"), hash("a", b"bc"));
+
+        let bh = DomainSeparated::new(BuildHasherDefault::<Fnv>::new(), "ab");
+        let mut hasher = bh.build_hasher();
+        hasher.write(b"c");
+        assert_eq!(hasher.finish(), hash("ab", b"c"));
+    }
+}
@@ -0,0 +1,307 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+#[cfg(feature = "alloc")]
+use crate::Hasher;
+use crate::{Hash, HasherWrite};
+
+/// Dyn compatible version of [`Hash`].
+///
+/// This is implemented for all types that implement [`Hash`], and `dyn DynHash` implements
+/// [`Hash`] in turn, so values of different types can be stored as e.g. `Box<dyn DynHash>` and
+/// still be hashed with any hasher.
+///
+/// ```
+/// # use anyhash::{DynHash, Hash, Hasher, HasherWrite};
+/// # #[derive(Default)]
+/// # struct MyHasher(u64);
+/// # impl HasherWrite for MyHasher {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3); }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for MyHasher {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+/// let values: [&dyn DynHash; 2] = [&1_u32, &"two"];
+///
+/// let mut hasher = MyHasher::default();
+/// values.hash(&mut hasher);
+/// # let _ = hasher.finish();
+/// ```
+pub trait DynHash {
+    /// Feeds this value into the given [`HasherWrite`].
+    fn dyn_hash(&self, state: &mut dyn HasherWrite);
+}
+
+impl<T: ?Sized + Hash> DynHash for T {
+    #[inline]
+    fn dyn_hash(&self, mut state: &mut dyn HasherWrite) {
+        self.hash(&mut state);
+    }
+}
+
+macro_rules! impl_hash_for_dyn {
+    ($($t:ty),* $(,)?) => { $(
+        impl Hash for $t {
+            #[inline]
+            fn hash<H: HasherWrite>(&self, state: &mut H) {
+                self.dyn_hash(state);
+            }
+        }
+    )* };
+}
+
+impl_hash_for_dyn!(
+    dyn DynHash + '_,
+    dyn DynHash + Send + '_,
+    dyn DynHash + Send + Sync + '_,
+);
+
+/// Hash value of one of the widths supported by `AnyHasher`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HashOutput {
+    /// 32-bit hash.
+    U32(u32),
+
+    /// 64-bit hash.
+    U64(u64),
+
+    /// 128-bit hash.
+    U128(u128),
+}
+
+impl HashOutput {
+    /// Get the width of the hash in bits.
+    #[inline]
+    pub const fn bits(&self) -> u32 {
+        match self {
+            Self::U32(_) => 32,
+            Self::U64(_) => 64,
+            Self::U128(_) => 128,
+        }
+    }
+
+    /// Get the hash zero extended to `u128`.
+    #[inline]
+    pub const fn to_u128(self) -> u128 {
+        match self {
+            Self::U32(hash) => hash as u128,
+            Self::U64(hash) => hash as u128,
+            Self::U128(hash) => hash,
+        }
+    }
+}
+
+macro_rules! impl_hash_output_from {
+    ($($t:ty: $v:ident),* $(,)?) => { $(
+        impl From<$t> for HashOutput {
+            #[inline]
+            fn from(hash: $t) -> Self {
+                Self::$v(hash)
+            }
+        }
+    )* };
+}
+
+impl_hash_output_from!(u32: U32, u64: U64, u128: U128);
+
+#[cfg(feature = "alloc")]
+macro_rules! impl_any_writes {
+    ($field:tt; $($t:ty: $fn:ident),* $(,)?) => { $(
+        #[inline]
+        fn $fn(&mut self, i: $t) {
+            self.$field.$fn(i);
+        }
+    )* };
+}
+#[cfg(feature = "alloc")]
+trait ErasedHasher: HasherWrite {
+    fn finish_erased(&self) -> HashOutput;
+}
+
+#[cfg(feature = "alloc")]
+struct Erased<H, T> {
+    hasher: H,
+    _pd: PhantomData<fn() -> T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<H: HasherWrite, T> HasherWrite for Erased<H, T> {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.hasher.write(bytes);
+    }
+
+    impl_any_writes! {
+        hasher;
+        u8: write_u8,
+        u16: write_u16,
+        u32: write_u32,
+        u64: write_u64,
+        u128: write_u128,
+        usize: write_usize,
+        i8: write_i8,
+        i16: write_i16,
+        i32: write_i32,
+        i64: write_i64,
+        i128: write_i128,
+        isize: write_isize,
+        f32: write_f32,
+        f64: write_f64,
+        &str: write_str,
+    }
+
+    #[inline]
+    fn write_length_prefix(&mut self, len: usize) {
+        self.hasher.write_length_prefix(len);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<H: Hasher<T>, T: Into<HashOutput>> ErasedHasher for Erased<H, T> {
+    #[inline]
+    fn finish_erased(&self) -> HashOutput {
+        self.hasher.finish().into()
+    }
+}
+
+/// Type erased hasher.
+///
+/// This can hold any hasher that makes `u32`, `u64` or `u128` hashes, so hashers can be chosen
+/// at runtime. Writes are forwarded to the wrapped hasher unchanged, and the hash is returned as
+/// a [`HashOutput`].
+///
+/// ```
+/// # use anyhash::{AnyHasher, Hash, HashOutput, Hasher, HasherWrite};
+/// # #[derive(Default)]
+/// # struct MyHasher(u64);
+/// # impl HasherWrite for MyHasher {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3); }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for MyHasher {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+/// let mut hasher = AnyHasher::new(MyHasher::default());
+/// "hello".hash(&mut hasher);
+/// assert!(matches!(hasher.finish(), HashOutput::U64(_)));
+/// ```
+#[cfg(feature = "alloc")]
+pub struct AnyHasher<'a>(Box<dyn ErasedHasher + 'a>);
+
+#[cfg(feature = "alloc")]
+impl<'a> AnyHasher<'a> {
+    /// Create a new `AnyHasher` wrapping `hasher`.
+    ///
+    /// If the hasher can make hashes of more than one width, the width has to be given
+    /// explicitly, e.g. `AnyHasher::new::<u64, _>(hasher)`.
+    #[inline]
+    pub fn new<T, H>(hasher: H) -> Self
+    where
+        T: Into<HashOutput> + 'a,
+        H: Hasher<T> + 'a,
+    {
+        Self(Box::new(Erased {
+            hasher,
+            _pd: PhantomData,
+        }))
+    }
+
+    /// Get the wrapped hasher as a `dyn HasherWrite`.
+    #[inline]
+    pub fn as_dyn_write(&mut self) -> &mut (dyn HasherWrite + 'a) {
+        &mut *self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl HasherWrite for AnyHasher<'_> {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes);
+    }
+
+    impl_any_writes! {
+        0;
+        u8: write_u8,
+        u16: write_u16,
+        u32: write_u32,
+        u64: write_u64,
+        u128: write_u128,
+        usize: write_usize,
+        i8: write_i8,
+        i16: write_i16,
+        i32: write_i32,
+        i64: write_i64,
+        i128: write_i128,
+        isize: write_isize,
+        f32: write_f32,
+        f64: write_f64,
+        &str: write_str,
+    }
+
+    #[inline]
+    fn write_length_prefix(&mut self, len: usize) {
+        self.0.write_length_prefix(len);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Hasher<HashOutput> for AnyHasher<'_> {
+    #[inline]
+    fn finish(&self) -> HashOutput {
+        self.0.finish_erased()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Debug for AnyHasher<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AnyHasher").finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use alloc::{boxed::Box, vec, vec::Vec};
+
+    #[derive(Default)]
+    struct Fnv(u64);
+
+    impl HasherWrite for Fnv {
+        fn write(&mut self, bytes: &[u8]) {
+            for &b in bytes {
+                self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3);
+            }
+        }
+    }
+
+    impl Hasher<u64> for Fnv {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn dyn_hash() {
+        let values: Vec<Box<dyn DynHash>> = vec![Box::new(1_u32), Box::new("two")];
+
+        let mut expected = Fnv::default();
+        expected.write_length_prefix(values.len());
+        1_u32.hash(&mut expected);
+        "two".hash(&mut expected);
+
+        let mut hasher = AnyHasher::new(Fnv::default());
+        values.hash(&mut hasher);
+        assert_eq!(hasher.finish(), HashOutput::U64(expected.finish()));
+    }
+}
@@ -0,0 +1,609 @@
+//! Hasher, collections and functions for FarmHash fingerprints.
+
+// based on the reference implementation at https://github.com/google/farmhash
+
+use crate::{
+    impl_core_hasher, BuildHasherDefault, EndianIndependentAlgorithm, Hasher, HasherWrite,
+};
+use alloc::vec::Vec;
+
+impl_core_hasher!(FarmFingerprint);
+
+const K0: u64 = 0xc3a5c85c97cb3127;
+const K1: u64 = 0xb492b66fbe98f273;
+const K2: u64 = 0x9ae16a3b2f90404f;
+const K_MUL: u64 = 0x9ddfea08eb382d69;
+
+/// Calculate the FarmHash `Fingerprint64` of `bytes`.
+///
+/// Fingerprints are stable: they're the same on all platforms and won't change
+/// between versions.
+#[inline]
+pub fn fingerprint64(bytes: &[u8]) -> u64 {
+    na::hash64(bytes)
+}
+
+/// Calculate the FarmHash `Fingerprint128` of `bytes`.
+///
+/// Fingerprints are stable: they're the same on all platforms and won't change
+/// between versions. The low 64 bits of the result are the first half of the
+/// reference implementation's `uint128_t` pair.
+#[inline]
+pub fn fingerprint128(bytes: &[u8]) -> u128 {
+    let (lo, hi) = cc::city_hash_128(bytes);
+    lo as u128 | (hi as u128) << 64
+}
+
+/// [`BuildHasher`](crate::BuildHasher) implementation for the [`FarmFingerprint`] hasher (zero sized).
+/// Fingerprints don't use a seed.
+pub type FarmFingerprintBuildHasher = BuildHasherDefault<FarmFingerprint>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`FarmFingerprint`] hasher.
+pub type FarmFingerprintHashMap<K, V> = std::collections::HashMap<K, V, FarmFingerprintBuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`FarmFingerprint`] hasher.
+pub type FarmFingerprintHashSet<T> = std::collections::HashSet<T, FarmFingerprintBuildHasher>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`FarmFingerprint`] hasher.
+pub type FarmFingerprintHeaplessIndexMap<K, V, const N: usize> =
+    heapless::IndexMap<K, V, FarmFingerprintBuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`FarmFingerprint`] hasher.
+pub type FarmFingerprintHeaplessIndexSet<T, const N: usize> =
+    heapless::IndexSet<T, FarmFingerprintBuildHasher, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`FarmFingerprint`] hasher.
+pub type FarmFingerprintHashBrownMap<K, V> = hashbrown::HashMap<K, V, FarmFingerprintBuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`FarmFingerprint`] hasher.
+pub type FarmFingerprintHashBrownSet<T> = hashbrown::HashSet<T, FarmFingerprintBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`FarmFingerprint`] hasher.
+pub type FarmFingerprintIndexMap<K, V> = indexmap::IndexMap<K, V, FarmFingerprintBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`FarmFingerprint`] hasher.
+pub type FarmFingerprintIndexSet<T> = indexmap::IndexSet<T, FarmFingerprintBuildHasher>;
+
+/// Hasher calculating FarmHash fingerprints.
+///
+/// This implements [`Hasher<u64>`] for `Fingerprint64` and [`Hasher<u128>`] for
+/// `Fingerprint128`, so the hash of bytes written to it is the same as [`fingerprint64`]
+/// and [`fingerprint128`] of those bytes.
+///
+/// FarmHash picks a different algorithm depending on the length of the input, so it can't
+/// be calculated incrementally. This hasher buffers all data written to it and hashes it
+/// when the hash is finished.
+#[derive(Clone, Default)]
+pub struct FarmFingerprint {
+    buffer: Vec<u8>,
+}
+
+impl FarmFingerprint {
+    /// Create a new `FarmFingerprint` hasher.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { buffer: Vec::new() }
+    }
+}
+
+impl EndianIndependentAlgorithm for FarmFingerprint {}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for FarmFingerprint {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.buffer.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for FarmFingerprint {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for FarmFingerprint {}
+
+impl HasherWrite for FarmFingerprint {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+}
+
+impl Hasher<u64> for FarmFingerprint {
+    #[inline]
+    fn finish(&self) -> u64 {
+        fingerprint64(&self.buffer)
+    }
+}
+
+impl Hasher<u128> for FarmFingerprint {
+    #[inline]
+    fn finish(&self) -> u128 {
+        fingerprint128(&self.buffer)
+    }
+}
+
+#[inline(always)]
+fn fetch64(s: &[u8], i: usize) -> u64 {
+    u64::from_le_bytes(s[i..i + 8].try_into().unwrap())
+}
+
+#[inline(always)]
+fn fetch32(s: &[u8], i: usize) -> u64 {
+    u32::from_le_bytes(s[i..i + 4].try_into().unwrap()) as u64
+}
+
+#[inline(always)]
+const fn shift_mix(val: u64) -> u64 {
+    val ^ (val >> 47)
+}
+
+#[inline(always)]
+const fn hash_len_16_mul(u: u64, v: u64, mul: u64) -> u64 {
+    let mut a = (u ^ v).wrapping_mul(mul);
+    a ^= a >> 47;
+    let mut b = (v ^ a).wrapping_mul(mul);
+    b ^= b >> 47;
+    b.wrapping_mul(mul)
+}
+
+#[inline(always)]
+const fn hash_len_16(u: u64, v: u64) -> u64 {
+    hash_len_16_mul(u, v, K_MUL)
+}
+
+fn hash_len_0_to_16(s: &[u8]) -> u64 {
+    let len = s.len();
+    if len >= 8 {
+        let mul = K2.wrapping_add(len as u64 * 2);
+        let a = fetch64(s, 0).wrapping_add(K2);
+        let b = fetch64(s, len - 8);
+        let c = b.rotate_right(37).wrapping_mul(mul).wrapping_add(a);
+        let d = a.rotate_right(25).wrapping_add(b).wrapping_mul(mul);
+        hash_len_16_mul(c, d, mul)
+    } else if len >= 4 {
+        let mul = K2.wrapping_add(len as u64 * 2);
+        let a = fetch32(s, 0);
+        hash_len_16_mul(len as u64 + (a << 3), fetch32(s, len - 4), mul)
+    } else if len > 0 {
+        let y = s[0] as u64 + ((s[len >> 1] as u64) << 8);
+        let z = len as u64 + ((s[len - 1] as u64) << 2);
+        shift_mix(y.wrapping_mul(K2) ^ z.wrapping_mul(K0)).wrapping_mul(K2)
+    } else {
+        K2
+    }
+}
+
+#[inline(always)]
+fn weak_hash_len_32_with_seeds(s: &[u8], i: usize, mut a: u64, mut b: u64) -> (u64, u64) {
+    let w = fetch64(s, i);
+    let x = fetch64(s, i + 8);
+    let y = fetch64(s, i + 16);
+    let z = fetch64(s, i + 24);
+    a = a.wrapping_add(w);
+    b = b.wrapping_add(a).wrapping_add(z).rotate_right(21);
+    let c = a;
+    a = a.wrapping_add(x).wrapping_add(y);
+    b = b.wrapping_add(a.rotate_right(44));
+    (a.wrapping_add(z), b.wrapping_add(c))
+}
+
+// `farmhashna`, used for `Fingerprint64`
+mod na {
+    use super::*;
+
+    fn hash_len_17_to_32(s: &[u8]) -> u64 {
+        let len = s.len();
+        let mul = K2.wrapping_add(len as u64 * 2);
+        let a = fetch64(s, 0).wrapping_mul(K1);
+        let b = fetch64(s, 8);
+        let c = fetch64(s, len - 8).wrapping_mul(mul);
+        let d = fetch64(s, len - 16).wrapping_mul(K2);
+        hash_len_16_mul(
+            a.wrapping_add(b)
+                .rotate_right(43)
+                .wrapping_add(c.rotate_right(30))
+                .wrapping_add(d),
+            a.wrapping_add(b.wrapping_add(K2).rotate_right(18))
+                .wrapping_add(c),
+            mul,
+        )
+    }
+
+    fn hash_len_33_to_64(s: &[u8]) -> u64 {
+        let len = s.len();
+        let mul = K2.wrapping_add(len as u64 * 2);
+        let a = fetch64(s, 0).wrapping_mul(K2);
+        let b = fetch64(s, 8);
+        let c = fetch64(s, len - 8).wrapping_mul(mul);
+        let d = fetch64(s, len - 16).wrapping_mul(K2);
+        let y = a
+            .wrapping_add(b)
+            .rotate_right(43)
+            .wrapping_add(c.rotate_right(30))
+            .wrapping_add(d);
+        let z = hash_len_16_mul(
+            y,
+            a.wrapping_add(b.wrapping_add(K2).rotate_right(18))
+                .wrapping_add(c),
+            mul,
+        );
+        let e = fetch64(s, 16).wrapping_mul(mul);
+        let f = fetch64(s, 24);
+        let g = y.wrapping_add(fetch64(s, len - 32)).wrapping_mul(mul);
+        let h = z.wrapping_add(fetch64(s, len - 24)).wrapping_mul(mul);
+        hash_len_16_mul(
+            e.wrapping_add(f)
+                .rotate_right(43)
+                .wrapping_add(g.rotate_right(30))
+                .wrapping_add(h),
+            e.wrapping_add(f.wrapping_add(a).rotate_right(18))
+                .wrapping_add(g),
+            mul,
+        )
+    }
+
+    pub(super) fn hash64(s: &[u8]) -> u64 {
+        const SEED: u64 = 81;
+
+        let len = s.len();
+        if len <= 16 {
+            return hash_len_0_to_16(s);
+        } else if len <= 32 {
+            return hash_len_17_to_32(s);
+        } else if len <= 64 {
+            return hash_len_33_to_64(s);
+        }
+
+        let mut x = SEED;
+        let mut y = SEED.wrapping_mul(K1).wrapping_add(113);
+        let mut z = shift_mix(y.wrapping_mul(K2).wrapping_add(113)).wrapping_mul(K2);
+        let mut v = (0, 0);
+        let mut w = (0, 0);
+        x = x.wrapping_mul(K2).wrapping_add(fetch64(s, 0));
+
+        let end = ((len - 1) / 64) * 64;
+        let last64 = len - 64;
+        let mut i = 0;
+        while i != end {
+            x = x
+                .wrapping_add(y)
+                .wrapping_add(v.0)
+                .wrapping_add(fetch64(s, i + 8))
+                .rotate_right(37)
+                .wrapping_mul(K1);
+            y = y
+                .wrapping_add(v.1)
+                .wrapping_add(fetch64(s, i + 48))
+                .rotate_right(42)
+                .wrapping_mul(K1);
+            x ^= w.1;
+            y = y.wrapping_add(v.0).wrapping_add(fetch64(s, i + 40));
+            z = z.wrapping_add(w.0).rotate_right(33).wrapping_mul(K1);
+            v = weak_hash_len_32_with_seeds(s, i, v.1.wrapping_mul(K1), x.wrapping_add(w.0));
+            w = weak_hash_len_32_with_seeds(
+                s,
+                i + 32,
+                z.wrapping_add(w.1),
+                y.wrapping_add(fetch64(s, i + 16)),
+            );
+            core::mem::swap(&mut z, &mut x);
+            i += 64;
+        }
+
+        let mul = K1.wrapping_add((z & 0xff) << 1);
+        let i = last64;
+        w.0 = w.0.wrapping_add(((len - 1) & 63) as u64);
+        v.0 = v.0.wrapping_add(w.0);
+        w.0 = w.0.wrapping_add(v.0);
+        x = x
+            .wrapping_add(y)
+            .wrapping_add(v.0)
+            .wrapping_add(fetch64(s, i + 8))
+            .rotate_right(37)
+            .wrapping_mul(mul);
+        y = y
+            .wrapping_add(v.1)
+            .wrapping_add(fetch64(s, i + 48))
+            .rotate_right(42)
+            .wrapping_mul(mul);
+        x ^= w.1.wrapping_mul(9);
+        y = y
+            .wrapping_add(v.0.wrapping_mul(9))
+            .wrapping_add(fetch64(s, i + 40));
+        z = z.wrapping_add(w.0).rotate_right(33).wrapping_mul(mul);
+        v = weak_hash_len_32_with_seeds(s, i, v.1.wrapping_mul(mul), x.wrapping_add(w.0));
+        w = weak_hash_len_32_with_seeds(
+            s,
+            i + 32,
+            z.wrapping_add(w.1),
+            y.wrapping_add(fetch64(s, i + 16)),
+        );
+        core::mem::swap(&mut z, &mut x);
+        hash_len_16_mul(
+            hash_len_16_mul(v.0, w.0, mul)
+                .wrapping_add(shift_mix(y).wrapping_mul(K0))
+                .wrapping_add(z),
+            hash_len_16_mul(v.1, w.1, mul).wrapping_add(x),
+            mul,
+        )
+    }
+}
+
+// `farmhashcc`, used for `Fingerprint128`
+mod cc {
+    use super::*;
+
+    fn city_murmur(s: &[u8], seed: (u64, u64)) -> (u64, u64) {
+        let len = s.len();
+        let (mut a, mut b) = seed;
+        let mut c;
+        let mut d;
+        if len <= 16 {
+            a = shift_mix(a.wrapping_mul(K1)).wrapping_mul(K1);
+            c = b.wrapping_mul(K1).wrapping_add(hash_len_0_to_16(s));
+            d = shift_mix(a.wrapping_add(if len >= 8 { fetch64(s, 0) } else { c }));
+        } else {
+            c = hash_len_16(fetch64(s, len - 8).wrapping_add(K1), a);
+            d = hash_len_16(
+                b.wrapping_add(len as u64),
+                c.wrapping_add(fetch64(s, len - 16)),
+            );
+            a = a.wrapping_add(d);
+            let mut i = 0;
+            while i + 16 < len {
+                a ^= shift_mix(fetch64(s, i).wrapping_mul(K1)).wrapping_mul(K1);
+                a = a.wrapping_mul(K1);
+                b ^= a;
+                c ^= shift_mix(fetch64(s, i + 8).wrapping_mul(K1)).wrapping_mul(K1);
+                c = c.wrapping_mul(K1);
+                d ^= c;
+                i += 16;
+            }
+        }
+        a = hash_len_16(a, c);
+        b = hash_len_16(d, b);
+        (a ^ b, hash_len_16(b, a))
+    }
+
+    fn city_hash_128_with_seed(s: &[u8], start: usize, seed: (u64, u64)) -> (u64, u64) {
+        let mut len = s.len() - start;
+        if len < 128 {
+            return city_murmur(&s[start..], seed);
+        }
+
+        let (mut x, mut y) = seed;
+        let mut z = (len as u64).wrapping_mul(K1);
+        let mut i = start;
+        let mut v = (0, 0);
+        let mut w = (0, 0);
+        v.0 = (y ^ K1)
+            .rotate_right(49)
+            .wrapping_mul(K1)
+            .wrapping_add(fetch64(s, i));
+        v.1 =
+            v.0.rotate_right(42)
+                .wrapping_mul(K1)
+                .wrapping_add(fetch64(s, i + 8));
+        w.0 = y
+            .wrapping_add(z)
+            .rotate_right(35)
+            .wrapping_mul(K1)
+            .wrapping_add(x);
+        w.1 = x
+            .wrapping_add(fetch64(s, i + 88))
+            .rotate_right(53)
+            .wrapping_mul(K1);
+
+        while len >= 128 {
+            for _ in 0..2 {
+                x = x
+                    .wrapping_add(y)
+                    .wrapping_add(v.0)
+                    .wrapping_add(fetch64(s, i + 8))
+                    .rotate_right(37)
+                    .wrapping_mul(K1);
+                y = y
+                    .wrapping_add(v.1)
+                    .wrapping_add(fetch64(s, i + 48))
+                    .rotate_right(42)
+                    .wrapping_mul(K1);
+                x ^= w.1;
+                y = y.wrapping_add(v.0).wrapping_add(fetch64(s, i + 40));
+                z = z.wrapping_add(w.0).rotate_right(33).wrapping_mul(K1);
+                v = weak_hash_len_32_with_seeds(s, i, v.1.wrapping_mul(K1), x.wrapping_add(w.0));
+                w = weak_hash_len_32_with_seeds(
+                    s,
+                    i + 32,
+                    z.wrapping_add(w.1),
+                    y.wrapping_add(fetch64(s, i + 16)),
+                );
+                core::mem::swap(&mut z, &mut x);
+                i += 64;
+            }
+            len -= 128;
+        }
+
+        x = x.wrapping_add(v.0.wrapping_add(z).rotate_right(49).wrapping_mul(K0));
+        y = y.wrapping_mul(K0).wrapping_add(w.1.rotate_right(37));
+        z = z.wrapping_mul(K0).wrapping_add(w.0.rotate_right(27));
+        w.0 = w.0.wrapping_mul(9);
+        v.0 = v.0.wrapping_mul(K0);
+
+        // hash up to 4 chunks of 32 bytes from the end of the input
+        let mut tail_done = 0;
+        while tail_done < len {
+            tail_done += 32;
+            let j = i + len - tail_done;
+            y = x
+                .wrapping_add(y)
+                .rotate_right(42)
+                .wrapping_mul(K0)
+                .wrapping_add(v.1);
+            w.0 = w.0.wrapping_add(fetch64(s, j + 16));
+            x = x.wrapping_mul(K0).wrapping_add(w.0);
+            z = z.wrapping_add(w.1).wrapping_add(fetch64(s, j));
+            w.1 = w.1.wrapping_add(v.0);
+            v = weak_hash_len_32_with_seeds(s, j, v.0.wrapping_add(z), v.1);
+            v.0 = v.0.wrapping_mul(K0);
+        }
+
+        x = hash_len_16(x, v.0);
+        y = hash_len_16(y.wrapping_add(z), w.0);
+        (
+            hash_len_16(x.wrapping_add(v.1), w.1).wrapping_add(y),
+            hash_len_16(x.wrapping_add(w.1), y.wrapping_add(v.1)),
+        )
+    }
+
+    pub(super) fn city_hash_128(s: &[u8]) -> (u64, u64) {
+        if s.len() >= 16 {
+            city_hash_128_with_seed(s, 16, (fetch64(s, 0), fetch64(s, 8).wrapping_add(K0)))
+        } else {
+            city_hash_128_with_seed(s, 0, (K0, K1))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::RawBytes, BuildHasher, Hash};
+
+    fn fp64<T: Hash>(x: T) -> u64 {
+        FarmFingerprintBuildHasher::new().hash_one(x)
+    }
+
+    fn fp128<T: Hash>(x: T) -> u128 {
+        FarmFingerprintBuildHasher::new().hash_one(x)
+    }
+
+    #[test]
+    fn lengths() {
+        let mut data = [0; 511];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = (i * 97 + 13) as u8;
+        }
+        for (len, expected_64, expected_128) in [
+            (0, 0x9ae16a3b2f90404f, 0x3cb540c392e51e293df09dfc64c09a2b),
+            (1, 0x8aa334f91c5bc106, 0x3f2e30a909767a9555d9c21abd0c5819),
+            (3, 0xdc1653e99f5757b1, 0xfbc2313117ea4072ca7217cf0e35bcbc),
+            (4, 0x2d790214226c5fbe, 0x8d37c0c574d0d3703153ecf15cbdc521),
+            (7, 0x85fe4e637ae9076b, 0x85058a7dbb6897571b0259f5a3511ad3),
+            (8, 0xb9e8b20266c80a81, 0x8cb1e38458ccd5123b8374b927e85434),
+            (15, 0x9ddcdcfe8d46e13e, 0x802a2ce3a0f9200ea4bf9b81a5d649b6),
+            (16, 0x776c70da521cc0b3, 0xb1253d0f16397c1eb5ccf9b3c4247726),
+            (17, 0x110c23d0e1d2cd0d, 0x87caf20c88a07ffd2ec5fa12fa9884a4),
+            (31, 0x5b2be1a65b096176, 0x3fe52b8131b4afbf1da0d3feb1452cc0),
+            (32, 0x3115860ebbe20e31, 0x001aaf6c6cae8a6cfa20f13dadfc1fd1),
+            (33, 0xdcf262e4ef7469a7, 0x71c4286a575f1097cd2bfb8870844063),
+            (63, 0x310eb4f044551ee8, 0x2c40ad862d86daaf36297830fceca1fe),
+            (64, 0x8d30588ef704198d, 0x8af4c162df995f8a78b44b2c3592ebf9),
+            (65, 0x66ce6aa1904347f1, 0xdea0941db9fd09885c5e9b51a4a797dc),
+            (127, 0xba509f8a342b8986, 0x4f039f3d0cfc5b8c5d578134ba384c69),
+            (128, 0xc184037fe2087a8e, 0x276566e54f3a9e3c58e127642256e9de),
+            (129, 0x406457c3a8188551, 0x867333d81c0d3f082a1e248394163727),
+            (200, 0xdd8b308340a47028, 0xb6795b6e793428442889fd35d78c0dd7),
+            (255, 0xd16ec8be40574008, 0x53fe595290d1b52ca1918ffdac129602),
+            (256, 0x46848bb1447915c9, 0xa03c85509181e85a6e247ccaebad5f55),
+            (300, 0x6832c1ad3664c00d, 0x516fbf94b8d516431b6104178f1261cb),
+            (511, 0x7e83007103826905, 0xd5e0dcc291a1db9cbe304b39bd17edf7),
+        ] {
+            assert_eq!(fingerprint64(&data[..len]), expected_64, "len {len}");
+            assert_eq!(fingerprint128(&data[..len]), expected_128, "len {len}");
+            assert_eq!(fp64(RawBytes(&data[..len])), expected_64, "len {len}");
+            assert_eq!(fp128(RawBytes(&data[..len])), expected_128, "len {len}");
+        }
+    }
+
+    hash_test_vectors! {
+        [fp64]
+        a: 0xb3454265b6df75e3,
+        ab: 0xaa8d6e5242ada51e,
+        abc: 0x24a5b3a074e7f369,
+        abcd: 0x1a5502de4a1f8101,
+        abcde: 0xc22f4663e54e04d4,
+        abcdef: 0xc329379e6a03c2cd,
+        abcdefg: 0x3c40c92b1ccb7355,
+        abcdefgh: 0xfee9d22990c82909,
+        abcdefghi: 0x332c8ed4dae5ba42,
+        abcdefghij: 0x8a3abb6a5f3fb7fb,
+        abcdefghijk: 0x1d6b2cd8cf5aae69,
+        abcdefghijkl: 0x4ec73f47499f33db,
+        abcdefghijklm: 0x76ab2d67057b8b97,
+        abcdefghijklmn: 0x4c159970a55cf6b7,
+        abcdefghijklmno: 0x687dfc2653bb2c1d,
+        abcdefghijklmnop: 0x54630bad7705cebd,
+        abcdefghijklmnopq: 0x9e2106c72d7793a8,
+        abcdefghijklmnopqr: 0x7c952901630a514c,
+        abcdefghijklmnopqrs: 0x96c3cf727e8ee1c9,
+        abcdefghijklmnopqrst: 0x65fed21c0de0497e,
+        abcdefghijklmnopqrstu: 0x9cfcb27c23a9e46a,
+        abcdefghijklmnopqrstuv: 0x2ef7f205bce7f7fc,
+        abcdefghijklmnopqrstuvw: 0xb00fc31e78e8aad7,
+        abcdefghijklmnopqrstuvwx: 0x9d2f5fc719b00ef7,
+        abcdefghijklmnopqrstuvwxy: 0x61c061f2fe328c6b,
+        abcdefghijklmnopqrstuvwxyz: 0x5ead741ce7ac31bd,
+        abcdefghijklmnopqrstuvwxyz0: 0xeb0447679203ec3c,
+        abcdefghijklmnopqrstuvwxyz01: 0x1a45dc5529308780,
+        abcdefghijklmnopqrstuvwxyz012: 0x859ea3849584d43c,
+        abcdefghijklmnopqrstuvwxyz0123: 0x327ba3fc2ad6fafb,
+        abcdefghijklmnopqrstuvwxyz01234: 0x6e388c9af6bb4dc,
+        abcdefghijklmnopqrstuvwxyz012345: 0xcf7d548b260f41a,
+        abcdefghijklmnopqrstuvwxyz0123456: 0xf49eb04963c87e0b,
+        abcdefghijklmnopqrstuvwxyz01234567: 0x28346964dd1b8968,
+        abcdefghijklmnopqrstuvwxyz012345678: 0xea763d23d01c2068,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x788f0b802692bbfb,
+
+        [fp128]
+        a: 0x52a71e38f43be5616e97d6bbdfc0a0c4,
+        ab: 0xcfdbce01c0e7622e13e834f38a6c88b8,
+        abc: 0xa085f09013029e453980b2afd2126c04,
+        abcd: 0x0906d778016538d9b8d7175e11647e82,
+        abcde: 0xf7776b2eaa1583e1940fcbbc468d384f,
+        abcdef: 0x7da95bbe683b00b06f7c444b0a4eb3eb,
+        abcdefg: 0xcfb5d54dd0ac69599e5daa7baf7e4573,
+        abcdefgh: 0x56f19716a4032fcb60f2a826d4d614ef,
+        abcdefghi: 0x61aa40e4e386bd9c7b5fd93102612d91,
+        abcdefghij: 0x0f628f07a0123c87a2dff876385556e8,
+        abcdefghijk: 0x688c4430888e8b0e8ea36aa39a73b93d,
+        abcdefghijkl: 0x2240df1bf772afaadea9b394e19a1591,
+        abcdefghijklm: 0x79d4e34c5a59770c12331566e6a1badc,
+        abcdefghijklmn: 0x987a15efe44feaef875ed15f43a58136,
+        abcdefghijklmno: 0x48cd9c197d51c7639022b88bb489a83b,
+        abcdefghijklmnop: 0xacb1d53a406594f343c9d056b7071aff,
+        abcdefghijklmnopq: 0xe1a046b294d61ad02fce242bec10261e,
+        abcdefghijklmnopqr: 0x6caffaba20a11f3a3267b10eadd448c0,
+        abcdefghijklmnopqrs: 0xf9fd72163a486e8058d1306f36893006,
+        abcdefghijklmnopqrst: 0x159d0a6abb74f200e61905d41903cc3f,
+        abcdefghijklmnopqrstu: 0xbb7c14549f86f579b737e40363cd7d60,
+        abcdefghijklmnopqrstuv: 0xe4735e2d9a0730a5048f0cf4772b1052,
+        abcdefghijklmnopqrstuvw: 0xc9c3ddb1b5475522bcf60a7dee47e574,
+        abcdefghijklmnopqrstuvwx: 0x0862daf7e5240f16c1a0e31a1c192241,
+        abcdefghijklmnopqrstuvwxy: 0x87258d6e1f483ad3143999f25c551c84,
+        abcdefghijklmnopqrstuvwxyz: 0x94b10b6be3c658e7eef61c31242f468d,
+        abcdefghijklmnopqrstuvwxyz0: 0x5172f92ecf45c59a41eab94245bb7b71,
+        abcdefghijklmnopqrstuvwxyz01: 0x97299330a7fdaa935e10d7f98ac94caa,
+        abcdefghijklmnopqrstuvwxyz012: 0xda63c5d0cd6c3133c5bd51a0066ca421,
+        abcdefghijklmnopqrstuvwxyz0123: 0x8cea89b6420a98942c22f52097165092,
+        abcdefghijklmnopqrstuvwxyz01234: 0x8380d31dc2bb80c9f655a8b34ace5c8c,
+        abcdefghijklmnopqrstuvwxyz012345: 0x8fd17bd02fde6207f4cbd56b41755ac8,
+        abcdefghijklmnopqrstuvwxyz0123456: 0xecb888f95f16075541a80a696cd65fb4,
+        abcdefghijklmnopqrstuvwxyz01234567: 0xbc909d8bde92b9f5571a837a399c006e,
+        abcdefghijklmnopqrstuvwxyz012345678: 0xd58fa92935f22afb68438e7a4aa2b3b8,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x33b306e505032e01e3269642685768d5,
+    }
+}
@@ -0,0 +1,234 @@
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display},
+};
+
+use crate::{Hash, HasherWrite};
+
+/// Extension trait for hashing floats.
+///
+/// Floats don't implement [`Hash`], because `0.0 == -0.0` and `NaN != NaN`. This trait hashes
+/// them by their canonical bit pattern instead, where `-0.0` is the same as `0.0` and all NaNs
+/// are the same, and converts them to the [`TotalF32`] and [`TotalF64`] wrappers, which
+/// implement [`Hash`], `Eq` and `Ord` in the same way.
+///
+/// ```
+/// # use anyhash::FloatHashExt;
+/// assert_eq!((-0.0_f64).canonical_bits(), 0.0_f64.canonical_bits());
+/// assert_eq!(f64::NAN.total(), (-f64::NAN).total());
+/// ```
+pub trait FloatHashExt: Copy {
+    /// Unsigned integer type with the same size as the float.
+    type Bits;
+
+    /// Wrapper type that implements [`Hash`], `Eq` and `Ord`.
+    type Total;
+
+    /// Get the canonical bit pattern of the float, with `-0.0` normalized to `0.0` and all NaNs
+    /// normalized to the same positive quiet NaN.
+    fn canonical_bits(self) -> Self::Bits;
+
+    /// Wrap the float in a type that implements [`Hash`], `Eq` and `Ord`.
+    fn total(self) -> Self::Total;
+
+    /// Feed the float into the given [`HasherWrite`] with [`HasherWrite::write_f32`] or
+    /// [`HasherWrite::write_f64`], which write the canonical bit pattern by default.
+    fn hash_canonical<H: HasherWrite>(self, state: &mut H);
+}
+
+macro_rules! impl_total_float {
+    ($($(#[$attr:meta])* $total:ident($t:ty, $bits:ty, $nan:literal): $write:ident, $write_bits:ident;)*) => { $(
+        $(#[$attr])*
+        #[derive(Clone, Copy, Default)]
+        #[repr(transparent)]
+        pub struct $total(pub $t);
+
+        impl $total {
+            /// Get the canonical bit pattern of the wrapped float.
+            #[inline]
+            pub fn canonical_bits(self) -> $bits {
+                if self.0.is_nan() {
+                    $nan
+                } else if self.0 == 0.0 {
+                    0
+                } else {
+                    self.0.to_bits()
+                }
+            }
+
+            /// Unwrap the wrapped float.
+            #[inline]
+            pub const fn into_inner(self) -> $t {
+                self.0
+            }
+
+            #[inline]
+            fn canonical(self) -> $t {
+                <$t>::from_bits(self.canonical_bits())
+            }
+        }
+
+        impl FloatHashExt for $t {
+            type Bits = $bits;
+            type Total = $total;
+
+            #[inline]
+            fn canonical_bits(self) -> $bits {
+                $total(self).canonical_bits()
+            }
+
+            #[inline]
+            fn total(self) -> $total {
+                $total(self)
+            }
+
+            #[inline]
+            fn hash_canonical<H: HasherWrite>(self, state: &mut H) {
+                state.$write(self);
+            }
+        }
+
+        impl Hash for $total {
+            #[inline]
+            fn hash<H: HasherWrite>(&self, state: &mut H) {
+                self.0.hash_canonical(state);
+            }
+        }
+
+        impl core::hash::Hash for $total {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                state.$write_bits(self.canonical_bits());
+            }
+        }
+
+        impl PartialEq for $total {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.canonical_bits() == other.canonical_bits()
+            }
+        }
+
+        impl Eq for $total {}
+
+        impl PartialOrd for $total {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $total {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.canonical().total_cmp(&other.canonical())
+            }
+        }
+
+        impl From<$t> for $total {
+            #[inline]
+            fn from(value: $t) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$total> for $t {
+            #[inline]
+            fn from(value: $total) -> Self {
+                value.0
+            }
+        }
+
+        impl Debug for $total {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl Display for $total {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+    )* };
+}
+
+impl_total_float! {
+    /// `f32` wrapper that implements [`Hash`], `Eq` and `Ord`.
+    ///
+    /// The float is hashed and compared by its canonical bit pattern, so `-0.0` is equal to
+    /// `0.0`, and all NaNs are equal to each other and greater than all other values.
+    /// Otherwise it's ordered like [`f32::total_cmp`].
+    TotalF32(f32, u32, 0x7fc0_0000): write_f32, write_u32;
+
+    /// `f64` wrapper that implements [`Hash`], `Eq` and `Ord`.
+    ///
+    /// The float is hashed and compared by its canonical bit pattern, so `-0.0` is equal to
+    /// `0.0`, and all NaNs are equal to each other and greater than all other values.
+    /// Otherwise it's ordered like [`f64::total_cmp`].
+    TotalF64(f64, u64, 0x7ff8_0000_0000_0000): write_f64, write_u64;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Fnv(u64);
+
+    impl HasherWrite for Fnv {
+        fn write(&mut self, bytes: &[u8]) {
+            for &b in bytes {
+                self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3);
+            }
+        }
+    }
+
+    fn hash<T: Hash>(value: T) -> u64 {
+        let mut hasher = Fnv(0xcbf29ce484222325);
+        value.hash(&mut hasher);
+        hasher.0
+    }
+
+    #[test]
+    fn canonical() {
+        assert_eq!(TotalF32(-0.0), TotalF32(0.0));
+        assert_eq!(hash(TotalF32(-0.0)), hash(TotalF32(0.0)));
+        assert_eq!(TotalF64(f64::NAN), TotalF64(-f64::NAN));
+        assert_eq!(
+            hash(TotalF64(f64::NAN)),
+            hash(TotalF64(f64::from_bits(0x7ff0_0000_0000_0001)))
+        );
+        assert_ne!(hash(TotalF64(1.0)), hash(TotalF64(-1.0)));
+    }
+
+    #[test]
+    fn write_float() {
+        let mut a = Fnv(0);
+        a.write_f32(-0.0);
+        a.write_f64(-f64::NAN);
+        let mut b = Fnv(0);
+        b.write_u32(0);
+        b.write_u64(0x7ff8_0000_0000_0000);
+        assert_eq!(a.0, b.0);
+    }
+
+    #[test]
+    fn ordering() {
+        let mut values = [
+            TotalF64(f64::NAN),
+            TotalF64(1.0),
+            TotalF64(-0.0),
+            TotalF64(f64::NEG_INFINITY),
+            TotalF64(-1.0),
+            TotalF64(f64::INFINITY),
+        ];
+        values.sort();
+        assert_eq!(
+            values.map(TotalF64::canonical_bits),
+            [f64::NEG_INFINITY, -1.0, 0.0, 1.0, f64::INFINITY, f64::NAN].map(f64::canonical_bits)
+        );
+        assert_eq!(TotalF32(0.0).cmp(&TotalF32(-0.0)), Ordering::Equal);
+    }
+}
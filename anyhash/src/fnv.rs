@@ -4,7 +4,7 @@ use core::{fmt::Debug, marker::PhantomData, ops::BitXorAssign};
 
 use crate::{
     impl_core_build_hasher, impl_core_hasher, BuildHasher, EndianIndependentAlgorithm, Hasher,
-    HasherWrite,
+    HasherCheckpoint, HasherWrite, SeedableBuildHasher,
 };
 
 #[cfg(feature = "bnum")]
@@ -45,6 +45,14 @@ impl<T: Type, V: Version> Default for FnvBuildHasher<T, V> {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize, V> zeroize::Zeroize for FnvBuildHasher<T, V> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 impl<T: Type, V: Version> BuildHasher<T> for FnvBuildHasher<T, V> {
     type Hasher = Fnv<T, V>;
 
@@ -54,6 +62,13 @@ impl<T: Type, V: Version> BuildHasher<T> for FnvBuildHasher<T, V> {
     }
 }
 
+impl<T: Type, V: Version> SeedableBuildHasher for FnvBuildHasher<T, V> {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seed(T::from_seed(seed))
+    }
+}
+
 /// [`BuildHasher`] implementation for the [`Fnv1a`] hasher using the default seed (zero sized).
 pub type Fnv1aBuildHasherDefault = FnvBuildHasherDefault<V1A>;
 
@@ -94,6 +109,57 @@ pub type Fnv1aHashSet<T> = std::collections::HashSet<T, Fnv1aBuildHasher<u64>>;
 /// `HashSet` from `std` configured to use the [`Fnv1a64`] hasher with the default seed.
 pub type Fnv1aHashSetDefault<T> = std::collections::HashSet<T, Fnv1aBuildHasherDefault>;
 
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`Fnv1a64`] hasher.
+pub type Fnv1aHeaplessIndexMap<K, V, const N: usize> =
+    heapless::IndexMap<K, V, Fnv1aBuildHasher<u64>, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`Fnv1a64`] hasher with the default seed.
+pub type Fnv1aHeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, Fnv1aBuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`Fnv1a64`] hasher.
+pub type Fnv1aHeaplessIndexSet<T, const N: usize> = heapless::IndexSet<T, Fnv1aBuildHasher<u64>, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`Fnv1a64`] hasher with the default seed.
+pub type Fnv1aHeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, Fnv1aBuildHasherDefault, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`Fnv1a64`] hasher.
+pub type Fnv1aHashBrownMap<K, V> = hashbrown::HashMap<K, V, Fnv1aBuildHasher<u64>>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`Fnv1a64`] hasher with the default seed.
+pub type Fnv1aHashBrownMapDefault<K, V> = hashbrown::HashMap<K, V, Fnv1aBuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`Fnv1a64`] hasher.
+pub type Fnv1aHashBrownSet<T> = hashbrown::HashSet<T, Fnv1aBuildHasher<u64>>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`Fnv1a64`] hasher with the default seed.
+pub type Fnv1aHashBrownSetDefault<T> = hashbrown::HashSet<T, Fnv1aBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`Fnv1a64`] hasher.
+pub type Fnv1aIndexMap<K, V> = indexmap::IndexMap<K, V, Fnv1aBuildHasher<u64>>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`Fnv1a64`] hasher with the default seed.
+pub type Fnv1aIndexMapDefault<K, V> = indexmap::IndexMap<K, V, Fnv1aBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`Fnv1a64`] hasher.
+pub type Fnv1aIndexSet<T> = indexmap::IndexSet<T, Fnv1aBuildHasher<u64>>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`Fnv1a64`] hasher with the default seed.
+pub type Fnv1aIndexSetDefault<T> = indexmap::IndexSet<T, Fnv1aBuildHasherDefault>;
+
 /// Hasher using the Fnv1a 32-bit algorithm.
 pub type Fnv1a32 = Fnv1a<u32>;
 
@@ -130,6 +196,9 @@ mod sealed {
 
         /// Wrapping multiply.
         fn wrapping_mul(self, rhs: Self) -> Self;
+
+        /// Convert a 128-bit seed to this type.
+        fn from_seed(seed: u128) -> Self;
     }
 
     /// Fnv version configuration.
@@ -147,6 +216,11 @@ impl Type for u32 {
     fn wrapping_mul(self, rhs: Self) -> Self {
         self.wrapping_mul(rhs)
     }
+
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        seed as Self
+    }
 }
 
 impl Type for u64 {
@@ -157,6 +231,11 @@ impl Type for u64 {
     fn wrapping_mul(self, rhs: Self) -> Self {
         self.wrapping_mul(rhs)
     }
+
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        seed as Self
+    }
 }
 
 impl Type for u128 {
@@ -167,6 +246,11 @@ impl Type for u128 {
     fn wrapping_mul(self, rhs: Self) -> Self {
         self.wrapping_mul(rhs)
     }
+
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        seed
+    }
 }
 
 #[cfg(feature = "bnum")]
@@ -178,6 +262,11 @@ impl Type for U128 {
     fn wrapping_mul(self, rhs: Self) -> Self {
         self.wrapping_mul(rhs)
     }
+
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::from_digits([seed as u64, (seed >> 64) as u64])
+    }
 }
 
 #[cfg(feature = "bnum")]
@@ -194,6 +283,14 @@ impl Type for U256 {
     fn wrapping_mul(self, rhs: Self) -> Self {
         self.wrapping_mul(rhs)
     }
+
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        let mut digits = [0; 4];
+        digits[0] = seed as u64;
+        digits[1] = (seed >> 64) as u64;
+        Self::from_digits(digits)
+    }
 }
 
 #[cfg(feature = "bnum")]
@@ -214,6 +311,14 @@ impl Type for U512 {
     fn wrapping_mul(self, rhs: Self) -> Self {
         self.wrapping_mul(rhs)
     }
+
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        let mut digits = [0; 8];
+        digits[0] = seed as u64;
+        digits[1] = (seed >> 64) as u64;
+        Self::from_digits(digits)
+    }
 }
 
 #[cfg(feature = "bnum")]
@@ -260,6 +365,14 @@ impl Type for U1024 {
     fn wrapping_mul(self, rhs: Self) -> Self {
         self.wrapping_mul(rhs)
     }
+
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        let mut digits = [0; 16];
+        digits[0] = seed as u64;
+        digits[1] = (seed >> 64) as u64;
+        Self::from_digits(digits)
+    }
 }
 
 /// Selector for Fnv1.
@@ -281,6 +394,32 @@ impl Version for V1A {
 /// Hasher using the Fnv1a algorithm.
 pub type Fnv1a<T> = Fnv<T, V1A>;
 
+macro_rules! impl_fnv1a_const {
+    ($($fn:ident: $t:ty),* $(,)?) => { $(
+        #[doc = concat!("Calculate the ", stringify!($t), " Fnv1a hash of `bytes` at compile time.")]
+        ///
+        /// This gives the same result as writing `bytes` to a [`Fnv1a`] hasher with the default
+        /// seed. Use it to make constants, e.g. for hashes of strings used as `match` keys.
+        #[inline]
+        pub const fn $fn(bytes: &[u8]) -> $t {
+            let mut hash = <$t as Type>::OFFSET_BASIS;
+            let mut i = 0;
+            while i < bytes.len() {
+                hash ^= bytes[i] as $t;
+                hash = hash.wrapping_mul(<$t as Type>::PRIME);
+                i += 1;
+            }
+            hash
+        }
+    )* };
+}
+
+impl_fnv1a_const! {
+    fnv1a_32: u32,
+    fnv1a_64: u64,
+    fnv1a_128: u128,
+}
+
 #[derive(Clone)]
 /// Hasher using a variant of the Fnv algorithm.
 pub struct Fnv<T, V>(T, PhantomData<fn() -> V>);
@@ -311,6 +450,14 @@ impl<T: Type, V: Version> Default for Fnv<T, V> {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize, V> zeroize::Zeroize for Fnv<T, V> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 impl<T: Type, V: Version> HasherWrite for Fnv<T, V> {
     #[inline]
     fn write(&mut self, bytes: &[u8]) {
@@ -333,6 +480,55 @@ impl<T: Type, V: Version> Hasher<T> for Fnv<T, V> {
     }
 }
 
+impl<T: Type, V: Version> HasherCheckpoint for Fnv<T, V> {
+    type Snapshot = T;
+
+    #[inline]
+    fn checkpoint(&self) -> T {
+        self.0
+    }
+
+    #[inline]
+    fn restore(&mut self, snapshot: T) {
+        self.0 = snapshot;
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+
+    impl<T: Type + Serialize, V: Version> Serialize for FnvBuildHasher<T, V> {
+        #[inline]
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.0.serialize(serializer)
+        }
+    }
+
+    impl<'de, T: Type + Deserialize<'de>, V: Version> Deserialize<'de> for FnvBuildHasher<T, V> {
+        #[inline]
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            T::deserialize(deserializer).map(Self::with_seed)
+        }
+    }
+
+    impl<T: Type + Serialize, V: Version> Serialize for Fnv<T, V> {
+        #[inline]
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.0.serialize(serializer)
+        }
+    }
+
+    impl<'de, T: Type + Deserialize<'de>, V: Version> Deserialize<'de> for Fnv<T, V> {
+        #[inline]
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            T::deserialize(deserializer).map(Self::with_seed)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::any::type_name;
@@ -385,7 +581,22 @@ mod tests {
         assert_eq!(fnv1a_custom_seed(()), 0x5555555555555555);
     }
 
-    test_bytes_hash! {
+    #[test]
+    fn fnv1a_const() {
+        const HASH: u64 = fnv1a_64(b"abcdef");
+        assert_eq!(HASH, 0xd80bda3fbe244a0a);
+        fn hash<T>(mut hasher: impl Hasher<T>, bytes: &[u8]) -> T {
+            hasher.write(bytes);
+            hasher.finish()
+        }
+        for bytes in [&b""[..], b"a", b"hello world"] {
+            assert_eq!(fnv1a_32(bytes), hash(Fnv1a32::new(), bytes));
+            assert_eq!(fnv1a_64(bytes), hash(Fnv1a64::new(), bytes));
+            assert_eq!(fnv1a_128(bytes), hash(Fnv1a128::new(), bytes));
+        }
+    }
+
+    hash_test_vectors! {
         [fnv1a_default_seed]
         a: 0xaf63dc4c8601ec8c,
         ab: 0x89c4407b545986a,
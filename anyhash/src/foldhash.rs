@@ -0,0 +1,693 @@
+//! Hasher and collections using the foldhash algorithm.
+
+// based on version 0.2 of the reference implementation at https://github.com/orlp/foldhash
+
+use core::marker::PhantomData;
+
+use crate::{
+    impl_core_build_hasher, impl_core_hasher, random::ambient_seed, BuildHasher,
+    BuildHasherDefault, EndianIndependentAlgorithm, Hasher, HasherWrite, SeedableBuildHasher,
+};
+
+impl_core_build_hasher!(impl<V: Variant> FoldBuildHasher<V>);
+impl_core_hasher!(impl<V: Variant> FoldHasher<V>);
+
+// hexadecimal digits of pi
+const ARBITRARY0: u64 = 0x243f6a8885a308d3;
+const ARBITRARY3: u64 = 0x082efa98ec4e6c89;
+const ARBITRARY4: u64 = 0x452821e638d01377;
+const ARBITRARY5: u64 = 0xbe5466cf34e90c6c;
+
+const FIXED_SHARED_SEED: [u64; 6] = [
+    0xc0ac29b7c97c50dd,
+    0x3f84d5b5b5470917,
+    0x9216d5d98979fb1b,
+    0xd1310ba698dfb5ac,
+    0x2ffd72dbd01adfb7,
+    0xb8e1afed6a267e96,
+];
+
+#[inline(always)]
+const fn folded_multiply(x: u64, y: u64) -> u64 {
+    let r = x as u128 * y as u128;
+    r as u64 ^ (r >> 64) as u64
+}
+
+#[inline(always)]
+fn r4(bytes: &[u8], i: usize) -> u64 {
+    u32::from_le_bytes(bytes[i..i + 4].try_into().unwrap()) as u64
+}
+
+#[inline(always)]
+fn r8(bytes: &[u8], i: usize) -> u64 {
+    u64::from_le_bytes(bytes[i..i + 8].try_into().unwrap())
+}
+
+/// Generate the shared seed from a 64-bit seed, like `SharedSeed::from_u64`.
+const fn shared_seed(seed: u64) -> [u64; 6] {
+    // zeroes are a weak point for the multiply-mix, so force some bits on
+    const FORCED_ONES: u64 = (1 << 63) | (1 << 31) | 1;
+    let mut seeds = [0; 6];
+    let mut seed = seed;
+    let mut i = 0;
+    while i < 6 {
+        seed = folded_multiply(seed, ARBITRARY5);
+        seed = folded_multiply(seed, ARBITRARY5);
+        seed = folded_multiply(seed, ARBITRARY5);
+        seeds[i] = seed | FORCED_ONES;
+        i += 1;
+    }
+    seeds
+}
+
+use sealed::Variant;
+mod sealed {
+    /// Variant trait for foldhash.
+    pub trait Variant: Clone + core::fmt::Debug + Default {
+        /// Whether to spend some extra time to improve the quality of the hash.
+        const QUALITY: bool;
+    }
+}
+
+/// Selector for the fast variant of foldhash, which is optimized for use in hash maps.
+#[derive(Clone, Debug, Default)]
+pub struct Fast;
+
+/// Selector for the quality variant of foldhash, which has better statistical properties
+/// for algorithms like HyperLogLog or MinHash.
+#[derive(Clone, Debug, Default)]
+pub struct Quality;
+
+impl Variant for Fast {
+    const QUALITY: bool = false;
+}
+
+impl Variant for Quality {
+    const QUALITY: bool = true;
+}
+
+/// [`BuildHasher`] implementation for the [`FoldHasher`] hasher.
+///
+/// [`FoldBuildHasher::new`] and `Default` use a random seed, so each `FoldBuildHasher`
+/// calculates different hashes, which gives some resistance against HashDoS attacks. The seed
+/// is generated with [`ambient_seed`]. If you need the same hashes every time, use
+/// [`FoldBuildHasher::with_seed`], [`FoldBuildHasher::with_seeds`] or the zero sized
+/// [`FoldBuildHasherDefault`] instead.
+#[derive(Clone, Debug)]
+pub struct FoldBuildHasher<V: Variant = Fast> {
+    per_hasher_seed: u64,
+    shared_seed: [u64; 6],
+    _variant: PhantomData<fn() -> V>,
+}
+
+impl<V: Variant> FoldBuildHasher<V> {
+    /// Create a [`BuildHasher`] for [`FoldHasher`] using a random seed.
+    #[inline]
+    pub fn new() -> Self {
+        Self::from_seed(ambient_seed())
+    }
+
+    /// Create a [`BuildHasher`] for [`FoldHasher`] with a custom per-hasher seed and the
+    /// default shared seed. This is compatible with `FixedState::with_seed` from the
+    /// reference implementation.
+    #[inline]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self::with_shared_seed(seed, FIXED_SHARED_SEED)
+    }
+
+    /// Create a [`BuildHasher`] for [`FoldHasher`] with a custom per-hasher seed and a shared
+    /// seed generated from `shared_seed`. This is compatible with
+    /// `SeedableRandomState::with_seed` and `SharedSeed::from_u64` from the reference
+    /// implementation.
+    #[inline]
+    pub const fn with_seeds(seed: u64, shared_seed: u64) -> Self {
+        Self::with_shared_seed(seed, self::shared_seed(shared_seed))
+    }
+
+    #[inline]
+    const fn with_shared_seed(seed: u64, shared_seed: [u64; 6]) -> Self {
+        let seed = if V::QUALITY {
+            folded_multiply(seed, ARBITRARY4)
+        } else {
+            seed
+        };
+        Self {
+            per_hasher_seed: seed ^ ARBITRARY3,
+            shared_seed,
+            _variant: PhantomData,
+        }
+    }
+}
+
+impl<V: Variant> BuildHasher<u64> for FoldBuildHasher<V> {
+    type Hasher = FoldHasher<V>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seeds(self.per_hasher_seed, self.shared_seed)
+    }
+}
+
+impl<V: Variant> SeedableBuildHasher for FoldBuildHasher<V> {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seeds(seed as u64, (seed >> 64) as u64)
+    }
+}
+
+impl<V: Variant> Default for FoldBuildHasher<V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Variant> zeroize::Zeroize for FoldBuildHasher<V> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.per_hasher_seed.zeroize();
+        self.shared_seed.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Variant> Drop for FoldBuildHasher<V> {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Variant> zeroize::ZeroizeOnDrop for FoldBuildHasher<V> {}
+
+/// [`BuildHasher`] implementation for the [`FoldHasher`] hasher using the default seed (zero sized).
+pub type FoldBuildHasherDefault<V = Fast> = BuildHasherDefault<FoldHasher<V>>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the fast [`FoldHasher`] hasher with a random seed.
+pub type FoldHashMap<K, V> = std::collections::HashMap<K, V, FoldBuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the fast [`FoldHasher`] hasher with the default seed.
+pub type FoldHashMapDefault<K, V> = std::collections::HashMap<K, V, FoldBuildHasherDefault>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the fast [`FoldHasher`] hasher with a random seed.
+pub type FoldHashSet<T> = std::collections::HashSet<T, FoldBuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the fast [`FoldHasher`] hasher with the default seed.
+pub type FoldHashSetDefault<T> = std::collections::HashSet<T, FoldBuildHasherDefault>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the fast [`FoldHasher`] hasher with a random seed.
+pub type FoldHeaplessIndexMap<K, V, const N: usize> = heapless::IndexMap<K, V, FoldBuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the fast [`FoldHasher`] hasher with the default
+/// seed.
+pub type FoldHeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, FoldBuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the fast [`FoldHasher`] hasher with a random seed.
+pub type FoldHeaplessIndexSet<T, const N: usize> = heapless::IndexSet<T, FoldBuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the fast [`FoldHasher`] hasher with the default
+/// seed.
+pub type FoldHeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, FoldBuildHasherDefault, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the fast [`FoldHasher`] hasher with a random seed.
+pub type FoldHashBrownMap<K, V> = hashbrown::HashMap<K, V, FoldBuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the fast [`FoldHasher`] hasher with the default
+/// seed.
+pub type FoldHashBrownMapDefault<K, V> = hashbrown::HashMap<K, V, FoldBuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the fast [`FoldHasher`] hasher with a random seed.
+pub type FoldHashBrownSet<T> = hashbrown::HashSet<T, FoldBuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the fast [`FoldHasher`] hasher with the default
+/// seed.
+pub type FoldHashBrownSetDefault<T> = hashbrown::HashSet<T, FoldBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the fast [`FoldHasher`] hasher with a random seed.
+pub type FoldIndexMap<K, V> = indexmap::IndexMap<K, V, FoldBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the fast [`FoldHasher`] hasher with the default
+/// seed.
+pub type FoldIndexMapDefault<K, V> = indexmap::IndexMap<K, V, FoldBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the fast [`FoldHasher`] hasher with a random seed.
+pub type FoldIndexSet<T> = indexmap::IndexSet<T, FoldBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the fast [`FoldHasher`] hasher with the default
+/// seed.
+pub type FoldIndexSetDefault<T> = indexmap::IndexSet<T, FoldBuildHasherDefault>;
+
+/// Hasher using the foldhash algorithm.
+///
+/// The [`Fast`] variant is optimized for hash maps, and the [`Quality`] variant has better
+/// statistical properties. Like the reference implementation, each call to `write` is
+/// processed separately, so the hash of a byte stream depends on how it was split up into
+/// writes. Written bytes hash the same as with the reference implementation on 64-bit little
+/// endian hosts, but integers are hashed as bytes, so they don't use its faster integer path.
+#[derive(Clone)]
+pub struct FoldHasher<V: Variant = Fast> {
+    accumulator: u64,
+    seeds: [u64; 6],
+    _variant: PhantomData<fn() -> V>,
+}
+
+impl<V: Variant> FoldHasher<V> {
+    /// Create a new `FoldHasher` using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seeds(ARBITRARY3, FIXED_SHARED_SEED)
+    }
+
+    #[inline]
+    const fn with_seeds(per_hasher_seed: u64, seeds: [u64; 6]) -> Self {
+        Self {
+            accumulator: per_hasher_seed,
+            seeds,
+            _variant: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    fn hash_bytes_short(&self, bytes: &[u8]) -> u64 {
+        let len = bytes.len();
+        let mut s0 = self.accumulator;
+        let mut s1 = self.seeds[1];
+        if len >= 8 {
+            s0 ^= r8(bytes, 0);
+            s1 ^= r8(bytes, len - 8);
+        } else if len >= 4 {
+            s0 ^= r4(bytes, 0);
+            s1 ^= r4(bytes, len - 4);
+        } else if len > 0 {
+            s0 ^= bytes[0] as u64;
+            s1 ^= (bytes[len - 1] as u64) << 8 | bytes[len / 2] as u64;
+        }
+        folded_multiply(s0, s1)
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn hash_bytes_long(&self, mut v: &[u8]) -> u64 {
+        let seeds = &self.seeds;
+        let mut s0 = self.accumulator;
+        let mut s1 = s0.wrapping_add(seeds[1]);
+
+        if v.len() > 128 {
+            let mut s2 = s0.wrapping_add(seeds[2]);
+            let mut s3 = s0.wrapping_add(seeds[3]);
+
+            if v.len() > 256 {
+                let mut s4 = s0.wrapping_add(seeds[4]);
+                let mut s5 = s0.wrapping_add(seeds[5]);
+                loop {
+                    s0 = folded_multiply(r8(v, 0) ^ s0, r8(v, 48) ^ seeds[0]);
+                    s1 = folded_multiply(r8(v, 8) ^ s1, r8(v, 56) ^ seeds[0]);
+                    s2 = folded_multiply(r8(v, 16) ^ s2, r8(v, 64) ^ seeds[0]);
+                    s3 = folded_multiply(r8(v, 24) ^ s3, r8(v, 72) ^ seeds[0]);
+                    s4 = folded_multiply(r8(v, 32) ^ s4, r8(v, 80) ^ seeds[0]);
+                    s5 = folded_multiply(r8(v, 40) ^ s5, r8(v, 88) ^ seeds[0]);
+                    v = &v[96..];
+                    if v.len() <= 256 {
+                        break;
+                    }
+                }
+                s0 ^= s4;
+                s1 ^= s5;
+            }
+
+            loop {
+                s0 = folded_multiply(r8(v, 0) ^ s0, r8(v, 32) ^ seeds[0]);
+                s1 = folded_multiply(r8(v, 8) ^ s1, r8(v, 40) ^ seeds[0]);
+                s2 = folded_multiply(r8(v, 16) ^ s2, r8(v, 48) ^ seeds[0]);
+                s3 = folded_multiply(r8(v, 24) ^ s3, r8(v, 56) ^ seeds[0]);
+                v = &v[64..];
+                if v.len() <= 128 {
+                    break;
+                }
+            }
+            s0 ^= s2;
+            s1 ^= s3;
+        }
+
+        let len = v.len();
+        s0 = folded_multiply(r8(v, 0) ^ s0, r8(v, len - 16) ^ seeds[0]);
+        s1 = folded_multiply(r8(v, 8) ^ s1, r8(v, len - 8) ^ seeds[0]);
+        if len >= 32 {
+            s0 = folded_multiply(r8(v, 16) ^ s0, r8(v, len - 32) ^ seeds[0]);
+            s1 = folded_multiply(r8(v, 24) ^ s1, r8(v, len - 24) ^ seeds[0]);
+            if len >= 64 {
+                s0 = folded_multiply(r8(v, 32) ^ s0, r8(v, len - 48) ^ seeds[0]);
+                s1 = folded_multiply(r8(v, 40) ^ s1, r8(v, len - 40) ^ seeds[0]);
+                if len >= 96 {
+                    s0 = folded_multiply(r8(v, 48) ^ s0, r8(v, len - 64) ^ seeds[0]);
+                    s1 = folded_multiply(r8(v, 56) ^ s1, r8(v, len - 56) ^ seeds[0]);
+                }
+            }
+        }
+        s0 ^ s1
+    }
+}
+
+impl<V: Variant> EndianIndependentAlgorithm for FoldHasher<V> {}
+
+impl<V: Variant> Default for FoldHasher<V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Variant> zeroize::Zeroize for FoldHasher<V> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.accumulator.zeroize();
+        self.seeds.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Variant> Drop for FoldHasher<V> {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Variant> zeroize::ZeroizeOnDrop for FoldHasher<V> {}
+
+impl<V: Variant> HasherWrite for FoldHasher<V> {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.accumulator = self.accumulator.rotate_right(bytes.len() as u32);
+        self.accumulator = if bytes.len() <= 16 {
+            self.hash_bytes_short(bytes)
+        } else {
+            self.hash_bytes_long(bytes)
+        };
+    }
+}
+
+impl<V: Variant> Hasher<u64> for FoldHasher<V> {
+    #[inline]
+    fn finish(&self) -> u64 {
+        if V::QUALITY {
+            folded_multiply(self.accumulator, ARBITRARY0)
+        } else {
+            self.accumulator
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::RawBytes, Hash};
+
+    fn fast<T: Hash>(x: T) -> u64 {
+        FoldBuildHasherDefault::<Fast>::new().hash_one(x)
+    }
+
+    fn quality<T: Hash>(x: T) -> u64 {
+        FoldBuildHasherDefault::<Quality>::new().hash_one(x)
+    }
+
+    fn hash<V: Variant>(build_hasher: &FoldBuildHasher<V>, bytes: &[u8]) -> u64 {
+        let mut hasher = build_hasher.build_hasher();
+        hasher.write(bytes);
+        hasher.finish()
+    }
+
+    #[test]
+    fn lengths() {
+        let mut data = [0; 600];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = (i * 7 + 1) as u8;
+        }
+        for (len, fast, quality, fast_shared, quality_shared) in [
+            (
+                0,
+                0x43ec04b47a68171d,
+                0xfc246988e2e8162f,
+                0x650fc00dec9500c4,
+                0x9cfd5dbf450bad8d,
+            ),
+            (
+                1,
+                0x5e5d428a6556650,
+                0xb9992878f5538f9d,
+                0x4327d0e901fe6081,
+                0x707ab70529d10500,
+            ),
+            (
+                3,
+                0x9065605209f42b06,
+                0xf7b3f4020e60d244,
+                0xfa5b906d66a54ce8,
+                0xa366d594973562d1,
+            ),
+            (
+                4,
+                0xe01f0d3456400497,
+                0x58bf6e13bcef042d,
+                0x2556a58b22719ce1,
+                0x3b223236933c00ec,
+            ),
+            (
+                7,
+                0xd857a0e5da941dd5,
+                0xd0c25517dbd96bd2,
+                0xa8cf146e2fbab899,
+                0xbf1818eee2b2cfb6,
+            ),
+            (
+                8,
+                0x48daa83ba59c6239,
+                0xfd987eea8dab5fb7,
+                0xf23b2bcc3b9be7ca,
+                0x7f7e6dc881913586,
+            ),
+            (
+                16,
+                0x135d99ab84eb3b17,
+                0x8b090ce7c925deb4,
+                0xe4d641dbba89ef9d,
+                0x7158b35ccd9eeb55,
+            ),
+            (
+                17,
+                0x33ee7836f5da1abc,
+                0x8ad861507f397a4f,
+                0xa086f15dac1fa50e,
+                0x41a5d81aa008d214,
+            ),
+            (
+                32,
+                0xa55b823d97753ee1,
+                0xf4ed57f3b18b6a29,
+                0xa3d6e9d2a45d1e6e,
+                0x579b8903ca6e5717,
+            ),
+            (
+                63,
+                0xb2be592797678a88,
+                0x4648b1f730650f63,
+                0xbce199416591acc0,
+                0xd4d2bcff2e460c7e,
+            ),
+            (
+                64,
+                0xe68171e24a39acdb,
+                0x1075d565dad7f165,
+                0x5b17f0b34b93d1d5,
+                0xf52885cf30508221,
+            ),
+            (
+                96,
+                0x3134eb7c15c4e61e,
+                0x872bba4bde8eb10d,
+                0xad30f13df719fead,
+                0xb829d4dc1ce52aa5,
+            ),
+            (
+                128,
+                0x615c0228fbe111ed,
+                0x1c924645f5608aba,
+                0x34c165bf4971b48e,
+                0x8d7f2a24beaa2962,
+            ),
+            (
+                129,
+                0x16cc9f327aedb81b,
+                0xc423cf5dd596f667,
+                0xa472d20a1e3d5353,
+                0xc973d721bf6066fa,
+            ),
+            (
+                256,
+                0xc2254fc687846ae0,
+                0x783b92117f53a3b4,
+                0x953c0f1fc59d1931,
+                0x82e4266cb1dc8d74,
+            ),
+            (
+                257,
+                0x1ed4180c1ad773e1,
+                0x1245b7e71b9573b0,
+                0x9851f0bf7bebed28,
+                0x1c394734e4b305f8,
+            ),
+            (
+                300,
+                0x45154b78e9be5051,
+                0x262cc74d93dc54f2,
+                0x12ae3810c227c4ca,
+                0xbabd43425a417f55,
+            ),
+            (
+                600,
+                0x91b8d3b3744ab8d7,
+                0xeb97c8d1918d3927,
+                0xa82e2d85205be543,
+                0x81e4a6b7fa35df93,
+            ),
+        ] {
+            let data = &data[..len];
+            assert_eq!(
+                hash(&FoldBuildHasher::<Fast>::with_seed(0x1234), data),
+                fast
+            );
+            assert_eq!(
+                hash(&FoldBuildHasher::<Quality>::with_seed(0x1234), data),
+                quality
+            );
+            assert_eq!(
+                hash(&FoldBuildHasher::<Fast>::with_seeds(0x1234, 0x5678), data),
+                fast_shared
+            );
+            assert_eq!(
+                hash(
+                    &FoldBuildHasher::<Quality>::with_seeds(0x1234, 0x5678),
+                    data
+                ),
+                quality_shared
+            );
+        }
+    }
+
+    #[test]
+    fn seeds() {
+        assert_eq!(
+            hash(&FoldBuildHasher::<Fast>::with_seed(0), b"a"),
+            fast(RawBytes(b"a"))
+        );
+        assert_eq!(
+            hash(&FoldBuildHasher::<Quality>::with_seed(0), b"a"),
+            quality(RawBytes(b"a"))
+        );
+
+        let random = FoldBuildHasher::<Fast>::new();
+        let a = hash(&random, b"a");
+        assert_eq!(a, hash(&random.clone(), b"a"));
+        assert_ne!(a, hash(&FoldBuildHasher::<Fast>::new(), b"a"));
+    }
+
+    hash_test_vectors! {
+        [fast]
+        a: 0x4d9532b828b2c6e4,
+        ab: 0xdbe2392da47d6f37,
+        abc: 0xcbd2b5cac6226954,
+        abcd: 0xc9cb913fcf28b382,
+        abcde: 0x481c58741c1a7794,
+        abcdef: 0x62db0d7a60d4c2c8,
+        abcdefg: 0x5dbe6e2cd40db7ec,
+        abcdefgh: 0xb47490e938110938,
+        abcdefghi: 0x61da34b80e97a5f,
+        abcdefghij: 0xf5763af35bde39ed,
+        abcdefghijk: 0xa740221972b9244a,
+        abcdefghijkl: 0x93b9f47fb1b0689e,
+        abcdefghijklm: 0x9688ecce43dec921,
+        abcdefghijklmn: 0x10b68ce85859e740,
+        abcdefghijklmno: 0x42bc39225d47ee83,
+        abcdefghijklmnop: 0xcd70611d3b8c6110,
+        abcdefghijklmnopq: 0x95f842c02e403d25,
+        abcdefghijklmnopqr: 0xb835f589c9a39fd4,
+        abcdefghijklmnopqrs: 0xb2bfc2998b94896b,
+        abcdefghijklmnopqrst: 0x8f1bcf37e63ebec9,
+        abcdefghijklmnopqrstu: 0xe3305ca4255305ad,
+        abcdefghijklmnopqrstuv: 0xe4500a54ab47ace5,
+        abcdefghijklmnopqrstuvw: 0x6e46575ee7d0f140,
+        abcdefghijklmnopqrstuvwx: 0x7dc04d35014ba483,
+        abcdefghijklmnopqrstuvwxy: 0xe55678951d66fdd,
+        abcdefghijklmnopqrstuvwxyz: 0xfb4cdcd12b598774,
+        abcdefghijklmnopqrstuvwxyz0: 0x1e9f11e4664e2f2,
+        abcdefghijklmnopqrstuvwxyz01: 0xda6069a8711b4df3,
+        abcdefghijklmnopqrstuvwxyz012: 0xde4fd9ae7561e2e3,
+        abcdefghijklmnopqrstuvwxyz0123: 0xde021b65e5d7fa3c,
+        abcdefghijklmnopqrstuvwxyz01234: 0xa27a76e99ef015a2,
+        abcdefghijklmnopqrstuvwxyz012345: 0x968e2e53ea2946ab,
+        abcdefghijklmnopqrstuvwxyz0123456: 0xeb333af84f3efe65,
+        abcdefghijklmnopqrstuvwxyz01234567: 0xf222474cbd455f6c,
+        abcdefghijklmnopqrstuvwxyz012345678: 0xdc5ee7e11d4f56be,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0xdccec03d15a2ce13,
+
+        [quality]
+        a: 0x3199587abf734b40,
+        ab: 0xc7de07a88de19765,
+        abc: 0xf62aeea924431991,
+        abcd: 0xb2166e3246988bd1,
+        abcde: 0x1d9325424d1375df,
+        abcdef: 0x18e7233e90809d66,
+        abcdefg: 0x7de7c862aec32010,
+        abcdefgh: 0xd36d44744194637b,
+        abcdefghi: 0xcc74023b87fd095f,
+        abcdefghij: 0x4397657a3ac996d1,
+        abcdefghijk: 0xacfd7eb6533df9fe,
+        abcdefghijkl: 0xea98f2f74dc8d737,
+        abcdefghijklm: 0xaecb92a55a45f103,
+        abcdefghijklmn: 0xf8aa7917c6b5776c,
+        abcdefghijklmno: 0x9a0ae1ac65295d0a,
+        abcdefghijklmnop: 0xdcff4f8d8d55cf48,
+        abcdefghijklmnopq: 0x349aa9cf71f67dec,
+        abcdefghijklmnopqr: 0x6efcfcc157152208,
+        abcdefghijklmnopqrs: 0x9dc1b44bf0f533ab,
+        abcdefghijklmnopqrst: 0x45240e9712c2ca32,
+        abcdefghijklmnopqrstu: 0xd59a1c55c988cf34,
+        abcdefghijklmnopqrstuv: 0x8b65f06820ae6bb2,
+        abcdefghijklmnopqrstuvw: 0x1fabfeabbc903d7b,
+        abcdefghijklmnopqrstuvwx: 0x552e3c771f1a46ac,
+        abcdefghijklmnopqrstuvwxy: 0x9d339c46fdf2b327,
+        abcdefghijklmnopqrstuvwxyz: 0xa8b3ab4990e762e5,
+        abcdefghijklmnopqrstuvwxyz0: 0x3117f19d1d237b35,
+        abcdefghijklmnopqrstuvwxyz01: 0x97b73321e5fb6187,
+        abcdefghijklmnopqrstuvwxyz012: 0x8207b1878d4581f9,
+        abcdefghijklmnopqrstuvwxyz0123: 0xe36f5352e7c836aa,
+        abcdefghijklmnopqrstuvwxyz01234: 0xdf59b7c9084005e0,
+        abcdefghijklmnopqrstuvwxyz012345: 0x98acfb7a7cf81f9a,
+        abcdefghijklmnopqrstuvwxyz0123456: 0xdeb2f3d6ae843d62,
+        abcdefghijklmnopqrstuvwxyz01234567: 0xf8d6ec70a04cf9d3,
+        abcdefghijklmnopqrstuvwxyz012345678: 0xb1e926511de9046b,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0xfd48cb1a427fdd3d,
+    }
+}
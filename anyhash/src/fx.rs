@@ -0,0 +1,418 @@
+//! Hasher and collections using the Fx hashing algorithm from rustc.
+
+// based on the original version of the hasher used in the rust compiler and the fxhash crate
+
+use core::ops::BitXor;
+
+use crate::{
+    impl_core_build_hasher, impl_core_hasher, BuildHasher, BuildHasherDefault,
+    EndianIndependentAlgorithm, Hasher, HasherWrite, SeedableBuildHasher,
+};
+
+impl_core_build_hasher!(FxBuildHasher<u64>; FxBuildHasher<u32> via u32);
+impl_core_hasher!(FxHasher<u64>; FxHasher<u32> via u32);
+
+/// [`BuildHasher`] implementation for the [`FxHasher`] hasher.
+/// If you don't need support for using custom seeds, use the zero sized
+/// [`FxBuildHasherDefault`] instead.
+#[derive(Clone, Debug)]
+pub struct FxBuildHasher<T>(T);
+
+impl<T: Type> FxBuildHasher<T> {
+    /// Create a [`BuildHasher`] for [`FxHasher`] using the default seed.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_seed(T::default())
+    }
+
+    /// Create a [`BuildHasher`] for [`FxHasher`] with a custom seed.
+    #[inline]
+    pub const fn with_seed(seed: T) -> Self {
+        Self(seed)
+    }
+}
+
+impl<T: Type> BuildHasher<T> for FxBuildHasher<T> {
+    type Hasher = FxHasher<T>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seed(self.0)
+    }
+}
+
+impl<T: Type> SeedableBuildHasher for FxBuildHasher<T> {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seed(T::from_seed(seed))
+    }
+}
+
+impl<T: Type> Default for FxBuildHasher<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize> zeroize::Zeroize for FxBuildHasher<T> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// [`BuildHasher`] implementation for the [`FxHasher`] hasher using the default seed (zero sized).
+pub type FxBuildHasherDefault<T> = BuildHasherDefault<FxHasher<T>>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`FxHasher64`] hasher.
+pub type FxHashMap<K, V> = std::collections::HashMap<K, V, FxBuildHasher<u64>>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`FxHasher64`] hasher with the default seed.
+pub type FxHashMapDefault<K, V> = std::collections::HashMap<K, V, FxBuildHasherDefault<u64>>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`FxHasher64`] hasher.
+pub type FxHashSet<T> = std::collections::HashSet<T, FxBuildHasher<u64>>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`FxHasher64`] hasher with the default seed.
+pub type FxHashSetDefault<T> = std::collections::HashSet<T, FxBuildHasherDefault<u64>>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`FxHasher64`] hasher.
+pub type FxHeaplessIndexMap<K, V, const N: usize> = heapless::IndexMap<K, V, FxBuildHasher<u64>, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`FxHasher64`] hasher with the default seed.
+pub type FxHeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, FxBuildHasherDefault<u64>, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`FxHasher64`] hasher.
+pub type FxHeaplessIndexSet<T, const N: usize> = heapless::IndexSet<T, FxBuildHasher<u64>, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`FxHasher64`] hasher with the default seed.
+pub type FxHeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, FxBuildHasherDefault<u64>, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`FxHasher64`] hasher.
+pub type FxHashBrownMap<K, V> = hashbrown::HashMap<K, V, FxBuildHasher<u64>>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`FxHasher64`] hasher with the default seed.
+pub type FxHashBrownMapDefault<K, V> = hashbrown::HashMap<K, V, FxBuildHasherDefault<u64>>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`FxHasher64`] hasher.
+pub type FxHashBrownSet<T> = hashbrown::HashSet<T, FxBuildHasher<u64>>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`FxHasher64`] hasher with the default seed.
+pub type FxHashBrownSetDefault<T> = hashbrown::HashSet<T, FxBuildHasherDefault<u64>>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`FxHasher64`] hasher.
+pub type FxIndexMap<K, V> = indexmap::IndexMap<K, V, FxBuildHasher<u64>>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`FxHasher64`] hasher with the default seed.
+pub type FxIndexMapDefault<K, V> = indexmap::IndexMap<K, V, FxBuildHasherDefault<u64>>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`FxHasher64`] hasher.
+pub type FxIndexSet<T> = indexmap::IndexSet<T, FxBuildHasher<u64>>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`FxHasher64`] hasher with the default seed.
+pub type FxIndexSetDefault<T> = indexmap::IndexSet<T, FxBuildHasherDefault<u64>>;
+
+/// Hasher using the 32-bit Fx algorithm.
+pub type FxHasher32 = FxHasher<u32>;
+
+/// Hasher using the 64-bit Fx algorithm.
+pub type FxHasher64 = FxHasher<u64>;
+
+use sealed::Type;
+mod sealed {
+    use super::*;
+
+    /// Configuration trait for the Fx hashers.
+    pub trait Type: Copy + Default + BitXor<Output = Self> {
+        /// Multiplier.
+        const K: Self;
+
+        /// Hash a single word.
+        #[inline(always)]
+        fn hash_word(self, word: Self) -> Self {
+            self.rotate_left(5).bitxor(word).wrapping_mul(Self::K)
+        }
+
+        /// Hash bytes as words of this type.
+        fn write(self, bytes: &[u8]) -> Self;
+
+        /// Rotate left.
+        fn rotate_left(self, n: u32) -> Self;
+
+        /// Wrapping multiply.
+        fn wrapping_mul(self, rhs: Self) -> Self;
+
+        /// Convert a 128-bit seed to this type.
+        fn from_seed(seed: u128) -> Self;
+    }
+}
+
+impl Type for u32 {
+    const K: Self = 0x27220a95;
+
+    #[inline]
+    fn write(mut self, bytes: &[u8]) -> Self {
+        let mut chunks = bytes.chunks_exact(4);
+        for chunk in &mut chunks {
+            self = self.hash_word(u32::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        for &byte in chunks.remainder() {
+            self = self.hash_word(byte as u32);
+        }
+        self
+    }
+
+    #[inline]
+    fn rotate_left(self, n: u32) -> Self {
+        self.rotate_left(n)
+    }
+
+    #[inline]
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        self.wrapping_mul(rhs)
+    }
+
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        seed as Self
+    }
+}
+
+impl Type for u64 {
+    const K: Self = 0x517cc1b727220a95;
+
+    #[inline]
+    fn write(mut self, bytes: &[u8]) -> Self {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self = self.hash_word(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        let mut bytes = chunks.remainder();
+        if bytes.len() >= 4 {
+            self = self.hash_word(u32::from_le_bytes(bytes[..4].try_into().unwrap()) as u64);
+            bytes = &bytes[4..];
+        }
+        for &byte in bytes {
+            self = self.hash_word(byte as u64);
+        }
+        self
+    }
+
+    #[inline]
+    fn rotate_left(self, n: u32) -> Self {
+        self.rotate_left(n)
+    }
+
+    #[inline]
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        self.wrapping_mul(rhs)
+    }
+
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        seed as Self
+    }
+}
+
+/// Hasher using the Fx algorithm, as used in the rust compiler.
+///
+/// This is a very fast multiply-rotate hasher that works well for small keys like integers,
+/// but it has poor quality and no protection against collision attacks. Each call to
+/// `write` is processed separately, so the hash of a byte stream depends on how it was
+/// split up into writes.
+#[derive(Clone)]
+pub struct FxHasher<T>(T);
+
+impl<T: Type> FxHasher<T> {
+    /// Create a new `FxHasher` using the default seed.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_seed(T::default())
+    }
+
+    /// Create a new `FxHasher` with a custom seed.
+    #[inline]
+    pub const fn with_seed(seed: T) -> Self {
+        Self(seed)
+    }
+}
+
+impl<T> EndianIndependentAlgorithm for FxHasher<T> {}
+
+impl<T: Type> Default for FxHasher<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize> zeroize::Zeroize for FxHasher<T> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Type> HasherWrite for FxHasher<T> {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0 = self.0.write(bytes);
+    }
+}
+
+impl<T: Type> Hasher<T> for FxHasher<T> {
+    #[inline]
+    fn finish(&self) -> T {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::RawBytes, Hash};
+
+    fn fx32<T: Hash>(x: T) -> u32 {
+        FxBuildHasherDefault::<u32>::new().hash_one(x)
+    }
+
+    fn fx64<T: Hash>(x: T) -> u64 {
+        FxBuildHasherDefault::<u64>::new().hash_one(x)
+    }
+
+    #[test]
+    fn words() {
+        let mut hasher = FxHasher64::new();
+        hasher.write(b"abcdefghijklmnopqrstuvwxyz0123456789");
+        assert_eq!(hasher.finish(), 0x7a3f29320af432c0);
+
+        let mut hasher = FxHasher32::new();
+        hasher.write(b"abcdefghijklmnopqrstuvwxyz0123456789");
+        assert_eq!(hasher.finish(), 0x40be8fdf);
+    }
+
+    #[test]
+    fn integers() {
+        let mut hasher = FxHasher64::new();
+        hasher.write(&0x0123456789abcdef_u64.to_le_bytes());
+        hasher.write(&0xdeadbeef_u32.to_le_bytes());
+        hasher.write(&[7]);
+        assert_eq!(hasher.finish(), 0x3e5ec026e717f6c2);
+
+        let mut hasher = FxHasher32::new();
+        hasher.write(&0xdeadbeef_u32.to_le_bytes());
+        hasher.write(&[7]);
+        assert_eq!(hasher.finish(), 0x28702c0a);
+    }
+
+    #[test]
+    fn seed() {
+        assert_ne!(
+            FxBuildHasher::with_seed(1_u64).hash_one(RawBytes(b"a")),
+            fx64(RawBytes(b"a"))
+        );
+        assert_ne!(
+            FxBuildHasher::with_seed(1_u32).hash_one(RawBytes(b"a")),
+            fx32(RawBytes(b"a"))
+        );
+    }
+
+    hash_test_vectors! {
+        [fx32]
+        a: 0xd3e60275,
+        ab: 0x7fb253b8,
+        abc: 0x9259b9dc,
+        abcd: 0x6029822e,
+        abcde: 0xe743255d,
+        abcdef: 0x3a4289e2,
+        abcdefg: 0x1fe13ea0,
+        abcdefgh: 0xd4b0d047,
+        abcdefghi: 0xea02bb8f,
+        abcdefghij: 0xf46302e3,
+        abcdefghijk: 0xe5786a39,
+        abcdefghijkl: 0x1023a190,
+        abcdefghijklm: 0xbd57b09b,
+        abcdefghijklmn: 0x764a178d,
+        abcdefghijklmno: 0x440a3f55,
+        abcdefghijklmnop: 0x26b81fb8,
+        abcdefghijklmnopq: 0xfd839919,
+        abcdefghijklmnopqr: 0x9d9e8dd1,
+        abcdefghijklmnopqrs: 0x56d7e740,
+        abcdefghijklmnopqrst: 0x69043d56,
+        abcdefghijklmnopqrstu: 0xf0118d18,
+        abcdefghijklmnopqrstuv: 0xc3162b88,
+        abcdefghijklmnopqrstuvw: 0x2d175b9b,
+        abcdefghijklmnopqrstuvwx: 0xba6321e1,
+        abcdefghijklmnopqrstuvwxy: 0x450e2566,
+        abcdefghijklmnopqrstuvwxyz: 0xe3db779a,
+        abcdefghijklmnopqrstuvwxyz0: 0xf56de5dc,
+        abcdefghijklmnopqrstuvwxyz01: 0x146c12db,
+        abcdefghijklmnopqrstuvwxyz012: 0xc5104590,
+        abcdefghijklmnopqrstuvwxyz0123: 0xdcbb6107,
+        abcdefghijklmnopqrstuvwxyz01234: 0x3fb52e7b,
+        abcdefghijklmnopqrstuvwxyz012345: 0xf7edeba,
+        abcdefghijklmnopqrstuvwxyz0123456: 0xf02d0e43,
+        abcdefghijklmnopqrstuvwxyz01234567: 0x51ae6c7d,
+        abcdefghijklmnopqrstuvwxyz012345678: 0xa7a443fa,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x2fe66c71,
+
+        [fx64]
+        a: 0xe0456665d3e60275,
+        ab: 0xce0daa6a7e9336,
+        abc: 0x62fd7437241e1adf,
+        abcd: 0x12cda94232579628,
+        abcde: 0x2805e3c903b0eaf3,
+        abcdef: 0xe20e64dfd629d5bf,
+        abcdefg: 0x7101d33b15eeeb37,
+        abcdefgh: 0x828949b32fa9e7fe,
+        abcdefghi: 0x4d04c83a441010ad,
+        abcdefghij: 0x3623394458f6487f,
+        abcdefghijk: 0xa78a2b0a515b8f11,
+        abcdefghijkl: 0xc053c96133d02d38,
+        abcdefghijklm: 0x2ce92e0f4e5f0919,
+        abcdefghijklmn: 0x9c8924ee926078a7,
+        abcdefghijklmno: 0xfcdfd0075c4d16cc,
+        abcdefghijklmnop: 0xe646cc24d30a2e1b,
+        abcdefghijklmnopq: 0x3f51fced39f30891,
+        abcdefghijklmnopqr: 0xca488d397b80fd79,
+        abcdefghijklmnopqrs: 0x1d4f8cd0d1dea12,
+        abcdefghijklmnopqrst: 0x9d6756de68a59044,
+        abcdefghijklmnopqrstu: 0x5a0d9296388429de,
+        abcdefghijklmnopqrstuv: 0x83cda3688bfb2701,
+        abcdefghijklmnopqrstuvw: 0x40974d7ab0e74f53,
+        abcdefghijklmnopqrstuvwx: 0xff2045697d69db50,
+        abcdefghijklmnopqrstuvwxy: 0xc3a1ad87d148e95e,
+        abcdefghijklmnopqrstuvwxyz: 0x1fde01888b32b94a,
+        abcdefghijklmnopqrstuvwxyz0: 0x88f07038fe9f9def,
+        abcdefghijklmnopqrstuvwxyz01: 0xc440105555c6f0c0,
+        abcdefghijklmnopqrstuvwxyz012: 0x3fc433c9e1c9b472,
+        abcdefghijklmnopqrstuvwxyz0123: 0xe94d1c2805b97184,
+        abcdefghijklmnopqrstuvwxyz01234: 0x34eeffeda13aec5d,
+        abcdefghijklmnopqrstuvwxyz012345: 0x8f80c7297f6bfa8f,
+        abcdefghijklmnopqrstuvwxyz0123456: 0xcb0bb0cb61ba5ed3,
+        abcdefghijklmnopqrstuvwxyz01234567: 0x561cc83c06091b66,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x8b8fc4c72203dcda,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x96b2cbcdd7d38388,
+    }
+}
@@ -0,0 +1,531 @@
+//! Hasher and collections using the GxHash algorithm.
+
+// based on version 3 of the reference implementation at https://github.com/ogxd/gxhash
+
+use crate::{
+    aes_round::{dispatch, AesFn, AesRound},
+    impl_core_build_hasher, impl_core_hasher, BuildHasher, BuildHasherDefault,
+    EndianIndependentAlgorithm, Hasher, HasherWrite, SeedableBuildHasher,
+};
+
+impl_core_build_hasher!(GxBuildHasher);
+impl_core_hasher!(GxHasher);
+
+const KEYS: [u128; 3] = [
+    0xfc3bc28e_89c222e5_b09d3e21_f2784542,
+    0x39132bd9_b361dc58_cb6b2e9b_03fce279,
+    0xc78b122b_5544b1b7_689d2b7d_d0012e32,
+];
+
+#[inline(always)]
+fn r16(bytes: &[u8], i: usize) -> u128 {
+    u128::from_le_bytes(bytes[i..i + 16].try_into().unwrap())
+}
+
+/// Add the bytes of `a` and `b` separately, like `_mm_add_epi8`.
+#[inline(always)]
+const fn add_bytes(a: u128, b: u128) -> u128 {
+    const H: u128 = u128::from_ne_bytes([0x80; 16]);
+    ((a & !H) + (b & !H)) ^ ((a ^ b) & H)
+}
+
+/// Load up to 16 bytes and add the number of bytes to each byte.
+#[inline(always)]
+fn partial(bytes: &[u8]) -> u128 {
+    let mut buffer = [0; 16];
+    buffer[..bytes.len()].copy_from_slice(bytes);
+    add_bytes(
+        u128::from_le_bytes(buffer),
+        u128::from_ne_bytes([bytes.len() as u8; 16]),
+    )
+}
+
+#[inline(always)]
+fn compress_all<A: AesRound>(bytes: &[u8]) -> u128 {
+    let len = bytes.len();
+    if len == 0 {
+        return 0;
+    } else if len <= 16 {
+        return partial(bytes);
+    }
+
+    // start with the partial block, so the rest of the data is whole blocks
+    let extra = len % 16;
+    let (mut hash, mut blocks) = if extra == 0 {
+        (r16(bytes, 0), &bytes[16..])
+    } else {
+        (partial(&bytes[..extra]), &bytes[extra..])
+    };
+
+    let mut v0 = r16(blocks, 0);
+    blocks = &blocks[16..];
+    if len > 32 {
+        v0 = A::aesenc(v0, r16(blocks, 0));
+        blocks = &blocks[16..];
+        if len > 48 {
+            v0 = A::aesenc(v0, r16(blocks, 0));
+            blocks = &blocks[16..];
+            if len > 64 {
+                hash = compress_many::<A>(blocks, hash, len);
+            }
+        }
+    }
+
+    A::aesenclast(hash, A::aesenc(A::aesenc(v0, KEYS[0]), KEYS[1]))
+}
+
+#[inline(always)]
+fn compress_many<A: AesRound>(blocks: &[u8], mut hash: u128, len: usize) -> u128 {
+    // process single blocks until the rest is a whole number of 8 block groups
+    let (single, groups) = blocks.split_at(blocks.len() % 128);
+    for block in single.chunks_exact(16) {
+        hash = A::aesenc(hash, r16(block, 0));
+    }
+
+    let mut t1 = 0;
+    let mut t2 = 0;
+    let mut lane1 = hash;
+    let mut lane2 = hash;
+    for group in groups.chunks_exact(128) {
+        let v: [u128; 8] = core::array::from_fn(|i| r16(group, i * 16));
+        let tmp1 = A::aesenc(A::aesenc(A::aesenc(v[0], v[2]), v[4]), v[6]);
+        let tmp2 = A::aesenc(A::aesenc(A::aesenc(v[1], v[3]), v[5]), v[7]);
+        t1 = add_bytes(t1, KEYS[0]);
+        t2 = add_bytes(t2, KEYS[1]);
+        lane1 = A::aesenclast(A::aesenc(tmp1, t1), lane1);
+        lane2 = A::aesenclast(A::aesenc(tmp2, t2), lane2);
+    }
+
+    let len = len as u32 as u128;
+    let len = len | len << 32 | len << 64 | len << 96;
+    A::aesenc(add_bytes(lane1, len), add_bytes(lane2, len))
+}
+
+#[inline(always)]
+fn finalize<A: AesRound>(hash: u128) -> u128 {
+    let hash = A::aesenc(hash, KEYS[0]);
+    let hash = A::aesenc(hash, KEYS[1]);
+    A::aesenclast(hash, KEYS[2])
+}
+
+#[inline(always)]
+const fn seed_state(seed: i64) -> u128 {
+    seed as u64 as u128 | (seed as u64 as u128) << 64
+}
+
+/// Calculate the 128-bit GxHash of `bytes` in a single pass.
+///
+/// This is compatible with `gxhash128` from the reference implementation. It gives a
+/// different result than hashing the same bytes with [`GxHasher`].
+#[inline]
+pub fn gxhash128(bytes: &[u8], seed: i64) -> u128 {
+    struct Hash<'a>(&'a [u8], i64);
+
+    impl AesFn for Hash<'_> {
+        type Output = u128;
+
+        #[inline(always)]
+        fn call<A: AesRound>(self) -> u128 {
+            finalize::<A>(A::aesenc(compress_all::<A>(self.0), seed_state(self.1)))
+        }
+    }
+
+    dispatch(Hash(bytes, seed))
+}
+
+/// Calculate the 64-bit GxHash of `bytes` in a single pass.
+///
+/// This is compatible with `gxhash64` from the reference implementation. It gives a
+/// different result than hashing the same bytes with [`GxHasher`].
+#[inline]
+pub fn gxhash64(bytes: &[u8], seed: i64) -> u64 {
+    gxhash128(bytes, seed) as u64
+}
+
+/// [`BuildHasher`] implementation for the [`GxHasher`] hasher.
+/// If you don't need support for using custom seeds, use the zero sized
+/// [`GxBuildHasherDefault`] instead.
+#[derive(Clone, Debug)]
+pub struct GxBuildHasher(i64);
+
+impl GxBuildHasher {
+    /// Create a [`BuildHasher`] for [`GxHasher`] using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Create a [`BuildHasher`] for [`GxHasher`] with a custom seed.
+    #[inline]
+    pub const fn with_seed(seed: i64) -> Self {
+        Self(seed)
+    }
+}
+
+impl BuildHasher<u64> for GxBuildHasher {
+    type Hasher = GxHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seed(self.0)
+    }
+}
+
+impl BuildHasher<u128> for GxBuildHasher {
+    type Hasher = GxHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seed(self.0)
+    }
+}
+
+impl SeedableBuildHasher for GxBuildHasher {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seed(seed as i64)
+    }
+}
+
+impl Default for GxBuildHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for GxBuildHasher {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for GxBuildHasher {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for GxBuildHasher {}
+
+/// [`BuildHasher`] implementation for the [`GxHasher`] hasher using the default seed (zero sized).
+pub type GxBuildHasherDefault = BuildHasherDefault<GxHasher>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`GxHasher`] hasher.
+pub type GxHashMap<K, V> = std::collections::HashMap<K, V, GxBuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`GxHasher`] hasher with the default seed.
+pub type GxHashMapDefault<K, V> = std::collections::HashMap<K, V, GxBuildHasherDefault>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`GxHasher`] hasher.
+pub type GxHashSet<T> = std::collections::HashSet<T, GxBuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`GxHasher`] hasher with the default seed.
+pub type GxHashSetDefault<T> = std::collections::HashSet<T, GxBuildHasherDefault>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`GxHasher`] hasher.
+pub type GxHeaplessIndexMap<K, V, const N: usize> = heapless::IndexMap<K, V, GxBuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`GxHasher`] hasher with the default seed.
+pub type GxHeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, GxBuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`GxHasher`] hasher.
+pub type GxHeaplessIndexSet<T, const N: usize> = heapless::IndexSet<T, GxBuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`GxHasher`] hasher with the default seed.
+pub type GxHeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, GxBuildHasherDefault, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`GxHasher`] hasher.
+pub type GxHashBrownMap<K, V> = hashbrown::HashMap<K, V, GxBuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`GxHasher`] hasher with the default seed.
+pub type GxHashBrownMapDefault<K, V> = hashbrown::HashMap<K, V, GxBuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`GxHasher`] hasher.
+pub type GxHashBrownSet<T> = hashbrown::HashSet<T, GxBuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`GxHasher`] hasher with the default seed.
+pub type GxHashBrownSetDefault<T> = hashbrown::HashSet<T, GxBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`GxHasher`] hasher.
+pub type GxIndexMap<K, V> = indexmap::IndexMap<K, V, GxBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`GxHasher`] hasher with the default seed.
+pub type GxIndexMapDefault<K, V> = indexmap::IndexMap<K, V, GxBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`GxHasher`] hasher.
+pub type GxIndexSet<T> = indexmap::IndexSet<T, GxBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`GxHasher`] hasher with the default seed.
+pub type GxIndexSetDefault<T> = indexmap::IndexSet<T, GxBuildHasherDefault>;
+
+/// Hasher using the GxHash algorithm, with 64-bit and 128-bit output.
+///
+/// This uses AES-NI on x86 and the crypto extensions on ARMv8 when they're available. With
+/// the `std` feature, support is detected at runtime; otherwise the hardware is only used if
+/// the `aes` target feature is enabled at compile time. Without hardware support, a portable
+/// implementation that calculates the same hashes is used, but it's much slower.
+///
+/// Like the reference implementation's `GxHasher`, each call to `write` is processed
+/// separately, so the hash of a byte stream depends on how it was split up into writes.
+/// Use [`gxhash64`] or [`gxhash128`] to hash a byte slice in one go.
+#[derive(Clone)]
+pub struct GxHasher(u128);
+
+impl GxHasher {
+    /// Create a new `GxHasher` using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Create a new `GxHasher` with a custom seed.
+    #[inline]
+    pub const fn with_seed(seed: i64) -> Self {
+        Self(seed_state(seed))
+    }
+}
+
+impl EndianIndependentAlgorithm for GxHasher {}
+
+impl Default for GxHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for GxHasher {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for GxHasher {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for GxHasher {}
+
+impl HasherWrite for GxHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        struct Write<'a>(u128, &'a [u8]);
+
+        impl AesFn for Write<'_> {
+            type Output = u128;
+
+            #[inline(always)]
+            fn call<A: AesRound>(self) -> u128 {
+                A::aesenclast(compress_all::<A>(self.1), A::aesenc(self.0, KEYS[0]))
+            }
+        }
+
+        self.0 = dispatch(Write(self.0, bytes));
+    }
+}
+
+impl Hasher<u128> for GxHasher {
+    #[inline]
+    fn finish(&self) -> u128 {
+        struct Finish(u128);
+
+        impl AesFn for Finish {
+            type Output = u128;
+
+            #[inline(always)]
+            fn call<A: AesRound>(self) -> u128 {
+                finalize::<A>(self.0)
+            }
+        }
+
+        dispatch(Finish(self.0))
+    }
+}
+
+impl Hasher<u64> for GxHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        Hasher::<u128>::finish(self) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aes_round::Soft, Hash};
+
+    fn default_seed<T: Hash>(x: T) -> u64 {
+        GxBuildHasherDefault::new().hash_one(x)
+    }
+
+    fn default_seed_128<T: Hash>(x: T) -> u128 {
+        GxBuildHasherDefault::new().hash_one(x)
+    }
+
+    fn data() -> [u8; 300] {
+        let mut data = [0; 300];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = (i * 7 + 1) as u8;
+        }
+        data
+    }
+
+    // stability test vectors for `gxhash32` from the reference implementation
+    #[test]
+    fn reference() {
+        assert_eq!(gxhash64(&[0; 0], 0) as u32, 2533353535);
+        assert_eq!(gxhash64(&[0; 1], 0) as u32, 4243413987);
+        assert_eq!(gxhash64(&[0; 1000], 0) as u32, 2401749549);
+        assert_eq!(gxhash64(&[42; 4242], 42) as u32, 4156851105);
+        assert_eq!(gxhash64(&[42; 4242], -42) as u32, 1981427771);
+        assert_eq!(
+            gxhash128(b"hello world", 0),
+            0x253091e654891f5e2700ced979e41ef3
+        );
+    }
+
+    #[test]
+    fn software_fallback() {
+        struct Soft128<'a>(&'a [u8], i64);
+
+        impl Soft128<'_> {
+            fn hash(self) -> u128 {
+                finalize::<Soft>(Soft::aesenc(
+                    compress_all::<Soft>(self.0),
+                    seed_state(self.1),
+                ))
+            }
+        }
+
+        let data = data();
+        for len in 0..=data.len() {
+            assert_eq!(
+                gxhash128(&data[..len], len as i64),
+                Soft128(&data[..len], len as i64).hash(),
+                "len {len}"
+            );
+        }
+    }
+
+    #[test]
+    fn hasher() {
+        let data = data();
+        let mut hasher = GxHasher::with_seed(0x1234);
+        hasher.write(&data[..100]);
+        hasher.write(&data[100..]);
+        let hash: u128 = hasher.finish();
+        assert_eq!(hash, 0x5b99318f0441b2d9220c77b03f95e8f0);
+        assert_eq!(Hasher::<u64>::finish(&hasher), hash as u64);
+        assert_ne!(hash, GxHasher::new().finish());
+        assert_ne!(hash, gxhash128(&data, 0x1234));
+    }
+
+    hash_test_vectors! {
+        [default_seed]
+        a: 0x89fef082ef65a82e,
+        ab: 0xe3e116d3c433a535,
+        abc: 0xb8d5366d43e2f76c,
+        abcd: 0x4ab8355a4d1370d4,
+        abcde: 0x6f6a679a65ffa900,
+        abcdef: 0x6621149bba6920be,
+        abcdefg: 0xb07bcdaeaf95e953,
+        abcdefgh: 0x6a89821c36a07587,
+        abcdefghi: 0x9203301dd22ac748,
+        abcdefghij: 0x138bccfc0cd67fe9,
+        abcdefghijk: 0x107f93acf9cd48cf,
+        abcdefghijkl: 0xb6c15816290c66c7,
+        abcdefghijklm: 0x748240b75b3ac471,
+        abcdefghijklmn: 0x56ed588413152ab1,
+        abcdefghijklmno: 0xa8ccf643a687a4a9,
+        abcdefghijklmnop: 0xe19d7fd8eca987cf,
+        abcdefghijklmnopq: 0xb25a439ca31ad0af,
+        abcdefghijklmnopqr: 0x892a3cc7f28aeca2,
+        abcdefghijklmnopqrs: 0x16404be240d6c152,
+        abcdefghijklmnopqrst: 0x40b36e1c93760ff9,
+        abcdefghijklmnopqrstu: 0x97107878701633c4,
+        abcdefghijklmnopqrstuv: 0x8c667694071fd84f,
+        abcdefghijklmnopqrstuvw: 0x9ec450466c1eae8f,
+        abcdefghijklmnopqrstuvwx: 0x6e71a439adb9cc87,
+        abcdefghijklmnopqrstuvwxy: 0x3a55d4273f26ed72,
+        abcdefghijklmnopqrstuvwxyz: 0xdf6ed652537ef3e5,
+        abcdefghijklmnopqrstuvwxyz0: 0x809d1953d741de60,
+        abcdefghijklmnopqrstuvwxyz01: 0x6c3d5081f690d59d,
+        abcdefghijklmnopqrstuvwxyz012: 0x86fc514ce400f1dc,
+        abcdefghijklmnopqrstuvwxyz0123: 0x9c609f68bb6c1368,
+        abcdefghijklmnopqrstuvwxyz01234: 0x7c8eddf9fe87575a,
+        abcdefghijklmnopqrstuvwxyz012345: 0xf37d6ffb21192061,
+        abcdefghijklmnopqrstuvwxyz0123456: 0xbcd2c95209165432,
+        abcdefghijklmnopqrstuvwxyz01234567: 0x4f9e9bda3810a8dd,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x4a15a55a5108a023,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0xe48e392a377f8cfe,
+
+        [default_seed_128]
+        a: 0x186500b44b961c2589fef082ef65a82e,
+        ab: 0xea75a8d385f68373e3e116d3c433a535,
+        abc: 0xd52f7438abe33979b8d5366d43e2f76c,
+        abcd: 0xf41f7db2d5bf564d4ab8355a4d1370d4,
+        abcde: 0xf2d40741579366426f6a679a65ffa900,
+        abcdef: 0xb7bce07122634b986621149bba6920be,
+        abcdefg: 0x4f237216557e575eb07bcdaeaf95e953,
+        abcdefgh: 0xb0b8ad6ec1577c766a89821c36a07587,
+        abcdefghi: 0x3ee09b1ed26231b49203301dd22ac748,
+        abcdefghij: 0x91ccbca1bbe16cd4138bccfc0cd67fe9,
+        abcdefghijk: 0x96f5f2349e8eaf77107f93acf9cd48cf,
+        abcdefghijkl: 0x6baca41157c00646b6c15816290c66c7,
+        abcdefghijklm: 0x89a23fba3a15e5f4748240b75b3ac471,
+        abcdefghijklmn: 0x17ae1401d845a90456ed588413152ab1,
+        abcdefghijklmno: 0x2871da8f7fef1187a8ccf643a687a4a9,
+        abcdefghijklmnop: 0x698a153b6fcd0aa1e19d7fd8eca987cf,
+        abcdefghijklmnopq: 0xbb8a13edb10fc266b25a439ca31ad0af,
+        abcdefghijklmnopqr: 0xe3797487b9e21da2892a3cc7f28aeca2,
+        abcdefghijklmnopqrs: 0xa953bc04cc8037b316404be240d6c152,
+        abcdefghijklmnopqrst: 0xe140eeb48dbb854540b36e1c93760ff9,
+        abcdefghijklmnopqrstu: 0xd128fd0e413f6b9997107878701633c4,
+        abcdefghijklmnopqrstuv: 0xf7df525c79b4c5f8c667694071fd84f,
+        abcdefghijklmnopqrstuvw: 0xa63b2f69998c13a89ec450466c1eae8f,
+        abcdefghijklmnopqrstuvwx: 0x3da54ee1aad982816e71a439adb9cc87,
+        abcdefghijklmnopqrstuvwxy: 0x2aa430e2549e61e73a55d4273f26ed72,
+        abcdefghijklmnopqrstuvwxyz: 0x8c28ca07cd42f01edf6ed652537ef3e5,
+        abcdefghijklmnopqrstuvwxyz0: 0xd4738d58273952809d1953d741de60,
+        abcdefghijklmnopqrstuvwxyz01: 0x815d6d2634fb5f826c3d5081f690d59d,
+        abcdefghijklmnopqrstuvwxyz012: 0xe787e0cb30cc3a4d86fc514ce400f1dc,
+        abcdefghijklmnopqrstuvwxyz0123: 0x461a01af1bea7cd99c609f68bb6c1368,
+        abcdefghijklmnopqrstuvwxyz01234: 0x9d2cb0460702320d7c8eddf9fe87575a,
+        abcdefghijklmnopqrstuvwxyz012345: 0x64caf3ee792627c9f37d6ffb21192061,
+        abcdefghijklmnopqrstuvwxyz0123456: 0xdab6929b040dd76abcd2c95209165432,
+        abcdefghijklmnopqrstuvwxyz01234567: 0xe609f62123afda924f9e9bda3810a8dd,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x58445dbca3d406f04a15a55a5108a023,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0xcb41a156d692cbe2e48e392a377f8cfe,
+    }
+}
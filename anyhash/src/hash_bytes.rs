@@ -0,0 +1,197 @@
+use crate::{BuildHasher, Hash, Hasher};
+
+/// Hash types that can be converted to a byte array in a defined byte order.
+pub trait ToHashBytes {
+    /// The byte array type.
+    type Bytes: AsRef<[u8]> + AsMut<[u8]> + Copy;
+
+    /// Convert the hash to bytes in little endian byte order.
+    fn to_le_hash_bytes(&self) -> Self::Bytes;
+
+    /// Convert the hash to bytes in big endian byte order.
+    fn to_be_hash_bytes(&self) -> Self::Bytes;
+}
+
+macro_rules! impl_to_hash_bytes {
+    ($($t:ty),* $(,)?) => { $(
+        impl ToHashBytes for $t {
+            type Bytes = [u8; core::mem::size_of::<$t>()];
+
+            #[inline]
+            fn to_le_hash_bytes(&self) -> Self::Bytes {
+                self.to_le_bytes()
+            }
+
+            #[inline]
+            fn to_be_hash_bytes(&self) -> Self::Bytes {
+                self.to_be_bytes()
+            }
+        }
+    )* };
+}
+
+impl_to_hash_bytes!(u8, u16, u32, u64, u128);
+
+/// Byte arrays, like the output of cryptographic hashes, have no byte order, so they're
+/// returned unchanged.
+impl<const N: usize> ToHashBytes for [u8; N] {
+    type Bytes = [u8; N];
+
+    #[inline]
+    fn to_le_hash_bytes(&self) -> Self::Bytes {
+        *self
+    }
+
+    #[inline]
+    fn to_be_hash_bytes(&self) -> Self::Bytes {
+        *self
+    }
+}
+
+#[cfg(feature = "bnum")]
+mod bnum_impls {
+    use bnum::BUint;
+
+    use super::*;
+
+    macro_rules! impl_buint {
+        ($($n:literal),*) => { $(
+            impl ToHashBytes for BUint<$n> {
+                type Bytes = [u8; $n * 8];
+
+                #[inline]
+                fn to_le_hash_bytes(&self) -> Self::Bytes {
+                    let mut bytes = [0; $n * 8];
+                    for (chunk, digit) in bytes.chunks_exact_mut(8).zip(self.digits()) {
+                        chunk.copy_from_slice(&digit.to_le_bytes());
+                    }
+                    bytes
+                }
+
+                #[inline]
+                fn to_be_hash_bytes(&self) -> Self::Bytes {
+                    let mut bytes = [0; $n * 8];
+                    let digits = self.digits().iter().rev();
+                    for (chunk, digit) in bytes.chunks_exact_mut(8).zip(digits) {
+                        chunk.copy_from_slice(&digit.to_be_bytes());
+                    }
+                    bytes
+                }
+            }
+        )* };
+    }
+
+    impl_buint!(2, 4, 8, 16);
+}
+
+/// Extension trait for [`Hasher`]s to get the hash as a byte array in a defined byte order.
+///
+/// This is implemented for all hashers with a hash type that implements [`ToHashBytes`].
+///
+/// ```
+/// # use anyhash::{HashBytes, Hasher, HasherWrite};
+/// # struct Fnv1a32(u32);
+/// # impl HasherWrite for Fnv1a32 {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes {
+/// #             self.0 = (self.0 ^ b as u32).wrapping_mul(0x01000193);
+/// #         }
+/// #     }
+/// # }
+/// # impl Hasher<u32> for Fnv1a32 {
+/// #     fn finish(&self) -> u32 {
+/// #         self.0
+/// #     }
+/// # }
+/// let mut hasher = Fnv1a32(0x811c9dc5);
+/// hasher.write(b"hello");
+/// assert_eq!(hasher.finish_le_bytes(), [0xab, 0x2c, 0x9f, 0x4f]);
+/// assert_eq!(hasher.finish_be_bytes(), [0x4f, 0x9f, 0x2c, 0xab]);
+/// ```
+pub trait HashBytes<T: ToHashBytes>: Hasher<T> {
+    /// Returns the hash of the values written so far as bytes in little endian byte order.
+    #[inline]
+    fn finish_le_bytes(&self) -> T::Bytes {
+        self.finish().to_le_hash_bytes()
+    }
+
+    /// Returns the hash of the values written so far as bytes in big endian byte order.
+    #[inline]
+    fn finish_be_bytes(&self) -> T::Bytes {
+        self.finish().to_be_hash_bytes()
+    }
+}
+
+impl<T: ToHashBytes, H: ?Sized + Hasher<T>> HashBytes<T> for H {}
+
+/// Extension trait for [`BuildHasher`]s to get hashes as byte arrays in a defined byte order.
+///
+/// This is implemented for all build hashers with a hash type that implements [`ToHashBytes`].
+pub trait BuildHashBytes<T: ToHashBytes>: BuildHasher<T> {
+    /// Calculates the hash of a single value as bytes in little endian byte order.
+    #[inline]
+    fn hash_one_le_bytes<U: Hash>(&self, x: U) -> T::Bytes {
+        self.hash_one(x).to_le_hash_bytes()
+    }
+
+    /// Calculates the hash of a single value as bytes in big endian byte order.
+    #[inline]
+    fn hash_one_be_bytes<U: Hash>(&self, x: U) -> T::Bytes {
+        self.hash_one(x).to_be_hash_bytes()
+    }
+}
+
+impl<T: ToHashBytes, BH: ?Sized + BuildHasher<T>> BuildHashBytes<T> for BH {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HasherWrite;
+
+    #[derive(Default)]
+    struct Const;
+
+    impl HasherWrite for Const {
+        fn write(&mut self, _: &[u8]) {}
+    }
+
+    impl Hasher<u32> for Const {
+        fn finish(&self) -> u32 {
+            0x01020304
+        }
+    }
+
+    impl Hasher<[u8; 3]> for Const {
+        fn finish(&self) -> [u8; 3] {
+            [1, 2, 3]
+        }
+    }
+
+    #[test]
+    fn bytes() {
+        assert_eq!(HashBytes::<u32>::finish_le_bytes(&Const), [4, 3, 2, 1]);
+        assert_eq!(HashBytes::<u32>::finish_be_bytes(&Const), [1, 2, 3, 4]);
+        assert_eq!(HashBytes::<[u8; 3]>::finish_le_bytes(&Const), [1, 2, 3]);
+        assert_eq!(HashBytes::<[u8; 3]>::finish_be_bytes(&Const), [1, 2, 3]);
+
+        let bh = crate::BuildHasherDefault::<Const>::new();
+        assert_eq!(
+            BuildHashBytes::<u32>::hash_one_le_bytes(&bh, 0),
+            [4, 3, 2, 1]
+        );
+    }
+
+    #[cfg(feature = "bnum")]
+    #[test]
+    fn bnum() {
+        let x = bnum::types::U128::from(0x0102030405060708090a0b0c0d0e0f10_u128);
+        assert_eq!(
+            x.to_le_hash_bytes(),
+            0x0102030405060708090a0b0c0d0e0f10_u128.to_le_bytes()
+        );
+        assert_eq!(
+            x.to_be_hash_bytes(),
+            0x0102030405060708090a0b0c0d0e0f10_u128.to_be_bytes()
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    ops::{Deref, DerefMut},
+};
+
+use crate::{compat::CoreAsAnyHasher, Hash, HasherWrite};
+
+/// Wrapper that implements `core::hash::Hash` for any type that implements [`Hash`].
+///
+/// This makes it possible to use types that only implement [`Hash`] as keys in collections
+/// from `std`, without implementing `core::hash::Hash` for each of them with
+/// [`impl_core_hash`](crate::impl_core_hash). The wrapper also implements [`Hash`], and
+/// derefs to the wrapped value.
+///
+/// ```
+/// # use anyhash::{Hash, Hashed};
+/// # use std::collections::HashSet;
+/// #[derive(Hash, PartialEq, Eq)]
+/// struct Id(u32);
+///
+/// let mut set = HashSet::new();
+/// set.insert(Hashed(Id(1)));
+/// assert!(set.contains(&Hashed(Id(1))));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Hashed<T: ?Sized>(pub T);
+
+impl<T> Hashed<T> {
+    /// Unwrap the wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: ?Sized + Hash> core::hash::Hash for Hashed<T> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(&mut CoreAsAnyHasher::new(state));
+    }
+}
+
+impl<T: ?Sized + Hash> Hash for Hashed<T> {
+    #[inline]
+    fn hash<H: HasherWrite>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<T> From<T> for Hashed<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: ?Sized> Deref for Hashed<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> DerefMut for Hashed<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: ?Sized> Borrow<T> for Hashed<T> {
+    #[inline]
+    fn borrow(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> BorrowMut<T> for Hashed<T> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: ?Sized> AsRef<T> for Hashed<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> AsMut<T> for Hashed<T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
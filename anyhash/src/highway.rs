@@ -0,0 +1,662 @@
+//! Hasher and collections using the HighwayHash algorithm.
+
+// based on the portable reference implementation at https://github.com/google/highwayhash
+
+use crate::{
+    impl_core_build_hasher, impl_core_hasher, BuildHasher, BuildHasherDefault,
+    EndianIndependentAlgorithm, Hasher, HasherWrite, KeyedBuildHasher, SeedableBuildHasher,
+};
+
+#[cfg(feature = "bnum")]
+use bnum::types::U256;
+
+impl_core_build_hasher!(HighwayBuildHasher);
+impl_core_hasher!(Highway);
+
+const INIT0: [u64; 4] = [
+    0xdbe6d5d5fe4cce2f,
+    0xa4093822299f31d0,
+    0x13198a2e03707344,
+    0x243f6a8885a308d3,
+];
+
+const INIT1: [u64; 4] = [
+    0x3bd39e10cb0ef593,
+    0xc0acf169b5f18a8c,
+    0xbe5466cf34e90c6c,
+    0x452821e638d01377,
+];
+
+/// [`BuildHasher`] implementation for the [`Highway`] hasher.
+/// If you don't need support for using custom keys, use the zero sized
+/// [`HighwayBuildHasherDefault`] instead.
+///
+/// HighwayHash only protects against hash flooding if the key is secret and random,
+/// for example by using [`AmbientSeedBuildHasher`](crate::random::AmbientSeedBuildHasher).
+#[derive(Clone, Debug)]
+pub struct HighwayBuildHasher([u64; 4]);
+
+impl HighwayBuildHasher {
+    /// Create a [`BuildHasher`] for [`Highway`] using the default key (all zeros).
+    #[inline]
+    pub const fn new() -> Self {
+        Self([0; 4])
+    }
+
+    /// Create a [`BuildHasher`] for [`Highway`] with a custom key.
+    #[inline]
+    pub const fn with_key(key: [u64; 4]) -> Self {
+        Self(key)
+    }
+
+    /// Create a [`BuildHasher`] for [`Highway`] with a custom key in the 32 byte format
+    /// used by the HighwayHash reference implementation.
+    #[inline]
+    pub const fn with_key_bytes(key: &[u8; 32]) -> Self {
+        Self(Highway::key_from_bytes(key))
+    }
+}
+
+impl BuildHasher<u64> for HighwayBuildHasher {
+    type Hasher = Highway;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_key(self.0)
+    }
+}
+
+impl BuildHasher<u128> for HighwayBuildHasher {
+    type Hasher = Highway;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_key(self.0)
+    }
+}
+
+#[cfg(feature = "bnum")]
+impl BuildHasher<U256> for HighwayBuildHasher {
+    type Hasher = Highway;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_key(self.0)
+    }
+}
+
+impl SeedableBuildHasher for HighwayBuildHasher {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_key([seed as u64, (seed >> 64) as u64, 0, 0])
+    }
+}
+
+impl KeyedBuildHasher for HighwayBuildHasher {
+    const KEY_LEN: usize = 32;
+
+    #[inline]
+    fn from_key(key: &[u8]) -> Self {
+        Self::with_key_bytes(key.try_into().expect("key must be 32 bytes"))
+    }
+}
+
+impl Default for HighwayBuildHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for HighwayBuildHasher {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for HighwayBuildHasher {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for HighwayBuildHasher {}
+
+/// [`BuildHasher`] implementation for the [`Highway`] hasher using the default key (zero sized).
+pub type HighwayBuildHasherDefault = BuildHasherDefault<Highway>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`Highway`] hasher.
+pub type HighwayHashMap<K, V> = std::collections::HashMap<K, V, HighwayBuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`Highway`] hasher with the default key.
+pub type HighwayHashMapDefault<K, V> = std::collections::HashMap<K, V, HighwayBuildHasherDefault>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`Highway`] hasher.
+pub type HighwayHashSet<T> = std::collections::HashSet<T, HighwayBuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`Highway`] hasher with the default key.
+pub type HighwayHashSetDefault<T> = std::collections::HashSet<T, HighwayBuildHasherDefault>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`Highway`] hasher.
+pub type HighwayHeaplessIndexMap<K, V, const N: usize> =
+    heapless::IndexMap<K, V, HighwayBuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`Highway`] hasher with the default key.
+pub type HighwayHeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, HighwayBuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`Highway`] hasher.
+pub type HighwayHeaplessIndexSet<T, const N: usize> = heapless::IndexSet<T, HighwayBuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`Highway`] hasher with the default key.
+pub type HighwayHeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, HighwayBuildHasherDefault, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`Highway`] hasher.
+pub type HighwayHashBrownMap<K, V> = hashbrown::HashMap<K, V, HighwayBuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`Highway`] hasher with the default key.
+pub type HighwayHashBrownMapDefault<K, V> = hashbrown::HashMap<K, V, HighwayBuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`Highway`] hasher.
+pub type HighwayHashBrownSet<T> = hashbrown::HashSet<T, HighwayBuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`Highway`] hasher with the default key.
+pub type HighwayHashBrownSetDefault<T> = hashbrown::HashSet<T, HighwayBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`Highway`] hasher.
+pub type HighwayIndexMap<K, V> = indexmap::IndexMap<K, V, HighwayBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`Highway`] hasher with the default key.
+pub type HighwayIndexMapDefault<K, V> = indexmap::IndexMap<K, V, HighwayBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`Highway`] hasher.
+pub type HighwayIndexSet<T> = indexmap::IndexSet<T, HighwayBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`Highway`] hasher with the default key.
+pub type HighwayIndexSetDefault<T> = indexmap::IndexSet<T, HighwayBuildHasherDefault>;
+
+#[derive(Clone)]
+struct State {
+    v0: [u64; 4],
+    v1: [u64; 4],
+    mul0: [u64; 4],
+    mul1: [u64; 4],
+}
+
+impl State {
+    const fn new(key: [u64; 4]) -> Self {
+        let mut v0 = [0; 4];
+        let mut v1 = [0; 4];
+        let mut i = 0;
+        while i < 4 {
+            v0[i] = INIT0[i] ^ key[i];
+            v1[i] = INIT1[i] ^ key[i].rotate_left(32);
+            i += 1;
+        }
+        Self {
+            v0,
+            v1,
+            mul0: INIT0,
+            mul1: INIT1,
+        }
+    }
+
+    #[inline(always)]
+    fn zipper_merge_and_add(v1: u64, v0: u64, add1: &mut u64, add0: &mut u64) {
+        *add0 = add0.wrapping_add(
+            (((v0 & 0xff000000) | (v1 & 0xff00000000)) >> 24)
+                | (((v0 & 0xff0000000000) | (v1 & 0xff000000000000)) >> 16)
+                | (v0 & 0xff0000)
+                | ((v0 & 0xff00) << 32)
+                | ((v1 & 0xff00000000000000) >> 8)
+                | (v0 << 56),
+        );
+        *add1 = add1.wrapping_add(
+            (((v1 & 0xff000000) | (v0 & 0xff00000000)) >> 24)
+                | (v1 & 0xff0000)
+                | ((v1 & 0xff0000000000) >> 16)
+                | ((v1 & 0xff00) << 24)
+                | ((v0 & 0xff000000000000) >> 8)
+                | ((v1 & 0xff) << 48)
+                | (v0 & 0xff00000000000000),
+        );
+    }
+
+    fn update(&mut self, lanes: [u64; 4]) {
+        for (i, lane) in lanes.into_iter().enumerate() {
+            self.v1[i] = self.v1[i].wrapping_add(self.mul0[i].wrapping_add(lane));
+            self.mul0[i] ^= (self.v1[i] & 0xffffffff).wrapping_mul(self.v0[i] >> 32);
+            self.v0[i] = self.v0[i].wrapping_add(self.mul1[i]);
+            self.mul1[i] ^= (self.v0[i] & 0xffffffff).wrapping_mul(self.v1[i] >> 32);
+        }
+        let [v00, v01, v02, v03] = &mut self.v0;
+        Self::zipper_merge_and_add(self.v1[1], self.v1[0], v01, v00);
+        Self::zipper_merge_and_add(self.v1[3], self.v1[2], v03, v02);
+        let [v10, v11, v12, v13] = &mut self.v1;
+        Self::zipper_merge_and_add(self.v0[1], self.v0[0], v11, v10);
+        Self::zipper_merge_and_add(self.v0[3], self.v0[2], v13, v12);
+    }
+
+    #[inline]
+    fn update_packet(&mut self, packet: &[u8]) {
+        let mut lanes = [0; 4];
+        for (lane, bytes) in lanes.iter_mut().zip(packet.chunks_exact(8)) {
+            *lane = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        self.update(lanes);
+    }
+
+    fn update_remainder(&mut self, bytes: &[u8]) {
+        let size = bytes.len();
+        let size_mod4 = size & 3;
+        let remainder = size & !3;
+        for v0 in &mut self.v0 {
+            *v0 = v0.wrapping_add(((size as u64) << 32).wrapping_add(size as u64));
+        }
+        for v1 in &mut self.v1 {
+            let lo = (*v1 as u32).rotate_left(size as u32);
+            let hi = ((*v1 >> 32) as u32).rotate_left(size as u32);
+            *v1 = lo as u64 | (hi as u64) << 32;
+        }
+        let mut packet = [0; 32];
+        packet[..remainder].copy_from_slice(&bytes[..remainder]);
+        if size & 16 != 0 {
+            packet[28..].copy_from_slice(&bytes[size - 4..]);
+        } else if size_mod4 != 0 {
+            packet[16] = bytes[remainder];
+            packet[17] = bytes[remainder + (size_mod4 >> 1)];
+            packet[18] = bytes[size - 1];
+        }
+        self.update_packet(&packet);
+    }
+
+    fn permute_and_update(&mut self) {
+        let v0 = self.v0;
+        self.update([
+            v0[2].rotate_left(32),
+            v0[3].rotate_left(32),
+            v0[0].rotate_left(32),
+            v0[1].rotate_left(32),
+        ]);
+    }
+
+    #[cfg(feature = "bnum")]
+    #[inline(always)]
+    fn modular_reduction(a3_unmasked: u64, a2: u64, a1: u64, a0: u64) -> (u64, u64) {
+        let a3 = a3_unmasked & 0x3fffffffffffffff;
+        let m1 = a1 ^ ((a3 << 1) | (a2 >> 63)) ^ ((a3 << 2) | (a2 >> 62));
+        let m0 = a0 ^ (a2 << 1) ^ (a2 << 2);
+        (m1, m0)
+    }
+}
+
+/// Hasher using the HighwayHash algorithm.
+///
+/// This implements [`Hasher<u64>`], [`Hasher<u128>`] and, with the `bnum` feature,
+/// `Hasher<U256>` for the 64-, 128- and 256-bit variants of HighwayHash.
+#[derive(Clone)]
+pub struct Highway {
+    state: State,
+    buffer: [u8; 32],
+    buffer_len: usize,
+}
+
+impl Highway {
+    /// Create a new `Highway` hasher using the default key (all zeros).
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_key([0; 4])
+    }
+
+    /// Create a new `Highway` hasher with a custom key.
+    #[inline]
+    pub const fn with_key(key: [u64; 4]) -> Self {
+        Self {
+            state: State::new(key),
+            buffer: [0; 32],
+            buffer_len: 0,
+        }
+    }
+
+    /// Create a new `Highway` hasher with a custom key in the 32 byte format
+    /// used by the HighwayHash reference implementation.
+    #[inline]
+    pub const fn with_key_bytes(key: &[u8; 32]) -> Self {
+        Self::with_key(Self::key_from_bytes(key))
+    }
+
+    const fn key_from_bytes(bytes: &[u8; 32]) -> [u64; 4] {
+        let mut key = [0; 4];
+        let mut i = 0;
+        while i < 32 {
+            key[i / 8] |= (bytes[i] as u64) << (8 * (i % 8));
+            i += 1;
+        }
+        key
+    }
+
+    fn finish_state(&self, rounds: usize) -> State {
+        let mut state = self.state.clone();
+        if self.buffer_len != 0 {
+            state.update_remainder(&self.buffer[..self.buffer_len]);
+        }
+        for _ in 0..rounds {
+            state.permute_and_update();
+        }
+        state
+    }
+
+    #[cfg(feature = "bnum")]
+    fn finish_256(&self) -> [u64; 4] {
+        let s = self.finish_state(10);
+        let (h1, h0) = State::modular_reduction(
+            s.v1[1].wrapping_add(s.mul1[1]),
+            s.v1[0].wrapping_add(s.mul1[0]),
+            s.v0[1].wrapping_add(s.mul0[1]),
+            s.v0[0].wrapping_add(s.mul0[0]),
+        );
+        let (h3, h2) = State::modular_reduction(
+            s.v1[3].wrapping_add(s.mul1[3]),
+            s.v1[2].wrapping_add(s.mul1[2]),
+            s.v0[3].wrapping_add(s.mul0[3]),
+            s.v0[2].wrapping_add(s.mul0[2]),
+        );
+        [h0, h1, h2, h3]
+    }
+}
+
+impl EndianIndependentAlgorithm for Highway {}
+
+impl Default for Highway {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Highway {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.state.v0.zeroize();
+        self.state.v1.zeroize();
+        self.state.mul0.zeroize();
+        self.state.mul1.zeroize();
+        self.buffer.zeroize();
+        self.buffer_len.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Highway {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Highway {}
+
+impl HasherWrite for Highway {
+    fn write(&mut self, mut bytes: &[u8]) {
+        if self.buffer_len != 0 {
+            let n = bytes.len().min(32 - self.buffer_len);
+            self.buffer[self.buffer_len..self.buffer_len + n].copy_from_slice(&bytes[..n]);
+            self.buffer_len += n;
+            bytes = &bytes[n..];
+            if self.buffer_len < 32 {
+                return;
+            }
+            self.state.update_packet(&self.buffer);
+            self.buffer_len = 0;
+        }
+
+        let mut chunks = bytes.chunks_exact(32);
+        for chunk in &mut chunks {
+            self.state.update_packet(chunk);
+        }
+
+        let remainder = chunks.remainder();
+        self.buffer[..remainder.len()].copy_from_slice(remainder);
+        self.buffer_len = remainder.len();
+    }
+}
+
+impl Hasher<u64> for Highway {
+    fn finish(&self) -> u64 {
+        let s = self.finish_state(4);
+        s.v0[0]
+            .wrapping_add(s.v1[0])
+            .wrapping_add(s.mul0[0])
+            .wrapping_add(s.mul1[0])
+    }
+}
+
+impl Hasher<u128> for Highway {
+    fn finish(&self) -> u128 {
+        let s = self.finish_state(6);
+        let lo = s.v0[0]
+            .wrapping_add(s.mul0[0])
+            .wrapping_add(s.v1[2])
+            .wrapping_add(s.mul1[2]);
+        let hi = s.v0[1]
+            .wrapping_add(s.mul0[1])
+            .wrapping_add(s.v1[3])
+            .wrapping_add(s.mul1[3]);
+        lo as u128 | (hi as u128) << 64
+    }
+}
+
+#[cfg(feature = "bnum")]
+impl Hasher<U256> for Highway {
+    #[inline]
+    fn finish(&self) -> U256 {
+        U256::from_digits(self.finish_256())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::RawBytes, Hash};
+
+    const KEY: [u64; 4] = [
+        0x0706050403020100,
+        0x0f0e0d0c0b0a0908,
+        0x1716151413121110,
+        0x1f1e1d1c1b1a1918,
+    ];
+
+    fn default_key<T: Hash>(x: T) -> u64 {
+        HighwayBuildHasherDefault::new().hash_one(x)
+    }
+
+    fn data() -> [u8; 64] {
+        let mut data = [0; 64];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        data
+    }
+
+    fn hash_chunked<T>(bytes: &[u8], chunk_size: usize) -> T
+    where
+        Highway: Hasher<T>,
+    {
+        let mut hasher = Highway::with_key(KEY);
+        for chunk in bytes.chunks(chunk_size) {
+            hasher.write(chunk);
+        }
+        hasher.finish()
+    }
+
+    #[test]
+    fn empty_default_key() {
+        assert_eq!(default_key(()), 0x7035da75b9d54469);
+    }
+
+    // reference vectors: key 00..1f, message 00..(len-1)
+    #[test]
+    fn reference_64() {
+        const EXPECTED: [u64; 34] = [
+            0x907a56de22c26e53,
+            0x7eab43aac7cddd78,
+            0xb8d0569ab0b53d62,
+            0x5c6befab8a463d80,
+            0xf205a46893007eda,
+            0x2b8a1668e4a94541,
+            0xbd4ccc325befca6f,
+            0x4d02ae1738f59482,
+            0xe1205108e55f3171,
+            0x32d2644ec77a1584,
+            0xf6e10acdb103a90b,
+            0xc3bbf4615b415c15,
+            0x243cc2040063fa9c,
+            0xa89a58ce65e641ff,
+            0x24b031a348455a23,
+            0x40793f86a449f33b,
+            0xcfab3489f97eb832,
+            0x19fe67d2c8c5c0e2,
+            0x04dd90a69c565cc2,
+            0x75d9518e2371c504,
+            0x38ad9b1141d3dd16,
+            0x0264432ccd8a70e0,
+            0xa9db5a6288683390,
+            0xd7b05492003f028c,
+            0x205f615aea59e51e,
+            0xeee0c89621052884,
+            0x1bfc1a93a7284f4f,
+            0x512175b5b70da91d,
+            0xf71f8976a0a2c639,
+            0xae093fef1f84e3e7,
+            0x22ca92b01161860f,
+            0x9fc7007ccf035a68,
+            0xa0c964d9ecd580fc,
+            0x2c90f73ca03181fc,
+        ];
+        let data = data();
+        let bh = HighwayBuildHasher::with_key(KEY);
+        for (len, &expected) in EXPECTED.iter().enumerate() {
+            let hash: u64 = bh.hash_one(RawBytes(&data[..len]));
+            assert_eq!(hash, expected, "len {len}");
+        }
+    }
+
+    #[test]
+    fn reference_128() {
+        let data = data();
+        let bh = HighwayBuildHasher::with_key(KEY);
+        for (len, expected) in [
+            (0, 0x33565e767f093e6f0fed268f9d8ffec7),
+            (1, 0xdc291df9eb9cdcb4d6b0a8893681e7a8),
+            (2, 0x78085638dc32e8683d15ad265a16da04),
+            (3, 0xbfe69a0fd9cedd790607621b295f0beb),
+        ] {
+            let hash: u128 = bh.hash_one(RawBytes(&data[..len]));
+            assert_eq!(hash, expected, "len {len}");
+        }
+    }
+
+    #[cfg(feature = "bnum")]
+    #[test]
+    fn reference_256() {
+        let hash: U256 = HighwayBuildHasher::with_key(KEY).hash_one(RawBytes(b""));
+        assert_eq!(
+            hash,
+            U256::from_digits([
+                0xdd44482ac2c874f5,
+                0xd946017313c7351f,
+                0xb3aebeccb98714ff,
+                0x41da233145751df4,
+            ])
+        );
+    }
+
+    #[test]
+    fn streaming() {
+        let data = data();
+        for len in 0..=data.len() {
+            let expected_64: u64 = hash_chunked(&data[..len], data.len());
+            let expected_128: u128 = hash_chunked(&data[..len], data.len());
+            for chunk_size in [1, 3, 7, 31, 33] {
+                assert_eq!(hash_chunked::<u64>(&data[..len], chunk_size), expected_64);
+                assert_eq!(hash_chunked::<u128>(&data[..len], chunk_size), expected_128);
+            }
+        }
+    }
+
+    #[test]
+    fn key_bytes() {
+        let mut key = [0; 32];
+        for (i, byte) in key.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        assert_eq!(
+            Hasher::<u64>::finish(&Highway::with_key_bytes(&key)),
+            Hasher::<u64>::finish(&Highway::with_key(KEY))
+        );
+    }
+
+    hash_test_vectors! {
+        [default_key]
+        a: 0xbd6efba9eb78e61f,
+        ab: 0x9ec3e01ced8812dd,
+        abc: 0x79c22973b1c34e09,
+        abcd: 0x41fd8b32af5c2f17,
+        abcde: 0xd2cdae26adb72754,
+        abcdef: 0xd7165602e15097af,
+        abcdefg: 0x1baefc18219ecf19,
+        abcdefgh: 0x2d414a97ccdf6470,
+        abcdefghi: 0x796cacfe258d8203,
+        abcdefghij: 0x54e0c94aae843e5,
+        abcdefghijk: 0x58d58872e0339bda,
+        abcdefghijkl: 0xa3465422e26286f6,
+        abcdefghijklm: 0x7aeb781f41189fd5,
+        abcdefghijklmn: 0x48430a7ab0a3adda,
+        abcdefghijklmno: 0x8d65f82b6726e3c8,
+        abcdefghijklmnop: 0xec41335c1e5e6be2,
+        abcdefghijklmnopq: 0x60757d5e2f06e697,
+        abcdefghijklmnopqr: 0xc2f2f759535d8346,
+        abcdefghijklmnopqrs: 0x51f1e4b664d46d87,
+        abcdefghijklmnopqrst: 0xa757e6b01b1edc3a,
+        abcdefghijklmnopqrstu: 0x4f9155704c9813f7,
+        abcdefghijklmnopqrstuv: 0x5d90db58702e134f,
+        abcdefghijklmnopqrstuvw: 0xab61a1c526c027e6,
+        abcdefghijklmnopqrstuvwx: 0x3e9cb3bea09d5866,
+        abcdefghijklmnopqrstuvwxy: 0x7089f86cc0aa4177,
+        abcdefghijklmnopqrstuvwxyz: 0xcd689051493f6e4a,
+        abcdefghijklmnopqrstuvwxyz0: 0x8db22b7dab93841d,
+        abcdefghijklmnopqrstuvwxyz01: 0xae99f1966c00b810,
+        abcdefghijklmnopqrstuvwxyz012: 0x2bb753caf407cd07,
+        abcdefghijklmnopqrstuvwxyz0123: 0xf7cdaee12a1fb2a8,
+        abcdefghijklmnopqrstuvwxyz01234: 0xd1658386a5ce8283,
+        abcdefghijklmnopqrstuvwxyz012345: 0xdd0cf3d150e732be,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x18a432dd5becd3e8,
+        abcdefghijklmnopqrstuvwxyz01234567: 0x2a14f4e14102c138,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x720185fa40037b3d,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0xdf4976fc2b60dab1,
+    }
+}
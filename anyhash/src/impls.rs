@@ -47,6 +47,8 @@ macro_rules! impl_hasher_deref {
             i64: write_i64,
             i128: write_i128,
             isize: write_isize,
+            f32: write_f32,
+            f64: write_f64,
         }
 
         #[inline]
@@ -244,6 +246,14 @@ impl_hash_tuple_and_fn! {
     (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11),
 }
 
+// hash function for the tests of the impls for other crates, unused if none of them are enabled
+#[cfg(all(test, feature = "fnv"))]
+#[allow(dead_code)]
+fn hash<T: Hash>(x: T) -> u64 {
+    use crate::{fnv::Fnv1aBuildHasherDefault, BuildHasher};
+    Fnv1aBuildHasherDefault::new().hash_one(x)
+}
+
 mod core_impls {
     use super::*;
     use core::{
@@ -255,6 +265,7 @@ mod core_impls {
         fmt::Error,
         marker::{PhantomData, PhantomPinned},
         mem::{discriminant, transmute, Discriminant, ManuallyDrop},
+        net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
         num::{
             NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
             NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating, Wrapping,
@@ -392,6 +403,70 @@ mod core_impls {
 
     impl_hash!(Duration);
 
+    impl Hash for Ipv4Addr {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            state.write(&self.octets());
+        }
+    }
+
+    impl Hash for Ipv6Addr {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            state.write(&self.octets());
+        }
+    }
+
+    impl Hash for IpAddr {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            match self {
+                IpAddr::V4(ip) => {
+                    state.write_u8(4);
+                    ip.hash(state);
+                }
+                IpAddr::V6(ip) => {
+                    state.write_u8(6);
+                    ip.hash(state);
+                }
+            }
+        }
+    }
+
+    impl Hash for SocketAddrV4 {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            self.ip().hash(state);
+            self.port().hash(state);
+        }
+    }
+
+    impl Hash for SocketAddrV6 {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            self.ip().hash(state);
+            self.port().hash(state);
+            self.flowinfo().hash(state);
+            self.scope_id().hash(state);
+        }
+    }
+
+    impl Hash for SocketAddr {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            match self {
+                SocketAddr::V4(addr) => {
+                    state.write_u8(4);
+                    addr.hash(state);
+                }
+                SocketAddr::V6(addr) => {
+                    state.write_u8(6);
+                    addr.hash(state);
+                }
+            }
+        }
+    }
+
     impl_empty_hash! {
         Infallible, Error, PhantomPinned, RangeFull
     }
@@ -421,18 +496,34 @@ mod core_impls {
     }
 }
 
+#[cfg(feature = "cell")]
+mod cell_impls {
+    use super::*;
+    use core::cell::{Cell, RefCell};
+
+    impl<T: Copy + Hash> Hash for Cell<T> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            self.get().hash(state)
+        }
+    }
+
+    /// Panics if the value is currently mutably borrowed.
+    impl<T: ?Sized + Hash> Hash for RefCell<T> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            self.borrow().hash(state)
+        }
+    }
+}
+
 #[cfg(feature = "alloc")]
 mod alloc_impls {
     use super::*;
     use alloc::{
         borrow::{Cow, ToOwned},
-        boxed::Box,
-        collections::{BTreeMap, BTreeSet, LinkedList, VecDeque},
         ffi::CString,
-        rc::Rc,
         string::String,
-        sync::Arc,
-        vec::Vec,
     };
 
     impl<B: ?Sized + Hash + ToOwned> Hash for Cow<'_, B> {
@@ -442,6 +533,32 @@ mod alloc_impls {
         }
     }
 
+    impl Hash for String {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            (**self).hash(state)
+        }
+    }
+
+    impl Hash for CString {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            Hash::hash(&**self, state);
+        }
+    }
+}
+
+#[cfg(all(feature = "alloc", not(feature = "nightly")))]
+mod alloc_global_impls {
+    use super::*;
+    use alloc::{
+        boxed::Box,
+        collections::{BTreeMap, BTreeSet, LinkedList, VecDeque},
+        rc::{self, Rc},
+        sync::{self, Arc},
+        vec::Vec,
+    };
+
     impl<T: ?Sized + Hash> Hash for Box<T> {
         #[inline]
         fn hash<H: HasherWrite>(&self, state: &mut H) {
@@ -471,10 +588,18 @@ mod alloc_impls {
         }
     }
 
-    impl Hash for String {
+    // weak pointers can't access the value, so they're hashed by address
+    impl<T: ?Sized> Hash for rc::Weak<T> {
         #[inline]
         fn hash<H: HasherWrite>(&self, state: &mut H) {
-            (**self).hash(state)
+            self.as_ptr().cast::<()>().hash(state)
+        }
+    }
+
+    impl<T: ?Sized> Hash for sync::Weak<T> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            self.as_ptr().cast::<()>().hash(state)
         }
     }
 
@@ -516,11 +641,101 @@ mod alloc_impls {
             }
         }
     }
+}
 
-    impl Hash for CString {
+// same as `alloc_global_impls`, but generic over the allocator
+#[cfg(all(feature = "alloc", feature = "nightly"))]
+mod alloc_allocator_impls {
+    use super::*;
+    use alloc::{
+        boxed::Box,
+        collections::{BTreeMap, BTreeSet, LinkedList, VecDeque},
+        rc::{self, Rc},
+        sync::{self, Arc},
+        vec::Vec,
+    };
+    use core::alloc::Allocator;
+
+    impl<T: ?Sized + Hash, A: Allocator> Hash for Box<T, A> {
         #[inline]
         fn hash<H: HasherWrite>(&self, state: &mut H) {
-            Hash::hash(&**self, state);
+            (**self).hash(state)
+        }
+    }
+
+    impl<T, H: ?Sized + Hasher<T>, A: Allocator> Hasher<T> for Box<H, A> {
+        impl_hasher_t_deref!();
+    }
+
+    impl<T: ?Sized + HasherWrite, A: Allocator> HasherWrite for Box<T, A> {
+        impl_hasher_deref!();
+    }
+
+    impl<T: ?Sized + Hash, A: Allocator> Hash for Rc<T, A> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            (**self).hash(state)
+        }
+    }
+
+    impl<T: ?Sized + Hash, A: Allocator> Hash for Arc<T, A> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            (**self).hash(state)
+        }
+    }
+
+    // weak pointers can't access the value, so they're hashed by address
+    impl<T: ?Sized, A: Allocator> Hash for rc::Weak<T, A> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            self.as_ptr().cast::<()>().hash(state)
+        }
+    }
+
+    impl<T: ?Sized, A: Allocator> Hash for sync::Weak<T, A> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            self.as_ptr().cast::<()>().hash(state)
+        }
+    }
+
+    impl<T: Hash, A: Allocator> Hash for Vec<T, A> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            (**self).hash(state)
+        }
+    }
+
+    impl<K: Hash, V: Hash, A: Allocator + Clone> Hash for BTreeMap<K, V, A> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            state.write_length_prefix(self.len());
+            for item in self {
+                item.hash(state);
+            }
+        }
+    }
+
+    impl_hash!(impl<T: core::hash::Hash, A: Allocator + Clone> BTreeSet<T, A>);
+
+    impl<T: Hash, A: Allocator> Hash for LinkedList<T, A> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            state.write_length_prefix(self.len());
+            for item in self {
+                item.hash(state);
+            }
+        }
+    }
+
+    impl<T: Hash, A: Allocator> Hash for VecDeque<T, A> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            state.write_length_prefix(self.len());
+            for item in self {
+                item.hash(state);
+            }
         }
     }
 }
@@ -528,10 +743,11 @@ mod alloc_impls {
 #[cfg(feature = "std")]
 mod std_impls {
     use super::*;
+    use crate::hash_unordered;
     use std::{
+        collections::{HashMap, HashSet},
         ffi::{OsStr, OsString},
         fs::FileType,
-        net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
         path::{Component, Path, PathBuf, Prefix, PrefixComponent},
         thread::ThreadId,
         time::{Instant, SystemTime},
@@ -549,13 +765,68 @@ mod std_impls {
         ThreadId;
         Instant;
         SystemTime;
-        SocketAddr;
-        SocketAddrV4;
-        SocketAddrV6;
-        IpAddr;
-        Ipv4Addr;
-        Ipv6Addr;
     );
+
+    /// Hashes the entries in a way that doesn't depend on their order, using
+    /// [`hash_unordered`].
+    impl<K: Hash, V: Hash, S> Hash for HashMap<K, V, S> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            hash_unordered(self, state);
+        }
+    }
+
+    /// Hashes the items in a way that doesn't depend on their order, using
+    /// [`hash_unordered`].
+    impl<T: Hash, S> Hash for HashSet<T, S> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            hash_unordered(self, state);
+        }
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+mod arrayvec_impls {
+    use arrayvec::{ArrayString, ArrayVec};
+
+    use super::*;
+
+    /// Hashes the same as a slice with the same elements.
+    impl<T: Hash, const CAP: usize> Hash for ArrayVec<T, CAP> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            (**self).hash(state)
+        }
+    }
+
+    /// Hashes the same as a `str` with the same contents.
+    impl<const CAP: usize> Hash for ArrayString<CAP> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            (**self).hash(state)
+        }
+    }
+
+    #[cfg(all(test, feature = "fnv"))]
+    mod tests {
+        use arrayvec::{ArrayString, ArrayVec};
+
+        use crate::impls::hash;
+
+        #[test]
+        fn same_as_slice_and_str() {
+            let full = ArrayVec::from([1_u32, 2, 3]);
+            let mut partial = ArrayVec::<u32, 8>::new();
+            partial.extend([1, 2, 3]);
+            assert_eq!(hash(&full), hash(&[1_u32, 2, 3][..]));
+            assert_eq!(hash(&partial), hash(&full));
+
+            let s = ArrayString::<3>::from("abc").unwrap();
+            assert_eq!(hash(s), hash("abc"));
+            assert_eq!(hash(ArrayString::<16>::from("abc").unwrap()), hash(s));
+        }
+    }
 }
 
 #[cfg(feature = "bnum")]
@@ -587,3 +858,677 @@ mod bnum_impls {
     impl_buint!(BUint, BUintD8, BUintD16, BUintD32);
     impl_bint!(BInt, BIntD8, BIntD16, BIntD32);
 }
+
+#[cfg(feature = "bytes")]
+mod bytes_impls {
+    use bytes::{Bytes, BytesMut};
+
+    use super::*;
+
+    /// Hashes the same as a byte slice with the same contents.
+    impl Hash for Bytes {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            (**self).hash(state)
+        }
+    }
+
+    /// Hashes the same as a byte slice with the same contents.
+    impl Hash for BytesMut {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            (**self).hash(state)
+        }
+    }
+
+    #[cfg(all(test, feature = "fnv"))]
+    mod tests {
+        use bytes::{Bytes, BytesMut};
+
+        use crate::impls::hash;
+
+        #[test]
+        fn same_as_slice() {
+            assert_eq!(hash(Bytes::from_static(b"abc")), hash(&b"abc"[..]));
+            assert_eq!(hash(BytesMut::from(&b"abc"[..])), hash(&b"abc"[..]));
+        }
+
+        #[test]
+        fn views() {
+            // only the bytes in view are hashed, not the rest of the shared buffer
+            let bytes = Bytes::from_static(b"xabcx");
+            assert_eq!(hash(bytes.slice(1..4)), hash(&b"abc"[..]));
+
+            let mut buf = BytesMut::from(&b"abcdef"[..]);
+            let tail = buf.split_off(3);
+            assert_eq!(hash(&buf), hash(&b"abc"[..]));
+            assert_eq!(hash(tail.freeze()), hash(&b"def"[..]));
+        }
+    }
+}
+
+#[cfg(feature = "compact_str")]
+mod compact_str_impls {
+    use compact_str::CompactString;
+
+    use super::*;
+
+    /// Hashes the same as a `str` with the same contents.
+    impl Hash for CompactString {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            self.as_str().hash(state)
+        }
+    }
+
+    #[cfg(all(test, feature = "fnv"))]
+    mod tests {
+        use compact_str::CompactString;
+
+        use crate::impls::hash;
+
+        #[test]
+        fn same_as_str() {
+            let long = "a string that's too long to be stored inline";
+            let inline = CompactString::new("abc");
+            let heap = CompactString::new(long);
+            assert!(!inline.is_heap_allocated() && heap.is_heap_allocated());
+            assert_eq!(hash(&inline), hash("abc"));
+            assert_eq!(hash(&heap), hash(long));
+
+            let static_str = CompactString::const_new("abc");
+            assert_eq!(hash(static_str), hash(inline));
+        }
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+mod hashbrown_impls {
+    use hashbrown::{HashMap, HashSet};
+
+    use super::*;
+    use crate::hash_unordered;
+
+    /// Hashes the entries in a way that doesn't depend on their order, using
+    /// [`hash_unordered`].
+    impl<K: Hash, V: Hash, S> Hash for HashMap<K, V, S> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            hash_unordered(self, state);
+        }
+    }
+
+    /// Hashes the items in a way that doesn't depend on their order, using
+    /// [`hash_unordered`].
+    impl<T: Hash, S> Hash for HashSet<T, S> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            hash_unordered(self, state);
+        }
+    }
+
+    #[cfg(all(test, feature = "fnv"))]
+    mod tests {
+        use crate::{
+            collections::HashSetExt,
+            fnv::{Fnv1aHashBrownMapDefault, Fnv1aHashBrownSetDefault},
+            impls::hash,
+        };
+
+        #[test]
+        fn unordered() {
+            let a: Fnv1aHashBrownSetDefault<u32> = (0..100).collect();
+            let mut b = Fnv1aHashBrownSetDefault::with_capacity(1000);
+            b.extend((0..100).rev());
+            assert_eq!(hash(&a), hash(&b));
+            b.remove(&7);
+            assert_ne!(hash(&a), hash(&b));
+        }
+
+        #[test]
+        fn map_entries() {
+            let a: Fnv1aHashBrownMapDefault<u32, u32> = (0..100).map(|i| (i, i * 2)).collect();
+            let mut b: Fnv1aHashBrownMapDefault<u32, u32> =
+                (0..100).rev().map(|i| (i, i * 2)).collect();
+            assert_eq!(hash(&a), hash(&b));
+            b.insert(7, 0);
+            assert_ne!(hash(&a), hash(&b));
+        }
+    }
+}
+
+#[cfg(feature = "heapless")]
+mod heapless_impls {
+    use heapless::{
+        string::{StringInner, StringStorage},
+        vec::{VecInner, VecStorage},
+        LenType,
+    };
+
+    use super::*;
+
+    /// Hashes the same as a slice with the same elements.
+    impl<T: Hash, LenT: LenType, S: ?Sized + VecStorage<T>> Hash for VecInner<T, LenT, S> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            (**self).hash(state)
+        }
+    }
+
+    /// Hashes the same as a `str` with the same contents.
+    impl<LenT: LenType, S: ?Sized + StringStorage> Hash for StringInner<LenT, S> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            (**self).hash(state)
+        }
+    }
+
+    #[cfg(all(test, feature = "fnv"))]
+    mod tests {
+        use heapless::{String, Vec};
+
+        use crate::{fnv::Fnv1aHeaplessIndexMapDefault, impls::hash};
+
+        #[test]
+        fn same_as_slice_and_str() {
+            let v = Vec::<u32, 4>::from_slice(&[1, 2, 3]).unwrap();
+            assert_eq!(hash(&v), hash(&[1_u32, 2, 3][..]));
+            assert_eq!(hash(v.as_view()), hash(&[1_u32, 2, 3][..]));
+            let s = String::<8>::try_from("abc").unwrap();
+            assert_eq!(hash(&s), hash("abc"));
+            assert_eq!(hash(s.as_view()), hash("abc"));
+        }
+
+        #[test]
+        fn capacity_independent() {
+            let small = Vec::<u32, 3>::from_slice(&[1, 2, 3]).unwrap();
+            let large = Vec::<u32, 16>::from_slice(&[1, 2, 3]).unwrap();
+            let large_len = Vec::<u32, 1000, u16>::from_slice(&[1, 2, 3]).unwrap();
+            assert_eq!(hash(&small), hash(&large));
+            assert_eq!(hash(&small), hash(&large_len));
+
+            let small = String::<3>::try_from("abc").unwrap();
+            let large = String::<64>::try_from("abc").unwrap();
+            assert_eq!(hash(&small), hash(&large));
+        }
+
+        #[test]
+        fn index_map() {
+            let mut map = Fnv1aHeaplessIndexMapDefault::<String<8>, u32, 4>::default();
+            map.insert(String::try_from("abc").unwrap(), 1).unwrap();
+            assert_eq!(map.get("abc"), Some(&1));
+        }
+    }
+}
+
+#[cfg(feature = "indexmap")]
+mod indexmap_impls {
+    use indexmap::{map, set, IndexMap, IndexSet};
+
+    use super::*;
+    use crate::hash_unordered;
+
+    /// Hashes the entries in a way that doesn't depend on their order, using
+    /// [`hash_unordered`], because maps with the same entries in a different order are equal.
+    /// Hash [`as_slice`](IndexMap::as_slice) to include the order.
+    impl<K: Hash, V: Hash, S> Hash for IndexMap<K, V, S> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            hash_unordered(self, state);
+        }
+    }
+
+    /// Hashes the items in a way that doesn't depend on their order, using
+    /// [`hash_unordered`], because sets with the same items in a different order are equal.
+    /// Hash [`as_slice`](IndexSet::as_slice) to include the order.
+    impl<T: Hash, S> Hash for IndexSet<T, S> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            hash_unordered(self, state);
+        }
+    }
+
+    /// Hashes the entries in order.
+    impl<K: Hash, V: Hash> Hash for map::Slice<K, V> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            state.write_length_prefix(self.len());
+            for item in self {
+                item.hash(state);
+            }
+        }
+    }
+
+    /// Hashes the items in order.
+    impl<T: Hash> Hash for set::Slice<T> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            state.write_length_prefix(self.len());
+            for item in self {
+                item.hash(state);
+            }
+        }
+    }
+
+    #[cfg(all(test, feature = "fnv"))]
+    mod tests {
+        use crate::{
+            collections::HashMapExt,
+            fnv::{Fnv1aIndexMapDefault, Fnv1aIndexSetDefault},
+            impls::hash,
+        };
+
+        #[test]
+        fn order() {
+            let mut a = Fnv1aIndexMapDefault::new();
+            a.insert(1, "a");
+            a.insert(2, "b");
+            let mut b = Fnv1aIndexMapDefault::new();
+            b.insert(2, "b");
+            b.insert(1, "a");
+            assert_eq!(a, b);
+            assert_eq!(hash(&a), hash(&b));
+            assert_ne!(hash(a.as_slice()), hash(b.as_slice()));
+        }
+
+        #[test]
+        fn set_order() {
+            let a: Fnv1aIndexSetDefault<u32> = [1, 2, 3].into_iter().collect();
+            let mut b: Fnv1aIndexSetDefault<u32> = [3, 2, 1].into_iter().collect();
+            assert_eq!(hash(&a), hash(&b));
+            assert_ne!(hash(a.as_slice()), hash(b.as_slice()));
+
+            b.sort();
+            assert_eq!(hash(a.as_slice()), hash(b.as_slice()));
+            b.swap_remove(&1);
+            assert_ne!(hash(&a), hash(&b));
+        }
+    }
+}
+
+#[cfg(feature = "ndarray")]
+mod ndarray_impls {
+    use ndarray::{ArrayBase, Data, Dimension};
+
+    use super::*;
+
+    /// Hashes the shape followed by the elements in logical (row-major) order, so arrays
+    /// that are equal hash the same regardless of their memory layout.
+    impl<S: Data, D: Dimension> Hash for ArrayBase<S, D>
+    where
+        S::Elem: Hash,
+    {
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            self.shape().hash(state);
+            for elem in self.iter() {
+                elem.hash(state);
+            }
+        }
+    }
+
+    #[cfg(all(test, feature = "fnv"))]
+    mod tests {
+        use ndarray::{s, Array2, Axis, ShapeBuilder};
+
+        use crate::impls::hash;
+
+        #[test]
+        fn layout_independent() {
+            let a = Array2::from_shape_fn((3, 4), |(i, j)| i * 4 + j);
+            let t = a.t().to_owned();
+            assert_eq!(hash(&a), hash(t.t()));
+            assert_eq!(
+                hash(a.slice(s![..;2, ..])),
+                hash(a.select(Axis(0), &[0, 2]))
+            );
+            assert_ne!(hash(&a), hash(a.to_shape((4, 3)).unwrap()));
+        }
+
+        #[test]
+        fn non_standard_layout() {
+            let a = Array2::from_shape_fn((3, 4), |(i, j)| i * 4 + j);
+
+            let f = Array2::from_shape_fn((3, 4).f(), |(i, j)| i * 4 + j);
+            assert!(!f.is_standard_layout());
+            assert_eq!(f, a);
+            assert_eq!(hash(&f), hash(&a));
+
+            let reversed = a.slice(s![..;-1, ..]);
+            assert!(reversed.strides()[0] < 0);
+            let expected = Array2::from_shape_fn((3, 4), |(i, j)| (2 - i) * 4 + j);
+            assert_eq!(hash(reversed), hash(&expected));
+            assert_ne!(hash(reversed), hash(&a));
+        }
+    }
+}
+
+#[cfg(feature = "num")]
+mod num_impls {
+    use num_bigint::{BigInt, BigUint, Sign};
+    use num_integer::Integer;
+    use num_rational::Ratio;
+
+    use super::*;
+
+    /// Hashes the 64-bit digits of the number from least to most significant, with a length
+    /// prefix. The hash is the same regardless of the digit size `num-bigint` uses internally.
+    impl Hash for BigUint {
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            let digits = self.iter_u64_digits();
+            state.write_length_prefix(digits.len());
+            for digit in digits {
+                state.write_u64(digit);
+            }
+        }
+    }
+
+    /// Hashes the sign as an `i8` of -1, 0 or 1, followed by the magnitude.
+    impl Hash for BigInt {
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            state.write_i8(match self.sign() {
+                Sign::Minus => -1,
+                Sign::NoSign => 0,
+                Sign::Plus => 1,
+            });
+            self.magnitude().hash(state);
+        }
+    }
+
+    /// Hashes the sign of the ratio as an `i8` of -1, 0 or 1, followed by the magnitudes of the
+    /// numerator and denominator in lowest terms minus one, so ratios that are equal hash the
+    /// same. Nothing is negated, so this doesn't overflow for values like `MIN / -1`. Ratios
+    /// with a zero denominator are hashed as they are.
+    impl<T: Clone + Integer + Hash> Hash for Ratio<T> {
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            // |x| - 1 for a non-zero `x`, which can't overflow
+            fn magnitude_minus_one<T: Clone + Integer>(x: T) -> T {
+                if x < T::zero() {
+                    T::zero() - (x + T::one())
+                } else {
+                    x - T::one()
+                }
+            }
+
+            let (numer, denom) = (self.numer(), self.denom());
+            if denom.is_zero() {
+                numer.hash(state);
+                denom.hash(state);
+            } else if numer.is_zero() {
+                state.write_i8(0);
+                T::zero().hash(state);
+                T::zero().hash(state);
+            } else {
+                // the gcd of `MIN` and `MIN` overflows, so handle equal values separately
+                let (numer, denom) = if numer == denom {
+                    (T::one(), T::one())
+                } else {
+                    let gcd = numer.gcd(denom);
+                    (numer.clone() / gcd.clone(), denom.clone() / gcd)
+                };
+                let negative = (numer < T::zero()) != (denom < T::zero());
+                state.write_i8(if negative { -1 } else { 1 });
+                magnitude_minus_one(numer).hash(state);
+                magnitude_minus_one(denom).hash(state);
+            }
+        }
+    }
+
+    #[cfg(all(test, feature = "fnv"))]
+    mod tests {
+        use num_bigint::{BigInt, BigUint};
+        use num_rational::Ratio;
+
+        use crate::impls::hash;
+
+        #[test]
+        fn big_int() {
+            let big = BigUint::from(u64::MAX) * 3_u32;
+            assert_eq!(
+                hash(&big),
+                hash(BigUint::from(u64::MAX) + u64::MAX + u64::MAX)
+            );
+            assert_ne!(hash(&big), hash(BigUint::from(u64::MAX)));
+            assert_ne!(
+                hash(BigInt::from(big.clone())),
+                hash(-BigInt::from(big.clone()))
+            );
+            assert_ne!(hash(BigInt::from(0)), hash(BigUint::from(0_u32)));
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn big_uint_digit_size_independent() {
+            use alloc::vec::Vec;
+
+            use crate::{
+                fnv::Fnv1aBuildHasherDefault, BuildHasher, Hash, RecordedWrite, RecordingHasher,
+            };
+
+            // an odd number of 32-bit digits, so the last 64-bit digit is only half used
+            for big in [
+                BigUint::from(7_u32),
+                (BigUint::from(5_u32) << 64) + u64::MAX,
+                BigUint::from(3_u32).pow(100),
+            ] {
+                // `to_u32_digits` is the same for both of `num-bigint`'s digit sizes
+                let expected: Vec<_> = big
+                    .to_u32_digits()
+                    .chunks(2)
+                    .map(|d| {
+                        let high = d.get(1).copied().unwrap_or(0);
+                        RecordedWrite::WriteU64(d[0] as u64 | (high as u64) << 32)
+                    })
+                    .collect();
+                let build_hasher = Fnv1aBuildHasherDefault::new();
+                let mut hasher =
+                    RecordingHasher::new(BuildHasher::<u64>::build_hasher(&build_hasher));
+                big.hash(&mut hasher);
+                assert_eq!(
+                    hasher.records()[0],
+                    RecordedWrite::LengthPrefix(expected.len())
+                );
+                assert_eq!(hasher.records()[1..], expected);
+            }
+        }
+
+        #[test]
+        fn ratio() {
+            assert_eq!(
+                hash(Ratio::new_raw(2_i64, 4)),
+                hash(Ratio::new_raw(-1_i64, -2))
+            );
+            assert_eq!(hash(Ratio::new_raw(0_i64, 3)), hash(Ratio::from(0_i64)));
+            assert_ne!(hash(Ratio::new(1_i64, 2)), hash(Ratio::new(1_i64, 3)));
+            assert_ne!(hash(Ratio::new(1_i64, 2)), hash(Ratio::new(-1_i64, 2)));
+            assert_eq!(hash(Ratio::new_raw(3_u32, 6)), hash(Ratio::new(1_u32, 2)));
+        }
+
+        #[test]
+        fn ratio_min() {
+            // the sign, followed by the magnitudes minus one
+            assert_eq!(
+                hash(Ratio::new_raw(i8::MIN, -1)),
+                hash((1_i8, 127_i8, 0_i8))
+            );
+            assert_eq!(
+                hash(Ratio::new_raw(i8::MIN, 1)),
+                hash((-1_i8, 127_i8, 0_i8))
+            );
+            assert_eq!(
+                hash(Ratio::new_raw(i8::MIN, i8::MIN)),
+                hash(Ratio::from(1_i8))
+            );
+            assert_eq!(
+                hash(Ratio::new_raw(i8::MIN, -2)),
+                hash(Ratio::new_raw(64_i8, 1))
+            );
+        }
+    }
+}
+
+#[cfg(feature = "smallvec")]
+mod smallvec_impls {
+    use smallvec::{Array, SmallVec};
+
+    use super::*;
+
+    /// Hashes the same as a slice or `Vec` with the same elements.
+    impl<A: Array> Hash for SmallVec<A>
+    where
+        A::Item: Hash,
+    {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            (**self).hash(state)
+        }
+    }
+
+    #[cfg(all(test, feature = "fnv"))]
+    mod tests {
+        use smallvec::{smallvec, SmallVec};
+
+        use crate::impls::hash;
+
+        #[test]
+        fn same_as_slice() {
+            let inline: SmallVec<[u32; 4]> = smallvec![1, 2, 3];
+            let mut spilled: SmallVec<[u32; 2]> = smallvec![1, 2, 3];
+            assert!(!inline.spilled() && spilled.spilled());
+            assert_eq!(hash(&inline), hash(&[1_u32, 2, 3][..]));
+            assert_eq!(hash(&spilled), hash(&inline));
+
+            spilled.truncate(2);
+            spilled.shrink_to_fit();
+            assert!(!spilled.spilled());
+            assert_eq!(hash(&spilled), hash(&[1_u32, 2][..]));
+        }
+    }
+}
+
+#[cfg(feature = "smol_str")]
+mod smol_str_impls {
+    use smol_str::SmolStr;
+
+    use super::*;
+
+    /// Hashes the same as a `str` with the same contents.
+    impl Hash for SmolStr {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            self.as_str().hash(state)
+        }
+    }
+
+    #[cfg(all(test, feature = "fnv"))]
+    mod tests {
+        use smol_str::SmolStr;
+
+        use crate::impls::hash;
+
+        #[test]
+        fn same_as_str() {
+            let long = "a string that's too long to be stored inline";
+            let inline = SmolStr::new("abc");
+            let heap = SmolStr::new(long);
+            assert!(!inline.is_heap_allocated() && heap.is_heap_allocated());
+            assert_eq!(hash(&inline), hash("abc"));
+            assert_eq!(hash(&heap), hash(long));
+
+            let static_str = SmolStr::new_static(long);
+            assert!(!static_str.is_heap_allocated());
+            assert_eq!(hash(static_str), hash(heap));
+        }
+    }
+}
+
+#[cfg(feature = "tinyvec")]
+mod tinyvec_impls {
+    use tinyvec::{Array, ArrayVec, SliceVec};
+
+    use super::*;
+
+    /// Hashes the same as a slice with the same elements.
+    impl<A: Array> Hash for ArrayVec<A>
+    where
+        A::Item: Hash,
+    {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            (**self).hash(state)
+        }
+    }
+
+    /// Hashes the same as a slice with the same elements.
+    impl<T: Hash> Hash for SliceVec<'_, T> {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            (**self).hash(state)
+        }
+    }
+
+    /// Hashes the same as a slice with the same elements.
+    #[cfg(feature = "alloc")]
+    impl<A: Array> Hash for tinyvec::TinyVec<A>
+    where
+        A::Item: Hash,
+    {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            (**self).hash(state)
+        }
+    }
+
+    #[cfg(all(test, feature = "fnv", feature = "alloc"))]
+    mod tests {
+        use tinyvec::{array_vec, tiny_vec, ArrayVec, SliceVec, TinyVec};
+
+        use crate::impls::hash;
+
+        #[test]
+        fn same_as_slice() {
+            let a: ArrayVec<[u32; 4]> = array_vec![1, 2, 3];
+            assert_eq!(hash(a), hash(&[1_u32, 2, 3][..]));
+
+            let inline: TinyVec<[u32; 4]> = tiny_vec![1, 2, 3];
+            let heap: TinyVec<[u32; 2]> = tiny_vec![1, 2, 3];
+            assert!(inline.is_inline() && heap.is_heap());
+            assert_eq!(hash(&inline), hash(a));
+            assert_eq!(hash(&heap), hash(a));
+
+            let mut buf = [1_u32, 2, 3, 0];
+            let s = SliceVec::from_slice_len(&mut buf, 3);
+            assert_eq!(hash(s), hash(a));
+        }
+    }
+}
+
+#[cfg(feature = "uuid")]
+mod uuid_impls {
+    use uuid::Uuid;
+
+    use super::*;
+
+    /// Hashes the 16 bytes of the UUID, like `core::hash::Hash` for `Uuid` does.
+    impl Hash for Uuid {
+        #[inline]
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            self.as_bytes().hash(state)
+        }
+    }
+
+    #[cfg(all(test, feature = "fnv"))]
+    mod tests {
+        use uuid::Uuid;
+
+        use crate::impls::hash;
+
+        #[test]
+        fn same_as_bytes() {
+            let uuid = Uuid::from_u128(0x0123_4567_89ab_cdef_fedc_ba98_7654_3210);
+            assert_eq!(hash(uuid), hash(uuid.as_bytes()));
+            assert_eq!(hash(uuid), hash(Uuid::from_bytes(*uuid.as_bytes())));
+            assert_ne!(hash(uuid), hash(Uuid::nil()));
+            assert_ne!(hash(Uuid::nil()), hash(Uuid::max()));
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use core::marker::PhantomData;
+
 use crate::{Hasher, HasherWrite};
 
 macro_rules! impl_hasher_core_fwd_writes {
@@ -56,6 +58,8 @@ macro_rules! impl_hasher_core_fwd {
     };
 }
 
+pub(crate) use {impl_hasher_core_fwd, impl_hasher_core_fwd_writes};
+
 #[repr(transparent)]
 pub struct WrapHasherWriteForCore<'a, H: HasherWrite>(&'a mut H);
 
@@ -75,44 +79,141 @@ impl<H: HasherWrite> core::hash::Hasher for WrapHasherWriteForCore<'_, H> {
     impl_hasher_core_fwd!();
 }
 
-#[repr(transparent)]
-pub struct WrapHasherU64ForCore<H: Hasher<u64>>(H);
+pub type WrapHasherU64ForCore<H> = crate::compat::AnyAsCoreHasher<H>;
+
+/// Strategy for converting a hash of type `T` to the `u64` required by `core::hash::Hasher`.
+/// The strategies are in the [`via`] module.
+pub trait Via<T> {
+    fn to_u64(hash: T) -> u64;
+}
+
+/// Strategies for `impl_core_hasher!(MyHasher via T strategy)`.
+#[allow(non_camel_case_types)]
+pub mod via {
+    use super::Via;
+
+    /// The strategy used if none is specified: `repeat` for `u32`, `xor_fold` for `u128`.
+    pub struct default;
+
+    /// Xor the high and low halves of a `u128` hash.
+    pub struct xor_fold;
+
+    /// Use the low 64 bits of a `u128` hash.
+    pub struct truncate;
+
+    /// Repeat a `u32` hash in the high and low halves, so both halves have hash bits.
+    pub struct repeat;
+
+    impl Via<u64> for default {
+        #[inline(always)]
+        fn to_u64(hash: u64) -> u64 {
+            hash
+        }
+    }
+
+    impl Via<u32> for default {
+        #[inline(always)]
+        fn to_u64(hash: u32) -> u64 {
+            repeat::to_u64(hash)
+        }
+    }
+
+    impl Via<u128> for default {
+        #[inline(always)]
+        fn to_u64(hash: u128) -> u64 {
+            xor_fold::to_u64(hash)
+        }
+    }
+
+    impl Via<u128> for xor_fold {
+        #[inline(always)]
+        fn to_u64(hash: u128) -> u64 {
+            hash as u64 ^ (hash >> 64) as u64
+        }
+    }
+
+    impl Via<u128> for truncate {
+        #[inline(always)]
+        fn to_u64(hash: u128) -> u64 {
+            hash as u64
+        }
+    }
+
+    impl Via<u32> for repeat {
+        #[inline(always)]
+        fn to_u64(hash: u32) -> u64 {
+            (hash as u64) << 32 | hash as u64
+        }
+    }
+}
 
-impl<H: Hasher<u64>> WrapHasherU64ForCore<H> {
+pub struct WrapHasherViaForCore<H: Hasher<T>, T, V: Via<T>>(H, PhantomData<fn() -> (T, V)>);
+
+impl<H: Hasher<T>, T, V: Via<T>> WrapHasherViaForCore<H, T, V> {
     #[inline(always)]
     pub const fn new(hasher: H) -> Self {
-        Self(hasher)
+        Self(hasher, PhantomData)
     }
 }
 
-impl<H: Hasher<u64>> ::core::hash::Hasher for WrapHasherU64ForCore<H> {
+impl<H: Hasher<T>, T, V: Via<T>> ::core::hash::Hasher for WrapHasherViaForCore<H, T, V> {
     #[inline(always)]
     fn finish(&self) -> u64 {
-        H::finish(&self.0)
+        V::to_u64(H::finish(&self.0))
     }
 
     impl_hasher_core_fwd!(&mut);
 }
 
-#[repr(transparent)]
-pub struct WrapCoreForHasherU64<'a, H: core::hash::Hasher>(&'a mut H);
+pub type WrapCoreForHasherU64<'a, H> = crate::compat::CoreAsAnyHasher<&'a mut H>;
 
-impl<'a, H: core::hash::Hasher> WrapCoreForHasherU64<'a, H> {
-    #[inline(always)]
-    pub fn new(hasher: &'a mut H) -> Self {
-        Self(hasher)
+// used by `hash_test_vectors!`
+pub struct RawBytes<'a>(pub &'a [u8]);
+
+impl crate::Hash for RawBytes<'_> {
+    #[inline]
+    fn hash<H: HasherWrite>(&self, state: &mut H) {
+        crate::Hash::hash_slice(self.0, state)
     }
 }
 
-impl<H: ::core::hash::Hasher> Hasher<u64> for WrapCoreForHasherU64<'_, H> {
-    #[inline(always)]
-    fn finish(&self) -> u64 {
-        H::finish(self.0)
-    }
+// used by `define_hash_collections!`
+pub mod collections {
+    #[cfg(feature = "hashbrown")]
+    pub use hashbrown;
+    #[cfg(feature = "heapless")]
+    pub use heapless;
+    #[cfg(feature = "indexmap")]
+    pub use indexmap;
+    #[cfg(feature = "std")]
+    pub use std::collections::{HashMap as StdHashMap, HashSet as StdHashSet};
 }
 
-impl<H: ::core::hash::Hasher> HasherWrite for WrapCoreForHasherU64<'_, H> {
-    impl_hasher_core_fwd!();
+#[cfg(feature = "bytemuck")]
+pub use bytemuck::NoUninit;
+
+// used by the derive for `#[hash(pod)]`
+#[cfg(feature = "bytemuck")]
+#[inline]
+pub fn hash_pod<T: NoUninit, H: HasherWrite>(value: &T, state: &mut H) {
+    state.write(bytemuck::bytes_of(value));
+}
+
+// used by the derive for `#[hash(pod)]`
+#[cfg(feature = "bytemuck")]
+#[inline]
+pub fn hash_pod_slice<T: NoUninit, H: HasherWrite>(data: &[T], state: &mut H) {
+    state.write(bytemuck::cast_slice(data));
+}
+
+/// The 64-bit finalizer from MurmurHash3.
+#[inline]
+pub(crate) const fn fmix64(mut k: u64) -> u64 {
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xff51afd7ed558ccd);
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xc4ceb9fe1a85ec53);
+    k ^ (k >> 33)
 }
 
 #[cfg(feature = "bytemuck")]
@@ -208,4 +309,12 @@ pub(crate) mod bm {
             &mut self.0
         }
     }
+
+    #[cfg(feature = "zeroize")]
+    impl<N: ConstValue> zeroize::Zeroize for Buffer<N> {
+        #[inline]
+        fn zeroize(&mut self) {
+            bytemuck::bytes_of_mut(&mut self.0).zeroize();
+        }
+    }
 }
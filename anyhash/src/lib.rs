@@ -1,6 +1,7 @@
 #![no_std]
-#![cfg_attr(feature = "nightly", feature(doc_auto_cfg))]
+#![cfg_attr(feature = "nightly", feature(doc_cfg))]
 #![cfg_attr(feature = "nightly", feature(hasher_prefixfree_extras))]
+#![cfg_attr(feature = "nightly", feature(allocator_api))]
 #![deny(missing_docs)]
 #![doc = include_str!("../README.md")]
 
@@ -15,8 +16,274 @@ extern crate std;
 use core::{any::type_name, fmt, fmt::Debug, marker::PhantomData};
 
 /// Derive macro for [`Hash`].
+///
+/// # Attributes
+///
+/// The generated implementation requires the types of the hashed fields that mention a type
+/// parameter to implement [`Hash`], except for `PhantomData`, which always implements it.
+///
+/// The derive can be configured with `#[hash(...)]` attributes on the type:
+///
+/// - `bound = "..."`: Use the given where clause predicates instead of the ones generated by
+///   the derive. Use `bound = ""` to add no bounds at all.
+/// - `core`: Also implement `core::hash::Hash` for the type, like
+///   [`impl_core_hash`] does, so it can be used with hashers and
+///   collections that use the traits from `core`.
+/// - `crate = "path"`: Use `path` as the path to this crate in the generated code, instead of
+///   `::anyhash`. Use this if the crate is renamed or re-exported from another crate.
+/// - `order(...)`: For structs, hash the fields in the given order instead of the order they're
+///   declared in. All hashed fields must be listed, by name or by index for tuple structs.
+///   This keeps hashes stable when fields are reordered in the declaration.
+/// - `pod`: Hash the raw bytes of a struct or union with a single write, instead of hashing
+///   each field. Slices of the type are also hashed with a single write, which is much faster
+///   than hashing each element for large slices of small structs. The type must implement
+///   [`bytemuck::NoUninit`](https://docs.rs/bytemuck/latest/bytemuck/trait.NoUninit.html), e.g.
+///   by deriving it or `Pod`, so it has no padding, and this requires the `bytemuck` feature.
+///   This is the only way to derive `Hash` for a union. The bytes are hashed in native byte
+///   order, so the hashes differ between big and little endian targets.
+/// - `sort_fields`: For structs with named fields, hash the fields sorted by name instead of in
+///   the order they're declared in. Reordering the fields then doesn't change the hash. Can't be
+///   used with `order`.
+/// - `stable_discriminant`: For enums, write the index of the variant in declaration order as a
+///   `u32` instead of hashing `core::mem::discriminant`, whose hash isn't guaranteed to be the
+///   same between compilers or builds. Use this if hashes are persisted or compared between
+///   builds. Adding variants at the end of the enum doesn't change the hashes of the others.
+/// - `tag = "..."`: Write the given tag with [`HasherWriteExt::write_domain`] before anything
+///   else. This separates the hashes of types whose fields would otherwise be written the same
+///   way.
+/// - `tag_fields`: Write the name of each named field with [`HasherWrite::write_str`] before
+///   its value. This makes the hash sensitive to fields being renamed or reordered, which is
+///   useful for fingerprinting the schema of a type rather than only its values.
+/// - `transparent`: For structs with a single hashed field, hash the field exactly like the
+///   field's type does, without any other data. A newtype like `struct Name(String)` then has the
+///   same hash as the string it wraps, so e.g. it can be looked up with a `&str`. Slices of the
+///   type also hash like slices of the field's type. The struct must be `#[repr(transparent)]`.
+///   Can't be used with `tag`, `tag_fields` or `version`.
+/// - `use_discriminant`: For enums, hash the value of the discriminant of the variant as the
+///   integer type from the `repr` attribute, or `isize` if there isn't one. With explicit
+///   discriminants, the hashes don't change if variants are reordered or new ones are added.
+/// - `version = N`: Write the version number `N` as a `u64` after the tag, if any, and before
+///   the fields. Bump the version to deliberately change the hash of a type, e.g. to invalidate
+///   caches.
+///
+/// ```
+/// # use anyhash::Hash;
+/// #[derive(Hash)]
+/// #[hash(tag = "Config", tag_fields, version = 2)]
+/// struct Config {
+///     width: u32,
+///     height: u32,
+/// }
+/// ```
+///
+/// ```
+/// # use anyhash::Hash;
+/// #[derive(Hash)]
+/// #[hash(bound = "T: Hash")]
+/// struct Node<'a, T> {
+///     value: T,
+///     children: &'a [Node<'a, T>],
+/// }
+/// ```
+///
+/// ```
+/// # use anyhash::Hash;
+/// #[derive(Hash)]
+/// #[hash(stable_discriminant)]
+/// enum Shape {
+///     Circle { radius: u32 },
+///     Square { side: u32 },
+/// }
+/// ```
+///
+/// ```
+/// # use anyhash::Hash;
+/// #[derive(Hash)]
+/// #[hash(use_discriminant)]
+/// #[repr(u8)]
+/// enum Status {
+///     Active = 1,
+///     Suspended = 4,
+///     Deleted,
+/// }
+/// ```
+///
+/// ```
+/// # use anyhash::Hash;
+/// #[derive(Hash)]
+/// #[hash(transparent)]
+/// #[repr(transparent)]
+/// struct Name(&'static str);
+/// ```
+///
+/// ```
+/// # use anyhash::Hash;
+/// # use std::collections::HashSet;
+/// #[derive(Hash, PartialEq, Eq)]
+/// #[hash(core)]
+/// struct Id(u32);
+///
+/// let mut set = HashSet::new();
+/// set.insert(Id(1));
+/// ```
+///
+/// ```
+/// # use anyhash::Hash;
+/// #[derive(Hash)]
+/// #[hash(order(id, name))]
+/// struct User {
+///     name: &'static str,
+///     id: u32,
+/// }
+/// ```
+///
+/// The derive also works for structs with `#[repr(packed)]`. Their fields may be unaligned, so
+/// each hashed field is copied before it's hashed, and the field types must implement `Copy`.
+///
+/// ```
+/// # use anyhash::Hash;
+/// #[derive(Hash)]
+/// #[repr(C, packed)]
+/// struct Header {
+///     kind: u8,
+///     len: u32,
+/// }
+/// ```
+///
+/// Fields can be configured with `#[hash(...)]` attributes on the field:
+///
+/// - `skip`: Don't hash the field. Use this for fields that don't contribute to the identity
+///   of the value, like caches. The field type doesn't need to implement [`Hash`].
+///
+/// ```
+/// # use anyhash::Hash;
+/// # use core::cell::Cell;
+/// #[derive(Hash)]
+/// struct Shape {
+///     sides: u32,
+///     #[hash(skip)]
+///     cached_area: Cell<Option<f64>>,
+/// }
+/// ```
 pub use anyhash_macros::Hash;
 
+/// Define type aliases for hash collections that use a [`BuildHasher`].
+///
+/// `define_hash_collections!(pub Foo = FooBuildHasher)` defines these aliases with the given
+/// visibility, for the collections whose features are enabled:
+///
+/// - `FooHashMap<K, V>` and `FooHashSet<T>`, from `std` (feature `std`)
+/// - `FooHashBrownMap<K, V>` and `FooHashBrownSet<T>`, from `hashbrown` (feature `hashbrown`)
+/// - `FooIndexMap<K, V>` and `FooIndexSet<T>`, from `indexmap` (feature `indexmap`)
+/// - `FooHeaplessIndexMap<K, V, N>` and `FooHeaplessIndexSet<T, N>`, from `heapless` (feature
+///   `heapless`). Create these with `Default::default()`.
+///
+/// Use the [`HashMapExt`](collections::HashMapExt) and [`HashSetExt`](collections::HashSetExt)
+/// traits to create the collections with `new` and `with_capacity`.
+///
+/// ```
+/// # use anyhash::{define_hash_collections, BuildHasherDefault, Hasher, HasherWrite};
+/// # #[derive(Default)]
+/// # struct MyHasher(u64);
+/// # impl HasherWrite for MyHasher {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3); }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for MyHasher {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+/// # anyhash::impl_core_hasher!(MyHasher);
+/// define_hash_collections!(pub My = BuildHasherDefault<MyHasher>);
+/// ```
+pub use anyhash_macros::define_hash_collections;
+
+/// Derive macro for [`BuildHasher`] for structs that hold the seed of a hasher.
+///
+/// The hasher type is set with `#[build_hasher(hasher = "...")]`. The derive implements
+/// `BuildHasher<u64>`, or `BuildHasher<T>` for each type in `#[build_hasher(hash = "...")]`,
+/// and makes hashers by passing clones of the fields to the hasher's `with_seed` function. Unit
+/// structs make hashers with `Default` instead.
+///
+/// It also adds a `with_seed` constructor that takes the fields as arguments. With
+/// `#[build_hasher(new)]` it adds a `new` constructor that uses the default value of each field
+/// as well, which requires the field types to implement `Default`.
+///
+/// `#[build_hasher(hash_map = "Name", hash_set = "Name")]` defines `HashMap` and `HashSet` type
+/// aliases from `std` that use the build hasher. This also implements `core::hash::BuildHasher`
+/// so the collections can be used, which requires `BuildHasher<u64>`.
+///
+/// If this crate is renamed or re-exported from another crate, pass the path to it with
+/// `#[build_hasher(crate = "path")]`.
+///
+/// ```
+/// # use anyhash::{BuildHasher, Hasher, HasherWrite};
+/// struct MyHasher(u64);
+///
+/// impl MyHasher {
+///     fn with_seed(seed: u64) -> Self {
+///         Self(seed ^ 0xcbf29ce484222325)
+///     }
+/// }
+/// # impl HasherWrite for MyHasher {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3); }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for MyHasher {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+///
+/// #[derive(BuildHasher, Clone, Debug)]
+/// #[build_hasher(hasher = "MyHasher", new, hash_map = "MyHashMap")]
+/// struct MyBuildHasher {
+///     seed: u64,
+/// }
+///
+/// let hash: u64 = MyBuildHasher::with_seed(1).hash_one("hello");
+/// let mut map: MyHashMap<u32, u32> = MyHashMap::with_hasher(MyBuildHasher::new());
+/// map.insert(1, 2);
+/// assert_eq!(map.get(&1), Some(&2));
+/// # let _ = hash;
+/// ```
+pub use anyhash_macros::BuildHasher;
+
+/// Derive macro that implements [`HasherWrite`] and [`Hasher<T>`] for a wrapper around another
+/// hasher by forwarding to it.
+///
+/// The wrapped hasher is the only field of the struct, or the field marked with
+/// `#[hasher_forward]`. All write methods and `finish` are forwarded to it, and the wrapper
+/// implements `Hasher<T>` for every `T` the wrapped hasher does.
+///
+/// If this crate is renamed or re-exported from another crate, pass the path to it with
+/// `#[hasher_forward(crate = "path")]` on the struct.
+///
+/// ```
+/// # use anyhash::{Hash, Hasher, HasherForward, HasherWrite};
+/// # #[derive(Default)]
+/// # struct MyHasher(u64);
+/// # impl HasherWrite for MyHasher {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3); }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for MyHasher {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+/// #[derive(HasherForward)]
+/// struct LabeledHasher<H> {
+///     #[hasher_forward]
+///     inner: H,
+///     label: &'static str,
+/// }
+///
+/// let mut hasher = LabeledHasher { inner: MyHasher::default(), label: "keys" };
+/// "hello".hash(&mut hasher);
+/// let hash: u64 = hasher.finish();
+/// # let _ = (hash, hasher.label);
+/// ```
+pub use anyhash_macros::HasherForward;
+
 /// Implement `core::Hash::Hash` for types that already implement [`Hash`].
 ///
 /// ```
@@ -52,6 +319,33 @@ pub use anyhash_macros::Hash;
 ///     impl<'a, T, U: 'a> MyOtherType<'a, T, u32, U> where Self: Display;
 /// }
 /// ```
+///
+/// Types can also be given as paths, e.g. for types from other modules.
+///
+/// ```
+/// # use anyhash::*;
+/// mod shapes {
+///     #[derive(anyhash::Hash)]
+///     pub struct Circle<T>(pub T);
+/// }
+///
+/// impl_core_hash!(shapes::Circle<u32>; impl<T: Hash> shapes::Circle<Option<T>>);
+/// ```
+///
+/// If this crate is renamed or re-exported from another crate, pass the path to it in a
+/// `#[hash(crate = "path")]` attribute before the types. The other `impl_*` macros accept the
+/// same attribute.
+///
+/// ```
+/// mod facade {
+///     pub mod anyhash {
+///         pub use ::anyhash::*;
+///     }
+/// }
+/// # #[derive(anyhash::Hash)]
+/// # struct MyType;
+/// facade::anyhash::impl_core_hash!(#[hash(crate = "facade::anyhash")] MyType);
+/// ```
 pub use anyhash_macros::impl_core_hash;
 
 /// Implement `core::Hash::Hasher` for types that already implement [`Hasher<u64>`].
@@ -109,6 +403,35 @@ pub use anyhash_macros::impl_core_hash;
 ///     impl<'a, T, U: 'a> MyOtherType<'a, T, u32, U> where Self: Display;
 /// }
 /// ```
+///
+/// Hashers that don't implement [`Hasher<u64>`] can be bridged by adding `via` followed by
+/// the hash type they do implement and optionally a strategy for converting the hash to `u64`:
+///
+/// - `xor_fold`: Xor the high and low halves of a `u128` hash. This is the default for `u128`.
+/// - `truncate`: Use the low 64 bits of a `u128` hash.
+/// - `repeat`: Repeat a `u32` hash in both halves of the `u64`. This is the default for `u32`.
+///
+/// ```
+/// # use anyhash::*;
+/// # struct MyType;
+/// # impl Hasher<u128> for MyType {
+/// #   fn finish(&self) -> u128 { 0 }
+/// # }
+/// # impl HasherWrite for MyType {
+/// #   fn write(&mut self, _: &[u8]) {}
+/// # }
+/// # struct MyOtherType<T>(T);
+/// # impl<T> Hasher<u32> for MyOtherType<T> {
+/// #   fn finish(&self) -> u32 { 0 }
+/// # }
+/// # impl<T> HasherWrite for MyOtherType<T> {
+/// #   fn write(&mut self, _: &[u8]) {}
+/// # }
+/// impl_core_hasher! {
+///     MyType via u128 xor_fold;
+///     impl<T> MyOtherType<T> via u32 where T: Clone;
+/// }
+/// ```
 pub use anyhash_macros::impl_core_hasher;
 
 /// Implement `core::Hash::BuildHasher` for types that already implement [`BuildHasher<u64>`].
@@ -179,6 +502,28 @@ pub use anyhash_macros::impl_core_hasher;
 ///     impl<'a, T, U: 'a> MyOtherType<'a, T, u32, U> where Self: Display;
 /// }
 /// ```
+///
+/// Build hashers whose hashers don't implement [`Hasher<u64>`] can be bridged with `via`,
+/// the same way as for [`impl_core_hasher`].
+///
+/// ```
+/// # use anyhash::*;
+/// # struct H;
+/// # impl Hasher<u128> for H {
+/// #   fn finish(&self) -> u128 { 0 }
+/// # }
+/// # impl HasherWrite for H {
+/// #   fn write(&mut self, _: &[u8]) {}
+/// # }
+/// # struct MyType;
+/// # impl BuildHasher<u128> for MyType {
+/// #   type Hasher = H;
+/// #   fn build_hasher(&self) -> Self::Hasher { H }
+/// # }
+/// impl_core_build_hasher!(MyType via u128 truncate);
+///
+/// let map: std::collections::HashMap<u32, u32, MyType> = std::collections::HashMap::with_hasher(MyType);
+/// ```
 pub use anyhash_macros::impl_core_build_hasher;
 
 /// Implement [`Hash`] for types that already implement `core::hash::Hash`.
@@ -218,6 +563,37 @@ pub use anyhash_macros::impl_core_build_hasher;
 ///     impl<'a, T: core::hash::Hash, U: 'a + core::hash::Hash> MyOtherType<'a, T, u32, U> where Self: Display;
 /// }
 /// ```
+///
+/// [`Hash::hash`] accepts any [`HasherWrite`], which has no `finish` method, so `Hash` can't be
+/// implemented for types whose `core::hash::Hash` implementation calls `finish`, not even for
+/// specific hash widths. Such types can still be hashed with a concrete [`Hasher<u64>`] by
+/// wrapping it in [`AnyAsCoreHasher`](compat::AnyAsCoreHasher), which forwards `finish`.
+///
+/// ```
+/// # use anyhash::{compat::AnyAsCoreHasher, Hasher, HasherWrite};
+/// # #[derive(Default)]
+/// # struct MyHasher(u64);
+/// # impl HasherWrite for MyHasher {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3); }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for MyHasher {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+/// struct Legacy(u32);
+///
+/// impl core::hash::Hash for Legacy {
+///     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+///         let checksum = state.finish();
+///         state.write_u64(checksum ^ self.0 as u64);
+///     }
+/// }
+///
+/// let mut hasher = MyHasher::default();
+/// core::hash::Hash::hash(&Legacy(1), &mut AnyAsCoreHasher::new(&mut hasher));
+/// # let _ = hasher.finish();
+/// ```
 pub use anyhash_macros::impl_hash;
 
 macro_rules! define_writes_for_hasher {
@@ -264,6 +640,28 @@ macro_rules! define_writes_for_hasher {
             isize: write_isize,
         }
 
+        /// Writes a single `f32` into this
+        #[doc = $desc]
+        ///
+        /// The default implementation writes the canonical bit pattern of the float with
+        /// `write_u32`, so `-0.0` is written the same way as `0.0`, and all NaNs are written the
+        /// same way. See [`FloatHashExt::canonical_bits`].
+        #[inline]
+        fn write_f32(&mut self, f: f32) {
+            self.write_u32(crate::FloatHashExt::canonical_bits(f));
+        }
+
+        /// Writes a single `f64` into this
+        #[doc = $desc]
+        ///
+        /// The default implementation writes the canonical bit pattern of the float with
+        /// `write_u64`, so `-0.0` is written the same way as `0.0`, and all NaNs are written the
+        /// same way. See [`FloatHashExt::canonical_bits`].
+        #[inline]
+        fn write_f64(&mut self, f: f64) {
+            self.write_u64(crate::FloatHashExt::canonical_bits(f));
+        }
+
         /// Writes a length prefix into this
         #[doc = $desc]
         #[inline]
@@ -294,8 +692,108 @@ macro_rules! define_writes_for_hasher {
     };
 }
 
-#[cfg(all(test, any(feature = "fnv", feature = "xxh64")))]
-macro_rules! test_bytes_hash {
+/// Implement the methods of [`HasherWrite`] by forwarding them to a field.
+///
+/// Use this in an implementation of `HasherWrite` for a type that wraps another hasher. Pass the
+/// field that holds the wrapped hasher as `self.0` or `self.name`. All write methods are
+/// forwarded, so the wrapped hasher's own implementations of them are used.
+///
+/// See also [`HasherForward`](derive@HasherForward), which also implements [`Hasher<T>`].
+///
+/// ```
+/// # use anyhash::{impl_hasher_forward, Hasher, HasherWrite};
+/// struct Wrapper<H> {
+///     inner: H,
+/// }
+///
+/// impl<H: HasherWrite> HasherWrite for Wrapper<H> {
+///     impl_hasher_forward!(self.inner);
+/// }
+///
+/// impl<H: Hasher<u64>> Hasher<u64> for Wrapper<H> {
+///     fn finish(&self) -> u64 {
+///         self.inner.finish()
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! impl_hasher_forward {
+    (self.$field:tt) => {
+        #[inline]
+        fn write(&mut self, bytes: &[u8]) {
+            $crate::HasherWrite::write(&mut self.$field, bytes)
+        }
+
+        $crate::impl_hasher_forward! {
+            @writes self.$field;
+            u8: write_u8,
+            u16: write_u16,
+            u32: write_u32,
+            u64: write_u64,
+            u128: write_u128,
+            usize: write_usize,
+            i8: write_i8,
+            i16: write_i16,
+            i32: write_i32,
+            i64: write_i64,
+            i128: write_i128,
+            isize: write_isize,
+            f32: write_f32,
+            f64: write_f64,
+            usize: write_length_prefix,
+            &str: write_str,
+        }
+    };
+
+    (@writes self.$field:tt; $($t:ty: $fn:ident),* $(,)?) => { $(
+        #[inline]
+        fn $fn(&mut self, i: $t) {
+            $crate::HasherWrite::$fn(&mut self.$field, i)
+        }
+    )* };
+}
+
+/// Define tests that check a hash function against known hashes of byte strings.
+///
+/// This is meant for testing implementations of hashers. Each group starts with the name of a
+/// function in brackets, which takes a value implementing [`Hash`] and returns its hash. The
+/// rest of the group is a list of `bytes: hash` pairs, where `bytes` is an identifier whose
+/// name is the string of bytes to hash. The bytes are written to the hasher with a single call
+/// to [`HasherWrite::write`].
+///
+/// A module with a test is defined for each group, so use this in a module with tests.
+///
+/// ```
+/// # use anyhash::{hash_test_vectors, BuildHasher, BuildHasherDefault, Hash, Hasher, HasherWrite};
+/// # struct MyHasher(u64);
+/// # impl Default for MyHasher {
+/// #     fn default() -> Self { Self(0xcbf29ce484222325) }
+/// # }
+/// # impl HasherWrite for MyHasher {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3); }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for MyHasher {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+/// #[cfg(test)]
+/// mod tests {
+///     use super::*;
+///
+///     fn my_hash(x: impl Hash) -> u64 {
+///         BuildHasherDefault::<MyHasher>::new().hash_one(x)
+///     }
+///
+///     hash_test_vectors! {
+///         [my_hash]
+///         a: 0xaf63dc4c8601ec8c,
+///         ab: 0x089c4407b545986a,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! hash_test_vectors {
     ($([$hashfn:ident] $($bs:ident: $hash:expr),* $(,)?)*) => { $(
         mod $hashfn {
             use super::*;
@@ -303,27 +801,159 @@ macro_rules! test_bytes_hash {
             #[test]
             fn test() {
                 $(
-                    assert_eq!($hashfn($crate::tests::RawBytes(stringify!($bs).as_bytes())), $hash);
+                    assert_eq!(
+                        $hashfn($crate::internal::RawBytes(stringify!($bs).as_bytes())),
+                        $hash,
+                        "hash of `{}`",
+                        stringify!($bs),
+                    );
                 )*
             }
         }
     )* };
 }
 
+#[cfg(feature = "adler32")]
+pub mod adler32;
+
+#[cfg(feature = "aes")]
+pub mod aes;
+
+#[cfg(feature = "auto")]
+pub mod auto;
+
+#[cfg(feature = "blake3")]
+pub mod blake3;
+
+#[cfg(feature = "crc")]
+pub mod crc;
+
+#[cfg(feature = "crc32")]
+pub mod crc32;
+
+#[cfg(feature = "crc64")]
+pub mod crc64;
+
+#[cfg(feature = "sha2")]
+pub mod crypto;
+
+#[cfg(feature = "farmhash")]
+pub mod farmhash;
+
 #[cfg(feature = "fnv")]
 pub mod fnv;
 
+#[cfg(feature = "foldhash")]
+pub mod foldhash;
+
+#[cfg(feature = "fx")]
+pub mod fx;
+
+#[cfg(feature = "gxhash")]
+pub mod gxhash;
+
+#[cfg(feature = "highway")]
+pub mod highway;
+
+#[cfg(feature = "lookup3")]
+pub mod lookup3;
+
+#[cfg(feature = "murmur2")]
+pub mod murmur2;
+
+#[cfg(feature = "murmur3")]
+pub mod murmur3;
+
+#[cfg(feature = "rapidhash")]
+pub mod rapidhash;
+
+#[cfg(feature = "rolling")]
+pub mod rolling;
+
+#[cfg(feature = "siphash")]
+pub mod siphash;
+
 #[cfg(feature = "spooky")]
 pub mod spooky;
 
+#[cfg(feature = "t1ha")]
+pub mod t1ha;
+
+#[cfg(feature = "wyhash")]
+pub mod wyhash;
+
+#[cfg(feature = "xxh3")]
+pub mod xxh3;
+
 #[cfg(feature = "xxh64")]
 pub mod xxh64;
 
+pub mod collections;
+pub mod compat;
+pub mod random;
+
 #[doc(hidden)]
 pub mod internal;
 
 mod impls;
 
+#[cfg(any(feature = "aes", feature = "gxhash"))]
+mod aes_round;
+
+mod by_address;
+pub use by_address::ByAddress;
+
+mod cast;
+pub use cast::{BuildHasherCast, CastMethod, FoldXor, HasherCast, Resize};
+
+mod ct;
+pub use ct::{ct_eq, ConstantTimeEq, CtHashValue};
+
+mod domain;
+pub use domain::{DomainSeparated, HasherWriteExt};
+
+mod dyn_hash;
+#[cfg(feature = "alloc")]
+pub use dyn_hash::AnyHasher;
+pub use dyn_hash::{DynHash, HashOutput};
+
+mod float;
+pub use float::{FloatHashExt, TotalF32, TotalF64};
+
+mod hash_bytes;
+pub use hash_bytes::{BuildHashBytes, HashBytes, ToHashBytes};
+
+mod hashed;
+pub use hashed::Hashed;
+
+mod mix;
+pub use mix::{Fmix, MixedBuildHasher, MixedHasher, Mixer, SplitMix};
+
+mod multi;
+pub use multi::{IndexedBuildHasher, MultiBuildHasher, MultiHasher, PairBuildHasher, PairHasher};
+
+#[cfg(feature = "alloc")]
+mod recording;
+#[cfg(feature = "alloc")]
+pub use recording::{RecordedWrite, RecordingHasher};
+
+mod salt;
+pub use salt::{SaltedBuildHasher, SaltedHasher};
+
+mod shard;
+pub use shard::ShardedBuildHasher;
+
+mod strict;
+pub use strict::StrictHasher;
+
+mod typed;
+pub use typed::{HashValue, TypedBuildHasher, TypedHasher};
+
+mod unordered;
+#[cfg(feature = "alloc")]
+pub use unordered::hash_unordered;
+pub use unordered::{hash_unordered_with, UnorderedCombiner};
+
 /// A hashable type.
 pub trait Hash {
     /// Feeds this value into the given [`HasherWrite`].
@@ -346,6 +976,73 @@ pub trait Hash {
 pub trait Hasher<T>: HasherWrite {
     /// Returns the hash value for the values written so far.
     fn finish(&self) -> T;
+
+    /// Returns the hash value for the values written so far, consuming the hasher.
+    ///
+    /// Hashers that would have to copy internal buffers in [`finish`](Hasher::finish) can
+    /// implement this to finalize in place instead. The default implementation calls `finish`.
+    #[inline]
+    fn finish_once(self) -> T
+    where
+        Self: Sized,
+    {
+        self.finish()
+    }
+}
+
+/// A [`Hasher`] that can save its state and restore it later.
+///
+/// This lets parsers that do speculative work roll the hash back to an earlier point without
+/// hashing the data before it again.
+///
+/// ```
+/// # use anyhash::{Hasher, HasherCheckpoint, HasherWrite};
+/// # #[derive(Default)]
+/// # struct MyHasher(u64);
+/// # impl HasherWrite for MyHasher {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3); }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for MyHasher {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+/// # impl HasherCheckpoint for MyHasher {
+/// #     type Snapshot = u64;
+/// #     fn checkpoint(&self) -> u64 { self.0 }
+/// #     fn restore(&mut self, snapshot: u64) { self.0 = snapshot; }
+/// # }
+/// let mut hasher = MyHasher::default();
+/// hasher.write(b"prefix");
+/// let snapshot = hasher.checkpoint();
+/// hasher.write(b"speculative");
+/// hasher.restore(snapshot);
+/// hasher.write(b"actual");
+///
+/// let mut expected = MyHasher::default();
+/// expected.write(b"prefixactual");
+/// assert_eq!(hasher.finish(), expected.finish());
+/// ```
+pub trait HasherCheckpoint: HasherWrite {
+    /// The saved state of the hasher.
+    type Snapshot;
+
+    /// Save the current state of the hasher.
+    fn checkpoint(&self) -> Self::Snapshot;
+
+    /// Restore a state saved by [`checkpoint`](HasherCheckpoint::checkpoint). Everything
+    /// written after the checkpoint is forgotten.
+    fn restore(&mut self, snapshot: Self::Snapshot);
+}
+
+/// A [`Hasher`] for a checksum algorithm where the checksums of two pieces of data can be
+/// combined into the checksum of the concatenated data.
+///
+/// This makes it possible to calculate the checksums of several pieces of data in parallel
+/// and combine them afterwards.
+pub trait Checksum<T>: Hasher<T> {
+    /// Combine checksum `a` with checksum `b` of the `len_b` bytes that follow it.
+    fn combine(a: T, b: T, len_b: u64) -> T;
 }
 
 /// A trait for writing data to a hasher.
@@ -369,10 +1066,36 @@ pub trait BuildHasher<T> {
     fn hash_one<U: Hash>(&self, x: U) -> T {
         let mut hasher = self.build_hasher();
         x.hash(&mut hasher);
-        hasher.finish()
+        hasher.finish_once()
     }
 }
 
+/// Trait for [`BuildHasher`]s that can be created from a seed.
+///
+/// This is used by types that generate seeds at runtime, like
+/// [`AmbientSeedBuildHasher`](random::AmbientSeedBuildHasher). Algorithms with a seed smaller
+/// than 128 bits use the low bits of the seed.
+pub trait SeedableBuildHasher {
+    /// Create a new `BuildHasher` from a 128-bit seed.
+    fn from_seed(seed: u128) -> Self;
+}
+
+/// Trait for [`BuildHasher`]s for keyed algorithms, like SipHash, HighwayHash and BLAKE3 in
+/// keyed mode.
+///
+/// This makes it possible to use keyed algorithms interchangeably, without caring about how
+/// each of them lays out its key.
+pub trait KeyedBuildHasher: Sized {
+    /// The size of the key in bytes.
+    const KEY_LEN: usize;
+
+    /// Create a new `BuildHasher` from a key of [`KEY_LEN`](KeyedBuildHasher::KEY_LEN) bytes.
+    ///
+    /// # Panics
+    /// Panics if the length of `key` isn't `KEY_LEN`.
+    fn from_key(key: &[u8]) -> Self;
+}
+
 /// Used to create a default [`BuildHasher`] instance for types that implement [`Hasher`]
 /// and Default.
 pub struct BuildHasherDefault<H>(PhantomData<fn() -> H>);
@@ -416,6 +1139,53 @@ impl<H> Debug for BuildHasherDefault<H> {
     }
 }
 
+/// [`BuildHasher`] that creates hashers by calling a closure.
+///
+/// ```
+/// # use anyhash::{BuildHasher, BuildHasherFn, Hasher, HasherWrite};
+/// # struct MyHasher(u64);
+/// # impl HasherWrite for MyHasher {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3); }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for MyHasher {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+/// let seed = 1234;
+/// let build_hasher = BuildHasherFn::new(move || MyHasher(seed));
+/// let hash: u64 = build_hasher.hash_one("hello");
+/// # let _ = hash;
+/// ```
+#[derive(Clone, Copy)]
+pub struct BuildHasherFn<F>(F);
+
+impl_core_build_hasher!(impl<F: Fn() -> H, H: Hasher<u64>> BuildHasherFn<F>);
+
+impl<F> BuildHasherFn<F> {
+    /// Create a new `BuildHasherFn` that creates hashers by calling `f`.
+    #[inline]
+    pub const fn new(f: F) -> Self {
+        Self(f)
+    }
+}
+
+impl<T, H: Hasher<T>, F: Fn() -> H> BuildHasher<T> for BuildHasherFn<F> {
+    type Hasher = H;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        (self.0)()
+    }
+}
+
+impl<F> Debug for BuildHasherFn<F> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BuildHasherFn<{}>", type_name::<F>())
+    }
+}
+
 /// Marker trait for hashers that, given the same byte stream, calculates the same hash
 /// on hosts of different endiannesses.
 pub trait EndianIndependentAlgorithm {}
@@ -467,6 +1237,11 @@ impl<T, H: Hasher<T>> Hasher<T> for HasherLe<T, H> {
     fn finish(&self) -> T {
         self.0.finish()
     }
+
+    #[inline]
+    fn finish_once(self) -> T {
+        self.0.finish_once()
+    }
 }
 
 impl<T, H: Hasher<T>> HasherWrite for HasherLe<T, H> {
@@ -526,6 +1301,11 @@ impl<T, H: Hasher<T>> Hasher<T> for HasherBe<T, H> {
     fn finish(&self) -> T {
         self.0.finish()
     }
+
+    #[inline]
+    fn finish_once(self) -> T {
+        self.0.finish_once()
+    }
 }
 
 impl<T, H: Hasher<T>> HasherWrite for HasherBe<T, H> {
@@ -646,14 +1426,5 @@ impl<BH: Default> Default for HasherBeBuildHasher<BH> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
-    pub struct RawBytes<'a>(pub &'a [u8]);
-
-    impl Hash for RawBytes<'_> {
-        #[inline]
-        fn hash<H: HasherWrite>(&self, state: &mut H) {
-            Hash::hash_slice(self.0, state)
-        }
-    }
+    pub use crate::internal::RawBytes;
 }
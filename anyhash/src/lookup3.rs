@@ -0,0 +1,512 @@
+//! Hashers and collections using Bob Jenkins' lookup3 algorithm.
+
+// based on the reference implementation at https://burtleburtle.net/bob/c/lookup3.c
+
+use crate::{
+    impl_core_build_hasher, impl_core_hasher, BuildHasher, BuildHasherDefault,
+    EndianIndependentAlgorithm, Hasher, HasherWrite, SeedableBuildHasher,
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+impl_core_build_hasher!(impl<V: Variant> Lookup3BuildHasher<V>);
+impl_core_hasher!(impl<V: Variant> Lookup3<V>);
+
+use sealed::Variant;
+mod sealed {
+    /// Variant trait for lookup3.
+    pub trait Variant: Clone + core::fmt::Debug + Default {
+        /// Whether to read words as big endian.
+        const BIG_ENDIAN: bool;
+    }
+}
+
+/// Selector for `hashlittle`, which reads words as little endian.
+#[derive(Clone, Debug, Default)]
+pub struct Little;
+
+/// Selector for `hashbig`, which reads words as big endian.
+#[derive(Clone, Debug, Default)]
+pub struct Big;
+
+impl Variant for Little {
+    const BIG_ENDIAN: bool = false;
+}
+
+impl Variant for Big {
+    const BIG_ENDIAN: bool = true;
+}
+
+/// [`BuildHasher`] implementation for the [`Lookup3`] hasher.
+/// If you don't need support for using custom seeds, use the zero sized
+/// [`Lookup3BuildHasherDefault`] instead.
+#[derive(Clone, Debug)]
+pub struct Lookup3BuildHasher<V: Variant = Little>(u32, u32, PhantomData<fn() -> V>);
+
+impl<V: Variant> Lookup3BuildHasher<V> {
+    /// Create a [`BuildHasher`] for [`Lookup3`] using the default seeds (zero).
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seeds(0, 0)
+    }
+
+    /// Create a [`BuildHasher`] for [`Lookup3`] with a custom seed.
+    /// This is the same as `with_seeds(seed, 0)`.
+    #[inline]
+    pub const fn with_seed(seed: u32) -> Self {
+        Self::with_seeds(seed, 0)
+    }
+
+    /// Create a [`BuildHasher`] for [`Lookup3`] with two custom seeds.
+    /// See [`Lookup3::with_seeds`].
+    #[inline]
+    pub const fn with_seeds(pc: u32, pb: u32) -> Self {
+        Self(pc, pb, PhantomData)
+    }
+}
+
+impl<V: Variant> BuildHasher<u32> for Lookup3BuildHasher<V> {
+    type Hasher = Lookup3<V>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seeds(self.0, self.1)
+    }
+}
+
+impl<V: Variant> BuildHasher<u64> for Lookup3BuildHasher<V> {
+    type Hasher = Lookup3<V>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seeds(self.0, self.1)
+    }
+}
+
+impl<V: Variant> SeedableBuildHasher for Lookup3BuildHasher<V> {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seeds(seed as u32, (seed >> 32) as u32)
+    }
+}
+
+impl<V: Variant> Default for Lookup3BuildHasher<V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Variant> zeroize::Zeroize for Lookup3BuildHasher<V> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+        self.1.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Variant> Drop for Lookup3BuildHasher<V> {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Variant> zeroize::ZeroizeOnDrop for Lookup3BuildHasher<V> {}
+
+/// [`BuildHasher`] implementation for the [`Lookup3`] hasher using the default seed (zero sized).
+pub type Lookup3BuildHasherDefault<V = Little> = BuildHasherDefault<Lookup3<V>>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`Lookup3`] hasher.
+pub type Lookup3HashMap<K, V> = std::collections::HashMap<K, V, Lookup3BuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`Lookup3`] hasher with the default seed.
+pub type Lookup3HashMapDefault<K, V> = std::collections::HashMap<K, V, Lookup3BuildHasherDefault>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`Lookup3`] hasher.
+pub type Lookup3HashSet<T> = std::collections::HashSet<T, Lookup3BuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`Lookup3`] hasher with the default seed.
+pub type Lookup3HashSetDefault<T> = std::collections::HashSet<T, Lookup3BuildHasherDefault>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`Lookup3`] hasher.
+pub type Lookup3HeaplessIndexMap<K, V, const N: usize> =
+    heapless::IndexMap<K, V, Lookup3BuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`Lookup3`] hasher with the default seed.
+pub type Lookup3HeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, Lookup3BuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`Lookup3`] hasher.
+pub type Lookup3HeaplessIndexSet<T, const N: usize> = heapless::IndexSet<T, Lookup3BuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`Lookup3`] hasher with the default seed.
+pub type Lookup3HeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, Lookup3BuildHasherDefault, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`Lookup3`] hasher.
+pub type Lookup3HashBrownMap<K, V> = hashbrown::HashMap<K, V, Lookup3BuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`Lookup3`] hasher with the default seed.
+pub type Lookup3HashBrownMapDefault<K, V> = hashbrown::HashMap<K, V, Lookup3BuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`Lookup3`] hasher.
+pub type Lookup3HashBrownSet<T> = hashbrown::HashSet<T, Lookup3BuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`Lookup3`] hasher with the default seed.
+pub type Lookup3HashBrownSetDefault<T> = hashbrown::HashSet<T, Lookup3BuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`Lookup3`] hasher.
+pub type Lookup3IndexMap<K, V> = indexmap::IndexMap<K, V, Lookup3BuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`Lookup3`] hasher with the default seed.
+pub type Lookup3IndexMapDefault<K, V> = indexmap::IndexMap<K, V, Lookup3BuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`Lookup3`] hasher.
+pub type Lookup3IndexSet<T> = indexmap::IndexSet<T, Lookup3BuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`Lookup3`] hasher with the default seed.
+pub type Lookup3IndexSetDefault<T> = indexmap::IndexSet<T, Lookup3BuildHasherDefault>;
+
+/// Hasher using the lookup3 `hashlittle` algorithm.
+pub type HashLittle = Lookup3<Little>;
+
+/// Hasher using the lookup3 `hashbig` algorithm.
+pub type HashBig = Lookup3<Big>;
+
+/// Hasher using Bob Jenkins' lookup3 algorithm.
+///
+/// [`Hasher<u32>`] gives the same hashes as `hashlittle` or `hashbig` from the reference
+/// implementation, depending on the variant. [`Hasher<u64>`] combines both results of
+/// `hashlittle2` as `c | b << 32`. The reference implementation doesn't have a 64-bit
+/// version of `hashbig`, so that's calculated the same way as for `hashlittle2`.
+///
+/// lookup3 mixes the length of the input into the initial state, so it can't be
+/// calculated incrementally. This hasher buffers all data written to it and hashes it
+/// when the hash is finished. Prefer a streaming hasher unless you need compatibility
+/// with existing lookup3 hashes.
+#[derive(Clone)]
+pub struct Lookup3<V: Variant = Little> {
+    pc: u32,
+    pb: u32,
+    buffer: Vec<u8>,
+    _variant: PhantomData<fn() -> V>,
+}
+
+impl<V: Variant> Lookup3<V> {
+    /// Create a new `Lookup3` hasher using the default seeds (zero).
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seeds(0, 0)
+    }
+
+    /// Create a new `Lookup3` hasher with a custom seed.
+    /// This is the same as `with_seeds(seed, 0)`.
+    #[inline]
+    pub const fn with_seed(seed: u32) -> Self {
+        Self::with_seeds(seed, 0)
+    }
+
+    /// Create a new `Lookup3` hasher with two custom seeds. These are the initial values
+    /// of `pc` and `pb` for `hashlittle2`. `pc` is the same as `initval` for `hashlittle`.
+    #[inline]
+    pub const fn with_seeds(pc: u32, pb: u32) -> Self {
+        Self {
+            pc,
+            pb,
+            buffer: Vec::new(),
+            _variant: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    fn read(bytes: &[u8]) -> u32 {
+        let bytes = bytes.try_into().unwrap();
+        if V::BIG_ENDIAN {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        }
+    }
+
+    #[inline(always)]
+    fn mix(a: &mut u32, b: &mut u32, c: &mut u32) {
+        *a = a.wrapping_sub(*c) ^ c.rotate_left(4);
+        *c = c.wrapping_add(*b);
+        *b = b.wrapping_sub(*a) ^ a.rotate_left(6);
+        *a = a.wrapping_add(*c);
+        *c = c.wrapping_sub(*b) ^ b.rotate_left(8);
+        *b = b.wrapping_add(*a);
+        *a = a.wrapping_sub(*c) ^ c.rotate_left(16);
+        *c = c.wrapping_add(*b);
+        *b = b.wrapping_sub(*a) ^ a.rotate_left(19);
+        *a = a.wrapping_add(*c);
+        *c = c.wrapping_sub(*b) ^ b.rotate_left(4);
+        *b = b.wrapping_add(*a);
+    }
+
+    #[inline(always)]
+    fn final_mix(a: &mut u32, b: &mut u32, c: &mut u32) {
+        *c = (*c ^ *b).wrapping_sub(b.rotate_left(14));
+        *a = (*a ^ *c).wrapping_sub(c.rotate_left(11));
+        *b = (*b ^ *a).wrapping_sub(a.rotate_left(25));
+        *c = (*c ^ *b).wrapping_sub(b.rotate_left(16));
+        *a = (*a ^ *c).wrapping_sub(c.rotate_left(4));
+        *b = (*b ^ *a).wrapping_sub(a.rotate_left(14));
+        *c = (*c ^ *b).wrapping_sub(b.rotate_left(24));
+    }
+
+    fn hash(&self) -> (u32, u32) {
+        let mut bytes = self.buffer.as_slice();
+        let mut a = 0xdeadbeef_u32
+            .wrapping_add(bytes.len() as u32)
+            .wrapping_add(self.pc);
+        let mut b = a;
+        let mut c = a.wrapping_add(self.pb);
+
+        while bytes.len() > 12 {
+            a = a.wrapping_add(Self::read(&bytes[0..4]));
+            b = b.wrapping_add(Self::read(&bytes[4..8]));
+            c = c.wrapping_add(Self::read(&bytes[8..12]));
+            Self::mix(&mut a, &mut b, &mut c);
+            bytes = &bytes[12..];
+        }
+
+        if bytes.is_empty() {
+            return (c, b);
+        }
+
+        let mut tail = [0; 12];
+        tail[..bytes.len()].copy_from_slice(bytes);
+        a = a.wrapping_add(Self::read(&tail[0..4]));
+        b = b.wrapping_add(Self::read(&tail[4..8]));
+        c = c.wrapping_add(Self::read(&tail[8..12]));
+        Self::final_mix(&mut a, &mut b, &mut c);
+        (c, b)
+    }
+}
+
+impl<V: Variant> EndianIndependentAlgorithm for Lookup3<V> {}
+
+impl<V: Variant> Default for Lookup3<V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Variant> zeroize::Zeroize for Lookup3<V> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.pc.zeroize();
+        self.pb.zeroize();
+        self.buffer.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Variant> Drop for Lookup3<V> {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Variant> zeroize::ZeroizeOnDrop for Lookup3<V> {}
+
+impl<V: Variant> HasherWrite for Lookup3<V> {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+}
+
+impl<V: Variant> Hasher<u32> for Lookup3<V> {
+    #[inline]
+    fn finish(&self) -> u32 {
+        self.hash().0
+    }
+}
+
+impl<V: Variant> Hasher<u64> for Lookup3<V> {
+    #[inline]
+    fn finish(&self) -> u64 {
+        let (c, b) = self.hash();
+        c as u64 | (b as u64) << 32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::RawBytes, Hash};
+
+    fn little<T: Hash>(x: T) -> u32 {
+        Lookup3BuildHasherDefault::<Little>::new().hash_one(x)
+    }
+
+    fn little_64<T: Hash>(x: T) -> u64 {
+        Lookup3BuildHasherDefault::<Little>::new().hash_one(x)
+    }
+
+    fn big<T: Hash>(x: T) -> u32 {
+        Lookup3BuildHasherDefault::<Big>::new().hash_one(x)
+    }
+
+    // from `driver5` in the reference implementation
+    #[test]
+    fn reference() {
+        const FOUR_SCORE: &[u8] = b"Four score and seven years ago";
+        for (pc, pb, bytes, expected) in [
+            (0, 0, b"".as_slice(), 0xdeadbeef_deadbeef),
+            (0, 0xdeadbeef, b"", 0xdeadbeef_bd5b7dde),
+            (0xdeadbeef, 0xdeadbeef, b"", 0xbd5b7dde_9c093ccd),
+            (0, 0, FOUR_SCORE, 0xce7226e6_17770551),
+            (0, 1, FOUR_SCORE, 0xbd371de4_e3607cae),
+            (1, 0, FOUR_SCORE, 0x6cbea4b3_cd628161),
+        ] {
+            let hash: u64 =
+                Lookup3BuildHasher::<Little>::with_seeds(pc, pb).hash_one(RawBytes(bytes));
+            assert_eq!(hash, expected);
+        }
+        let hash: u32 = Lookup3BuildHasher::<Little>::with_seed(1).hash_one(RawBytes(FOUR_SCORE));
+        assert_eq!(hash, 0xcd628161);
+    }
+
+    hash_test_vectors! {
+        [little]
+        a: 0x58d68708,
+        ab: 0xfbb3a8df,
+        abc: 0xe397631,
+        abcd: 0xb5f4889c,
+        abcde: 0x26d72de,
+        abcdef: 0xd6fa502e,
+        abcdefg: 0xb11ad4a5,
+        abcdefgh: 0x2995c3be,
+        abcdefghi: 0xac6572b4,
+        abcdefghij: 0x8bf7d2ef,
+        abcdefghijk: 0x5f61edf8,
+        abcdefghijkl: 0x4012f87b,
+        abcdefghijklm: 0x928128f9,
+        abcdefghijklmn: 0x2bb84ef8,
+        abcdefghijklmno: 0xa9ce8fb6,
+        abcdefghijklmnop: 0x11347272,
+        abcdefghijklmnopq: 0x8938634e,
+        abcdefghijklmnopqr: 0x1ceaf360,
+        abcdefghijklmnopqrs: 0x2a80e47,
+        abcdefghijklmnopqrst: 0x372707b2,
+        abcdefghijklmnopqrstu: 0xdfa3b04b,
+        abcdefghijklmnopqrstuv: 0xa9752892,
+        abcdefghijklmnopqrstuvw: 0x4e25bfff,
+        abcdefghijklmnopqrstuvwx: 0x1b631fea,
+        abcdefghijklmnopqrstuvwxy: 0x6c29c5e2,
+        abcdefghijklmnopqrstuvwxyz: 0x7538b5bd,
+        abcdefghijklmnopqrstuvwxyz0: 0x71b486e3,
+        abcdefghijklmnopqrstuvwxyz01: 0x89cad6f1,
+        abcdefghijklmnopqrstuvwxyz012: 0x66bbd676,
+        abcdefghijklmnopqrstuvwxyz0123: 0x21a32994,
+        abcdefghijklmnopqrstuvwxyz01234: 0x1a795b4d,
+        abcdefghijklmnopqrstuvwxyz012345: 0xbec914ca,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x3a84fb6a,
+        abcdefghijklmnopqrstuvwxyz01234567: 0xcefabcb8,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x13d5107c,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0xed5169ac,
+
+        [little_64]
+        a: 0x582647ac58d68708,
+        ab: 0x6b79a0f2fbb3a8df,
+        abc: 0x3c03be9e0e397631,
+        abcd: 0xe20dd3fab5f4889c,
+        abcde: 0x3cd18df026d72de,
+        abcdef: 0x23a820a4d6fa502e,
+        abcdefg: 0x59ac1d9db11ad4a5,
+        abcdefgh: 0xc79695242995c3be,
+        abcdefghi: 0xd99384ffac6572b4,
+        abcdefghij: 0x4e0e39008bf7d2ef,
+        abcdefghijk: 0x83d518745f61edf8,
+        abcdefghijkl: 0x75b50ec04012f87b,
+        abcdefghijklm: 0xf04ab68928128f9,
+        abcdefghijklmn: 0xe692ebc02bb84ef8,
+        abcdefghijklmno: 0x263aa706a9ce8fb6,
+        abcdefghijklmnop: 0x53dbcb2611347272,
+        abcdefghijklmnopq: 0xa76ba1678938634e,
+        abcdefghijklmnopqr: 0x414e47f61ceaf360,
+        abcdefghijklmnopqrs: 0xf5cffd0d02a80e47,
+        abcdefghijklmnopqrst: 0x680bc047372707b2,
+        abcdefghijklmnopqrstu: 0xfb0a4886dfa3b04b,
+        abcdefghijklmnopqrstuv: 0xb53c7ea8a9752892,
+        abcdefghijklmnopqrstuvw: 0x3af8a8a84e25bfff,
+        abcdefghijklmnopqrstuvwx: 0xe0bd774e1b631fea,
+        abcdefghijklmnopqrstuvwxy: 0x1fb0330d6c29c5e2,
+        abcdefghijklmnopqrstuvwxyz: 0x7ded7e167538b5bd,
+        abcdefghijklmnopqrstuvwxyz0: 0x7ecb22be71b486e3,
+        abcdefghijklmnopqrstuvwxyz01: 0x5af2209689cad6f1,
+        abcdefghijklmnopqrstuvwxyz012: 0x65b7d7b766bbd676,
+        abcdefghijklmnopqrstuvwxyz0123: 0x13689cc21a32994,
+        abcdefghijklmnopqrstuvwxyz01234: 0x5046b1361a795b4d,
+        abcdefghijklmnopqrstuvwxyz012345: 0x7b79ff54bec914ca,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x57b6ebf3a84fb6a,
+        abcdefghijklmnopqrstuvwxyz01234567: 0x61d33268cefabcb8,
+        abcdefghijklmnopqrstuvwxyz012345678: 0xb084cb8813d5107c,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x3bfd310aed5169ac,
+
+        [big]
+        a: 0xe4ecaa40,
+        ab: 0xe1354f9b,
+        abc: 0xb94b42a0,
+        abcd: 0x9fd986e3,
+        abcde: 0xf69eef3f,
+        abcdef: 0xad26837f,
+        abcdefg: 0xb3b8d0f3,
+        abcdefgh: 0x6dc39bd2,
+        abcdefghi: 0x33f760c1,
+        abcdefghij: 0xf46bcde4,
+        abcdefghijk: 0xa239badd,
+        abcdefghijkl: 0xfa89d110,
+        abcdefghijklm: 0x5699f99f,
+        abcdefghijklmn: 0xbc2d48bd,
+        abcdefghijklmno: 0x1a4b7e5c,
+        abcdefghijklmnop: 0x300a3c11,
+        abcdefghijklmnopq: 0x10c4804,
+        abcdefghijklmnopqr: 0x369cab44,
+        abcdefghijklmnopqrs: 0x3ce522d6,
+        abcdefghijklmnopqrst: 0xc48c9a5a,
+        abcdefghijklmnopqrstu: 0xe28d72de,
+        abcdefghijklmnopqrstuv: 0x4708cac7,
+        abcdefghijklmnopqrstuvw: 0xd18b01e6,
+        abcdefghijklmnopqrstuvwx: 0xcbb952ce,
+        abcdefghijklmnopqrstuvwxy: 0xf41f07f1,
+        abcdefghijklmnopqrstuvwxyz: 0xf005d0b,
+        abcdefghijklmnopqrstuvwxyz0: 0xfcf4020f,
+        abcdefghijklmnopqrstuvwxyz01: 0xee0a731a,
+        abcdefghijklmnopqrstuvwxyz012: 0xc3c5ed42,
+        abcdefghijklmnopqrstuvwxyz0123: 0x6b5117c7,
+        abcdefghijklmnopqrstuvwxyz01234: 0x1083a9b7,
+        abcdefghijklmnopqrstuvwxyz012345: 0x84ea5f35,
+        abcdefghijklmnopqrstuvwxyz0123456: 0xa7dd5a40,
+        abcdefghijklmnopqrstuvwxyz01234567: 0x5b1643a8,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x2b12f19c,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x1e6b3da5,
+    }
+}
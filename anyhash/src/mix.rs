@@ -0,0 +1,286 @@
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+use crate::{
+    impl_core_build_hasher, impl_core_hasher, internal::fmix64, BuildHasher,
+    EndianIndependentAlgorithm, EndianIndependentWrites, Hasher, HasherWrite,
+};
+
+/// Finalization mixer used by [`MixedHasher`] to scramble a finished hash.
+///
+/// A good mixer makes every output bit depend on every input bit, which improves the quality
+/// of fast hashers with weak final states, like FNV.
+pub trait Mixer<T> {
+    /// Mix the hash `value`.
+    fn mix(value: T) -> T;
+}
+
+/// [`Mixer`] using the `fmix32` and `fmix64` finalizers from MurmurHash3.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Fmix;
+
+impl Mixer<u32> for Fmix {
+    #[inline]
+    fn mix(mut value: u32) -> u32 {
+        value ^= value >> 16;
+        value = value.wrapping_mul(0x85ebca6b);
+        value ^= value >> 13;
+        value = value.wrapping_mul(0xc2b2ae35);
+        value ^ (value >> 16)
+    }
+}
+
+impl Mixer<u64> for Fmix {
+    #[inline]
+    fn mix(value: u64) -> u64 {
+        fmix64(value)
+    }
+}
+
+/// [`Mixer`] using the output function of the SplitMix64 random number generator.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SplitMix;
+
+impl Mixer<u64> for SplitMix {
+    #[inline]
+    fn mix(mut value: u64) -> u64 {
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d049bb133111eb);
+        value ^ (value >> 31)
+    }
+}
+
+macro_rules! impl_mixed_writes {
+    ($($t:ty: $fn:ident),* $(,)?) => { $(
+        #[inline]
+        fn $fn(&mut self, i: $t) {
+            self.hasher.$fn(i);
+        }
+    )* };
+}
+
+/// Wrapper for hashers that applies the [`Mixer`] `M` to the hash when finishing.
+///
+/// All writes are forwarded to the wrapped hasher.
+///
+/// ```
+/// # use anyhash::{Fmix, Hasher, HasherWrite, MixedHasher};
+/// # #[derive(Default)]
+/// # struct Sum(u64);
+/// # impl HasherWrite for Sum {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 += b as u64; }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for Sum {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+/// let mut hasher = MixedHasher::<_, Fmix>::new(Sum::default());
+/// hasher.write(&[1]);
+/// let hash: u64 = hasher.finish();
+/// assert_eq!(hash, 0xb456bcfc34c2cb2c);
+/// ```
+pub struct MixedHasher<H, M> {
+    hasher: H,
+    _pd: PhantomData<fn() -> M>,
+}
+
+impl_core_hasher!(impl<H: Hasher<u64>, M: Mixer<u64>> MixedHasher<H, M>);
+
+impl<H, M> MixedHasher<H, M> {
+    /// Create a new `MixedHasher` wrapping `hasher`.
+    #[inline]
+    pub const fn new(hasher: H) -> Self {
+        Self {
+            hasher,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Get a reference to the wrapped hasher.
+    #[inline]
+    pub const fn get_ref(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Unwrap the wrapped hasher.
+    #[inline]
+    pub fn into_inner(self) -> H {
+        self.hasher
+    }
+}
+
+impl<T, H: Hasher<T>, M: Mixer<T>> Hasher<T> for MixedHasher<H, M> {
+    #[inline]
+    fn finish(&self) -> T {
+        M::mix(self.hasher.finish())
+    }
+
+    #[inline]
+    fn finish_once(self) -> T {
+        M::mix(self.hasher.finish_once())
+    }
+}
+
+impl<H: HasherWrite, M> HasherWrite for MixedHasher<H, M> {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.hasher.write(bytes);
+    }
+
+    impl_mixed_writes! {
+        u8: write_u8,
+        u16: write_u16,
+        u32: write_u32,
+        u64: write_u64,
+        u128: write_u128,
+        usize: write_usize,
+        i8: write_i8,
+        i16: write_i16,
+        i32: write_i32,
+        i64: write_i64,
+        i128: write_i128,
+        isize: write_isize,
+        f32: write_f32,
+        f64: write_f64,
+        &str: write_str,
+    }
+
+    #[inline]
+    fn write_length_prefix(&mut self, len: usize) {
+        self.hasher.write_length_prefix(len);
+    }
+}
+
+impl<H: EndianIndependentAlgorithm, M> EndianIndependentAlgorithm for MixedHasher<H, M> {}
+
+impl<H: EndianIndependentWrites, M> EndianIndependentWrites for MixedHasher<H, M> {}
+
+impl<H: Debug, M> Debug for MixedHasher<H, M> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MixedHasher").field(&self.hasher).finish()
+    }
+}
+
+impl<H: Clone, M> Clone for MixedHasher<H, M> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.hasher.clone())
+    }
+}
+
+impl<H: Default, M> Default for MixedHasher<H, M> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(H::default())
+    }
+}
+
+/// `BuildHasher` for making [`MixedHasher`] hashers.
+pub struct MixedBuildHasher<BH, M> {
+    build_hasher: BH,
+    _pd: PhantomData<fn() -> M>,
+}
+
+impl_core_build_hasher!(impl<BH: BuildHasher<u64>, M: Mixer<u64>> MixedBuildHasher<BH, M>);
+
+impl<BH, M> MixedBuildHasher<BH, M> {
+    /// Create a new `MixedBuildHasher` wrapping `build_hasher`.
+    #[inline]
+    pub const fn new(build_hasher: BH) -> Self {
+        Self {
+            build_hasher,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Get a reference to the wrapped `BuildHasher`.
+    #[inline]
+    pub const fn get_ref(&self) -> &BH {
+        &self.build_hasher
+    }
+}
+
+impl<T, BH: BuildHasher<T>, M: Mixer<T>> BuildHasher<T> for MixedBuildHasher<BH, M> {
+    type Hasher = MixedHasher<BH::Hasher, M>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        MixedHasher::new(self.build_hasher.build_hasher())
+    }
+}
+
+impl<BH: Debug, M> Debug for MixedBuildHasher<BH, M> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MixedBuildHasher")
+            .field(&self.build_hasher)
+            .finish()
+    }
+}
+
+impl<BH: Clone, M> Clone for MixedBuildHasher<BH, M> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.build_hasher.clone())
+    }
+}
+
+impl<BH: Default, M> Default for MixedBuildHasher<BH, M> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(BH::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BuildHasherDefault;
+
+    #[derive(Default)]
+    struct Last(u64);
+
+    impl HasherWrite for Last {
+        fn write(&mut self, _: &[u8]) {}
+
+        fn write_u64(&mut self, i: u64) {
+            self.0 = i;
+        }
+    }
+
+    impl Hasher<u64> for Last {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+    }
+
+    impl Hasher<u32> for Last {
+        fn finish(&self) -> u32 {
+            self.0 as u32
+        }
+    }
+
+    #[test]
+    fn mixers() {
+        assert_eq!(<Fmix as Mixer<u32>>::mix(1), 0x514e28b7);
+        assert_eq!(<Fmix as Mixer<u64>>::mix(1), 0xb456bcfc34c2cb2c);
+        assert_eq!(
+            <SplitMix as Mixer<u64>>::mix(0x9e3779b97f4a7c15),
+            0xe220a8397b1dcdaf
+        );
+    }
+
+    #[test]
+    fn build_hasher() {
+        let bh = MixedBuildHasher::<BuildHasherDefault<Last>, SplitMix>::default();
+        let hash: u64 = bh.hash_one(0x9e3779b97f4a7c15_u64);
+        assert_eq!(hash, 0xe220a8397b1dcdaf);
+        let bh = MixedBuildHasher::<BuildHasherDefault<Last>, Fmix>::default();
+        let hash: u32 = bh.hash_one(1_u64);
+        assert_eq!(hash, 0x514e28b7);
+    }
+}
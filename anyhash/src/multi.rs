@@ -0,0 +1,508 @@
+use core::fmt::{self, Debug};
+
+use crate::{
+    impl_core_build_hasher, internal::fmix64, BuildHasher, Hasher, HasherWrite, SeedableBuildHasher,
+};
+
+// Derive the seed for hash function number `index` from `seed`
+fn index_seed(seed: u128, index: u64) -> u128 {
+    let i = fmix64(index ^ 0x9e3779b97f4a7c15);
+    let lo = fmix64(seed as u64 ^ i);
+    let hi = fmix64((seed >> 64) as u64 ^ i.rotate_left(32));
+    lo as u128 | (hi as u128) << 64
+}
+
+macro_rules! impl_multi_writes {
+    ($($t:ty: $fn:ident),* $(,)?) => { $(
+        #[inline]
+        fn $fn(&mut self, i: $t) {
+            for hasher in &mut self.0 {
+                hasher.$fn(i);
+            }
+        }
+    )* };
+}
+
+macro_rules! impl_pair_writes {
+    ($($t:ty: $fn:ident),* $(,)?) => { $(
+        #[inline]
+        fn $fn(&mut self, i: $t) {
+            self.0.$fn(i);
+            self.1.$fn(i);
+        }
+    )* };
+}
+
+/// Hasher that runs `K` instances of a hasher over the same writes, and finishes to an array
+/// of all `K` hashes.
+///
+/// The hashers should be seeded differently to get independent hashes. This is the building
+/// block for Bloom filters, count-min sketches and similar structures that need several hash
+/// functions. [`MultiBuildHasher`] can create independently seeded hashers.
+///
+/// ```
+/// # use anyhash::{Hasher, HasherWrite, MultiHasher};
+/// # #[derive(Clone, Default)]
+/// # struct MyHasher(u64);
+/// # impl HasherWrite for MyHasher {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3); }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for MyHasher {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+/// let mut hasher = MultiHasher::new([MyHasher(1), MyHasher(2), MyHasher(3)]);
+/// hasher.write(b"hello");
+/// let [a, b, c]: [u64; 3] = hasher.finish();
+/// assert!(a != b && b != c);
+/// ```
+#[derive(Clone)]
+pub struct MultiHasher<H, const K: usize>([H; K]);
+
+impl<H, const K: usize> MultiHasher<H, K> {
+    /// Create a new `MultiHasher` from `K` hashers.
+    #[inline]
+    pub const fn new(hashers: [H; K]) -> Self {
+        Self(hashers)
+    }
+
+    /// Get a reference to the wrapped hashers.
+    #[inline]
+    pub const fn get_ref(&self) -> &[H; K] {
+        &self.0
+    }
+
+    /// Unwrap the wrapped hashers.
+    #[inline]
+    pub fn into_inner(self) -> [H; K] {
+        self.0
+    }
+}
+
+impl<T, H: Hasher<T>, const K: usize> Hasher<[T; K]> for MultiHasher<H, K> {
+    #[inline]
+    fn finish(&self) -> [T; K] {
+        core::array::from_fn(|i| self.0[i].finish())
+    }
+}
+
+impl<H: HasherWrite, const K: usize> HasherWrite for MultiHasher<H, K> {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for hasher in &mut self.0 {
+            hasher.write(bytes);
+        }
+    }
+
+    impl_multi_writes! {
+        u8: write_u8,
+        u16: write_u16,
+        u32: write_u32,
+        u64: write_u64,
+        u128: write_u128,
+        usize: write_usize,
+        i8: write_i8,
+        i16: write_i16,
+        i32: write_i32,
+        i64: write_i64,
+        i128: write_i128,
+        isize: write_isize,
+        f32: write_f32,
+        f64: write_f64,
+        &str: write_str,
+    }
+
+    #[inline]
+    fn write_length_prefix(&mut self, len: usize) {
+        for hasher in &mut self.0 {
+            hasher.write_length_prefix(len);
+        }
+    }
+}
+
+impl<H: Debug, const K: usize> Debug for MultiHasher<H, K> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MultiHasher").field(&self.0).finish()
+    }
+}
+
+/// `BuildHasher` for making [`MultiHasher`] hashers from `K` build hashers.
+///
+/// With [`MultiBuildHasher::from_seed`], each build hasher is created with a different seed
+/// derived from the given seed. `Default` uses the seed zero.
+#[derive(Clone)]
+pub struct MultiBuildHasher<BH, const K: usize>([BH; K]);
+
+impl<BH, const K: usize> MultiBuildHasher<BH, K> {
+    /// Create a new `MultiBuildHasher` from `K` build hashers.
+    #[inline]
+    pub const fn new(build_hashers: [BH; K]) -> Self {
+        Self(build_hashers)
+    }
+
+    /// Get a reference to the wrapped build hashers.
+    #[inline]
+    pub const fn get_ref(&self) -> &[BH; K] {
+        &self.0
+    }
+}
+
+impl<T, BH: BuildHasher<T>, const K: usize> BuildHasher<[T; K]> for MultiBuildHasher<BH, K> {
+    type Hasher = MultiHasher<BH::Hasher, K>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        MultiHasher::new(core::array::from_fn(|i| self.0[i].build_hasher()))
+    }
+}
+
+impl<BH: SeedableBuildHasher, const K: usize> SeedableBuildHasher for MultiBuildHasher<BH, K> {
+    /// Create `K` build hashers, each seeded with a different mix of `seed` and its index.
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::new(core::array::from_fn(|i| {
+            BH::from_seed(index_seed(seed, i as u64))
+        }))
+    }
+}
+
+impl<BH: SeedableBuildHasher, const K: usize> Default for MultiBuildHasher<BH, K> {
+    #[inline]
+    fn default() -> Self {
+        Self::from_seed(0)
+    }
+}
+
+impl<BH: Debug, const K: usize> Debug for MultiBuildHasher<BH, K> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MultiBuildHasher").field(&self.0).finish()
+    }
+}
+
+/// `BuildHasher` for hash function number `i` of a family of independent hash functions.
+///
+/// Structures like Bloom filters and cuckoo tables need several hash functions that can be
+/// picked by index. There are two ways to derive them:
+///
+/// - [`IndexedBuildHasher::new`] works with any `BuildHasher`, and writes a mix of the index
+///   into each hasher before any other data.
+/// - [`IndexedBuildHasher::from_seed`] creates a [`SeedableBuildHasher`] with a seed derived
+///   from the seed and the index, so hashing doesn't need the extra write. It derives the same
+///   hash functions as [`MultiBuildHasher::from_seed`].
+///
+/// ```
+/// # use anyhash::{BuildHasher, BuildHasherDefault, Hasher, HasherWrite, IndexedBuildHasher};
+/// # #[derive(Default)]
+/// # struct MyHasher(u64);
+/// # impl HasherWrite for MyHasher {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3); }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for MyHasher {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+/// let h0 = IndexedBuildHasher::new(BuildHasherDefault::<MyHasher>::new(), 0);
+/// let h1 = IndexedBuildHasher::new(BuildHasherDefault::<MyHasher>::new(), 1);
+/// assert_ne!(h0.hash_one("key"), h1.hash_one("key"));
+/// ```
+#[derive(Clone)]
+pub struct IndexedBuildHasher<BH> {
+    build_hasher: BH,
+    index: u64,
+    prefix: Option<u64>,
+}
+
+impl_core_build_hasher!(impl<BH: BuildHasher<u64>> IndexedBuildHasher<BH>);
+
+impl<BH> IndexedBuildHasher<BH> {
+    /// Create hash function number `index` derived from `build_hasher`.
+    #[inline]
+    pub const fn new(build_hasher: BH, index: u64) -> Self {
+        Self {
+            build_hasher,
+            index,
+            prefix: Some(fmix64(index ^ 0x9e3779b97f4a7c15)),
+        }
+    }
+
+    /// Get a reference to the wrapped `BuildHasher`.
+    #[inline]
+    pub const fn get_ref(&self) -> &BH {
+        &self.build_hasher
+    }
+
+    /// Get the index of this hash function.
+    #[inline]
+    pub const fn index(&self) -> u64 {
+        self.index
+    }
+}
+
+impl<BH: SeedableBuildHasher> IndexedBuildHasher<BH> {
+    /// Create hash function number `index` by seeding a build hasher with a seed derived from
+    /// `seed` and `index`.
+    #[inline]
+    pub fn from_seed(seed: u128, index: u64) -> Self {
+        Self {
+            build_hasher: BH::from_seed(index_seed(seed, index)),
+            index,
+            prefix: None,
+        }
+    }
+}
+
+impl<T, BH: BuildHasher<T>> BuildHasher<T> for IndexedBuildHasher<BH> {
+    type Hasher = BH::Hasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = self.build_hasher.build_hasher();
+        if let Some(prefix) = self.prefix {
+            hasher.write_u64(prefix);
+        }
+        hasher
+    }
+}
+
+impl<BH: Debug> Debug for IndexedBuildHasher<BH> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IndexedBuildHasher")
+            .field("build_hasher", &self.build_hasher)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+/// Hasher that runs two hashers, which can be of different types, over the same writes, and
+/// finishes to a tuple of both hashes.
+///
+/// This calculates two independent hashes of a value in one traversal, like the pair of hashes
+/// used for double hashing in open addressing and cuckoo hashing. Use [`PairBuildHasher`] to
+/// create these.
+///
+/// ```
+/// # use anyhash::{BuildHasher, BuildHasherDefault, Hasher, HasherWrite, PairBuildHasher};
+/// # #[derive(Default)]
+/// # struct Fnv(u64);
+/// # impl HasherWrite for Fnv {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3); }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for Fnv {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+/// # #[derive(Default)]
+/// # struct Djb2(u32);
+/// # impl HasherWrite for Djb2 {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = self.0.wrapping_mul(33) ^ b as u32; }
+/// #     }
+/// # }
+/// # impl Hasher<u32> for Djb2 {
+/// #     fn finish(&self) -> u32 { self.0 }
+/// # }
+/// let bh = PairBuildHasher::new(
+///     BuildHasherDefault::<Fnv>::new(),
+///     BuildHasherDefault::<Djb2>::new(),
+/// );
+/// let (h1, h2): (u64, u32) = bh.hash_one("key");
+/// // probe sequence for double hashing
+/// let step = h2 as u64 | 1;
+/// let probes: Vec<_> = (0..4).map(|i| h1.wrapping_add(i * step) % 16).collect();
+/// # let _ = probes;
+/// ```
+#[derive(Clone, Default)]
+pub struct PairHasher<H1, H2>(H1, H2);
+
+impl<H1, H2> PairHasher<H1, H2> {
+    /// Create a new `PairHasher` from two hashers.
+    #[inline]
+    pub const fn new(hasher1: H1, hasher2: H2) -> Self {
+        Self(hasher1, hasher2)
+    }
+
+    /// Get references to the wrapped hashers.
+    #[inline]
+    pub const fn get_ref(&self) -> (&H1, &H2) {
+        (&self.0, &self.1)
+    }
+
+    /// Unwrap the wrapped hashers.
+    #[inline]
+    pub fn into_inner(self) -> (H1, H2) {
+        (self.0, self.1)
+    }
+}
+
+impl<T1, T2, H1: Hasher<T1>, H2: Hasher<T2>> Hasher<(T1, T2)> for PairHasher<H1, H2> {
+    #[inline]
+    fn finish(&self) -> (T1, T2) {
+        (self.0.finish(), self.1.finish())
+    }
+
+    #[inline]
+    fn finish_once(self) -> (T1, T2) {
+        (self.0.finish_once(), self.1.finish_once())
+    }
+}
+
+impl<H1: HasherWrite, H2: HasherWrite> HasherWrite for PairHasher<H1, H2> {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes);
+        self.1.write(bytes);
+    }
+
+    impl_pair_writes! {
+        u8: write_u8,
+        u16: write_u16,
+        u32: write_u32,
+        u64: write_u64,
+        u128: write_u128,
+        usize: write_usize,
+        i8: write_i8,
+        i16: write_i16,
+        i32: write_i32,
+        i64: write_i64,
+        i128: write_i128,
+        isize: write_isize,
+        f32: write_f32,
+        f64: write_f64,
+        &str: write_str,
+    }
+
+    #[inline]
+    fn write_length_prefix(&mut self, len: usize) {
+        self.0.write_length_prefix(len);
+        self.1.write_length_prefix(len);
+    }
+}
+
+impl<H1: Debug, H2: Debug> Debug for PairHasher<H1, H2> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PairHasher")
+            .field(&self.0)
+            .field(&self.1)
+            .finish()
+    }
+}
+
+/// `BuildHasher` for making [`PairHasher`] hashers from two build hashers.
+#[derive(Clone, Default)]
+pub struct PairBuildHasher<BH1, BH2>(BH1, BH2);
+
+impl<BH1, BH2> PairBuildHasher<BH1, BH2> {
+    /// Create a new `PairBuildHasher` from two build hashers.
+    #[inline]
+    pub const fn new(build_hasher1: BH1, build_hasher2: BH2) -> Self {
+        Self(build_hasher1, build_hasher2)
+    }
+
+    /// Get references to the wrapped build hashers.
+    #[inline]
+    pub const fn get_ref(&self) -> (&BH1, &BH2) {
+        (&self.0, &self.1)
+    }
+}
+
+impl<T1, T2, BH1: BuildHasher<T1>, BH2: BuildHasher<T2>> BuildHasher<(T1, T2)>
+    for PairBuildHasher<BH1, BH2>
+{
+    type Hasher = PairHasher<BH1::Hasher, BH2::Hasher>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        PairHasher::new(self.0.build_hasher(), self.1.build_hasher())
+    }
+}
+
+impl<BH1: Debug, BH2: Debug> Debug for PairBuildHasher<BH1, BH2> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PairBuildHasher")
+            .field(&self.0)
+            .field(&self.1)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct Fnv(u64);
+
+    impl HasherWrite for Fnv {
+        fn write(&mut self, bytes: &[u8]) {
+            for &b in bytes {
+                self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3);
+            }
+        }
+    }
+
+    impl Hasher<u64> for Fnv {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+    }
+
+    struct FnvBuildHasher(u64);
+
+    impl BuildHasher<u64> for FnvBuildHasher {
+        type Hasher = Fnv;
+
+        fn build_hasher(&self) -> Fnv {
+            Fnv(self.0)
+        }
+    }
+
+    impl SeedableBuildHasher for FnvBuildHasher {
+        fn from_seed(seed: u128) -> Self {
+            Self(seed as u64)
+        }
+    }
+
+    #[test]
+    fn multi() {
+        let bh = MultiBuildHasher::<FnvBuildHasher, 4>::default();
+        let hashes: [u64; 4] = bh.hash_one(1234_u32);
+        for (i, &hash) in hashes.iter().enumerate() {
+            assert_eq!(hash, bh.get_ref()[i].hash_one(1234_u32));
+            assert!(!hashes[..i].contains(&hash));
+        }
+        let other: [u64; 4] =
+            MultiBuildHasher::<FnvBuildHasher, 4>::from_seed(1).hash_one(1234_u32);
+        assert_ne!(hashes, other);
+    }
+
+    #[test]
+    fn indexed() {
+        let multi = MultiBuildHasher::<FnvBuildHasher, 3>::from_seed(5);
+        let hashes: [u64; 3] = multi.hash_one("key");
+        for (i, &hash) in hashes.iter().enumerate() {
+            let bh = IndexedBuildHasher::<FnvBuildHasher>::from_seed(5, i as u64);
+            assert_eq!(bh.hash_one("key"), hash);
+        }
+
+        let a = IndexedBuildHasher::new(FnvBuildHasher(0), 0).hash_one("key");
+        let b = IndexedBuildHasher::new(FnvBuildHasher(0), 1).hash_one("key");
+        assert_ne!(a, b);
+        assert_ne!(a, FnvBuildHasher(0).hash_one("key"));
+    }
+
+    #[test]
+    fn pair() {
+        let bh = PairBuildHasher::new(FnvBuildHasher(1), FnvBuildHasher(2));
+        let (a, b): (u64, u64) = bh.hash_one(1234_u32);
+        assert_eq!(a, FnvBuildHasher(1).hash_one(1234_u32));
+        assert_eq!(b, FnvBuildHasher(2).hash_one(1234_u32));
+    }
+}
@@ -0,0 +1,325 @@
+//! Hasher and collections using the MurmurHash2 algorithm.
+
+// based on the reference implementation at https://github.com/aappleby/smhasher
+
+use crate::{
+    impl_core_build_hasher, impl_core_hasher, BuildHasher, BuildHasherDefault,
+    EndianIndependentAlgorithm, Hasher, HasherWrite, SeedableBuildHasher,
+};
+use alloc::vec::Vec;
+
+impl_core_build_hasher!(Murmur2_64ABuildHasher);
+impl_core_hasher!(Murmur2_64A);
+
+const M: u64 = 0xc6a4a7935bd1e995;
+const R: u32 = 47;
+
+/// [`BuildHasher`] implementation for the [`Murmur2_64A`] hasher.
+/// If you don't need support for using custom seeds, use the zero sized
+/// [`Murmur2_64ABuildHasherDefault`] instead.
+#[derive(Clone, Debug)]
+pub struct Murmur2_64ABuildHasher(u64);
+
+impl Murmur2_64ABuildHasher {
+    /// Create a [`BuildHasher`] for [`Murmur2_64A`] using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Create a [`BuildHasher`] for [`Murmur2_64A`] with a custom seed.
+    #[inline]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self(seed)
+    }
+}
+
+impl BuildHasher<u64> for Murmur2_64ABuildHasher {
+    type Hasher = Murmur2_64A;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seed(self.0)
+    }
+}
+
+impl SeedableBuildHasher for Murmur2_64ABuildHasher {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seed(seed as u64)
+    }
+}
+
+impl Default for Murmur2_64ABuildHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Murmur2_64ABuildHasher {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Murmur2_64ABuildHasher {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Murmur2_64ABuildHasher {}
+
+/// [`BuildHasher`] implementation for the [`Murmur2_64A`] hasher using the default seed (zero sized).
+pub type Murmur2_64ABuildHasherDefault = BuildHasherDefault<Murmur2_64A>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`Murmur2_64A`] hasher.
+pub type Murmur2_64AHashMap<K, V> = std::collections::HashMap<K, V, Murmur2_64ABuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`Murmur2_64A`] hasher with the default seed.
+pub type Murmur2_64AHashMapDefault<K, V> =
+    std::collections::HashMap<K, V, Murmur2_64ABuildHasherDefault>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`Murmur2_64A`] hasher.
+pub type Murmur2_64AHashSet<T> = std::collections::HashSet<T, Murmur2_64ABuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`Murmur2_64A`] hasher with the default seed.
+pub type Murmur2_64AHashSetDefault<T> = std::collections::HashSet<T, Murmur2_64ABuildHasherDefault>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`Murmur2_64A`] hasher.
+pub type Murmur2_64AHeaplessIndexMap<K, V, const N: usize> =
+    heapless::IndexMap<K, V, Murmur2_64ABuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`Murmur2_64A`] hasher with the default seed.
+pub type Murmur2_64AHeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, Murmur2_64ABuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`Murmur2_64A`] hasher.
+pub type Murmur2_64AHeaplessIndexSet<T, const N: usize> =
+    heapless::IndexSet<T, Murmur2_64ABuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`Murmur2_64A`] hasher with the default seed.
+pub type Murmur2_64AHeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, Murmur2_64ABuildHasherDefault, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`Murmur2_64A`] hasher.
+pub type Murmur2_64AHashBrownMap<K, V> = hashbrown::HashMap<K, V, Murmur2_64ABuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`Murmur2_64A`] hasher with the default seed.
+pub type Murmur2_64AHashBrownMapDefault<K, V> =
+    hashbrown::HashMap<K, V, Murmur2_64ABuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`Murmur2_64A`] hasher.
+pub type Murmur2_64AHashBrownSet<T> = hashbrown::HashSet<T, Murmur2_64ABuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`Murmur2_64A`] hasher with the default seed.
+pub type Murmur2_64AHashBrownSetDefault<T> = hashbrown::HashSet<T, Murmur2_64ABuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`Murmur2_64A`] hasher.
+pub type Murmur2_64AIndexMap<K, V> = indexmap::IndexMap<K, V, Murmur2_64ABuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`Murmur2_64A`] hasher with the default seed.
+pub type Murmur2_64AIndexMapDefault<K, V> = indexmap::IndexMap<K, V, Murmur2_64ABuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`Murmur2_64A`] hasher.
+pub type Murmur2_64AIndexSet<T> = indexmap::IndexSet<T, Murmur2_64ABuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`Murmur2_64A`] hasher with the default seed.
+pub type Murmur2_64AIndexSetDefault<T> = indexmap::IndexSet<T, Murmur2_64ABuildHasherDefault>;
+
+/// Hasher using the 64-bit MurmurHash2 algorithm (`MurmurHash64A`).
+///
+/// MurmurHash2 mixes the length of the input into the initial state, so it can't be
+/// calculated incrementally. This hasher buffers all data written to it and hashes it
+/// when the hash is finished. Prefer a streaming hasher unless you need compatibility
+/// with existing MurmurHash2 hashes.
+#[derive(Clone)]
+pub struct Murmur2_64A {
+    seed: u64,
+    buffer: Vec<u8>,
+}
+
+impl Murmur2_64A {
+    /// Create a new `Murmur2_64A` hasher using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Create a new `Murmur2_64A` hasher with a custom seed.
+    #[inline]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            buffer: Vec::new(),
+        }
+    }
+
+    fn hash(bytes: &[u8], seed: u64) -> u64 {
+        let mut h = seed ^ (bytes.len() as u64).wrapping_mul(M);
+
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            let mut k = u64::from_le_bytes(chunk.try_into().unwrap());
+            k = k.wrapping_mul(M);
+            k ^= k >> R;
+            k = k.wrapping_mul(M);
+            h ^= k;
+            h = h.wrapping_mul(M);
+        }
+
+        let remainder = chunks.remainder();
+        if !remainder.is_empty() {
+            let mut tail = [0; 8];
+            tail[..remainder.len()].copy_from_slice(remainder);
+            h ^= u64::from_le_bytes(tail);
+            h = h.wrapping_mul(M);
+        }
+
+        h ^= h >> R;
+        h = h.wrapping_mul(M);
+        h ^ h >> R
+    }
+}
+
+impl EndianIndependentAlgorithm for Murmur2_64A {}
+
+impl Default for Murmur2_64A {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Murmur2_64A {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.seed.zeroize();
+        self.buffer.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Murmur2_64A {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Murmur2_64A {}
+
+impl HasherWrite for Murmur2_64A {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+}
+
+impl Hasher<u64> for Murmur2_64A {
+    #[inline]
+    fn finish(&self) -> u64 {
+        Self::hash(&self.buffer, self.seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::RawBytes, Hash};
+
+    fn default_seed<T: Hash>(x: T) -> u64 {
+        Murmur2_64ABuildHasherDefault::new().hash_one(x)
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(default_seed(()), 0);
+    }
+
+    #[test]
+    fn custom_seed() {
+        let mut data = [0; 17];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        let bh = Murmur2_64ABuildHasher::with_seed(0x55555555_55555555);
+        for (len, expected) in [
+            (0, 0xa912eaf8953d444e),
+            (1, 0x40ed0c39e9b7e418),
+            (3, 0x8d02ea2f85c337c9),
+            (7, 0xa0d5b12b151b9c0f),
+            (8, 0xaa8f80e5e04ae4d2),
+            (9, 0xc13d13759fd39fe5),
+            (15, 0x54905e437dd8c2ea),
+            (16, 0xd4d0c527410fc4bd),
+            (17, 0xe9a6216ea0b9ffab),
+        ] {
+            assert_eq!(bh.hash_one(RawBytes(&data[..len])), expected, "len {len}");
+        }
+    }
+
+    hash_test_vectors! {
+        [default_seed]
+        a: 0x71717d2d36b6b11,
+        ab: 0x62be85b2fe53d1f8,
+        abc: 0x9cc9c33498a95efb,
+        abcd: 0xec1044c45cc5097a,
+        abcde: 0x1182974836d6dbb7,
+        abcdef: 0xb78e3425fc996779,
+        abcdefg: 0x241aa52b0a62005d,
+        abcdefgh: 0xafdb0257ff41aa98,
+        abcdefghi: 0xc9b9d84356146ac2,
+        abcdefghij: 0xa33f0dbf245bcb51,
+        abcdefghijk: 0xfeff07a18c726536,
+        abcdefghijkl: 0xa24066d5378b01bb,
+        abcdefghijklm: 0xe126e825baaba665,
+        abcdefghijklmn: 0x9da2538e559887e9,
+        abcdefghijklmno: 0xfdaac8a629dcd46a,
+        abcdefghijklmnop: 0xd41a085973d213ba,
+        abcdefghijklmnopq: 0xf996a806d240427,
+        abcdefghijklmnopqr: 0x331b3bdae574a8df,
+        abcdefghijklmnopqrs: 0xc192501bf2b14ac4,
+        abcdefghijklmnopqrst: 0x7331249cbbf579ef,
+        abcdefghijklmnopqrstu: 0x5d445cbe8f77140d,
+        abcdefghijklmnopqrstuv: 0xd36d0cc200c3a1bb,
+        abcdefghijklmnopqrstuvw: 0xdc298fa56588a7e7,
+        abcdefghijklmnopqrstuvwx: 0xf3210474132c0d24,
+        abcdefghijklmnopqrstuvwxy: 0x8326b7e231cc4b24,
+        abcdefghijklmnopqrstuvwxyz: 0x6825d37a6f2a58d0,
+        abcdefghijklmnopqrstuvwxyz0: 0x380c09c2d8f73a15,
+        abcdefghijklmnopqrstuvwxyz01: 0xfb7878b2d40efa03,
+        abcdefghijklmnopqrstuvwxyz012: 0x57ae3364ec07bdc7,
+        abcdefghijklmnopqrstuvwxyz0123: 0xdbaf6466e160ae13,
+        abcdefghijklmnopqrstuvwxyz01234: 0xf25af86ef61952ac,
+        abcdefghijklmnopqrstuvwxyz012345: 0x468cc352d09ec8ec,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x2479140aff5ac5b6,
+        abcdefghijklmnopqrstuvwxyz01234567: 0xc3e2e830bdc89e16,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x7b05bf49da00266,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x48b3bc0410be2f9a,
+    }
+}
@@ -0,0 +1,789 @@
+//! Hashers and collections using the MurmurHash3 algorithm.
+
+// based on the reference implementation at https://github.com/aappleby/smhasher
+
+use crate::{
+    impl_core_build_hasher, impl_core_hasher, internal::fmix64, BuildHasher, BuildHasherDefault,
+    EndianIndependentAlgorithm, Hasher, HasherWrite, SeedableBuildHasher,
+};
+
+impl_core_build_hasher!(Murmur3x86_32BuildHasher via u32; Murmur3x64_128BuildHasher);
+impl_core_hasher!(Murmur3x86_32 via u32; Murmur3x64_128);
+
+const C1_32: u32 = 0xcc9e2d51;
+const C2_32: u32 = 0x1b873593;
+const C1_64: u64 = 0x87c37b91114253d5;
+const C2_64: u64 = 0x4cf5ad432745937f;
+
+#[inline(always)]
+const fn fmix32(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85ebca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2ae35);
+    h ^ h >> 16
+}
+
+/// [`BuildHasher`] implementation for the [`Murmur3x86_32`] hasher.
+/// If you don't need support for using custom seeds, use the zero sized
+/// [`Murmur3x86_32BuildHasherDefault`] instead.
+#[derive(Clone, Debug)]
+pub struct Murmur3x86_32BuildHasher(u32);
+
+impl Murmur3x86_32BuildHasher {
+    /// Create a [`BuildHasher`] for [`Murmur3x86_32`] using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Create a [`BuildHasher`] for [`Murmur3x86_32`] with a custom seed.
+    #[inline]
+    pub const fn with_seed(seed: u32) -> Self {
+        Self(seed)
+    }
+}
+
+impl BuildHasher<u32> for Murmur3x86_32BuildHasher {
+    type Hasher = Murmur3x86_32;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seed(self.0)
+    }
+}
+
+impl SeedableBuildHasher for Murmur3x86_32BuildHasher {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seed(seed as u32)
+    }
+}
+
+impl Default for Murmur3x86_32BuildHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Murmur3x86_32BuildHasher {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Murmur3x86_32BuildHasher {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Murmur3x86_32BuildHasher {}
+
+/// [`BuildHasher`] implementation for the [`Murmur3x86_32`] hasher using the default seed (zero sized).
+pub type Murmur3x86_32BuildHasherDefault = BuildHasherDefault<Murmur3x86_32>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`Murmur3x86_32`] hasher.
+pub type Murmur3x86_32HashMap<K, V> = std::collections::HashMap<K, V, Murmur3x86_32BuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`Murmur3x86_32`] hasher with the default seed.
+pub type Murmur3x86_32HashMapDefault<K, V> =
+    std::collections::HashMap<K, V, Murmur3x86_32BuildHasherDefault>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`Murmur3x86_32`] hasher.
+pub type Murmur3x86_32HashSet<T> = std::collections::HashSet<T, Murmur3x86_32BuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`Murmur3x86_32`] hasher with the default seed.
+pub type Murmur3x86_32HashSetDefault<T> =
+    std::collections::HashSet<T, Murmur3x86_32BuildHasherDefault>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`Murmur3x86_32`] hasher.
+pub type Murmur3x86_32HeaplessIndexMap<K, V, const N: usize> =
+    heapless::IndexMap<K, V, Murmur3x86_32BuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`Murmur3x86_32`] hasher with the default seed.
+pub type Murmur3x86_32HeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, Murmur3x86_32BuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`Murmur3x86_32`] hasher.
+pub type Murmur3x86_32HeaplessIndexSet<T, const N: usize> =
+    heapless::IndexSet<T, Murmur3x86_32BuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`Murmur3x86_32`] hasher with the default seed.
+pub type Murmur3x86_32HeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, Murmur3x86_32BuildHasherDefault, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`Murmur3x86_32`] hasher.
+pub type Murmur3x86_32HashBrownMap<K, V> = hashbrown::HashMap<K, V, Murmur3x86_32BuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`Murmur3x86_32`] hasher with the default seed.
+pub type Murmur3x86_32HashBrownMapDefault<K, V> =
+    hashbrown::HashMap<K, V, Murmur3x86_32BuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`Murmur3x86_32`] hasher.
+pub type Murmur3x86_32HashBrownSet<T> = hashbrown::HashSet<T, Murmur3x86_32BuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`Murmur3x86_32`] hasher with the default seed.
+pub type Murmur3x86_32HashBrownSetDefault<T> =
+    hashbrown::HashSet<T, Murmur3x86_32BuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`Murmur3x86_32`] hasher.
+pub type Murmur3x86_32IndexMap<K, V> = indexmap::IndexMap<K, V, Murmur3x86_32BuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`Murmur3x86_32`] hasher with the default seed.
+pub type Murmur3x86_32IndexMapDefault<K, V> =
+    indexmap::IndexMap<K, V, Murmur3x86_32BuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`Murmur3x86_32`] hasher.
+pub type Murmur3x86_32IndexSet<T> = indexmap::IndexSet<T, Murmur3x86_32BuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`Murmur3x86_32`] hasher with the default seed.
+pub type Murmur3x86_32IndexSetDefault<T> = indexmap::IndexSet<T, Murmur3x86_32BuildHasherDefault>;
+
+/// Hasher using the 32-bit x86 variant of the MurmurHash3 algorithm (`MurmurHash3_x86_32`).
+///
+/// This is bit-exact with the reference implementation, so it can be used where hashes
+/// have to match other implementations, like Kafka's partitioner.
+#[derive(Clone)]
+pub struct Murmur3x86_32 {
+    h1: u32,
+    tail: u32,
+    ntail: usize,
+    length: u32,
+}
+
+impl Murmur3x86_32 {
+    /// Create a new `Murmur3x86_32` hasher using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Create a new `Murmur3x86_32` hasher with a custom seed.
+    #[inline]
+    pub const fn with_seed(seed: u32) -> Self {
+        Self {
+            h1: seed,
+            tail: 0,
+            ntail: 0,
+            length: 0,
+        }
+    }
+
+    #[inline(always)]
+    const fn mix_k1(k1: u32) -> u32 {
+        k1.wrapping_mul(C1_32).rotate_left(15).wrapping_mul(C2_32)
+    }
+
+    #[inline(always)]
+    fn block(&mut self, k1: u32) {
+        self.h1 ^= Self::mix_k1(k1);
+        self.h1 = self
+            .h1
+            .rotate_left(13)
+            .wrapping_mul(5)
+            .wrapping_add(0xe6546b64);
+    }
+}
+
+impl EndianIndependentAlgorithm for Murmur3x86_32 {}
+
+impl Default for Murmur3x86_32 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Murmur3x86_32 {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.h1.zeroize();
+        self.tail.zeroize();
+        self.ntail.zeroize();
+        self.length.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Murmur3x86_32 {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Murmur3x86_32 {}
+
+impl HasherWrite for Murmur3x86_32 {
+    fn write(&mut self, mut bytes: &[u8]) {
+        self.length = self.length.wrapping_add(bytes.len() as u32);
+
+        if self.ntail != 0 {
+            let n = bytes.len().min(4 - self.ntail);
+            for (i, &byte) in bytes[..n].iter().enumerate() {
+                self.tail |= (byte as u32) << (8 * (self.ntail + i));
+            }
+            self.ntail += n;
+            bytes = &bytes[n..];
+            if self.ntail < 4 {
+                return;
+            }
+            self.block(self.tail);
+            self.tail = 0;
+            self.ntail = 0;
+        }
+
+        let mut chunks = bytes.chunks_exact(4);
+        for chunk in &mut chunks {
+            self.block(u32::from_le_bytes(chunk.try_into().unwrap()));
+        }
+
+        for (i, &byte) in chunks.remainder().iter().enumerate() {
+            self.tail |= (byte as u32) << (8 * i);
+        }
+        self.ntail = chunks.remainder().len();
+    }
+}
+
+impl Hasher<u32> for Murmur3x86_32 {
+    #[inline]
+    fn finish(&self) -> u32 {
+        let mut h1 = self.h1;
+        if self.ntail != 0 {
+            h1 ^= Self::mix_k1(self.tail);
+        }
+        fmix32(h1 ^ self.length)
+    }
+}
+
+/// [`BuildHasher`] implementation for the [`Murmur3x64_128`] hasher.
+/// If you don't need support for using custom seeds, use the zero sized
+/// [`Murmur3x64_128BuildHasherDefault`] instead.
+#[derive(Clone, Debug)]
+pub struct Murmur3x64_128BuildHasher(u32);
+
+impl Murmur3x64_128BuildHasher {
+    /// Create a [`BuildHasher`] for [`Murmur3x64_128`] using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Create a [`BuildHasher`] for [`Murmur3x64_128`] with a custom seed.
+    #[inline]
+    pub const fn with_seed(seed: u32) -> Self {
+        Self(seed)
+    }
+}
+
+impl BuildHasher<u64> for Murmur3x64_128BuildHasher {
+    type Hasher = Murmur3x64_128;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seed(self.0)
+    }
+}
+
+impl BuildHasher<u128> for Murmur3x64_128BuildHasher {
+    type Hasher = Murmur3x64_128;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seed(self.0)
+    }
+}
+
+impl SeedableBuildHasher for Murmur3x64_128BuildHasher {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seed(seed as u32)
+    }
+}
+
+impl Default for Murmur3x64_128BuildHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Murmur3x64_128BuildHasher {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Murmur3x64_128BuildHasher {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Murmur3x64_128BuildHasher {}
+
+/// [`BuildHasher`] implementation for the [`Murmur3x64_128`] hasher using the default seed (zero sized).
+pub type Murmur3x64_128BuildHasherDefault = BuildHasherDefault<Murmur3x64_128>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`Murmur3x64_128`] hasher.
+pub type Murmur3x64_128HashMap<K, V> = std::collections::HashMap<K, V, Murmur3x64_128BuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`Murmur3x64_128`] hasher with the default seed.
+pub type Murmur3x64_128HashMapDefault<K, V> =
+    std::collections::HashMap<K, V, Murmur3x64_128BuildHasherDefault>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`Murmur3x64_128`] hasher.
+pub type Murmur3x64_128HashSet<T> = std::collections::HashSet<T, Murmur3x64_128BuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`Murmur3x64_128`] hasher with the default seed.
+pub type Murmur3x64_128HashSetDefault<T> =
+    std::collections::HashSet<T, Murmur3x64_128BuildHasherDefault>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`Murmur3x64_128`] hasher.
+pub type Murmur3x64_128HeaplessIndexMap<K, V, const N: usize> =
+    heapless::IndexMap<K, V, Murmur3x64_128BuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`Murmur3x64_128`] hasher with the default
+/// seed.
+pub type Murmur3x64_128HeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, Murmur3x64_128BuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`Murmur3x64_128`] hasher.
+pub type Murmur3x64_128HeaplessIndexSet<T, const N: usize> =
+    heapless::IndexSet<T, Murmur3x64_128BuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`Murmur3x64_128`] hasher with the default
+/// seed.
+pub type Murmur3x64_128HeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, Murmur3x64_128BuildHasherDefault, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`Murmur3x64_128`] hasher.
+pub type Murmur3x64_128HashBrownMap<K, V> = hashbrown::HashMap<K, V, Murmur3x64_128BuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`Murmur3x64_128`] hasher with the default
+/// seed.
+pub type Murmur3x64_128HashBrownMapDefault<K, V> =
+    hashbrown::HashMap<K, V, Murmur3x64_128BuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`Murmur3x64_128`] hasher.
+pub type Murmur3x64_128HashBrownSet<T> = hashbrown::HashSet<T, Murmur3x64_128BuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`Murmur3x64_128`] hasher with the default
+/// seed.
+pub type Murmur3x64_128HashBrownSetDefault<T> =
+    hashbrown::HashSet<T, Murmur3x64_128BuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`Murmur3x64_128`] hasher.
+pub type Murmur3x64_128IndexMap<K, V> = indexmap::IndexMap<K, V, Murmur3x64_128BuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`Murmur3x64_128`] hasher with the default
+/// seed.
+pub type Murmur3x64_128IndexMapDefault<K, V> =
+    indexmap::IndexMap<K, V, Murmur3x64_128BuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`Murmur3x64_128`] hasher.
+pub type Murmur3x64_128IndexSet<T> = indexmap::IndexSet<T, Murmur3x64_128BuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`Murmur3x64_128`] hasher with the default
+/// seed.
+pub type Murmur3x64_128IndexSetDefault<T> = indexmap::IndexSet<T, Murmur3x64_128BuildHasherDefault>;
+
+/// Hasher using the 128-bit x64 variant of the MurmurHash3 algorithm (`MurmurHash3_x64_128`).
+///
+/// This implements [`Hasher<u128>`] for the full hash, and [`Hasher<u64>`] for its low
+/// 64 bits, which is what Cassandra uses for its tokens.
+#[derive(Clone)]
+pub struct Murmur3x64_128 {
+    h1: u64,
+    h2: u64,
+    buffer: [u8; 16],
+    buffer_len: usize,
+    length: u64,
+}
+
+impl Murmur3x64_128 {
+    /// Create a new `Murmur3x64_128` hasher using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Create a new `Murmur3x64_128` hasher with a custom seed.
+    #[inline]
+    pub const fn with_seed(seed: u32) -> Self {
+        Self {
+            h1: seed as u64,
+            h2: seed as u64,
+            buffer: [0; 16],
+            buffer_len: 0,
+            length: 0,
+        }
+    }
+
+    #[inline(always)]
+    const fn mix_k1(k1: u64) -> u64 {
+        k1.wrapping_mul(C1_64).rotate_left(31).wrapping_mul(C2_64)
+    }
+
+    #[inline(always)]
+    const fn mix_k2(k2: u64) -> u64 {
+        k2.wrapping_mul(C2_64).rotate_left(33).wrapping_mul(C1_64)
+    }
+
+    #[inline(always)]
+    fn block(&mut self, block: &[u8]) {
+        let k1 = u64::from_le_bytes(block[..8].try_into().unwrap());
+        let k2 = u64::from_le_bytes(block[8..].try_into().unwrap());
+
+        self.h1 ^= Self::mix_k1(k1);
+        self.h1 = self.h1.rotate_left(27).wrapping_add(self.h2);
+        self.h1 = self.h1.wrapping_mul(5).wrapping_add(0x52dce729);
+
+        self.h2 ^= Self::mix_k2(k2);
+        self.h2 = self.h2.rotate_left(31).wrapping_add(self.h1);
+        self.h2 = self.h2.wrapping_mul(5).wrapping_add(0x38495ab5);
+    }
+
+    fn finish_128(&self) -> (u64, u64) {
+        let (mut h1, mut h2) = (self.h1, self.h2);
+
+        let mut tail = [0; 16];
+        tail[..self.buffer_len].copy_from_slice(&self.buffer[..self.buffer_len]);
+        if self.buffer_len > 8 {
+            h2 ^= Self::mix_k2(u64::from_le_bytes(tail[8..].try_into().unwrap()));
+        }
+        if self.buffer_len > 0 {
+            h1 ^= Self::mix_k1(u64::from_le_bytes(tail[..8].try_into().unwrap()));
+        }
+
+        h1 ^= self.length;
+        h2 ^= self.length;
+        h1 = h1.wrapping_add(h2);
+        h2 = h2.wrapping_add(h1);
+        h1 = fmix64(h1);
+        h2 = fmix64(h2);
+        h1 = h1.wrapping_add(h2);
+        h2 = h2.wrapping_add(h1);
+        (h1, h2)
+    }
+}
+
+impl EndianIndependentAlgorithm for Murmur3x64_128 {}
+
+impl Default for Murmur3x64_128 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Murmur3x64_128 {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.h1.zeroize();
+        self.h2.zeroize();
+        self.buffer.zeroize();
+        self.buffer_len.zeroize();
+        self.length.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Murmur3x64_128 {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Murmur3x64_128 {}
+
+impl HasherWrite for Murmur3x64_128 {
+    fn write(&mut self, mut bytes: &[u8]) {
+        self.length = self.length.wrapping_add(bytes.len() as u64);
+
+        if self.buffer_len != 0 {
+            let n = bytes.len().min(16 - self.buffer_len);
+            self.buffer[self.buffer_len..self.buffer_len + n].copy_from_slice(&bytes[..n]);
+            self.buffer_len += n;
+            bytes = &bytes[n..];
+            if self.buffer_len < 16 {
+                return;
+            }
+            let buffer = self.buffer;
+            self.block(&buffer);
+            self.buffer_len = 0;
+        }
+
+        let mut chunks = bytes.chunks_exact(16);
+        for chunk in &mut chunks {
+            self.block(chunk);
+        }
+
+        let remainder = chunks.remainder();
+        self.buffer[..remainder.len()].copy_from_slice(remainder);
+        self.buffer_len = remainder.len();
+    }
+}
+
+impl Hasher<u64> for Murmur3x64_128 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.finish_128().0
+    }
+}
+
+impl Hasher<u128> for Murmur3x64_128 {
+    #[inline]
+    fn finish(&self) -> u128 {
+        let (h1, h2) = self.finish_128();
+        h1 as u128 | (h2 as u128) << 64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::RawBytes, Hash};
+
+    fn default_seed<T: Hash>(x: T) -> u32 {
+        Murmur3x86_32BuildHasherDefault::new().hash_one(x)
+    }
+
+    fn default_seed_128<T: Hash>(x: T) -> u128 {
+        Murmur3x64_128BuildHasherDefault::new().hash_one(x)
+    }
+
+    fn data() -> [u8; 32] {
+        let mut data = [0; 32];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        data
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(default_seed(()), 0);
+        assert_eq!(
+            Murmur3x86_32BuildHasher::with_seed(1).hash_one(()),
+            0x514e28b7
+        );
+        assert_eq!(
+            Murmur3x86_32BuildHasher::with_seed(0xffffffff).hash_one(()),
+            0x81f16f39
+        );
+    }
+
+    #[test]
+    fn custom_seed() {
+        let data = data();
+        let bh = Murmur3x86_32BuildHasher::with_seed(0x55555555);
+        for (len, expected) in [
+            (1, 0xc171d610),
+            (2, 0x357c4d85),
+            (3, 0x6b7a6b51),
+            (4, 0xf4d1710b),
+            (5, 0xe444a67f),
+            (7, 0xb0044764),
+            (8, 0xbcbe9ed6),
+            (13, 0xbeaab45a),
+            (20, 0x09e32006),
+        ] {
+            assert_eq!(bh.hash_one(RawBytes(&data[..len])), expected, "len {len}");
+        }
+    }
+
+    #[test]
+    fn streaming() {
+        let data = data();
+        for len in 0..=data.len() {
+            let expected = default_seed(RawBytes(&data[..len]));
+            for chunk_size in 1..6 {
+                let mut hasher = Murmur3x86_32::new();
+                for chunk in data[..len].chunks(chunk_size) {
+                    hasher.write(chunk);
+                }
+                assert_eq!(hasher.finish(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn empty_128() {
+        assert_eq!(default_seed_128(()), 0);
+        let hash: u128 = Murmur3x64_128BuildHasher::with_seed(0x55555555).hash_one(());
+        assert_eq!(hash, 0x0c93bce0b95583154021d0068eacc64f);
+    }
+
+    #[test]
+    fn custom_seed_128() {
+        let data = data();
+        let bh = Murmur3x64_128BuildHasher::with_seed(0x55555555);
+        for (len, expected) in [
+            (1, 0x00aa43171469527b9cf09cdbbb71e3e8),
+            (7, 0x4d7f443ccb819bfb68c0291e0bdd3baa),
+            (8, 0x727125743cb6c935711ad2b3f92b4e05),
+            (9, 0xe3429f1828a263a2d59084e12157820f),
+            (15, 0xbbe85d8037b25f890ebe618b455a9b6b),
+            (16, 0xfa33b995fecedda6b0fd7725a649e234),
+            (17, 0x76eb1f8ad65b3064009d5b9540ac075b),
+            (24, 0x687f5cfbcc8a102608f7c6368ffdb9f6),
+            (31, 0xc9442ef57cfb483a22f7f19f53cfc79e),
+            (32, 0x8f4cb579f31bb4e93018cd760f68219b),
+        ] {
+            let hash: u128 = bh.hash_one(RawBytes(&data[..len]));
+            assert_eq!(hash, expected, "len {len}");
+        }
+    }
+
+    #[test]
+    fn truncated_64() {
+        let data = data();
+        for len in 0..=data.len() {
+            let hash: u64 = Murmur3x64_128BuildHasher::new().hash_one(RawBytes(&data[..len]));
+            assert_eq!(hash, default_seed_128(RawBytes(&data[..len])) as u64);
+        }
+    }
+
+    #[test]
+    fn streaming_128() {
+        let data = data();
+        for len in 0..=data.len() {
+            let expected = default_seed_128(RawBytes(&data[..len]));
+            for chunk_size in [1, 3, 7, 15, 17] {
+                let mut hasher = Murmur3x64_128::new();
+                for chunk in data[..len].chunks(chunk_size) {
+                    hasher.write(chunk);
+                }
+                assert_eq!(Hasher::<u128>::finish(&hasher), expected);
+            }
+        }
+    }
+
+    hash_test_vectors! {
+        [default_seed]
+        a: 0x3c2569b2,
+        ab: 0x9bbfd75f,
+        abc: 0xb3dd93fa,
+        abcd: 0x43ed676a,
+        abcde: 0xe89b9af6,
+        abcdef: 0x6181c085,
+        abcdefg: 0x883c9b06,
+        abcdefgh: 0x49ddccc4,
+        abcdefghi: 0x421406f0,
+        abcdefghij: 0x88927791,
+        abcdefghijk: 0x5f3b25df,
+        abcdefghijkl: 0xa36f3d27,
+        abcdefghijklm: 0xf212161b,
+        abcdefghijklmn: 0xf8526df0,
+        abcdefghijklmno: 0x9d09f7d2,
+        abcdefghijklmnop: 0xe76291ed,
+        abcdefghijklmnopq: 0xb6655e4a,
+        abcdefghijklmnopqr: 0xc219a894,
+        abcdefghijklmnopqrs: 0x85bf5bc1,
+        abcdefghijklmnopqrst: 0xbe1c719a,
+        abcdefghijklmnopqrstu: 0x5a19e7ab,
+        abcdefghijklmnopqrstuv: 0x70b63cc7,
+        abcdefghijklmnopqrstuvw: 0xa51e4d1c,
+        abcdefghijklmnopqrstuvwx: 0xb0f93939,
+        abcdefghijklmnopqrstuvwxy: 0x3883561a,
+        abcdefghijklmnopqrstuvwxyz: 0xa34e036d,
+        abcdefghijklmnopqrstuvwxyz0: 0xb1ca8496,
+        abcdefghijklmnopqrstuvwxyz01: 0x77fa0967,
+        abcdefghijklmnopqrstuvwxyz012: 0x1dab887c,
+        abcdefghijklmnopqrstuvwxyz0123: 0x97e14548,
+        abcdefghijklmnopqrstuvwxyz01234: 0xbb459ce9,
+        abcdefghijklmnopqrstuvwxyz012345: 0xd14e3386,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x6f876ab7,
+        abcdefghijklmnopqrstuvwxyz01234567: 0xd606ab9f,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x75dd0937,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x5b4c2c16,
+
+        [default_seed_128]
+        a: 0xe6b53a48510e895a85555565f6597889,
+        ab: 0xe65ea7019b52d4ad938b11ea16ed1b2e,
+        abc: 0x3ba2744126ca2d52b4963f3f3fad7867,
+        abcd: 0xf2003e886073e875b87bb7d64656cd4f,
+        abcde: 0xc5c7eea04bcfec8c2036d091f496bbb8,
+        abcdef: 0xb07109993321845ce47d86bfaca3bf55,
+        abcdefg: 0x1c3aa23ab155bbb6a6cd2f9fc09ee499,
+        abcdefgh: 0x48890d60eb6940a1cc8a0ab037ef8c02,
+        abcdefghi: 0x79b53df5b741e0330547c0cff13c7964,
+        abcdefghij: 0xa24d85dc8c651ac9b6c15b0d772f8c99,
+        abcdefghijk: 0xbb7c31e2455ae771a895d0b8df789d02,
+        abcdefghijkl: 0x1f9e303272ff621c8ef39bb1e67ae194,
+        abcdefghijklm: 0x2e657bff0de7cc7f1648288da7c0fa73,
+        abcdefghijklmn: 0xee096027d26a332491d094a7f5c375e0,
+        abcdefghijklmno: 0x6a548c2d9c962a618abe2451890c2ffb,
+        abcdefghijklmnop: 0x4333d695b331eb1ac4ca3ca3224cb723,
+        abcdefghijklmnopq: 0xecda499da1110de47564747f88bda657,
+        abcdefghijklmnopqr: 0x71d4a8295a00c76b77f08cec907f8a50,
+        abcdefghijklmnopqrs: 0xa68a4d2ab66f94741590bc23de9e6d88,
+        abcdefghijklmnopqrst: 0x9609f42a5716d04b310b3726f937e2f1,
+        abcdefghijklmnopqrstu: 0xd7cc48c1e99b3cfbc9d568e279ffc93c,
+        abcdefghijklmnopqrstuv: 0x46db1f74a8f4a96d9588e330f4abf856,
+        abcdefghijklmnopqrstuvw: 0x83beb7eb1c54e9ff40480aba9d4f238e,
+        abcdefghijklmnopqrstuvwx: 0x790fcfa35321f2086494960fd4de2cf7,
+        abcdefghijklmnopqrstuvwxy: 0xedee1581399ebddb71e7cba42f07960f,
+        abcdefghijklmnopqrstuvwxyz: 0xe9ad9c89b6a7d529749c9d7e516f4aa9,
+        abcdefghijklmnopqrstuvwxyz0: 0x453ccf6ab10c93367e2ddc8410dde045,
+        abcdefghijklmnopqrstuvwxyz01: 0x0b41090b415ea9a88c064e06c8bb7f61,
+        abcdefghijklmnopqrstuvwxyz012: 0xfbfd84c95e94e31031efcff15e52947e,
+        abcdefghijklmnopqrstuvwxyz0123: 0xbf2286ef153218c941681900ec9b3341,
+        abcdefghijklmnopqrstuvwxyz01234: 0xbedbd26090f9ef7a4bf06228635658a8,
+        abcdefghijklmnopqrstuvwxyz012345: 0xedcb0722a1febf6816a127b539e20ae3,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x23bd1bc4319c6f3aeea5f18b80c96088,
+        abcdefghijklmnopqrstuvwxyz01234567: 0xaaf3676aca01947baf9601a9eb077d12,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x1aa8e701c31aaab7ffca714cc29b2b08,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x3996b9372e6d94c0f2596f5eb4ff5e3a,
+    }
+}
@@ -0,0 +1,391 @@
+//! Utilities for seeding hashers at runtime and generating random numbers with hashers.
+
+use core::{
+    fmt::{self, Debug},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::{
+    impl_core_build_hasher, internal::fmix64, BuildHasher, Hash, Hasher, SeedableBuildHasher,
+};
+
+/// [`BuildHasher`] wrapper that seeds the wrapped [`SeedableBuildHasher`] from ambient
+/// entropy.
+///
+/// The seed is derived from the addresses of a static, the stack and a function, which vary
+/// between processes when address space layout randomization is enabled, and from a global
+/// counter that gives every instance in a process a different seed. This doesn't make any
+/// system calls or depend on `getrandom`, so it works in wasm and sandboxed environments.
+///
+/// This gives some resistance against HashDoS attacks, but the seeds are much more
+/// predictable than real random numbers. Don't use this for anything cryptographic.
+///
+/// Clones keep the seed of the original, so they calculate the same hashes.
+pub struct AmbientSeedBuildHasher<BH>(BH);
+
+impl_core_build_hasher!(impl<BH> AmbientSeedBuildHasher<BH>);
+
+impl<BH: SeedableBuildHasher> AmbientSeedBuildHasher<BH> {
+    /// Create a new `AmbientSeedBuildHasher` with a new seed.
+    #[inline]
+    pub fn new() -> Self {
+        Self(BH::from_seed(ambient_seed()))
+    }
+}
+
+impl<BH> AmbientSeedBuildHasher<BH> {
+    /// Get a reference to the seeded `BuildHasher`.
+    #[inline]
+    pub const fn get_ref(&self) -> &BH {
+        &self.0
+    }
+}
+
+impl<T, BH: BuildHasher<T>> BuildHasher<T> for AmbientSeedBuildHasher<BH> {
+    type Hasher = BH::Hasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        self.0.build_hasher()
+    }
+}
+
+impl<BH: SeedableBuildHasher> Default for AmbientSeedBuildHasher<BH> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<BH: Clone> Clone for AmbientSeedBuildHasher<BH> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<BH: Debug> Debug for AmbientSeedBuildHasher<BH> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AmbientSeedBuildHasher")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<BH: zeroize::Zeroize> zeroize::Zeroize for AmbientSeedBuildHasher<BH> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// The seeded build hasher zeroizes itself when the wrapper drops it.
+#[cfg(feature = "zeroize")]
+impl<BH: zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for AmbientSeedBuildHasher<BH> {}
+
+/// Generate a 128-bit seed from ambient entropy. See [`AmbientSeedBuildHasher`].
+pub fn ambient_seed() -> u128 {
+    static ANCHOR: u8 = 0;
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let stack = 0_u8;
+    let stack = core::hint::black_box(&stack) as *const u8 as usize as u64;
+    let anchor = &ANCHOR as *const u8 as usize as u64;
+    let code = ambient_seed as fn() -> u128 as usize as u64;
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed) as u64;
+
+    let a = fmix64(anchor ^ fmix64(code).rotate_left(32));
+    let b = fmix64(stack ^ fmix64(count ^ 0x9e3779b97f4a7c15));
+    let lo = fmix64(a ^ b.rotate_left(29));
+    let hi = fmix64(b ^ a.wrapping_mul(0x9e3779b97f4a7c15));
+    lo as u128 | (hi as u128) << 64
+}
+
+/// [`BuildHasher`] wrapper that seeds the wrapped [`SeedableBuildHasher`] with a random seed.
+///
+/// Every instance gets a new seed. With the `getrandom` feature the seed comes from the
+/// operating system's random number generator. Otherwise it's derived from the random keys of
+/// `std`'s `RandomState`. This gives the same HashDoS resistance as `std`'s hash maps for any
+/// seedable algorithm and hash type.
+///
+/// Clones keep the seed of the original, so they calculate the same hashes.
+#[cfg(any(feature = "getrandom", feature = "std"))]
+pub struct RandomState<BH>(BH);
+
+#[cfg(any(feature = "getrandom", feature = "std"))]
+impl_core_build_hasher!(impl<BH> RandomState<BH>);
+
+#[cfg(any(feature = "getrandom", feature = "std"))]
+impl<BH: SeedableBuildHasher> RandomState<BH> {
+    /// Create a new `RandomState` with a new random seed.
+    #[inline]
+    pub fn new() -> Self {
+        Self(BH::from_seed(random_seed()))
+    }
+}
+
+#[cfg(any(feature = "getrandom", feature = "std"))]
+impl<BH> RandomState<BH> {
+    /// Get a reference to the seeded `BuildHasher`.
+    #[inline]
+    pub const fn get_ref(&self) -> &BH {
+        &self.0
+    }
+}
+
+#[cfg(any(feature = "getrandom", feature = "std"))]
+impl<T, BH: BuildHasher<T>> BuildHasher<T> for RandomState<BH> {
+    type Hasher = BH::Hasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        self.0.build_hasher()
+    }
+}
+
+#[cfg(any(feature = "getrandom", feature = "std"))]
+impl<BH: SeedableBuildHasher> Default for RandomState<BH> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(any(feature = "getrandom", feature = "std"))]
+impl<BH: Clone> Clone for RandomState<BH> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+#[cfg(any(feature = "getrandom", feature = "std"))]
+impl<BH: Debug> Debug for RandomState<BH> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RandomState").field(&self.0).finish()
+    }
+}
+
+#[cfg(all(any(feature = "getrandom", feature = "std"), feature = "zeroize"))]
+impl<BH: zeroize::Zeroize> zeroize::Zeroize for RandomState<BH> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// The seeded build hasher zeroizes itself when the wrapper drops it.
+#[cfg(all(any(feature = "getrandom", feature = "std"), feature = "zeroize"))]
+impl<BH: zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for RandomState<BH> {}
+
+/// Generate a random 128-bit seed. See [`RandomState`].
+///
+/// # Panics
+/// With the `getrandom` feature, this panics if the operating system's random number
+/// generator fails.
+#[cfg(feature = "getrandom")]
+pub fn random_seed() -> u128 {
+    let mut seed = [0; 16];
+    getrandom::fill(&mut seed).expect("failed to get random seed");
+    u128::from_le_bytes(seed)
+}
+
+/// Generate a random 128-bit seed. See [`RandomState`].
+#[cfg(all(feature = "std", not(feature = "getrandom")))]
+pub fn random_seed() -> u128 {
+    use std::{
+        collections::hash_map::RandomState,
+        hash::{BuildHasher, Hasher},
+    };
+
+    // `RandomState` hashes with secret random keys that are different for each instance
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(0x9e3779b97f4a7c15);
+    let lo = hasher.finish();
+    hasher.write_u64(0x6a09e667f3bcc908);
+    let hi = hasher.finish();
+    lo as u128 | (hi as u128) << 64
+}
+
+/// Deterministic pseudo random number generator using a [`Hasher<u64>`].
+///
+/// Each output is the hash of the state of the wrapped hasher followed by a 64-bit counter in
+/// little endian byte order (counter mode). The sequence of numbers only depends on the
+/// hasher's state, so the same seed and key always produce the same numbers. This is useful
+/// for deriving reproducible randomness for an entity from its id.
+///
+/// The quality of the numbers depends on the hasher. This is not a cryptographically secure
+/// generator unless the hasher is a cryptographic hash.
+///
+/// With the `rand_core` feature, this implements `rand_core::RngCore`.
+///
+/// ```
+/// # use anyhash::{Hasher, HasherWrite, random::HashRng};
+/// # #[derive(Clone, Default)]
+/// # struct MyHasher(u64);
+/// # impl HasherWrite for MyHasher {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3); }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for MyHasher {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+/// let entity_id = 1234_u32;
+/// let mut rng1 = HashRng::with_key(MyHasher::default(), entity_id);
+/// let mut rng2 = HashRng::with_key(MyHasher::default(), entity_id);
+/// assert_eq!(rng1.next_u64(), rng2.next_u64());
+/// ```
+#[derive(Clone, Debug)]
+pub struct HashRng<H> {
+    hasher: H,
+    counter: u64,
+}
+
+impl<H: Hasher<u64> + Clone> HashRng<H> {
+    /// Create a new `HashRng` from a hasher. The hasher may already have data written to it.
+    #[inline]
+    pub const fn new(hasher: H) -> Self {
+        Self { hasher, counter: 0 }
+    }
+
+    /// Create a new `HashRng` from a hasher after writing `key` to it.
+    #[inline]
+    pub fn with_key<K: Hash>(mut hasher: H, key: K) -> Self {
+        key.hash(&mut hasher);
+        Self::new(hasher)
+    }
+
+    /// Create a new `HashRng` from a hasher created by `build_hasher`.
+    #[inline]
+    pub fn from_build_hasher<BH: BuildHasher<u64, Hasher = H>>(build_hasher: &BH) -> Self {
+        Self::new(build_hasher.build_hasher())
+    }
+
+    /// Get the position in the sequence of 64-bit numbers.
+    #[inline]
+    pub const fn position(&self) -> u64 {
+        self.counter
+    }
+
+    /// Set the position in the sequence of 64-bit numbers.
+    #[inline]
+    pub fn set_position(&mut self, position: u64) {
+        self.counter = position;
+    }
+
+    /// Generate the next random `u64`.
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        let mut hasher = self.hasher.clone();
+        hasher.write(&self.counter.to_le_bytes());
+        self.counter = self.counter.wrapping_add(1);
+        hasher.finish()
+    }
+
+    /// Generate the next random `u32`.
+    #[inline]
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Fill `dest` with random bytes.
+    #[inline]
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut chunks = dest.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes());
+        }
+        let rest = chunks.into_remainder();
+        if !rest.is_empty() {
+            let n = rest.len();
+            rest.copy_from_slice(&self.next_u64().to_le_bytes()[..n]);
+        }
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl<H: Hasher<u64> + Clone> rand_core::RngCore for HashRng<H> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        HashRng::next_u32(self)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        HashRng::next_u64(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        HashRng::fill_bytes(self, dst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unique_seeds() {
+        assert_ne!(ambient_seed(), ambient_seed());
+    }
+
+    #[cfg(all(feature = "fnv", feature = "zeroize"))]
+    #[test]
+    fn zeroize_ambient_seed() {
+        use crate::fnv::Fnv1aBuildHasher;
+        use zeroize::Zeroize;
+
+        let mut bh = AmbientSeedBuildHasher::<Fnv1aBuildHasher<u64>>::new();
+        bh.zeroize();
+        assert_eq!(
+            bh.hash_one(1_u32),
+            Fnv1aBuildHasher::<u64>::with_seed(0).hash_one(1_u32)
+        );
+    }
+
+    #[cfg(any(feature = "getrandom", feature = "std"))]
+    #[test]
+    fn random_seeds() {
+        assert_ne!(random_seed(), random_seed());
+    }
+
+    #[cfg(all(
+        any(feature = "getrandom", feature = "std"),
+        feature = "fnv",
+        feature = "zeroize"
+    ))]
+    #[test]
+    fn zeroize_random_state() {
+        use crate::fnv::Fnv1aBuildHasher;
+        use zeroize::Zeroize;
+
+        let mut bh = RandomState::<Fnv1aBuildHasher<u64>>::new();
+        bh.zeroize();
+        assert_eq!(
+            bh.hash_one(1_u32),
+            Fnv1aBuildHasher::<u64>::with_seed(0).hash_one(1_u32)
+        );
+    }
+
+    #[cfg(feature = "fnv")]
+    #[test]
+    fn hash_rng() {
+        use crate::fnv::Fnv1a64;
+
+        let mut rng = HashRng::with_key(Fnv1a64::new(), 42_u64);
+        let a = rng.next_u64();
+        let b = rng.next_u64();
+        assert_ne!(a, b);
+
+        let mut bytes = [0; 12];
+        rng.set_position(0);
+        rng.fill_bytes(&mut bytes);
+        assert_eq!(bytes[..8], a.to_le_bytes());
+        assert_eq!(bytes[8..], b.to_le_bytes()[..4]);
+    }
+}
@@ -0,0 +1,460 @@
+//! Hasher and collections using the rapidhash algorithm.
+
+// based on version 3 of the reference implementation at https://github.com/Nicoshev/rapidhash
+
+use crate::{
+    impl_core_build_hasher, impl_core_hasher, BuildHasher, BuildHasherDefault,
+    EndianIndependentAlgorithm, Hasher, HasherWrite, SeedableBuildHasher,
+};
+
+impl_core_build_hasher!(RapidHashBuildHasher);
+impl_core_hasher!(RapidHash);
+
+const SECRET: [u64; 7] = [
+    0x2d358dccaa6c78a5,
+    0x8bb84b93962eacc9,
+    0x4b33a62ed433d4a3,
+    0x4d5a2da51de1aa47,
+    0xa0761d6478bd642f,
+    0xe7037ed1a0b428db,
+    0x90ed1765281c388c,
+];
+
+#[inline(always)]
+const fn rapid_mum(a: u64, b: u64) -> (u64, u64) {
+    let r = a as u128 * b as u128;
+    (r as u64, (r >> 64) as u64)
+}
+
+#[inline(always)]
+const fn rapid_mix(a: u64, b: u64) -> u64 {
+    let (a, b) = rapid_mum(a, b);
+    a ^ b
+}
+
+#[inline(always)]
+fn r8(bytes: &[u8], i: usize) -> u64 {
+    u64::from_le_bytes(bytes[i..i + 8].try_into().unwrap())
+}
+
+#[inline(always)]
+fn r4(bytes: &[u8], i: usize) -> u64 {
+    u32::from_le_bytes(bytes[i..i + 4].try_into().unwrap()) as u64
+}
+
+/// [`BuildHasher`] implementation for the [`RapidHash`] hasher.
+/// If you don't need support for using custom seeds, use the zero sized
+/// [`RapidHashBuildHasherDefault`] instead.
+#[derive(Clone, Debug)]
+pub struct RapidHashBuildHasher(u64);
+
+impl RapidHashBuildHasher {
+    /// Create a [`BuildHasher`] for [`RapidHash`] using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Create a [`BuildHasher`] for [`RapidHash`] with a custom seed.
+    #[inline]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self(seed)
+    }
+}
+
+impl BuildHasher<u64> for RapidHashBuildHasher {
+    type Hasher = RapidHash;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seed(self.0)
+    }
+}
+
+impl SeedableBuildHasher for RapidHashBuildHasher {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seed(seed as u64)
+    }
+}
+
+impl Default for RapidHashBuildHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for RapidHashBuildHasher {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for RapidHashBuildHasher {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for RapidHashBuildHasher {}
+
+/// [`BuildHasher`] implementation for the [`RapidHash`] hasher using the default seed (zero sized).
+pub type RapidHashBuildHasherDefault = BuildHasherDefault<RapidHash>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`RapidHash`] hasher.
+pub type RapidHashHashMap<K, V> = std::collections::HashMap<K, V, RapidHashBuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`RapidHash`] hasher with the default seed.
+pub type RapidHashHashMapDefault<K, V> =
+    std::collections::HashMap<K, V, RapidHashBuildHasherDefault>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`RapidHash`] hasher.
+pub type RapidHashHashSet<T> = std::collections::HashSet<T, RapidHashBuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`RapidHash`] hasher with the default seed.
+pub type RapidHashHashSetDefault<T> = std::collections::HashSet<T, RapidHashBuildHasherDefault>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`RapidHash`] hasher.
+pub type RapidHashHeaplessIndexMap<K, V, const N: usize> =
+    heapless::IndexMap<K, V, RapidHashBuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`RapidHash`] hasher with the default seed.
+pub type RapidHashHeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, RapidHashBuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`RapidHash`] hasher.
+pub type RapidHashHeaplessIndexSet<T, const N: usize> =
+    heapless::IndexSet<T, RapidHashBuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`RapidHash`] hasher with the default seed.
+pub type RapidHashHeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, RapidHashBuildHasherDefault, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`RapidHash`] hasher.
+pub type RapidHashHashBrownMap<K, V> = hashbrown::HashMap<K, V, RapidHashBuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`RapidHash`] hasher with the default seed.
+pub type RapidHashHashBrownMapDefault<K, V> = hashbrown::HashMap<K, V, RapidHashBuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`RapidHash`] hasher.
+pub type RapidHashHashBrownSet<T> = hashbrown::HashSet<T, RapidHashBuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`RapidHash`] hasher with the default seed.
+pub type RapidHashHashBrownSetDefault<T> = hashbrown::HashSet<T, RapidHashBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`RapidHash`] hasher.
+pub type RapidHashIndexMap<K, V> = indexmap::IndexMap<K, V, RapidHashBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`RapidHash`] hasher with the default seed.
+pub type RapidHashIndexMapDefault<K, V> = indexmap::IndexMap<K, V, RapidHashBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`RapidHash`] hasher.
+pub type RapidHashIndexSet<T> = indexmap::IndexSet<T, RapidHashBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`RapidHash`] hasher with the default seed.
+pub type RapidHashIndexSetDefault<T> = indexmap::IndexSet<T, RapidHashBuildHasherDefault>;
+
+/// Hasher using the rapidhash algorithm (version 3).
+///
+/// The hashes match `rapidhash` from the C++ reference implementation with the same seed.
+#[derive(Clone)]
+pub struct RapidHash {
+    seeds: [u64; 7],
+    buffer: [u8; 112],
+    buffer_len: usize,
+    prev: [u8; 16],
+    length: u64,
+}
+
+impl RapidHash {
+    /// Create a new `RapidHash` hasher using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Create a new `RapidHash` hasher with a custom seed.
+    #[inline]
+    pub const fn with_seed(seed: u64) -> Self {
+        let seed = seed ^ rapid_mix(seed ^ SECRET[2], SECRET[1]);
+        Self {
+            seeds: [seed; 7],
+            buffer: [0; 112],
+            buffer_len: 0,
+            prev: [0; 16],
+            length: 0,
+        }
+    }
+
+    #[inline]
+    fn process(&mut self, block: &[u8]) {
+        for (i, seed) in self.seeds.iter_mut().enumerate() {
+            *seed = rapid_mix(r8(block, i * 16) ^ SECRET[i], r8(block, i * 16 + 8) ^ *seed);
+        }
+        self.prev.copy_from_slice(&block[96..]);
+    }
+}
+
+impl EndianIndependentAlgorithm for RapidHash {}
+
+impl Default for RapidHash {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for RapidHash {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.seeds.zeroize();
+        self.buffer.zeroize();
+        self.buffer_len.zeroize();
+        self.prev.zeroize();
+        self.length.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for RapidHash {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for RapidHash {}
+
+impl HasherWrite for RapidHash {
+    fn write(&mut self, mut bytes: &[u8]) {
+        self.length = self.length.wrapping_add(bytes.len() as u64);
+
+        // blocks are only processed once it's known that more data follows them,
+        // because the last block is handled differently when the hash is finished
+        if self.buffer_len + bytes.len() <= 112 {
+            self.buffer[self.buffer_len..self.buffer_len + bytes.len()].copy_from_slice(bytes);
+            self.buffer_len += bytes.len();
+            return;
+        }
+
+        if self.buffer_len != 0 {
+            let n = 112 - self.buffer_len;
+            self.buffer[self.buffer_len..].copy_from_slice(&bytes[..n]);
+            bytes = &bytes[n..];
+            let buffer = self.buffer;
+            self.process(&buffer);
+        }
+
+        while bytes.len() > 112 {
+            self.process(&bytes[..112]);
+            bytes = &bytes[112..];
+        }
+
+        self.buffer[..bytes.len()].copy_from_slice(bytes);
+        self.buffer_len = bytes.len();
+    }
+}
+
+impl Hasher<u64> for RapidHash {
+    fn finish(&self) -> u64 {
+        let buffer = &self.buffer[..self.buffer_len];
+        let (a, b);
+
+        // the lanes all start out equal to the seed, so folding them
+        // gives back the seed if no blocks have been processed
+        let mut seed = self.seeds.iter().fold(0, |acc, seed| acc ^ seed);
+
+        if self.length <= 16 {
+            let len = buffer.len();
+            if len >= 8 {
+                seed ^= len as u64;
+                a = r8(buffer, 0);
+                b = r8(buffer, len - 8);
+            } else if len >= 4 {
+                seed ^= len as u64;
+                a = r4(buffer, 0);
+                b = r4(buffer, len - 4);
+            } else if len > 0 {
+                a = (buffer[0] as u64) << 45 | buffer[len - 1] as u64;
+                b = buffer[len >> 1] as u64;
+            } else {
+                a = 0;
+                b = 0;
+            }
+        } else {
+            const TAIL_SECRET: [usize; 6] = [2, 2, 1, 1, 2, 1];
+            let mut i = 0;
+            while buffer.len() - i > 16 {
+                let secret = SECRET[TAIL_SECRET[i / 16]];
+                seed = rapid_mix(r8(buffer, i) ^ secret, r8(buffer, i + 8) ^ seed);
+                i += 16;
+            }
+
+            // the last 16 bytes may start in the previous block
+            let mut last = [0; 128];
+            last[..16].copy_from_slice(&self.prev);
+            last[16..16 + buffer.len()].copy_from_slice(buffer);
+            a = r8(&last, buffer.len()) ^ buffer.len() as u64;
+            b = r8(&last, buffer.len() + 8);
+        }
+
+        let (a, b) = rapid_mum(a ^ SECRET[1], b ^ seed);
+        rapid_mix(a ^ 0xaaaaaaaaaaaaaaaa, b ^ SECRET[1] ^ buffer.len() as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::RawBytes, Hash};
+
+    fn default_seed<T: Hash>(x: T) -> u64 {
+        RapidHashBuildHasherDefault::new().hash_one(x)
+    }
+
+    fn data() -> [u8; 300] {
+        let mut data = [0; 300];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = (i * 7 + 1) as u8;
+        }
+        data
+    }
+
+    // test vectors from the reference implementation, using the index as the seed
+    #[test]
+    fn reference() {
+        for (seed, expected, msg) in [
+            (0, 0x338dc4be2cecdae, ""),
+            (1, 0x33121345e29cfadd, "a"),
+            (2, 0x4a8e8b0c46d7434c, "abc"),
+            (3, 0x105de616d78f6e20, "message digest"),
+            (4, 0x1ba66f1de4986621, "abcdefghijklmnopqrstuvwxyz"),
+        ] {
+            assert_eq!(
+                RapidHashBuildHasher::with_seed(seed).hash_one(RawBytes(msg.as_bytes())),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn lengths() {
+        let data = data();
+        for (len, default_seed, custom_seed) in [
+            (0, 0x338dc4be2cecdae, 0xef3cab45a1f5a48b),
+            (1, 0xdc3697f74433a2f0, 0xb048f8dd0634aca7),
+            (3, 0x42bc1ce4d0ebf222, 0xa7b6ed5ca7f210e6),
+            (4, 0xe3606c4a0295d767, 0xd7eb46479fa35e09),
+            (7, 0xe488591943a7b7a8, 0x26b108051b544bfb),
+            (8, 0xc5f6fc2cf60b07a, 0x62c802c81709f83f),
+            (16, 0xccd2d32fbdbc501f, 0x5e5170ebb4d6e867),
+            (17, 0xa7bba4a87e6814c5, 0x179ae060608a9b80),
+            (32, 0xde5bb4d16e4d0d4f, 0xf3d814732440e2ad),
+            (48, 0xc440a87a51afb38, 0x4a6c79b412feaa6),
+            (64, 0x8a0d9a28d53f997d, 0xe07c24b4b46d0093),
+            (80, 0xde486995c063696b, 0x91f5148d476be4e5),
+            (96, 0x2db6188e182d8313, 0xbb7462cbba4c301b),
+            (112, 0xb763c5b991abda0c, 0x28a25e40bb7d38bb),
+            (113, 0xc8f3bd2979de6390, 0x10a42a3385e612cb),
+            (128, 0x9c48d5b1bdebb158, 0xde08a1f80b3122aa),
+            (224, 0x386ee46bdda4efc8, 0x2a3d361a9838c5b2),
+            (225, 0xd559866741c88c12, 0x621625ffe1d65d10),
+            (240, 0x98dc3fba8913c1e0, 0x6a959deca4f36e4f),
+            (300, 0xb90f78eae80dc606, 0x579379154591db3d),
+        ] {
+            assert_eq!(
+                RapidHashBuildHasher::new().hash_one(RawBytes(&data[..len])),
+                default_seed,
+                "len {len}"
+            );
+            assert_eq!(
+                RapidHashBuildHasher::with_seed(0x1234).hash_one(RawBytes(&data[..len])),
+                custom_seed,
+                "len {len}"
+            );
+        }
+    }
+
+    #[test]
+    fn streaming() {
+        let data = data();
+        for len in 0..=data.len() {
+            let expected = default_seed(RawBytes(&data[..len]));
+            for chunk_size in [1, 5, 16, 111, 112, 113] {
+                let mut hasher = RapidHash::new();
+                for chunk in data[..len].chunks(chunk_size) {
+                    hasher.write(chunk);
+                }
+                assert_eq!(
+                    hasher.finish(),
+                    expected,
+                    "len {len}, chunk size {chunk_size}"
+                );
+            }
+        }
+    }
+
+    hash_test_vectors! {
+        [default_seed]
+        a: 0x599f47df33a2e1eb,
+        ab: 0x7b20ba72bb425975,
+        abc: 0xcb475beafa9c0da2,
+        abcd: 0xf8f44f4a65e26132,
+        abcde: 0xc5fb18456266e8b0,
+        abcdef: 0xfd615a3b9273e7a9,
+        abcdefg: 0x2760e84111b29a0d,
+        abcdefgh: 0xab159e602a29f41f,
+        abcdefghi: 0x70a51746ca96c1c6,
+        abcdefghij: 0xc9259e908c63950b,
+        abcdefghijk: 0x1021ad8053c20148,
+        abcdefghijkl: 0x7fd5cf0798b86811,
+        abcdefghijklm: 0x581aae01bc22b1bb,
+        abcdefghijklmn: 0xf167824f3d2e14c1,
+        abcdefghijklmno: 0x54c03d9b150448c0,
+        abcdefghijklmnop: 0xc78ae6a1774adb1e,
+        abcdefghijklmnopq: 0xc427c11a4463b8,
+        abcdefghijklmnopqr: 0xf76ea3171105e1bd,
+        abcdefghijklmnopqrs: 0xa18409c3adb67bcb,
+        abcdefghijklmnopqrst: 0x498ab83b820c58ef,
+        abcdefghijklmnopqrstu: 0x40d217a6dd3afcc5,
+        abcdefghijklmnopqrstuv: 0x6b8583ef6adaf44e,
+        abcdefghijklmnopqrstuvw: 0xe7d8f480364b0b5b,
+        abcdefghijklmnopqrstuvwx: 0x73a335b7e8b9f83b,
+        abcdefghijklmnopqrstuvwxy: 0xc104f95dd21361d7,
+        abcdefghijklmnopqrstuvwxyz: 0x2e1abe6bd50a7a46,
+        abcdefghijklmnopqrstuvwxyz0: 0x13c9235e72223691,
+        abcdefghijklmnopqrstuvwxyz01: 0xb8278498a97b8237,
+        abcdefghijklmnopqrstuvwxyz012: 0x83620e8056b862b3,
+        abcdefghijklmnopqrstuvwxyz0123: 0x8c69ba0368d74572,
+        abcdefghijklmnopqrstuvwxyz01234: 0xc55d873e50f9b659,
+        abcdefghijklmnopqrstuvwxyz012345: 0x7f6145bd11a4bfc6,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x7fc4dbe9f5cb6d39,
+        abcdefghijklmnopqrstuvwxyz01234567: 0x32eb01465bf513bf,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x58e4d541fc3c3ec1,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x28ca71f1c4b2c98,
+    }
+}
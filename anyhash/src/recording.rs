@@ -0,0 +1,284 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Debug};
+
+use crate::{impl_core_hasher, Hasher, HasherWrite};
+
+/// A single write call recorded by [`RecordingHasher`].
+///
+/// Integer writes record the value. The byte order they're written in is decided by the
+/// hasher that receives them.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RecordedWrite {
+    /// [`HasherWrite::write`]
+    Write(Vec<u8>),
+    /// [`HasherWrite::write_u8`]
+    WriteU8(u8),
+    /// [`HasherWrite::write_u16`]
+    WriteU16(u16),
+    /// [`HasherWrite::write_u32`]
+    WriteU32(u32),
+    /// [`HasherWrite::write_u64`]
+    WriteU64(u64),
+    /// [`HasherWrite::write_u128`]
+    WriteU128(u128),
+    /// [`HasherWrite::write_usize`]
+    WriteUsize(usize),
+    /// [`HasherWrite::write_i8`]
+    WriteI8(i8),
+    /// [`HasherWrite::write_i16`]
+    WriteI16(i16),
+    /// [`HasherWrite::write_i32`]
+    WriteI32(i32),
+    /// [`HasherWrite::write_i64`]
+    WriteI64(i64),
+    /// [`HasherWrite::write_i128`]
+    WriteI128(i128),
+    /// [`HasherWrite::write_isize`]
+    WriteIsize(isize),
+    /// [`HasherWrite::write_f32`], with the bits of the value
+    WriteF32(u32),
+    /// [`HasherWrite::write_f64`], with the bits of the value
+    WriteF64(u64),
+    /// [`HasherWrite::write_length_prefix`]
+    LengthPrefix(usize),
+    /// [`HasherWrite::write_str`]
+    Str(String),
+}
+
+impl RecordedWrite {
+    /// Make the same write call to `hasher`.
+    pub fn replay<H: HasherWrite + ?Sized>(&self, hasher: &mut H) {
+        match self {
+            Self::Write(bytes) => hasher.write(bytes),
+            Self::WriteU8(i) => hasher.write_u8(*i),
+            Self::WriteU16(i) => hasher.write_u16(*i),
+            Self::WriteU32(i) => hasher.write_u32(*i),
+            Self::WriteU64(i) => hasher.write_u64(*i),
+            Self::WriteU128(i) => hasher.write_u128(*i),
+            Self::WriteUsize(i) => hasher.write_usize(*i),
+            Self::WriteI8(i) => hasher.write_i8(*i),
+            Self::WriteI16(i) => hasher.write_i16(*i),
+            Self::WriteI32(i) => hasher.write_i32(*i),
+            Self::WriteI64(i) => hasher.write_i64(*i),
+            Self::WriteI128(i) => hasher.write_i128(*i),
+            Self::WriteIsize(i) => hasher.write_isize(*i),
+            Self::WriteF32(bits) => hasher.write_f32(f32::from_bits(*bits)),
+            Self::WriteF64(bits) => hasher.write_f64(f64::from_bits(*bits)),
+            Self::LengthPrefix(len) => hasher.write_length_prefix(*len),
+            Self::Str(s) => hasher.write_str(s),
+        }
+    }
+}
+
+macro_rules! impl_recording_writes {
+    ($($t:ty: $fn:ident => $variant:ident),* $(,)?) => { $(
+        #[inline]
+        fn $fn(&mut self, i: $t) {
+            self.records.push(RecordedWrite::$variant(i));
+            self.hasher.$fn(i);
+        }
+    )* };
+}
+
+/// Wrapper for hashers that records the sequence of write calls made to it.
+///
+/// This is meant for testing [`Hash`](crate::Hash) implementations, e.g. to check that a
+/// derived implementation and a manual one make the same writes. All writes are also forwarded
+/// to the wrapped hasher, so the hash is the same as without the wrapper.
+///
+/// ```
+/// # use anyhash::{Hash, RecordedWrite, RecordingHasher};
+/// # #[derive(Default)]
+/// # struct Null;
+/// # impl anyhash::HasherWrite for Null {
+/// #     fn write(&mut self, _: &[u8]) {}
+/// # }
+/// let mut hasher = RecordingHasher::new(Null);
+/// (1_u32, "hi").hash(&mut hasher);
+/// assert_eq!(
+///     hasher.records(),
+///     [RecordedWrite::WriteU32(1), RecordedWrite::Str("hi".into())]
+/// );
+/// ```
+pub struct RecordingHasher<H> {
+    hasher: H,
+    records: Vec<RecordedWrite>,
+}
+
+impl_core_hasher!(impl<H> RecordingHasher<H>);
+
+impl<H> RecordingHasher<H> {
+    /// Create a new `RecordingHasher` wrapping `hasher`.
+    #[inline]
+    pub const fn new(hasher: H) -> Self {
+        Self {
+            hasher,
+            records: Vec::new(),
+        }
+    }
+
+    /// Get a reference to the wrapped hasher.
+    #[inline]
+    pub const fn get_ref(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Get the write calls recorded so far.
+    #[inline]
+    pub fn records(&self) -> &[RecordedWrite] {
+        &self.records
+    }
+
+    /// Unwrap the wrapped hasher and the recorded write calls.
+    #[inline]
+    pub fn into_parts(self) -> (H, Vec<RecordedWrite>) {
+        (self.hasher, self.records)
+    }
+}
+
+impl<T, H: Hasher<T>> Hasher<T> for RecordingHasher<H> {
+    #[inline]
+    fn finish(&self) -> T {
+        self.hasher.finish()
+    }
+
+    #[inline]
+    fn finish_once(self) -> T {
+        self.hasher.finish_once()
+    }
+}
+
+impl<H: HasherWrite> HasherWrite for RecordingHasher<H> {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.records.push(RecordedWrite::Write(bytes.into()));
+        self.hasher.write(bytes);
+    }
+
+    impl_recording_writes! {
+        u8: write_u8 => WriteU8,
+        u16: write_u16 => WriteU16,
+        u32: write_u32 => WriteU32,
+        u64: write_u64 => WriteU64,
+        u128: write_u128 => WriteU128,
+        usize: write_usize => WriteUsize,
+        i8: write_i8 => WriteI8,
+        i16: write_i16 => WriteI16,
+        i32: write_i32 => WriteI32,
+        i64: write_i64 => WriteI64,
+        i128: write_i128 => WriteI128,
+        isize: write_isize => WriteIsize,
+        usize: write_length_prefix => LengthPrefix,
+    }
+
+    #[inline]
+    fn write_f32(&mut self, f: f32) {
+        self.records.push(RecordedWrite::WriteF32(f.to_bits()));
+        self.hasher.write_f32(f);
+    }
+
+    #[inline]
+    fn write_f64(&mut self, f: f64) {
+        self.records.push(RecordedWrite::WriteF64(f.to_bits()));
+        self.hasher.write_f64(f);
+    }
+
+    #[inline]
+    fn write_str(&mut self, s: &str) {
+        self.records.push(RecordedWrite::Str(s.into()));
+        self.hasher.write_str(s);
+    }
+}
+
+impl<H: Debug> Debug for RecordingHasher<H> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecordingHasher")
+            .field("hasher", &self.hasher)
+            .field("records", &self.records)
+            .finish()
+    }
+}
+
+impl<H: Clone> Clone for RecordingHasher<H> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            hasher: self.hasher.clone(),
+            records: self.records.clone(),
+        }
+    }
+}
+
+impl<H: Default> Default for RecordingHasher<H> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(H::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BuildHasher, BuildHasherDefault, Hash};
+
+    #[derive(Clone, Default)]
+    struct Fnv(u64);
+
+    impl HasherWrite for Fnv {
+        fn write(&mut self, bytes: &[u8]) {
+            for &b in bytes {
+                self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3);
+            }
+        }
+    }
+
+    impl Hasher<u64> for Fnv {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn transparent_slice() {
+        #[derive(Clone, Copy, Hash)]
+        #[hash(transparent)]
+        #[repr(transparent)]
+        struct Byte(u8);
+
+        let mut a = RecordingHasher::new(Fnv::default());
+        [Byte(1), Byte(2), Byte(3)].as_slice().hash(&mut a);
+        let mut b = RecordingHasher::new(Fnv::default());
+        [1_u8, 2, 3].as_slice().hash(&mut b);
+        assert_eq!(a.records(), b.records());
+        assert_eq!(a.finish(), b.finish());
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let value = (
+            1_u8,
+            -2_i64,
+            [3_u16, 4],
+            "five",
+            b"six".as_slice(),
+            crate::TotalF64(7.5),
+        );
+        let mut hasher = RecordingHasher::new(Fnv::default());
+        value.hash(&mut hasher);
+
+        let mut replayed = Fnv::default();
+        for record in hasher.records() {
+            record.replay(&mut replayed);
+        }
+        let expected = BuildHasherDefault::<Fnv>::new().hash_one(value);
+        assert_eq!(hasher.finish(), expected);
+        assert_eq!(replayed.finish(), expected);
+        assert!(hasher
+            .records()
+            .contains(&RecordedWrite::Str("five".into())));
+        assert!(hasher
+            .records()
+            .contains(&RecordedWrite::WriteF64(7.5_f64.to_bits())));
+    }
+}
@@ -0,0 +1,874 @@
+//! Rolling hashers, which can add bytes at the end and remove bytes from the start of the
+//! hashed data.
+//!
+//! Rolling hashes are useful for content defined chunking and substring search, where the hash
+//! of a window that slides over the data is needed at every position.
+
+use core::fmt::{self, Debug};
+
+use crate::{internal::fmix64, BuildHasher, EndianIndependentAlgorithm, Hasher, HasherWrite};
+
+/// A hasher that can remove bytes from the start of the hashed data.
+///
+/// Writing to a rolling hasher adds bytes at the end of the window of hashed data, like
+/// [`push`](RollingHasher::push). The [`finish`](Hasher::finish) method of its [`Hasher`]
+/// implementations returns the hash of the bytes currently in the window.
+pub trait RollingHasher: HasherWrite {
+    /// Add a byte at the end of the window.
+    fn push(&mut self, byte: u8);
+
+    /// Remove a byte from the start of the window.
+    ///
+    /// The hasher doesn't keep the data, so `byte` must be the oldest byte in the window,
+    /// or the hash will be wrong.
+    fn pop(&mut self, byte: u8);
+
+    /// Remove `old` from the start of the window and add `new` at the end.
+    #[inline]
+    fn roll(&mut self, old: u8, new: u8) {
+        self.pop(old);
+        self.push(new);
+    }
+
+    /// The number of bytes in the window.
+    fn window_len(&self) -> usize;
+}
+
+/// Fixed size window over a [`RollingHasher`].
+///
+/// Bytes written to the window are added to the hasher, and once the window is full, the
+/// oldest byte is removed each time a new byte is added, so [`finish`](Hasher::finish)
+/// always returns the hash of the last `N` bytes written. `N` must be greater than zero.
+#[derive(Clone)]
+pub struct RollingWindow<R, const N: usize> {
+    hasher: R,
+    buffer: [u8; N],
+    pos: usize,
+    len: usize,
+}
+
+impl<R, const N: usize> RollingWindow<R, N> {
+    const ASSERT_N: () = assert!(N != 0, "`N` must be greater than zero");
+
+    /// Create a new `RollingWindow` using an empty rolling hasher.
+    #[inline]
+    pub const fn new(hasher: R) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::ASSERT_N;
+        Self {
+            hasher,
+            buffer: [0; N],
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    /// Get a reference to the wrapped hasher.
+    #[inline]
+    pub const fn get_ref(&self) -> &R {
+        &self.hasher
+    }
+
+    /// The number of bytes in the window. This is `N` once the window is full.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no bytes have been written to the window.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if `N` or more bytes have been written to the window.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+}
+
+impl<R: RollingHasher, const N: usize> RollingWindow<R, N> {
+    /// Add a byte to the window, removing the oldest byte if the window is full.
+    #[inline]
+    pub fn roll(&mut self, byte: u8) {
+        if self.len == N {
+            self.hasher.roll(self.buffer[self.pos], byte);
+        } else {
+            self.hasher.push(byte);
+            self.len += 1;
+        }
+        self.buffer[self.pos] = byte;
+        self.pos = if self.pos + 1 == N { 0 } else { self.pos + 1 };
+    }
+}
+
+impl<R: Default, const N: usize> Default for RollingWindow<R, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(R::default())
+    }
+}
+
+impl<R: Debug, const N: usize> Debug for RollingWindow<R, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RollingWindow")
+            .field("hasher", &self.hasher)
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: RollingHasher, const N: usize> HasherWrite for RollingWindow<R, N> {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.roll(byte);
+        }
+    }
+}
+
+impl<T, R: RollingHasher + Hasher<T>, const N: usize> Hasher<T> for RollingWindow<R, N> {
+    #[inline]
+    fn finish(&self) -> T {
+        self.hasher.finish()
+    }
+}
+
+/// Table of random values for [`Buzhash`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct BuzhashTable([u64; 256]);
+
+impl BuzhashTable {
+    /// Generate a table from a seed.
+    ///
+    /// This is a `const fn`, so it can be used to initialize a `static` table:
+    ///
+    /// ```
+    /// # use anyhash::rolling::{Buzhash, BuzhashTable};
+    /// static TABLE: BuzhashTable = BuzhashTable::from_seed(0x1234);
+    /// let hasher = Buzhash::with_table(&TABLE);
+    /// ```
+    pub const fn from_seed(seed: u64) -> Self {
+        let mut table = [0; 256];
+        let mut i = 0;
+        while i < 256 {
+            table[i] = fmix64(seed.wrapping_add((i as u64 + 1).wrapping_mul(0x9e3779b97f4a7c15)));
+            i += 1;
+        }
+        Self(table)
+    }
+
+    /// Create a table from custom values.
+    #[inline]
+    pub const fn from_values(values: [u64; 256]) -> Self {
+        Self(values)
+    }
+
+    /// Get the values of the table.
+    #[inline]
+    pub const fn values(&self) -> &[u64; 256] {
+        &self.0
+    }
+}
+
+impl Debug for BuzhashTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BuzhashTable").finish_non_exhaustive()
+    }
+}
+
+static DEFAULT_TABLE: BuzhashTable = BuzhashTable::from_seed(0);
+
+/// [`BuildHasher`] implementation for the [`Buzhash`] hasher.
+#[derive(Clone, Copy, Debug)]
+pub struct BuzhashBuildHasher(&'static BuzhashTable);
+
+impl BuzhashBuildHasher {
+    /// Create a [`BuildHasher`] for [`Buzhash`] using the default table.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(&DEFAULT_TABLE)
+    }
+
+    /// Create a [`BuildHasher`] for [`Buzhash`] with a custom table.
+    #[inline]
+    pub const fn with_table(table: &'static BuzhashTable) -> Self {
+        Self(table)
+    }
+}
+
+impl BuildHasher<u64> for BuzhashBuildHasher {
+    type Hasher = Buzhash;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_table(self.0)
+    }
+}
+
+impl Default for BuzhashBuildHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Rolling hasher using the Buzhash (cyclic polynomial) algorithm.
+///
+/// Each byte is mapped to a random value using a [`BuzhashTable`], and the hash is the xor
+/// of the values rotated by their distance from the end of the window.
+///
+/// Buzhash is fast, but it's a poor general purpose hash function, so this hasher doesn't
+/// have collection type aliases.
+#[derive(Clone, Debug)]
+pub struct Buzhash {
+    table: &'static BuzhashTable,
+    hash: u64,
+    len: usize,
+}
+
+impl Buzhash {
+    /// Create a new `Buzhash` hasher using the default table.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_table(&DEFAULT_TABLE)
+    }
+
+    /// Create a new `Buzhash` hasher with a custom table.
+    #[inline]
+    pub const fn with_table(table: &'static BuzhashTable) -> Self {
+        Self {
+            table,
+            hash: 0,
+            len: 0,
+        }
+    }
+}
+
+impl Default for Buzhash {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EndianIndependentAlgorithm for Buzhash {}
+
+impl HasherWrite for Buzhash {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.push(byte);
+        }
+    }
+}
+
+impl Hasher<u64> for Buzhash {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+impl RollingHasher for Buzhash {
+    #[inline]
+    fn push(&mut self, byte: u8) {
+        self.hash = self.hash.rotate_left(1) ^ self.table.0[byte as usize];
+        self.len += 1;
+    }
+
+    #[inline]
+    fn pop(&mut self, byte: u8) {
+        debug_assert!(self.len != 0, "pop from empty window");
+        self.len -= 1;
+        self.hash ^= self.table.0[byte as usize].rotate_left(self.len as u32);
+    }
+
+    #[inline]
+    fn roll(&mut self, old: u8, new: u8) {
+        self.hash = self.hash.rotate_left(1)
+            ^ self.table.0[old as usize].rotate_left(self.len as u32)
+            ^ self.table.0[new as usize];
+    }
+
+    #[inline]
+    fn window_len(&self) -> usize {
+        self.len
+    }
+}
+
+use sealed::Word;
+mod sealed {
+    /// State type for [`RabinKarp`](super::RabinKarp).
+    pub trait Word: Copy + Eq + core::fmt::Debug {
+        /// Zero.
+        const ZERO: Self;
+
+        /// One.
+        const ONE: Self;
+
+        /// Default base.
+        const BASE: Self;
+
+        /// Default modulus.
+        const MODULUS: Self;
+
+        /// Convert a byte, reduced modulo `m`.
+        fn from_byte(byte: u8, m: Self) -> Self;
+
+        /// `a + b` modulo `m`, where zero means the whole range of the type.
+        fn add_mod(a: Self, b: Self, m: Self) -> Self;
+
+        /// `a - b` modulo `m`, where zero means the whole range of the type.
+        fn sub_mod(a: Self, b: Self, m: Self) -> Self;
+
+        /// `a * b` modulo `m`, where zero means the whole range of the type.
+        fn mul_mod(a: Self, b: Self, m: Self) -> Self;
+
+        /// The inverse of `a` modulo `m`, if it exists.
+        fn inv_mod(a: Self, m: Self) -> Option<Self>;
+
+        /// Fold to 64 bits.
+        fn fold64(self) -> u64;
+    }
+}
+
+macro_rules! impl_word {
+    ($($t:ty = $base:expr, $modulus:expr;)*) => { $(
+        impl Word for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const BASE: Self = $base;
+            const MODULUS: Self = $modulus;
+
+            #[inline]
+            fn from_byte(byte: u8, m: Self) -> Self {
+                if m == 0 {
+                    byte as Self
+                } else {
+                    byte as Self % m
+                }
+            }
+
+            #[inline]
+            fn add_mod(a: Self, b: Self, m: Self) -> Self {
+                let (sum, overflow) = a.overflowing_add(b);
+                if m != 0 && (overflow || sum >= m) {
+                    sum.wrapping_sub(m)
+                } else {
+                    sum
+                }
+            }
+
+            #[inline]
+            fn sub_mod(a: Self, b: Self, m: Self) -> Self {
+                if a >= b {
+                    a - b
+                } else {
+                    a.wrapping_sub(b).wrapping_add(m)
+                }
+            }
+
+            fn inv_mod(a: Self, m: Self) -> Option<Self> {
+                if m == 0 {
+                    // newton's method for inverses modulo a power of two
+                    if a & 1 == 0 {
+                        return None;
+                    }
+                    let mut x = a;
+                    for _ in 0..7 {
+                        x = x.wrapping_mul((2 as Self).wrapping_sub(a.wrapping_mul(x)));
+                    }
+                    return Some(x);
+                }
+                // extended euclid, with the coefficient kept modulo m
+                let (mut r0, mut r1) = (m, a % m);
+                let (mut s0, mut s1) = (0, 1 % m);
+                while r1 != 0 {
+                    let q = r0 / r1;
+                    (r0, r1) = (r1, r0 - q * r1);
+                    (s0, s1) = (s1, Self::sub_mod(s0, Self::mul_mod(q % m, s1, m), m));
+                }
+                (r0 == 1).then_some(s0)
+            }
+
+            #[inline]
+            fn fold64(self) -> u64 {
+                (self as u128 ^ (self as u128 >> 64)) as u64
+            }
+
+            #[inline]
+            fn mul_mod(a: Self, b: Self, m: Self) -> Self {
+                Self::mul_mod_impl(a, b, m)
+            }
+        }
+    )* };
+}
+
+impl_word! {
+    u64 = 0x1f3d5b79a3b2c1d7, (1 << 61) - 1;
+    u128 = 0x1f3d5b79a3b2c1d7_9e3779b97f4a7c15, (1 << 127) - 1;
+}
+
+trait MulMod: Sized {
+    fn mul_mod_impl(a: Self, b: Self, m: Self) -> Self;
+}
+
+impl MulMod for u64 {
+    #[inline]
+    fn mul_mod_impl(a: Self, b: Self, m: Self) -> Self {
+        if m == 0 {
+            a.wrapping_mul(b)
+        } else {
+            (a as u128 * b as u128 % m as u128) as u64
+        }
+    }
+}
+
+impl MulMod for u128 {
+    #[inline]
+    fn mul_mod_impl(a: Self, b: Self, m: Self) -> Self {
+        if m == 0 {
+            return a.wrapping_mul(b);
+        }
+        if (a | b) >> 64 == 0 && m >> 64 == 0 {
+            return a * b % m;
+        }
+        // double and add, since there's no wider type to multiply in
+        let a = a % m;
+        let mut r = 0;
+        for i in (0..128 - b.leading_zeros()).rev() {
+            r = Self::add_mod(r, r, m);
+            if (b >> i) & 1 != 0 {
+                r = Self::add_mod(r, a, m);
+            }
+        }
+        r
+    }
+}
+
+/// [`BuildHasher`] implementation for the [`RabinKarp`] hasher.
+#[derive(Clone, Copy, Debug)]
+pub struct RabinKarpBuildHasher<T: Word = u64> {
+    base: T,
+    modulus: T,
+}
+
+impl<T: Word> RabinKarpBuildHasher<T> {
+    /// Create a [`BuildHasher`] for [`RabinKarp`] using the default base and modulus.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_params(T::BASE, T::MODULUS)
+    }
+
+    /// Create a [`BuildHasher`] for [`RabinKarp`] with a custom base and modulus.
+    /// See [`RabinKarp::with_params`].
+    #[inline]
+    pub const fn with_params(base: T, modulus: T) -> Self {
+        Self { base, modulus }
+    }
+}
+
+impl<T: Word> BuildHasher<u64> for RabinKarpBuildHasher<T> {
+    type Hasher = RabinKarp<T>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_params(self.base, self.modulus)
+    }
+}
+
+impl BuildHasher<u128> for RabinKarpBuildHasher<u128> {
+    type Hasher = RabinKarp<u128>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_params(self.base, self.modulus)
+    }
+}
+
+impl<T: Word> Default for RabinKarpBuildHasher<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Rolling hasher using a Rabin-Karp style polynomial hash.
+///
+/// The hash of the bytes `c[0]..c[n]` in the window is
+/// `c[0] * base^(n-1) + c[1] * base^(n-2) + ... + c[n-1]` modulo the modulus. The state is
+/// either a [`u64`] or a [`u128`], selected with `T`. The default modulus is the Mersenne
+/// prime `2^61 - 1` for `u64` and `2^127 - 1` for `u128`.
+///
+/// This also works as a plain [`Hasher<u64>`] (and [`Hasher<u128>`] for `u128` state), where
+/// a `u128` hash is folded to 64 bits. The hash isn't mixed, so it's a poor general purpose hash
+/// function, and this hasher doesn't have collection type aliases.
+#[derive(Clone, Debug)]
+pub struct RabinKarp<T: Word = u64> {
+    base: T,
+    modulus: T,
+    base_inv: Option<T>,
+    // base^(len - 1), or zero when the window is empty
+    top: T,
+    hash: T,
+    len: usize,
+}
+
+impl<T: Word> RabinKarp<T> {
+    /// Create a new `RabinKarp` hasher using the default base and modulus.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_params(T::BASE, T::MODULUS)
+    }
+
+    /// Create a new `RabinKarp` hasher with a custom base and modulus.
+    ///
+    /// A modulus of zero uses the whole range of the state type, so arithmetic wraps around.
+    /// To be able to [`pop`](RollingHasher::pop) bytes, `base` must have an inverse modulo
+    /// `modulus`, which it does if the modulus is a prime that doesn't divide `base`,
+    /// or if the modulus is zero and `base` is odd. [`roll`](RollingHasher::roll) doesn't
+    /// have this restriction.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is one.
+    pub fn with_params(base: T, modulus: T) -> Self {
+        assert!(modulus != T::ONE, "modulus must not be one");
+        Self {
+            base,
+            modulus,
+            base_inv: T::inv_mod(base, modulus),
+            top: T::ZERO,
+            hash: T::ZERO,
+            len: 0,
+        }
+    }
+}
+
+impl<T: Word> Default for RabinKarp<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Word> EndianIndependentAlgorithm for RabinKarp<T> {}
+
+impl<T: Word> HasherWrite for RabinKarp<T> {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.push(byte);
+        }
+    }
+}
+
+impl<T: Word> Hasher<u64> for RabinKarp<T> {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash.fold64()
+    }
+}
+
+impl Hasher<u128> for RabinKarp<u128> {
+    #[inline]
+    fn finish(&self) -> u128 {
+        self.hash
+    }
+}
+
+impl<T: Word> RollingHasher for RabinKarp<T> {
+    #[inline]
+    fn push(&mut self, byte: u8) {
+        let m = self.modulus;
+        self.hash = T::add_mod(
+            T::mul_mod(self.hash, self.base, m),
+            T::from_byte(byte, m),
+            m,
+        );
+        self.top = if self.len == 0 {
+            T::ONE
+        } else {
+            T::mul_mod(self.top, self.base, m)
+        };
+        self.len += 1;
+    }
+
+    /// Remove a byte from the start of the window.
+    ///
+    /// # Panics
+    /// Panics if the base doesn't have an inverse modulo the modulus.
+    /// See [`RabinKarp::with_params`].
+    #[inline]
+    fn pop(&mut self, byte: u8) {
+        debug_assert!(self.len != 0, "pop from empty window");
+        let m = self.modulus;
+        let base_inv = self
+            .base_inv
+            .expect("base must have an inverse modulo the modulus to pop bytes");
+        self.hash = T::sub_mod(self.hash, T::mul_mod(T::from_byte(byte, m), self.top, m), m);
+        self.len -= 1;
+        self.top = if self.len == 0 {
+            T::ZERO
+        } else {
+            T::mul_mod(self.top, base_inv, m)
+        };
+    }
+
+    #[inline]
+    fn roll(&mut self, old: u8, new: u8) {
+        let m = self.modulus;
+        let hash = T::sub_mod(self.hash, T::mul_mod(T::from_byte(old, m), self.top, m), m);
+        self.hash = T::add_mod(T::mul_mod(hash, self.base, m), T::from_byte(new, m), m);
+    }
+
+    #[inline]
+    fn window_len(&self) -> usize {
+        self.len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Hash;
+
+    fn default_table<T: Hash>(x: T) -> u64 {
+        BuzhashBuildHasher::new().hash_one(x)
+    }
+
+    const DATA: &[u8] = b"the quick brown fox jumps over the lazy dog, 0123456789 times";
+
+    fn hash(bytes: &[u8]) -> u64 {
+        let mut hasher = Buzhash::new();
+        hasher.write(bytes);
+        hasher.finish()
+    }
+
+    #[test]
+    fn seed() {
+        static TABLE: BuzhashTable = BuzhashTable::from_seed(0x1234);
+        let mut hasher = Buzhash::with_table(&TABLE);
+        hasher.write(b"hello");
+        assert_eq!(hasher.finish(), 0xff184435445fbf37);
+    }
+
+    #[test]
+    fn push_pop() {
+        let mut hasher = Buzhash::new();
+        hasher.write(DATA);
+        for i in 0..DATA.len() {
+            assert_eq!(hasher.window_len(), DATA.len() - i);
+            assert_eq!(hasher.finish(), hash(&DATA[i..]));
+            hasher.pop(DATA[i]);
+        }
+        assert_eq!(hasher.finish(), 0);
+    }
+
+    #[test]
+    fn window() {
+        for len in [1, 5, 64, 65] {
+            let mut hasher = Buzhash::new();
+            hasher.write(&DATA[..len.min(DATA.len())]);
+            for i in len..DATA.len() {
+                hasher.roll(DATA[i - len], DATA[i]);
+                assert_eq!(hasher.finish(), hash(&DATA[i + 1 - len..=i]));
+            }
+        }
+
+        let mut window = RollingWindow::<Buzhash, 8>::default();
+        for (i, &byte) in DATA.iter().enumerate() {
+            window.write_u8(byte);
+            assert_eq!(window.is_full(), i >= 7);
+            assert_eq!(window.finish(), hash(&DATA[(i + 1).saturating_sub(8)..=i]));
+        }
+    }
+
+    fn default_u64<T: Hash>(x: T) -> u64 {
+        RabinKarpBuildHasher::<u64>::new().hash_one(x)
+    }
+
+    fn default_u128<T: Hash>(x: T) -> u128 {
+        RabinKarpBuildHasher::<u128>::new().hash_one(x)
+    }
+
+    fn rabin_karp<T: Word>(base: T, modulus: T) {
+        let hash = |bytes: &[u8]| -> u64 {
+            let mut hasher = RabinKarp::with_params(base, modulus);
+            hasher.write(bytes);
+            hasher.finish()
+        };
+
+        let mut hasher = RabinKarp::with_params(base, modulus);
+        hasher.write(DATA);
+        for i in 0..DATA.len() {
+            assert_eq!(hasher.window_len(), DATA.len() - i);
+            assert_eq!(Hasher::<u64>::finish(&hasher), hash(&DATA[i..]));
+            hasher.pop(DATA[i]);
+        }
+        assert_eq!(Hasher::<u64>::finish(&hasher), 0);
+
+        let mut window = RollingWindow::<_, 8>::new(RabinKarp::with_params(base, modulus));
+        for (i, &byte) in DATA.iter().enumerate() {
+            window.write_u8(byte);
+            assert_eq!(
+                Hasher::<u64>::finish(&window),
+                hash(&DATA[(i + 1).saturating_sub(8)..=i])
+            );
+        }
+    }
+
+    #[test]
+    fn rabin_karp_params() {
+        rabin_karp::<u64>(u64::BASE, u64::MODULUS);
+        rabin_karp::<u64>(257, 1_000_000_007);
+        rabin_karp::<u64>(0x100000001b3, 0);
+        rabin_karp::<u128>(u128::BASE, u128::MODULUS);
+        rabin_karp::<u128>(257, 1_000_000_007);
+        rabin_karp::<u128>(0x100000001b3, 0);
+        rabin_karp::<u128>(0x1234567890abcdef, (1 << 89) - 1);
+
+        let mut hasher = RabinKarp::<u64>::with_params(257, 1_000_000_007);
+        hasher.write(b"hello world");
+        assert_eq!(Hasher::<u64>::finish(&hasher), 0x2c14f19e);
+        let mut hasher = RabinKarp::<u64>::with_params(0x100000001b3, 0);
+        hasher.write(b"hello world");
+        assert_eq!(Hasher::<u64>::finish(&hasher), 0xd83929cb269f7044);
+        let mut hasher = RabinKarp::<u128>::with_params(0x100000001b3, 0);
+        hasher.write(b"hello world");
+        assert_eq!(
+            Hasher::<u128>::finish(&hasher),
+            0x99e76936ee412f8cd83929cb269f7044
+        );
+    }
+
+    #[test]
+    fn rabin_karp_roll_without_inverse() {
+        let mut hasher = RabinKarp::<u64>::with_params(256, 0);
+        let mut expected = RabinKarp::<u64>::with_params(256, 0);
+        hasher.write(b"abc");
+        hasher.roll(b'a', b'd');
+        expected.write(b"bcd");
+        assert_eq!(
+            Hasher::<u64>::finish(&hasher),
+            Hasher::<u64>::finish(&expected)
+        );
+    }
+
+    hash_test_vectors! {
+        [default_table]
+        a: 0x7ff13d2a0d7370ff,
+        ab: 0x28270b311752b140,
+        abc: 0x3f5eedb2d79eef98,
+        abcd: 0xe03b445463b5e7e4,
+        abcde: 0x6f0991bee3c63ed1,
+        abcdef: 0xbd056479010232cf,
+        abcdefg: 0x6836a423132463fb,
+        abcdefgh: 0x8b9fd2d0268b3332,
+        abcdefghi: 0x4bfab1ea1f333dde,
+        abcdefghij: 0x5724447f8ec6c1b4,
+        abcdefghijk: 0x583d911bf0bacc16,
+        abcdefghijkl: 0x8672779ddcbac1c7,
+        abcdefghijklm: 0xd22bc55267fcb9c4,
+        abcdefghijklmn: 0xcb83d0234bdb090c,
+        abcdefghijklmno: 0xc72efde199709584,
+        abcdefghijklmnop: 0xc9f55bc9267d31cc,
+        abcdefghijklmnopq: 0xd4a7174084fec7c7,
+        abcdefghijklmnopqr: 0x8b94d64aa5856888,
+        abcdefghijklmnopqrs: 0xdc35149c6ba30dbd,
+        abcdefghijklmnopqrst: 0xfa1a2ac4bde77822,
+        abcdefghijklmnopqrstu: 0x16ba5735e115cc31,
+        abcdefghijklmnopqrstuv: 0x8ec488b0af018ab2,
+        abcdefghijklmnopqrstuvw: 0xe6bb4319248310a5,
+        abcdefghijklmnopqrstuvwx: 0x856b0921d59ba0ff,
+        abcdefghijklmnopqrstuvwxy: 0xeb227ee89497520e,
+        abcdefghijklmnopqrstuvwxyz: 0x192ec2936d9e7fb2,
+        abcdefghijklmnopqrstuvwxyz0: 0x5b4a277899f233ea,
+        abcdefghijklmnopqrstuvwxyz01: 0x1ca77b12107c3983,
+        abcdefghijklmnopqrstuvwxyz012: 0x353f4a7c0c010081,
+        abcdefghijklmnopqrstuvwxyz0123: 0x67aaf420c873e5cc,
+        abcdefghijklmnopqrstuvwxyz01234: 0x7d2a917bf581d441,
+        abcdefghijklmnopqrstuvwxyz012345: 0xfb892af0e0eb4d47,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x1f450fe77581f47,
+        abcdefghijklmnopqrstuvwxyz01234567: 0x7a64b42d149f2d70,
+        abcdefghijklmnopqrstuvwxyz012345678: 0xfe294a10e4f6eeb,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0xe984a26ad106658c,
+
+        [default_u64]
+        a: 0x61,
+        ab: 0x163fa91706bb7337,
+        abc: 0xc9ebddd58540feb,
+        abcd: 0x74f4ec1013bd022,
+        abcde: 0x974e592e9212e7c,
+        abcdef: 0x19d336af66443234,
+        abcdefg: 0x105e08cb48fa149f,
+        abcdefgh: 0xed05c78b9a454d7,
+        abcdefghi: 0x85f2b5dc00d7b9b,
+        abcdefghij: 0x1281fd390b0df1aa,
+        abcdefghijk: 0x1ad94e2b0bdaf10b,
+        abcdefghijkl: 0xfe995b65a1d4c94,
+        abcdefghijklm: 0x8151ff6098a6ed8,
+        abcdefghijklmn: 0x92d3e160d6181d5,
+        abcdefghijklmno: 0x69ba95ee672c7e8,
+        abcdefghijklmnop: 0x122e87e25e9e678c,
+        abcdefghijklmnopq: 0x10f41400c8ff7772,
+        abcdefghijklmnopqr: 0xd83bedf799cbeb6,
+        abcdefghijklmnopqrs: 0x9cd857d28dedea8,
+        abcdefghijklmnopqrst: 0x961a52e8ee9c821,
+        abcdefghijklmnopqrstu: 0x1c90f464c24fa486,
+        abcdefghijklmnopqrstuv: 0xcb6a0513ca45800,
+        abcdefghijklmnopqrstuvw: 0x7b59506fd77341c,
+        abcdefghijklmnopqrstuvwx: 0x1429d6ce5593e8c7,
+        abcdefghijklmnopqrstuvwxy: 0xcb9868ed83b2a9,
+        abcdefghijklmnopqrstuvwxyz: 0xee1626ac53eff61,
+        abcdefghijklmnopqrstuvwxyz0: 0x1b82782605ba94ef,
+        abcdefghijklmnopqrstuvwxyz01: 0x82cd884aaeda050,
+        abcdefghijklmnopqrstuvwxyz012: 0x1f525700f8a81140,
+        abcdefghijklmnopqrstuvwxyz0123: 0x1f246f274a62afc0,
+        abcdefghijklmnopqrstuvwxyz01234: 0x959db814302566f,
+        abcdefghijklmnopqrstuvwxyz012345: 0x19e72bde8b73d086,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x14d521e2d05825da,
+        abcdefghijklmnopqrstuvwxyz01234567: 0x164f3ea7292ed778,
+        abcdefghijklmnopqrstuvwxyz012345678: 0xd582572a6b4dc4c,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x80e135dc4ee7bb9,
+
+        [default_u128]
+        a: 0x61,
+        ab: 0x563fa91706bb72b2f3051f493b39046e,
+        abc: 0x5287ba5d7600ffcdbf051a78ebe50ce8,
+        abcd: 0x477bb7c0cfdea9a3540b897e067db056,
+        abcde: 0x243226b7344804660831a34d07d95d62,
+        abcdef: 0x1a571b14bc55f650401e82a62646cfd6,
+        abcdefg: 0x5bd59a77a8382145d5b4a2efeaa00156,
+        abcdefgh: 0x29553dd73af6515356ccd5b97738a1c7,
+        abcdefghi: 0x7522bb276b5ed1fba788310e882ebf9a,
+        abcdefghij: 0x1110bd27dd6472a7e469997e24f15c30,
+        abcdefghijk: 0x39445c1d9fb5557bd6b14468b4ab3b6d,
+        abcdefghijkl: 0x69dfc042fa91b327b719aab9f6fcb4ca,
+        abcdefghijklm: 0x432bc28752dee05053bf081e62879ea9,
+        abcdefghijklmn: 0x7a8709a18142599a2dafd617fd6e150,
+        abcdefghijklmno: 0x5a6b2d2c9a3a9380add37a0d107a65e7,
+        abcdefghijklmnop: 0x1708d95dc15a2cd1ba64e0ade3c3525,
+        abcdefghijklmnopq: 0x1e1c8bec1b2d0394a307c8377e554f02,
+        abcdefghijklmnopqr: 0x435c3f75f6183ba2c8423ffdfc5f4e29,
+        abcdefghijklmnopqrs: 0x4ef8fbf11e7beccb46608e3a0995c36b,
+        abcdefghijklmnopqrst: 0x11a971bd2d321a090a2c7089d1879ae0,
+        abcdefghijklmnopqrstu: 0x19115c8f5dbdc02f8d7a06a552543f95,
+        abcdefghijklmnopqrstuv: 0x309badeadbd5c1fae530fa9f6a351c27,
+        abcdefghijklmnopqrstuvw: 0x554a5be7667522c190a7d43680c1987e,
+        abcdefghijklmnopqrstuvwx: 0x56a47bbf68e265f0761e8c85e074dd50,
+        abcdefghijklmnopqrstuvwxy: 0x70dce353ad5bd3d39a443217743b17a8,
+        abcdefghijklmnopqrstuvwxyz: 0x7d7dcb95577b0e8b74d310538f29d04,
+        abcdefghijklmnopqrstuvwxyz0: 0x8ddad6cb9e13c936b1ac875c59f6f7d,
+        abcdefghijklmnopqrstuvwxyz01: 0x43487d8d16d3ed33a006fbeff368391e,
+        abcdefghijklmnopqrstuvwxyz012: 0x6dc07195298c57ab04512eede34638a2,
+        abcdefghijklmnopqrstuvwxyz0123: 0x522e7cb1ac454f6b0944951d28cf7b45,
+        abcdefghijklmnopqrstuvwxyz01234: 0x6808eaa7d970039f42ec2578e6e4a2e9,
+        abcdefghijklmnopqrstuvwxyz012345: 0x698095b864d2e777a590bd60d66bea45,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x4dc1ec2f26d481a681826febeb46ea63,
+        abcdefghijklmnopqrstuvwxyz01234567: 0xfed6619e550ec65f6e28f8dd037a015,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x7e712e5f1de8048a5c26c6d30ba17777,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x1219ce51c61c82fa8077683a6c2cea29,
+    }
+}
@@ -0,0 +1,286 @@
+use core::fmt::{self, Debug};
+
+use crate::{
+    impl_core_build_hasher, impl_core_hasher, BuildHasher, EndianIndependentAlgorithm,
+    EndianIndependentWrites, Hasher, HasherWrite,
+};
+
+// writes the salt with a length prefix, like `HasherWriteExt::write_domain`, so no salt is a
+// prefix of another
+#[inline]
+fn write_salt<H: ?Sized + HasherWrite>(hasher: &mut H, salt: &[u8]) {
+    hasher.write_u64(salt.len() as u64);
+    hasher.write(salt);
+}
+
+macro_rules! impl_salted_writes {
+    ($($t:ty: $fn:ident),* $(,)?) => { $(
+        #[inline]
+        fn $fn(&mut self, i: $t) {
+            self.hasher.$fn(i);
+        }
+    )* };
+}
+
+/// Hasher made by [`SaltedBuildHasher`].
+///
+/// If the build hasher was made with [`SaltedBuildHasher::with_suffix`], the salt is written
+/// again when finishing. [`finish`](Hasher::finish) has to clone the wrapped hasher to do that,
+/// which [`finish_once`](Hasher::finish_once) avoids.
+pub struct SaltedHasher<H, S> {
+    hasher: H,
+    suffix: Option<S>,
+}
+
+impl_core_hasher!(impl<H: Hasher<u64> + Clone, S: AsRef<[u8]>> SaltedHasher<H, S>);
+
+impl<H, S> SaltedHasher<H, S> {
+    /// Get a reference to the wrapped hasher.
+    #[inline]
+    pub const fn get_ref(&self) -> &H {
+        &self.hasher
+    }
+}
+
+impl<T, H: Hasher<T> + Clone, S: AsRef<[u8]>> Hasher<T> for SaltedHasher<H, S> {
+    #[inline]
+    fn finish(&self) -> T {
+        match &self.suffix {
+            Some(salt) => {
+                let mut hasher = self.hasher.clone();
+                write_salt(&mut hasher, salt.as_ref());
+                hasher.finish_once()
+            }
+            None => self.hasher.finish(),
+        }
+    }
+
+    #[inline]
+    fn finish_once(mut self) -> T {
+        if let Some(salt) = &self.suffix {
+            write_salt(&mut self.hasher, salt.as_ref());
+        }
+        self.hasher.finish_once()
+    }
+}
+
+impl<H: HasherWrite, S> HasherWrite for SaltedHasher<H, S> {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.hasher.write(bytes);
+    }
+
+    impl_salted_writes! {
+        u8: write_u8,
+        u16: write_u16,
+        u32: write_u32,
+        u64: write_u64,
+        u128: write_u128,
+        usize: write_usize,
+        i8: write_i8,
+        i16: write_i16,
+        i32: write_i32,
+        i64: write_i64,
+        i128: write_i128,
+        isize: write_isize,
+        f32: write_f32,
+        f64: write_f64,
+        &str: write_str,
+    }
+
+    #[inline]
+    fn write_length_prefix(&mut self, len: usize) {
+        self.hasher.write_length_prefix(len);
+    }
+}
+
+impl<H: EndianIndependentAlgorithm, S> EndianIndependentAlgorithm for SaltedHasher<H, S> {}
+
+impl<H: EndianIndependentWrites, S> EndianIndependentWrites for SaltedHasher<H, S> {}
+
+impl<H: Debug, S: Debug> Debug for SaltedHasher<H, S> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SaltedHasher")
+            .field("hasher", &self.hasher)
+            .field("suffix", &self.suffix)
+            .finish()
+    }
+}
+
+impl<H: Clone, S: Clone> Clone for SaltedHasher<H, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            hasher: self.hasher.clone(),
+            suffix: self.suffix.clone(),
+        }
+    }
+}
+
+/// `BuildHasher` wrapper that writes a salt into every hasher before any other data, and
+/// optionally after.
+///
+/// This separates hashes of different tables or tenants that use the same algorithm and seed.
+/// The salt is written as its length as a `u64` followed by its bytes, like
+/// [`HasherWriteExt::write_domain`](crate::HasherWriteExt::write_domain), so no salt is a prefix
+/// of another. It can be any type that implements `AsRef<[u8]>`, like `&'static [u8]`, a byte
+/// array or a `Vec<u8>`. It's cloned into each hasher if it's also written after the data.
+///
+/// ```
+/// # use anyhash::{BuildHasher, BuildHasherDefault, Hasher, HasherWrite, SaltedBuildHasher};
+/// # #[derive(Clone, Default)]
+/// # struct MyHasher(u64);
+/// # impl HasherWrite for MyHasher {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3); }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for MyHasher {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+/// let bh = BuildHasherDefault::<MyHasher>::new();
+/// let users = SaltedBuildHasher::new(bh.clone(), b"users");
+/// let groups = SaltedBuildHasher::new(bh, b"groups");
+/// assert_ne!(users.hash_one(1234_u32), groups.hash_one(1234_u32));
+/// ```
+pub struct SaltedBuildHasher<BH, S> {
+    build_hasher: BH,
+    salt: S,
+    suffix: bool,
+}
+
+impl_core_build_hasher!(
+    impl<BH: BuildHasher<u64>, S: AsRef<[u8]> + Clone> SaltedBuildHasher<BH, S>
+        where BH::Hasher: Clone
+);
+
+impl<BH, S> SaltedBuildHasher<BH, S> {
+    /// Create a new `SaltedBuildHasher` that writes `salt` before any other data.
+    #[inline]
+    pub const fn new(build_hasher: BH, salt: S) -> Self {
+        Self {
+            build_hasher,
+            salt,
+            suffix: false,
+        }
+    }
+
+    /// Create a new `SaltedBuildHasher` that writes `salt` both before and after all other
+    /// data.
+    #[inline]
+    pub const fn with_suffix(build_hasher: BH, salt: S) -> Self {
+        Self {
+            build_hasher,
+            salt,
+            suffix: true,
+        }
+    }
+
+    /// Get a reference to the wrapped `BuildHasher`.
+    #[inline]
+    pub const fn get_ref(&self) -> &BH {
+        &self.build_hasher
+    }
+
+    /// Get a reference to the salt.
+    #[inline]
+    pub const fn salt(&self) -> &S {
+        &self.salt
+    }
+}
+
+impl<T, BH: BuildHasher<T>, S: AsRef<[u8]> + Clone> BuildHasher<T> for SaltedBuildHasher<BH, S>
+where
+    BH::Hasher: Clone,
+{
+    type Hasher = SaltedHasher<BH::Hasher, S>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = self.build_hasher.build_hasher();
+        write_salt(&mut hasher, self.salt.as_ref());
+        SaltedHasher {
+            hasher,
+            suffix: self.suffix.then(|| self.salt.clone()),
+        }
+    }
+}
+
+impl<BH: Debug, S: Debug> Debug for SaltedBuildHasher<BH, S> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SaltedBuildHasher")
+            .field("build_hasher", &self.build_hasher)
+            .field("salt", &self.salt)
+            .field("suffix", &self.suffix)
+            .finish()
+    }
+}
+
+impl<BH: Clone, S: Clone> Clone for SaltedBuildHasher<BH, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            build_hasher: self.build_hasher.clone(),
+            salt: self.salt.clone(),
+            suffix: self.suffix,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BuildHasherDefault;
+
+    #[derive(Clone, Default)]
+    struct Fnv(u64);
+
+    impl HasherWrite for Fnv {
+        fn write(&mut self, bytes: &[u8]) {
+            for &b in bytes {
+                self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3);
+            }
+        }
+    }
+
+    impl Hasher<u64> for Fnv {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+    }
+
+    fn salted_hash(salt: &[u8], data: &[u8]) -> u64 {
+        let mut hasher =
+            SaltedBuildHasher::new(BuildHasherDefault::<Fnv>::new(), salt).build_hasher();
+        hasher.write(data);
+        hasher.finish()
+    }
+
+    #[test]
+    fn salted() {
+        let mut expected = Fnv::default();
+        expected.write_u64(4);
+        expected.write(b"saltdata");
+
+        let bh = BuildHasherDefault::<Fnv>::new();
+        let mut hasher = SaltedBuildHasher::new(bh.clone(), *b"salt").build_hasher();
+        hasher.write(b"data");
+        assert_eq!(Hasher::<u64>::finish(&hasher), expected.finish());
+
+        expected.write_u64(4);
+        expected.write(b"salt");
+        let mut hasher = SaltedBuildHasher::with_suffix(bh, b"salt").build_hasher();
+        hasher.write(b"data");
+        let hash: u64 = hasher.finish();
+        assert_eq!(hash, expected.finish());
+        assert_eq!(hasher.finish_once(), hash);
+    }
+
+    #[test]
+    fn salt_isnt_prefix() {
+        assert_ne!(salted_hash(b"ab", b"c"), salted_hash(b"a", b"bc"));
+        assert_ne!(salted_hash(b"", b"abc"), salted_hash(b"abc", b""));
+    }
+}
@@ -0,0 +1,97 @@
+use core::fmt::{self, Debug};
+
+use crate::{internal::fmix64, BuildHasher, Hash};
+
+/// Wrapper for a [`BuildHasher<u64>`] that calculates both a shard index and a hash for use
+/// within the shard from a single hashing pass.
+///
+/// The shard index is taken from the high bits of the hash, and the in-shard hash is a
+/// mixed version of the full hash, so hashes within a shard are still evenly distributed
+/// over all bits. This is useful for sharded maps and partitioned queues.
+///
+/// `SHARDS` must be greater than zero. It doesn't have to be a power of two.
+pub struct ShardedBuildHasher<BH, const SHARDS: usize>(BH);
+
+impl<BH, const SHARDS: usize> ShardedBuildHasher<BH, SHARDS> {
+    const ASSERT_SHARDS: () = assert!(SHARDS != 0, "`SHARDS` must be greater than zero");
+
+    /// Create a new `ShardedBuildHasher`.
+    #[inline]
+    pub const fn new(build_hasher: BH) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::ASSERT_SHARDS;
+        Self(build_hasher)
+    }
+
+    /// Get a reference to the wrapped `BuildHasher`.
+    #[inline]
+    pub const fn get_ref(&self) -> &BH {
+        &self.0
+    }
+
+    /// The number of shards.
+    #[inline]
+    pub const fn shards(&self) -> usize {
+        SHARDS
+    }
+
+    /// Split an already calculated hash into a shard index and an in-shard hash.
+    #[inline]
+    pub const fn split(hash: u64) -> (usize, u64) {
+        let shard = ((hash as u128 * SHARDS as u128) >> 64) as usize;
+        (shard, fmix64(hash))
+    }
+}
+
+impl<BH: BuildHasher<u64>, const SHARDS: usize> ShardedBuildHasher<BH, SHARDS> {
+    /// Calculate the shard index and in-shard hash of a single value.
+    #[inline]
+    pub fn hash_shard<U: Hash>(&self, x: U) -> (usize, u64) {
+        Self::split(self.0.hash_one(x))
+    }
+
+    /// Calculate the shard index of a single value.
+    #[inline]
+    pub fn shard<U: Hash>(&self, x: U) -> usize {
+        self.hash_shard(x).0
+    }
+}
+
+impl<BH: Debug, const SHARDS: usize> Debug for ShardedBuildHasher<BH, SHARDS> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShardedBuildHasher")
+            .field("build_hasher", &self.0)
+            .field("shards", &SHARDS)
+            .finish()
+    }
+}
+
+impl<BH: Clone, const SHARDS: usize> Clone for ShardedBuildHasher<BH, SHARDS> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<BH: Default, const SHARDS: usize> Default for ShardedBuildHasher<BH, SHARDS> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(BH::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split() {
+        type S = ShardedBuildHasher<(), 3>;
+        assert_eq!(S::split(0).0, 0);
+        assert_eq!(S::split(u64::MAX / 3).0, 0);
+        assert_eq!(S::split(u64::MAX / 3 + 1).0, 1);
+        assert_eq!(S::split(u64::MAX).0, 2);
+        assert_ne!(S::split(1).1, S::split(2).1);
+    }
+}
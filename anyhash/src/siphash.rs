@@ -0,0 +1,719 @@
+//! Hashers and collections using the SipHash algorithm.
+
+// based on the paper at https://www.aumasson.jp/siphash/siphash.pdf
+
+use crate::{
+    impl_core_build_hasher, impl_core_hasher, BuildHasher, BuildHasherDefault,
+    EndianIndependentAlgorithm, Hasher, HasherWrite, KeyedBuildHasher, SeedableBuildHasher,
+};
+use core::marker::PhantomData;
+
+impl_core_build_hasher!(impl<V: Variant> SipHashVBuildHasher<V>);
+impl_core_hasher!(impl<V: Variant> SipHashV<V>);
+
+use sealed::Variant;
+mod sealed {
+    /// Variant trait for SipHash.
+    pub trait Variant: Clone + core::fmt::Debug + Default {
+        /// Number of compression rounds per message block
+        const C_ROUNDS: usize;
+
+        /// Number of finalization rounds
+        const D_ROUNDS: usize;
+    }
+}
+
+/// Selector for SipHash-1-3.
+#[derive(Clone, Debug, Default)]
+pub struct V13;
+
+/// Selector for SipHash-2-4.
+#[derive(Clone, Debug, Default)]
+pub struct V24;
+
+impl Variant for V13 {
+    const C_ROUNDS: usize = 1;
+    const D_ROUNDS: usize = 3;
+}
+
+impl Variant for V24 {
+    const C_ROUNDS: usize = 2;
+    const D_ROUNDS: usize = 4;
+}
+
+/// [`BuildHasher`] implementation for the [`SipHash13`] hasher.
+pub type SipHash13BuildHasher = SipHashVBuildHasher<V13>;
+
+/// [`BuildHasher`] implementation for the [`SipHash24`] hasher.
+pub type SipHash24BuildHasher = SipHashVBuildHasher<V24>;
+
+/// [`BuildHasher`] implementation for the [`SipHashV`] hasher.
+/// If you don't need support for using custom keys, use the zero sized
+/// [`SipHash13BuildHasherDefault`] or [`SipHash24BuildHasherDefault`] instead.
+///
+/// SipHash only protects against hash flooding if the key is secret and random,
+/// for example by using [`AmbientSeedBuildHasher`](crate::random::AmbientSeedBuildHasher).
+#[derive(Clone, Debug)]
+pub struct SipHashVBuildHasher<V: Variant = V24>(u64, u64, PhantomData<fn() -> V>);
+
+impl<V: Variant> SipHashVBuildHasher<V> {
+    /// Create a [`BuildHasher`] for [`SipHashV`] using the default key (all zeros).
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_key(0, 0)
+    }
+
+    /// Create a [`BuildHasher`] for [`SipHashV`] with a custom key.
+    #[inline]
+    pub const fn with_key(k0: u64, k1: u64) -> Self {
+        Self(k0, k1, PhantomData)
+    }
+
+    /// Create a [`BuildHasher`] for [`SipHashV`] with a custom key in u128 format.
+    /// `k0` is the low 64 bits and `k1` is the high 64 bits.
+    #[inline]
+    pub const fn with_key_128(key: u128) -> Self {
+        Self::with_key(key as u64, (key >> 64) as u64)
+    }
+
+    /// Create a [`BuildHasher`] for [`SipHashV`] with a custom key in the 16 byte format
+    /// used by the SipHash reference implementation.
+    #[inline]
+    pub const fn with_key_bytes(key: &[u8; 16]) -> Self {
+        Self::with_key_128(u128::from_le_bytes(*key))
+    }
+}
+
+impl<V: Variant> BuildHasher<u64> for SipHashVBuildHasher<V> {
+    type Hasher = SipHashV<V>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_key(self.0, self.1)
+    }
+}
+
+impl<V: Variant> SeedableBuildHasher for SipHashVBuildHasher<V> {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_key_128(seed)
+    }
+}
+
+impl<V: Variant> KeyedBuildHasher for SipHashVBuildHasher<V> {
+    const KEY_LEN: usize = 16;
+
+    /// Create a [`BuildHasher`] with a key in the 16 byte format used by the SipHash reference
+    /// implementation.
+    #[inline]
+    fn from_key(key: &[u8]) -> Self {
+        Self::with_key_bytes(key.try_into().expect("key must be 16 bytes"))
+    }
+}
+
+impl<V: Variant> Default for SipHashVBuildHasher<V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Variant> zeroize::Zeroize for SipHashVBuildHasher<V> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+        self.1.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Variant> Drop for SipHashVBuildHasher<V> {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Variant> zeroize::ZeroizeOnDrop for SipHashVBuildHasher<V> {}
+
+/// [`BuildHasher`] implementation for the [`SipHash13`] hasher using the default key (zero sized).
+pub type SipHash13BuildHasherDefault = BuildHasherDefault<SipHash13>;
+
+/// [`BuildHasher`] implementation for the [`SipHash24`] hasher using the default key (zero sized).
+pub type SipHash24BuildHasherDefault = BuildHasherDefault<SipHash24>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`SipHash13`] hasher.
+pub type SipHash13HashMap<K, V> = std::collections::HashMap<K, V, SipHash13BuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`SipHash13`] hasher with the default key.
+pub type SipHash13HashMapDefault<K, V> =
+    std::collections::HashMap<K, V, SipHash13BuildHasherDefault>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`SipHash13`] hasher.
+pub type SipHash13HashSet<T> = std::collections::HashSet<T, SipHash13BuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`SipHash13`] hasher with the default key.
+pub type SipHash13HashSetDefault<T> = std::collections::HashSet<T, SipHash13BuildHasherDefault>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`SipHash24`] hasher.
+pub type SipHash24HashMap<K, V> = std::collections::HashMap<K, V, SipHash24BuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`SipHash24`] hasher with the default key.
+pub type SipHash24HashMapDefault<K, V> =
+    std::collections::HashMap<K, V, SipHash24BuildHasherDefault>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`SipHash24`] hasher.
+pub type SipHash24HashSet<T> = std::collections::HashSet<T, SipHash24BuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`SipHash24`] hasher with the default key.
+pub type SipHash24HashSetDefault<T> = std::collections::HashSet<T, SipHash24BuildHasherDefault>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`SipHash13`] hasher.
+pub type SipHash13HeaplessIndexMap<K, V, const N: usize> =
+    heapless::IndexMap<K, V, SipHash13BuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`SipHash13`] hasher with the default key.
+pub type SipHash13HeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, SipHash13BuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`SipHash13`] hasher.
+pub type SipHash13HeaplessIndexSet<T, const N: usize> =
+    heapless::IndexSet<T, SipHash13BuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`SipHash13`] hasher with the default key.
+pub type SipHash13HeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, SipHash13BuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`SipHash24`] hasher.
+pub type SipHash24HeaplessIndexMap<K, V, const N: usize> =
+    heapless::IndexMap<K, V, SipHash24BuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`SipHash24`] hasher with the default key.
+pub type SipHash24HeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, SipHash24BuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`SipHash24`] hasher.
+pub type SipHash24HeaplessIndexSet<T, const N: usize> =
+    heapless::IndexSet<T, SipHash24BuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`SipHash24`] hasher with the default key.
+pub type SipHash24HeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, SipHash24BuildHasherDefault, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`SipHash13`] hasher.
+pub type SipHash13HashBrownMap<K, V> = hashbrown::HashMap<K, V, SipHash13BuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`SipHash13`] hasher with the default key.
+pub type SipHash13HashBrownMapDefault<K, V> = hashbrown::HashMap<K, V, SipHash13BuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`SipHash13`] hasher.
+pub type SipHash13HashBrownSet<T> = hashbrown::HashSet<T, SipHash13BuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`SipHash13`] hasher with the default key.
+pub type SipHash13HashBrownSetDefault<T> = hashbrown::HashSet<T, SipHash13BuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`SipHash24`] hasher.
+pub type SipHash24HashBrownMap<K, V> = hashbrown::HashMap<K, V, SipHash24BuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`SipHash24`] hasher with the default key.
+pub type SipHash24HashBrownMapDefault<K, V> = hashbrown::HashMap<K, V, SipHash24BuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`SipHash24`] hasher.
+pub type SipHash24HashBrownSet<T> = hashbrown::HashSet<T, SipHash24BuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`SipHash24`] hasher with the default key.
+pub type SipHash24HashBrownSetDefault<T> = hashbrown::HashSet<T, SipHash24BuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`SipHash13`] hasher.
+pub type SipHash13IndexMap<K, V> = indexmap::IndexMap<K, V, SipHash13BuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`SipHash13`] hasher with the default key.
+pub type SipHash13IndexMapDefault<K, V> = indexmap::IndexMap<K, V, SipHash13BuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`SipHash13`] hasher.
+pub type SipHash13IndexSet<T> = indexmap::IndexSet<T, SipHash13BuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`SipHash13`] hasher with the default key.
+pub type SipHash13IndexSetDefault<T> = indexmap::IndexSet<T, SipHash13BuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`SipHash24`] hasher.
+pub type SipHash24IndexMap<K, V> = indexmap::IndexMap<K, V, SipHash24BuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`SipHash24`] hasher with the default key.
+pub type SipHash24IndexMapDefault<K, V> = indexmap::IndexMap<K, V, SipHash24BuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`SipHash24`] hasher.
+pub type SipHash24IndexSet<T> = indexmap::IndexSet<T, SipHash24BuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`SipHash24`] hasher with the default key.
+pub type SipHash24IndexSetDefault<T> = indexmap::IndexSet<T, SipHash24BuildHasherDefault>;
+
+/// Hasher using the SipHash-1-3 algorithm. This is the variant used by `std`'s `HashMap`.
+pub type SipHash13 = SipHashV<V13>;
+
+/// Hasher using the SipHash-2-4 algorithm.
+pub type SipHash24 = SipHashV<V24>;
+
+/// Hasher using the SipHash algorithm.
+#[derive(Clone)]
+pub struct SipHashV<V: Variant = V24> {
+    v: [u64; 4],
+    tail: u64,
+    ntail: usize,
+    length: u64,
+    _pd: PhantomData<fn() -> V>,
+}
+
+impl<V: Variant> SipHashV<V> {
+    /// Create a new `SipHash` hasher using the default key (all zeros).
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_key(0, 0)
+    }
+
+    /// Create a new `SipHash` hasher with a custom key.
+    #[inline]
+    pub const fn with_key(k0: u64, k1: u64) -> Self {
+        Self {
+            v: [
+                k0 ^ 0x736f6d6570736575,
+                k1 ^ 0x646f72616e646f6d,
+                k0 ^ 0x6c7967656e657261,
+                k1 ^ 0x7465646279746573,
+            ],
+            tail: 0,
+            ntail: 0,
+            length: 0,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Create a new `SipHash` hasher with a custom key in u128 format.
+    /// `k0` is the low 64 bits and `k1` is the high 64 bits.
+    #[inline]
+    pub const fn with_key_128(key: u128) -> Self {
+        Self::with_key(key as u64, (key >> 64) as u64)
+    }
+
+    /// Create a new `SipHash` hasher with a custom key in the 16 byte format
+    /// used by the SipHash reference implementation.
+    #[inline]
+    pub const fn with_key_bytes(key: &[u8; 16]) -> Self {
+        Self::with_key_128(u128::from_le_bytes(*key))
+    }
+
+    #[inline(always)]
+    fn round(v: &mut [u64; 4]) {
+        v[0] = v[0].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(13);
+        v[1] ^= v[0];
+        v[0] = v[0].rotate_left(32);
+        v[2] = v[2].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(16);
+        v[3] ^= v[2];
+        v[0] = v[0].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(21);
+        v[3] ^= v[0];
+        v[2] = v[2].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(17);
+        v[1] ^= v[2];
+        v[2] = v[2].rotate_left(32);
+    }
+
+    #[inline(always)]
+    fn compress(v: &mut [u64; 4], m: u64) {
+        v[3] ^= m;
+        for _ in 0..V::C_ROUNDS {
+            Self::round(v);
+        }
+        v[0] ^= m;
+    }
+}
+
+impl<V: Variant> EndianIndependentAlgorithm for SipHashV<V> {}
+
+impl<V: Variant> Default for SipHashV<V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Variant> zeroize::Zeroize for SipHashV<V> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.v.zeroize();
+        self.tail.zeroize();
+        self.ntail.zeroize();
+        self.length.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Variant> Drop for SipHashV<V> {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Variant> zeroize::ZeroizeOnDrop for SipHashV<V> {}
+
+impl<V: Variant> HasherWrite for SipHashV<V> {
+    fn write(&mut self, mut bytes: &[u8]) {
+        self.length = self.length.wrapping_add(bytes.len() as u64);
+
+        if self.ntail != 0 {
+            let n = bytes.len().min(8 - self.ntail);
+            for (i, &byte) in bytes[..n].iter().enumerate() {
+                self.tail |= (byte as u64) << (8 * (self.ntail + i));
+            }
+            self.ntail += n;
+            bytes = &bytes[n..];
+            if self.ntail < 8 {
+                return;
+            }
+            Self::compress(&mut self.v, self.tail);
+            self.tail = 0;
+            self.ntail = 0;
+        }
+
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            Self::compress(&mut self.v, u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+
+        for (i, &byte) in chunks.remainder().iter().enumerate() {
+            self.tail |= (byte as u64) << (8 * i);
+        }
+        self.ntail = chunks.remainder().len();
+    }
+}
+
+impl<V: Variant> Hasher<u64> for SipHashV<V> {
+    fn finish(&self) -> u64 {
+        let mut v = self.v;
+        Self::compress(&mut v, self.tail | self.length << 56);
+        v[2] ^= 0xff;
+        for _ in 0..V::D_ROUNDS {
+            Self::round(&mut v);
+        }
+        v[0] ^ v[1] ^ v[2] ^ v[3]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::RawBytes, Hash};
+
+    const KEY: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+    fn sip13<T: Hash>(x: T) -> u64 {
+        SipHash13BuildHasher::with_key_bytes(&KEY).hash_one(x)
+    }
+
+    fn sip24<T: Hash>(x: T) -> u64 {
+        SipHash24BuildHasher::with_key_bytes(&KEY).hash_one(x)
+    }
+
+    #[test]
+    fn default_key() {
+        assert_eq!(
+            SipHash13BuildHasherDefault::new().hash_one(()),
+            0xd1fba762150c532c
+        );
+        assert_eq!(
+            SipHash24BuildHasherDefault::new().hash_one(()),
+            0x1e924b9d737700d7
+        );
+    }
+
+    #[test]
+    fn keyed() {
+        fn keyed<BH: KeyedBuildHasher + BuildHasher<u64>>(key: &[u8]) -> u64 {
+            BH::from_key(key).hash_one(RawBytes(b"hello"))
+        }
+        assert_eq!(
+            keyed::<SipHash24BuildHasher>(&KEY),
+            sip24(RawBytes(b"hello"))
+        );
+        assert_eq!(SipHash13BuildHasher::KEY_LEN, 16);
+    }
+
+    // reference vectors: key 00..0f, message 00..(len-1)
+    #[test]
+    fn reference() {
+        const EXPECTED_13: [u64; 64] = [
+            0xabac0158050fc4dc,
+            0xc9f49bf37d57ca93,
+            0x82cb9b024dc7d44d,
+            0x8bf80ab8e7ddf7fb,
+            0xcf75576088d38328,
+            0xdef9d52f49533b67,
+            0xc50d2b50c59f22a7,
+            0xd3927d989bb11140,
+            0x369095118d299a8e,
+            0x25a48eb36c063de4,
+            0x79de85ee92ff097f,
+            0x70c118c1f94dc352,
+            0x78a384b157b4d9a2,
+            0x306f760c1229ffa7,
+            0x605aa111c0f95d34,
+            0xd320d86d2a519956,
+            0xcc4fdd1a7d908b66,
+            0x9cf2689063dbd80c,
+            0x8ffc389cb473e63e,
+            0xf21f9de58d297d1c,
+            0xc0dc2f46a6cce040,
+            0xb992abfe2b45f844,
+            0x7ffe7b9ba320872e,
+            0x525a0e7fdae6c123,
+            0xf464aeb267349c8c,
+            0x45cd5928705b0979,
+            0x3a3e35e3ca9913a5,
+            0xa91dc74e4ade3b35,
+            0xfb0bed02ef6cd00d,
+            0x88d93cb44ab1e1f4,
+            0x540f11d643c5e663,
+            0x2370dd1f8c21d1bc,
+            0x81157b6c16a7b60d,
+            0x4d54b9e57a8ff9bf,
+            0x759f12781f2a753e,
+            0xcea1a3bebf186b91,
+            0x2cf508d3ada26206,
+            0xb6101c2da3c33057,
+            0xb3f47496ae3a36a1,
+            0x626b57547b108392,
+            0xc1d2363299e41531,
+            0x667cc1923f1ad944,
+            0x65704ffec8138825,
+            0x24f280d1c28949a6,
+            0xc2ca1cedfaf8876b,
+            0xc2164bfc9f042196,
+            0xa16e9c9368b1d623,
+            0x49fb169c8b5114fd,
+            0x9f3143f8df074c46,
+            0xc6fdaf2412cc86b3,
+            0x7eaf49d10a52098f,
+            0x1cf313559d292f9a,
+            0xc44a30dda2f41f12,
+            0x36fae98943a71ed0,
+            0x318fb34c73f0bce6,
+            0xa27abf3670a7e980,
+            0xb4bcc0db243c6d75,
+            0x23f8d852fdb71513,
+            0x8f035f4da67d8a08,
+            0xd89cd0e5b7e8f148,
+            0xf6f4e6bcf7a644ee,
+            0xaec59ad80f1837f2,
+            0xc3b2f6154b6694e0,
+            0x9d199062b7bbb3a8,
+        ];
+        const EXPECTED_24: [u64; 64] = [
+            0x726fdb47dd0e0e31,
+            0x74f839c593dc67fd,
+            0xd6c8009d9a94f5a,
+            0x85676696d7fb7e2d,
+            0xcf2794e0277187b7,
+            0x18765564cd99a68d,
+            0xcbc9466e58fee3ce,
+            0xab0200f58b01d137,
+            0x93f5f5799a932462,
+            0x9e0082df0ba9e4b0,
+            0x7a5dbbc594ddb9f3,
+            0xf4b32f46226bada7,
+            0x751e8fbc860ee5fb,
+            0x14ea5627c0843d90,
+            0xf723ca908e7af2ee,
+            0xa129ca6149be45e5,
+            0x3f2acc7f57c29bdb,
+            0x699ae9f52cbe4794,
+            0x4bc1b3f0968dd39c,
+            0xbb6dc91da77961bd,
+            0xbed65cf21aa2ee98,
+            0xd0f2cbb02e3b67c7,
+            0x93536795e3a33e88,
+            0xa80c038ccd5ccec8,
+            0xb8ad50c6f649af94,
+            0xbce192de8a85b8ea,
+            0x17d835b85bbb15f3,
+            0x2f2e6163076bcfad,
+            0xde4daaaca71dc9a5,
+            0xa6a2506687956571,
+            0xad87a3535c49ef28,
+            0x32d892fad841c342,
+            0x7127512f72f27cce,
+            0xa7f32346f95978e3,
+            0x12e0b01abb051238,
+            0x15e034d40fa197ae,
+            0x314dffbe0815a3b4,
+            0x27990f029623981,
+            0xcadcd4e59ef40c4d,
+            0x9abfd8766a33735c,
+            0xe3ea96b5304a7d0,
+            0xad0c42d6fc585992,
+            0x187306c89bc215a9,
+            0xd4a60abcf3792b95,
+            0xf935451de4f21df2,
+            0xa9538f0419755787,
+            0xdb9acddff56ca510,
+            0xd06c98cd5c0975eb,
+            0xe612a3cb9ecba951,
+            0xc766e62cfcadaf96,
+            0xee64435a9752fe72,
+            0xa192d576b245165a,
+            0xa8787bf8ecb74b2,
+            0x81b3e73d20b49b6f,
+            0x7fa8220ba3b2ecea,
+            0x245731c13ca42499,
+            0xb78dbfaf3a8d83bd,
+            0xea1ad565322a1a0b,
+            0x60e61c23a3795013,
+            0x6606d7e446282b93,
+            0x6ca4ecb15c5f91e1,
+            0x9f626da15c9625f3,
+            0xe51b38608ef25f57,
+            0x958a324ceb064572,
+        ];
+
+        let message: [u8; 64] = core::array::from_fn(|i| i as u8);
+        for len in 0..64 {
+            assert_eq!(
+                sip13(RawBytes(&message[..len])),
+                EXPECTED_13[len],
+                "len {len}"
+            );
+            assert_eq!(
+                sip24(RawBytes(&message[..len])),
+                EXPECTED_24[len],
+                "len {len}"
+            );
+        }
+    }
+
+    #[test]
+    fn streaming() {
+        let message: [u8; 64] = core::array::from_fn(|i| i as u8);
+        for chunk_size in 1..10 {
+            let mut hasher = SipHash24::with_key_bytes(&KEY);
+            for chunk in message.chunks(chunk_size) {
+                hasher.write(chunk);
+            }
+            assert_eq!(hasher.finish(), sip24(RawBytes(&message)));
+        }
+    }
+
+    hash_test_vectors! {
+        [sip13]
+        a: 0x1c2697ab786a6237,
+        ab: 0xc149f5d943a15ed,
+        abc: 0x6fce24e8af8146eb,
+        abcd: 0x2b722dba445c0659,
+        abcde: 0x53ace3f1f252f978,
+        abcdef: 0xb5d886816a84416e,
+        abcdefg: 0x639b490caba831bb,
+        abcdefgh: 0x12d8c08c2ee9e620,
+        abcdefghi: 0x7e02bfd36e3aa6a2,
+        abcdefghij: 0x5e287ab75f9d9413,
+        abcdefghijk: 0x61a776dfdbd799c8,
+        abcdefghijkl: 0x6f27530630dc6b0f,
+        abcdefghijklm: 0x85ee74f14fbf0d08,
+        abcdefghijklmn: 0xcbb2f6ef6f1cf22a,
+        abcdefghijklmno: 0x19c1b464baa960a1,
+        abcdefghijklmnop: 0xa0a4466e7e02c46a,
+        abcdefghijklmnopq: 0xabe8494af38e15cf,
+        abcdefghijklmnopqr: 0xc99def4a20426d20,
+        abcdefghijklmnopqrs: 0xe1046ecb19fe9fa4,
+        abcdefghijklmnopqrst: 0x31b10b3d9e125930,
+        abcdefghijklmnopqrstu: 0x605f7aa3cec297bc,
+        abcdefghijklmnopqrstuv: 0x850b7821a57ceab6,
+        abcdefghijklmnopqrstuvw: 0x276c7c401120195c,
+        abcdefghijklmnopqrstuvwx: 0x2c71632b733d9f82,
+        abcdefghijklmnopqrstuvwxy: 0xc47ee15dcec582a9,
+        abcdefghijklmnopqrstuvwxyz: 0xde872b4d518c3561,
+        abcdefghijklmnopqrstuvwxyz0: 0x3300eaa6197a5982,
+        abcdefghijklmnopqrstuvwxyz01: 0x2d9143f2863587ba,
+        abcdefghijklmnopqrstuvwxyz012: 0x2780dd14ecbf4096,
+        abcdefghijklmnopqrstuvwxyz0123: 0x6ad5d21ad456ee62,
+        abcdefghijklmnopqrstuvwxyz01234: 0x6e5f6827ecc7b3a,
+        abcdefghijklmnopqrstuvwxyz012345: 0xf4c5ba2245781535,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x51c0ed29ec096c02,
+        abcdefghijklmnopqrstuvwxyz01234567: 0x7f2e274d626449c5,
+        abcdefghijklmnopqrstuvwxyz012345678: 0xa804acae62d42e46,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0xdbece5bf432fdc19,
+        [sip24]
+        a: 0x2ba3e8e9a71148ca,
+        ab: 0x42452c099a0d2f3,
+        abc: 0x5dbcfa53aa2007a5,
+        abcd: 0xb00051890be927b6,
+        abcde: 0xa74563e1ea79b873,
+        abcdef: 0x2a6e77e733c7c05d,
+        abcdefg: 0xdc18e8672ed188eb,
+        abcdefgh: 0xc329dda391d44470,
+        abcdefghi: 0xb2a9e1c46d9deca4,
+        abcdefghij: 0xb6c3b38b6c23fd9b,
+        abcdefghijk: 0x803db3d97aead1cf,
+        abcdefghijkl: 0xb78f3f7292f4a69c,
+        abcdefghijklm: 0xfacad7e6f4470ea,
+        abcdefghijklmn: 0x355ff884554c4c06,
+        abcdefghijklmno: 0x584860340018ed4,
+        abcdefghijklmnop: 0xd9511efec5dfda45,
+        abcdefghijklmnopq: 0x4170a8f25b9e41d2,
+        abcdefghijklmnopqr: 0xc547050f709c1c39,
+        abcdefghijklmnopqrs: 0x22ea6c2b5e5ca394,
+        abcdefghijklmnopqrst: 0x2cdb0179f38b1d8b,
+        abcdefghijklmnopqrstu: 0x392a3d7d1c212eab,
+        abcdefghijklmnopqrstuv: 0x8b20ec52e2a7bcb4,
+        abcdefghijklmnopqrstuvw: 0xf495003a48591ad5,
+        abcdefghijklmnopqrstuvwx: 0x83e8fb3dfcd2f297,
+        abcdefghijklmnopqrstuvwxy: 0x4d983539a04cbf6d,
+        abcdefghijklmnopqrstuvwxyz: 0x87162f9dd999915f,
+        abcdefghijklmnopqrstuvwxyz0: 0xceffd0988ffd8c94,
+        abcdefghijklmnopqrstuvwxyz01: 0xf748ff1d0b5cd161,
+        abcdefghijklmnopqrstuvwxyz012: 0x34d6dfb028802cd2,
+        abcdefghijklmnopqrstuvwxyz0123: 0xd6f3b0a02ffffb06,
+        abcdefghijklmnopqrstuvwxyz01234: 0xf5a09dfebccc89c6,
+        abcdefghijklmnopqrstuvwxyz012345: 0x1c9a745aaa9fa056,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x305139a0dadd0ac7,
+        abcdefghijklmnopqrstuvwxyz01234567: 0x218c741528cda3a2,
+        abcdefghijklmnopqrstuvwxyz012345678: 0xb74d3eefc369c6b0,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0xb520be83217728c0,
+    }
+}
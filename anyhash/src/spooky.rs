@@ -5,7 +5,7 @@
 use crate::{
     impl_core_build_hasher, impl_core_hasher,
     internal::{Buffer, N24},
-    BuildHasher, Hasher, HasherWrite,
+    BuildHasher, Hasher, HasherCheckpoint, HasherWrite, SeedableBuildHasher,
 };
 use bytemuck::{cast_slice, cast_slice_mut};
 use core::marker::PhantomData;
@@ -33,6 +33,8 @@ macro_rules! fallthrough_jump_table {
 
 impl_core_build_hasher!(impl<V: Version> SpookyVBuildHasher<V>);
 impl_core_hasher!(impl<V: Version> SpookyV<V>);
+impl_core_build_hasher!(impl<V: Version> SpookyShortVBuildHasher<V>);
+impl_core_hasher!(impl<V: Version> SpookyShortV<V>);
 
 use sealed::Version;
 mod sealed {
@@ -86,6 +88,13 @@ impl<V: Version> SpookyVBuildHasher<V> {
     }
 }
 
+impl<V: Version> SeedableBuildHasher for SpookyVBuildHasher<V> {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seed_128(seed)
+    }
+}
+
 impl<V: Version> Default for SpookyVBuildHasher<V> {
     #[inline]
     fn default() -> Self {
@@ -93,6 +102,26 @@ impl<V: Version> Default for SpookyVBuildHasher<V> {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl<V: Version> zeroize::Zeroize for SpookyVBuildHasher<V> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+        self.1.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Version> Drop for SpookyVBuildHasher<V> {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Version> zeroize::ZeroizeOnDrop for SpookyVBuildHasher<V> {}
+
 impl<V: Version> BuildHasher<u32> for SpookyVBuildHasher<V> {
     type Hasher = SpookyV<V>;
 
@@ -178,6 +207,57 @@ pub type SpookyHashSet<T> = std::collections::HashSet<T, SpookyBuildHasher>;
 /// `HashSet` from `std` configured to use the [`Spooky`] v2 hasher with the default seed.
 pub type SpookyHashSetDefault<T> = std::collections::HashSet<T, SpookyBuildHasherDefault>;
 
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`Spooky`] v2 hasher.
+pub type SpookyHeaplessIndexMap<K, V, const N: usize> =
+    heapless::IndexMap<K, V, SpookyBuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`Spooky`] v2 hasher with the default seed.
+pub type SpookyHeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, SpookyBuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`Spooky`] v2 hasher.
+pub type SpookyHeaplessIndexSet<T, const N: usize> = heapless::IndexSet<T, SpookyBuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`Spooky`] v2 hasher with the default seed.
+pub type SpookyHeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, SpookyBuildHasherDefault, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`Spooky`] v2 hasher.
+pub type SpookyHashBrownMap<K, V> = hashbrown::HashMap<K, V, SpookyBuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`Spooky`] v2 hasher with the default seed.
+pub type SpookyHashBrownMapDefault<K, V> = hashbrown::HashMap<K, V, SpookyBuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`Spooky`] v2 hasher.
+pub type SpookyHashBrownSet<T> = hashbrown::HashSet<T, SpookyBuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`Spooky`] v2 hasher with the default seed.
+pub type SpookyHashBrownSetDefault<T> = hashbrown::HashSet<T, SpookyBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`Spooky`] v2 hasher.
+pub type SpookyIndexMap<K, V> = indexmap::IndexMap<K, V, SpookyBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`Spooky`] v2 hasher with the default seed.
+pub type SpookyIndexMapDefault<K, V> = indexmap::IndexMap<K, V, SpookyBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`Spooky`] v2 hasher.
+pub type SpookyIndexSet<T> = indexmap::IndexSet<T, SpookyBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`Spooky`] v2 hasher with the default seed.
+pub type SpookyIndexSetDefault<T> = indexmap::IndexSet<T, SpookyBuildHasherDefault>;
+
 const SC_NUM_VARS: usize = 12;
 const SC_BLOCK_SIZE: usize = SC_NUM_VARS * 8;
 const SC_BUF_SIZE: usize = SC_BLOCK_SIZE * 2;
@@ -248,31 +328,68 @@ impl<V: Version> SpookyV<V> {
     }
 
     fn short(&self) -> u128 {
-        let length = self.length;
-
-        let mut remainder: usize = length % 32;
         let mut h = [self.state[0], self.state[1], SC_CONST, SC_CONST];
+        let i = self.length / 32 * 4;
+        for chunk in self.data.as_u64s()[..i].chunks_exact(4) {
+            Self::short_chunk(&mut h, chunk.try_into().unwrap());
+        }
+        Self::short_finish(h, &self.data.as_u64s()[i..], self.length)
+    }
 
-        let mut i = 0;
+    // Mix in the full block in the buffer, if any, and return the state, the offset of the
+    // final partial block and its length
+    fn last_block(&self) -> ([u64; SC_NUM_VARS], usize, u8) {
+        let mut h = self.state;
+        if self.remainder >= SC_BLOCK_SIZE as u8 {
+            Self::mix(
+                self.data.as_u64s()[..SC_NUM_VARS].try_into().unwrap(),
+                &mut h,
+            );
+            (h, SC_NUM_VARS, self.remainder - SC_BLOCK_SIZE as u8)
+        } else {
+            (h, 0, self.remainder)
+        }
+    }
+
+    // `data` is the final partial block, and is padded in place
+    fn finish_block(
+        mut h: [u64; SC_NUM_VARS],
+        data: &mut [u64; SC_NUM_VARS],
+        remainder: u8,
+    ) -> u128 {
+        let data_u8 = cast_slice_mut(&mut data[..]);
+        data_u8[remainder as usize..].fill(0);
+        data_u8[SC_BLOCK_SIZE - 1] = remainder;
+
+        if V::VERSION == 1 {
+            Self::mix(data, &mut h);
+        }
 
-        if length > 15 {
-            i = length / 32 * 4;
+        Self::end(data, &mut h);
 
-            for chunk in self.data.as_u64s()[..i].chunks_exact(4) {
-                h[2] = h[2].wrapping_add(chunk[0]);
-                h[3] = h[3].wrapping_add(chunk[1]);
-                Self::short_mix(&mut h);
-                h[0] = h[0].wrapping_add(chunk[2]);
-                h[1] = h[1].wrapping_add(chunk[3]);
-            }
+        h[0] as u128 | ((h[1] as u128) << 64)
+    }
 
-            if remainder >= 16 {
-                remainder -= 16;
-                h[2] = h[2].wrapping_add(self.data.as_u64s()[i]);
-                h[3] = h[3].wrapping_add(self.data.as_u64s()[i + 1]);
-                Self::short_mix(&mut h);
-                i += 2;
-            }
+    #[inline]
+    fn short_chunk(h: &mut [u64; 4], chunk: &[u64; 4]) {
+        h[2] = h[2].wrapping_add(chunk[0]);
+        h[3] = h[3].wrapping_add(chunk[1]);
+        Self::short_mix(h);
+        h[0] = h[0].wrapping_add(chunk[2]);
+        h[1] = h[1].wrapping_add(chunk[3]);
+    }
+
+    // `data` is the data remaining after the last full 32 byte chunk
+    fn short_finish(mut h: [u64; 4], data: &[u64], length: usize) -> u128 {
+        let mut remainder: usize = length % 32;
+        let mut i = 0;
+
+        if remainder >= 16 {
+            remainder -= 16;
+            h[2] = h[2].wrapping_add(data[0]);
+            h[3] = h[3].wrapping_add(data[1]);
+            Self::short_mix(&mut h);
+            i = 2;
         }
 
         if V::VERSION == 1 {
@@ -281,7 +398,7 @@ impl<V: Version> SpookyV<V> {
             h[3] = h[3].wrapping_add((length as u64).rotate_left(56));
         }
 
-        let data = &self.data.as_u64s()[i..i + (remainder + 7) / 8];
+        let data = &data[i..i + remainder.div_ceil(8)];
         let data_u8: &[u8] = cast_slice(data);
         let data_u32: &[u32] = cast_slice(data);
 
@@ -402,11 +519,38 @@ impl<V: Version> Default for SpookyV<V> {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl<V: Version> zeroize::Zeroize for SpookyV<V> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.data.zeroize();
+        self.state.zeroize();
+        self.length.zeroize();
+        self.remainder.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Version> Drop for SpookyV<V> {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Version> zeroize::ZeroizeOnDrop for SpookyV<V> {}
+
 impl<V: Version> Hasher<u32> for SpookyV<V> {
     #[inline]
     fn finish(&self) -> u32 {
         <Self as Hasher<u128>>::finish(self) as u32
     }
+
+    #[inline]
+    fn finish_once(self) -> u32 {
+        <Self as Hasher<u128>>::finish_once(self) as u32
+    }
 }
 
 impl<V: Version> Hasher<u64> for SpookyV<V> {
@@ -414,6 +558,26 @@ impl<V: Version> Hasher<u64> for SpookyV<V> {
     fn finish(&self) -> u64 {
         <Self as Hasher<u128>>::finish(self) as u64
     }
+
+    #[inline]
+    fn finish_once(self) -> u64 {
+        <Self as Hasher<u128>>::finish_once(self) as u64
+    }
+}
+
+/// The snapshot is a copy of the hasher, including its buffered input.
+impl<V: Version> HasherCheckpoint for SpookyV<V> {
+    type Snapshot = Self;
+
+    #[inline]
+    fn checkpoint(&self) -> Self {
+        self.clone()
+    }
+
+    #[inline]
+    fn restore(&mut self, snapshot: Self) {
+        *self = snapshot;
+    }
 }
 
 impl<V: Version> HasherWrite for SpookyV<V> {
@@ -473,7 +637,7 @@ impl<V: Version> HasherWrite for SpookyV<V> {
         let remainder = (length - length_to_end_64 * 8) as u8;
 
         if bytes.as_ptr().align_offset(8) == 0 {
-            let u64s = cast_slice(bytes);
+            let u64s = cast_slice(&bytes[..length_to_end_64 * 8]);
             for chunk in u64s.chunks_exact(SC_NUM_VARS) {
                 Self::mix(chunk.try_into().unwrap(), &mut h);
             }
@@ -501,36 +665,411 @@ impl<V: Version> Hasher<u128> for SpookyV<V> {
         if self.length < SC_BUF_SIZE {
             return self.short();
         }
-
-        let mut remainder: u8 = self.remainder;
-        let mut h = self.state;
-
-        let offset = if remainder >= SC_BLOCK_SIZE as u8 {
-            Self::mix(
-                self.data.as_u64s()[..SC_NUM_VARS].try_into().unwrap(),
-                &mut h,
-            );
-            remainder -= SC_BLOCK_SIZE as u8;
-            SC_NUM_VARS
-        } else {
-            0
-        };
-
+        let (h, offset, remainder) = self.last_block();
         let mut data: [u64; SC_NUM_VARS] = self.data.as_u64s()[offset..][..SC_NUM_VARS]
             .try_into()
             .unwrap();
-        let data_u8 = cast_slice_mut(&mut data[..]);
+        Self::finish_block(h, &mut data, remainder)
+    }
 
-        data_u8[remainder as usize..].fill(0);
-        data_u8[SC_BLOCK_SIZE - 1] = remainder;
+    #[inline]
+    fn finish_once(mut self) -> u128 {
+        if self.length < SC_BUF_SIZE {
+            return self.short();
+        }
+        let (h, offset, remainder) = self.last_block();
+        let data = (&mut self.data.as_u64s_mut()[offset..][..SC_NUM_VARS])
+            .try_into()
+            .unwrap();
+        Self::finish_block(h, data, remainder)
+    }
+}
 
-        if V::VERSION == 1 {
-            Self::mix(&data, &mut h);
+/// [`BuildHasher`] implementation for the [`SpookyShort`] v2 hasher.
+pub type SpookyShortBuildHasher = SpookyShortVBuildHasher<V2>;
+
+/// [`BuildHasher`] implementation for the [`SpookyShort`] hasher.
+#[derive(Clone, Debug)]
+pub struct SpookyShortVBuildHasher<V: Version = V2>(u64, u64, PhantomData<fn() -> V>);
+
+impl<V: Version> SpookyShortVBuildHasher<V> {
+    /// Create a [`BuildHasher`] for [`SpookyShort`] using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seed(0, 0)
+    }
+
+    /// Create a [`BuildHasher`] for [`SpookyShort`] with a custom seed.
+    #[inline]
+    pub const fn with_seed(seed1: u64, seed2: u64) -> Self {
+        Self(seed1, seed2, PhantomData)
+    }
+
+    /// Create a [`BuildHasher`] for [`SpookyShort`] with a custom seed in u128 format.
+    #[inline]
+    pub const fn with_seed_128(seed: u128) -> Self {
+        Self::with_seed(seed as u64, (seed >> 64) as u64)
+    }
+}
+
+impl<V: Version> SeedableBuildHasher for SpookyShortVBuildHasher<V> {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seed_128(seed)
+    }
+}
+
+impl<V: Version> Default for SpookyShortVBuildHasher<V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Version> zeroize::Zeroize for SpookyShortVBuildHasher<V> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+        self.1.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Version> Drop for SpookyShortVBuildHasher<V> {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Version> zeroize::ZeroizeOnDrop for SpookyShortVBuildHasher<V> {}
+
+impl<V: Version> BuildHasher<u32> for SpookyShortVBuildHasher<V> {
+    type Hasher = SpookyShortV<V>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seed(self.0, self.1)
+    }
+}
+
+impl<V: Version> BuildHasher<u64> for SpookyShortVBuildHasher<V> {
+    type Hasher = SpookyShortV<V>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seed(self.0, self.1)
+    }
+}
+
+impl<V: Version> BuildHasher<u128> for SpookyShortVBuildHasher<V> {
+    type Hasher = SpookyShortV<V>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seed(self.0, self.1)
+    }
+}
+
+/// [`BuildHasher`] implementation for the [`SpookyShort`] v2 hasher using the default seed
+/// (zero sized).
+pub type SpookyShortBuildHasherDefault = SpookyShortVBuildHasherDefault<V2>;
+
+/// [`BuildHasher`] implementation for the [`SpookyShort`] hasher using the default seed
+/// (zero sized).
+#[derive(Clone, Debug, Default)]
+pub struct SpookyShortVBuildHasherDefault<V: Version = V2>(PhantomData<fn() -> V>);
+
+impl<V: Version> SpookyShortVBuildHasherDefault<V> {
+    /// Create a [`BuildHasher`] for [`SpookyShort`] using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<V: Version> BuildHasher<u32> for SpookyShortVBuildHasherDefault<V> {
+    type Hasher = SpookyShortV<V>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::new()
+    }
+}
+
+impl<V: Version> BuildHasher<u64> for SpookyShortVBuildHasherDefault<V> {
+    type Hasher = SpookyShortV<V>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::new()
+    }
+}
+
+impl<V: Version> BuildHasher<u128> for SpookyShortVBuildHasherDefault<V> {
+    type Hasher = SpookyShortV<V>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::new()
+    }
+}
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`SpookyShort`] v2 hasher.
+pub type SpookyShortHashMap<K, V> = std::collections::HashMap<K, V, SpookyShortBuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`SpookyShort`] v2 hasher with the default seed.
+pub type SpookyShortHashMapDefault<K, V> =
+    std::collections::HashMap<K, V, SpookyShortBuildHasherDefault>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`SpookyShort`] v2 hasher.
+pub type SpookyShortHashSet<T> = std::collections::HashSet<T, SpookyShortBuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`SpookyShort`] v2 hasher with the default seed.
+pub type SpookyShortHashSetDefault<T> = std::collections::HashSet<T, SpookyShortBuildHasherDefault>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`SpookyShort`] v2 hasher.
+pub type SpookyShortHeaplessIndexMap<K, V, const N: usize> =
+    heapless::IndexMap<K, V, SpookyShortBuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`SpookyShort`] v2 hasher with the default
+/// seed.
+pub type SpookyShortHeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, SpookyShortBuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`SpookyShort`] v2 hasher.
+pub type SpookyShortHeaplessIndexSet<T, const N: usize> =
+    heapless::IndexSet<T, SpookyShortBuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`SpookyShort`] v2 hasher with the default
+/// seed.
+pub type SpookyShortHeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, SpookyShortBuildHasherDefault, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`SpookyShort`] v2 hasher.
+pub type SpookyShortHashBrownMap<K, V> = hashbrown::HashMap<K, V, SpookyShortBuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`SpookyShort`] v2 hasher with the default
+/// seed.
+pub type SpookyShortHashBrownMapDefault<K, V> =
+    hashbrown::HashMap<K, V, SpookyShortBuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`SpookyShort`] v2 hasher.
+pub type SpookyShortHashBrownSet<T> = hashbrown::HashSet<T, SpookyShortBuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`SpookyShort`] v2 hasher with the default
+/// seed.
+pub type SpookyShortHashBrownSetDefault<T> = hashbrown::HashSet<T, SpookyShortBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`SpookyShort`] v2 hasher.
+pub type SpookyShortIndexMap<K, V> = indexmap::IndexMap<K, V, SpookyShortBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`SpookyShort`] v2 hasher with the default
+/// seed.
+pub type SpookyShortIndexMapDefault<K, V> = indexmap::IndexMap<K, V, SpookyShortBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`SpookyShort`] v2 hasher.
+pub type SpookyShortIndexSet<T> = indexmap::IndexSet<T, SpookyShortBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`SpookyShort`] v2 hasher with the default
+/// seed.
+pub type SpookyShortIndexSetDefault<T> = indexmap::IndexSet<T, SpookyShortBuildHasherDefault>;
+
+/// Hasher using the short variant of the SpookyHash algorithm (v2).
+pub type SpookyShort = SpookyShortV<V2>;
+
+/// Hasher using the short variant of the SpookyHash algorithm.
+///
+/// [`Spooky`] uses this algorithm for inputs shorter than 192 bytes, and gives the same hash
+/// values for those. `SpookyShort` uses it for all inputs, which lets it keep a much smaller
+/// state, but it's slower than [`Spooky`] for long inputs and gives different hash values
+/// for them.
+#[derive(Clone)]
+pub struct SpookyShortV<V: Version = V2> {
+    state: [u64; 4],
+    buffer: [u64; 4],
+    length: usize,
+    _pd: PhantomData<fn() -> V>,
+}
+
+impl<V: Version> SpookyShortV<V> {
+    /// Create a new `SpookyShort` hasher with the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seed(0, 0)
+    }
+
+    /// Create a new `SpookyShort` hasher with a custom seed.
+    #[inline]
+    pub const fn with_seed(seed1: u64, seed2: u64) -> Self {
+        Self {
+            state: [seed1, seed2, SC_CONST, SC_CONST],
+            buffer: [0; 4],
+            length: 0,
+            _pd: PhantomData,
         }
+    }
 
-        Self::end(&data, &mut h);
+    /// Create a new `SpookyShort` hasher with a custom seed in u128 format.
+    #[inline]
+    pub const fn with_seed_128(seed: u128) -> Self {
+        Self::with_seed(seed as u64, (seed >> 64) as u64)
+    }
+}
 
-        h[0] as u128 | ((h[1] as u128) << 64)
+impl<V: Version> Default for SpookyShortV<V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Version> zeroize::Zeroize for SpookyShortV<V> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+        self.buffer.zeroize();
+        self.length.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Version> Drop for SpookyShortV<V> {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<V: Version> zeroize::ZeroizeOnDrop for SpookyShortV<V> {}
+
+/// The snapshot is a copy of the hasher, including its buffered input.
+impl<V: Version> HasherCheckpoint for SpookyShortV<V> {
+    type Snapshot = Self;
+
+    #[inline]
+    fn checkpoint(&self) -> Self {
+        self.clone()
+    }
+
+    #[inline]
+    fn restore(&mut self, snapshot: Self) {
+        *self = snapshot;
+    }
+}
+
+impl<V: Version> HasherWrite for SpookyShortV<V> {
+    #[inline]
+    fn write(&mut self, mut bytes: &[u8]) {
+        let pos = self.length % 32;
+        self.length += bytes.len();
+
+        if pos != 0 {
+            let n = (32 - pos).min(bytes.len());
+            cast_slice_mut::<_, u8>(&mut self.buffer)[pos..][..n].copy_from_slice(&bytes[..n]);
+            if pos + n < 32 {
+                return;
+            }
+            SpookyV::<V>::short_chunk(&mut self.state, &self.buffer);
+            bytes = &bytes[n..];
+        }
+
+        let mut chunks = bytes.chunks_exact(32);
+        for chunk in &mut chunks {
+            cast_slice_mut::<_, u8>(&mut self.buffer).copy_from_slice(chunk);
+            SpookyV::<V>::short_chunk(&mut self.state, &self.buffer);
+        }
+        let remainder = chunks.remainder();
+        cast_slice_mut::<_, u8>(&mut self.buffer)[..remainder.len()].copy_from_slice(remainder);
+    }
+}
+
+impl<V: Version> Hasher<u32> for SpookyShortV<V> {
+    #[inline]
+    fn finish(&self) -> u32 {
+        <Self as Hasher<u128>>::finish(self) as u32
+    }
+}
+
+impl<V: Version> Hasher<u64> for SpookyShortV<V> {
+    #[inline]
+    fn finish(&self) -> u64 {
+        <Self as Hasher<u128>>::finish(self) as u64
+    }
+}
+
+impl<V: Version> Hasher<u128> for SpookyShortV<V> {
+    #[inline]
+    fn finish(&self) -> u128 {
+        SpookyV::<V>::short_finish(self.state, &self.buffer, self.length)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+
+    impl<V: Version> Serialize for SpookyVBuildHasher<V> {
+        #[inline]
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            (self.0, self.1).serialize(serializer)
+        }
+    }
+
+    impl<'de, V: Version> Deserialize<'de> for SpookyVBuildHasher<V> {
+        #[inline]
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let (seed1, seed2) = <(u64, u64)>::deserialize(deserializer)?;
+            Ok(Self::with_seed(seed1, seed2))
+        }
+    }
+
+    /// Serializes as a tuple of the state, the buffer as little endian `u64`s, the total length
+    /// and the number of bytes in the buffer.
+    impl<V: Version> Serialize for SpookyV<V> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let data = self.data.as_u64s().map(u64::from_le);
+            (self.state, data, self.length as u64, self.remainder).serialize(serializer)
+        }
+    }
+
+    impl<'de, V: Version> Deserialize<'de> for SpookyV<V> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let (state, data, length, remainder) =
+                <([u64; SC_NUM_VARS], [u64; 2 * SC_NUM_VARS], u64, u8)>::deserialize(deserializer)?;
+            if remainder as usize >= SC_BUF_SIZE || remainder as u64 > length {
+                return Err(D::Error::custom("invalid buffer length"));
+            }
+            let length = usize::try_from(length).map_err(D::Error::custom)?;
+            let mut hasher = Self::new();
+            hasher.state = state;
+            *hasher.data.as_u64s_mut() = data.map(u64::to_le);
+            hasher.length = length;
+            hasher.remainder = remainder;
+            Ok(hasher)
+        }
     }
 }
 
@@ -721,4 +1260,90 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn finish_once() {
+        let buf: [u8; 600] = core::array::from_fn(|i| (i * 5 + 1) as u8);
+        for i in 0..buf.len() {
+            let mut hasher = SpookyV::<V2>::new();
+            hasher.write(&buf[..i]);
+            let hash: u128 = hasher.finish();
+            assert_eq!(
+                Hasher::<u128>::finish_once(hasher),
+                hash,
+                "wrong value at {i}"
+            );
+        }
+    }
+
+    #[test]
+    fn checkpoint() {
+        let buf: [u8; 500] = core::array::from_fn(|i| (i * 3 + 7) as u8);
+        for split in [0, 10, 100, 200, 300] {
+            let mut hasher = SpookyV::<V2>::new();
+            hasher.write(&buf[..split]);
+            let snapshot = hasher.checkpoint();
+            hasher.write(&[0xaa; 250]);
+            hasher.restore(snapshot);
+            hasher.write(&buf[split..]);
+            let hash: u128 = SpookyBuildHasherDefault::default().hash_one(RawBytes(&buf));
+            assert_eq!(
+                Hasher::<u128>::finish(&hasher),
+                hash,
+                "wrong value at {split}"
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let buf: [u8; 500] = core::array::from_fn(|i| (i * 3 + 7) as u8);
+        let hash: u128 = SpookyBuildHasher::with_seed(1, 2).hash_one(RawBytes(&buf));
+        for split in [0, 10, 100, 200, 300] {
+            let mut hasher = Spooky::with_seed(1, 2);
+            hasher.write(&buf[..split]);
+            let json = serde_json::to_string(&hasher).unwrap();
+            let mut hasher: Spooky = serde_json::from_str(&json).unwrap();
+            hasher.write(&buf[split..]);
+            assert_eq!(
+                Hasher::<u128>::finish(&hasher),
+                hash,
+                "wrong value at {split}"
+            );
+        }
+    }
+
+    fn short<V: Version>() {
+        let buf: [u8; 300] = core::array::from_fn(|i| (i * 7 + 3) as u8);
+        for seed in [0, 0x0123456789abcdef_fedcba9876543210] {
+            for i in 0..buf.len() {
+                let data = &buf[..i];
+                let short: u128 =
+                    SpookyShortVBuildHasher::<V>::with_seed_128(seed).hash_one(RawBytes(data));
+                if i < SC_BUF_SIZE {
+                    let spooky: u128 =
+                        SpookyVBuildHasher::<V>::with_seed_128(seed).hash_one(RawBytes(data));
+                    assert_eq!(short, spooky, "wrong value at {i}");
+                }
+                for split in [1, 13, 32, 45] {
+                    let mut hasher = SpookyShortV::<V>::with_seed_128(seed);
+                    for chunk in data.chunks(split) {
+                        hasher.write(chunk);
+                    }
+                    assert_eq!(Hasher::<u128>::finish(&hasher), short, "wrong value at {i}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn short_v1() {
+        short::<V1>();
+    }
+
+    #[test]
+    fn short_v2() {
+        short::<V2>();
+    }
 }
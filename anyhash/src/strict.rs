@@ -0,0 +1,218 @@
+use core::fmt::{self, Debug};
+
+use crate::{impl_core_hasher, Hasher, HasherWrite};
+
+macro_rules! impl_strict_writes {
+    ($($t:ty: $fn:ident),* $(,)?) => { $(
+        #[inline]
+        fn $fn(&mut self, i: $t) {
+            self.fixed();
+            self.hasher.$fn(i);
+        }
+    )* };
+}
+
+/// Wrapper for hashers that panics if the data written to it is ambiguous.
+///
+/// This is meant for testing [`Hash`](crate::Hash) implementations. A hash is only
+/// collision free if the data written to the hasher is prefix free, i.e. if no sequence of
+/// writes can be split up in a different way that results in the same bytes. `StrictHasher`
+/// tracks the structure of the writes and panics if it detects a pattern that breaks this:
+///
+/// - Two adjacent calls to [`HasherWrite::write`] where the first isn't framed. This is what
+///   happens when two variable-length byte slices are written without a length prefix, so
+///   `("ab", "c")` and `("a", "bc")` would hash the same.
+/// - A call to `write` is framed if it's immediately preceded by a call to
+///   [`HasherWrite::write_length_prefix`] or `write_usize` with the length of the written
+///   bytes, or if it's immediately followed by `write_u8(0xff)`, which is how strs are
+///   terminated.
+///
+/// All writes are forwarded to the wrapped hasher, so the hash is the same as without the
+/// wrapper.
+///
+/// ```should_panic
+/// # use anyhash::{Hash, HasherWrite, StrictHasher};
+/// struct Pair<'a>(&'a [u8], &'a [u8]);
+///
+/// impl Hash for Pair<'_> {
+///     fn hash<H: HasherWrite>(&self, state: &mut H) {
+///         // bug: missing length prefixes
+///         state.write(self.0);
+///         state.write(self.1);
+///     }
+/// }
+///
+/// # struct Null;
+/// # impl HasherWrite for Null {
+/// #     fn write(&mut self, _: &[u8]) {}
+/// # }
+/// Pair(b"ab", b"c").hash(&mut StrictHasher::new(Null)); // panics
+/// ```
+pub struct StrictHasher<H> {
+    hasher: H,
+    expected_len: Option<usize>,
+    unframed: bool,
+}
+
+impl_core_hasher!(impl<H> StrictHasher<H>);
+
+impl<H> StrictHasher<H> {
+    /// Create a new `StrictHasher` wrapping `hasher`.
+    #[inline]
+    pub const fn new(hasher: H) -> Self {
+        Self {
+            hasher,
+            expected_len: None,
+            unframed: false,
+        }
+    }
+
+    /// Get a reference to the wrapped hasher.
+    #[inline]
+    pub const fn get_ref(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Unwrap the wrapped hasher.
+    #[inline]
+    pub fn into_inner(self) -> H {
+        self.hasher
+    }
+
+    #[inline]
+    fn fixed(&mut self) {
+        self.expected_len = None;
+        self.unframed = false;
+    }
+}
+
+impl<T, H: Hasher<T>> Hasher<T> for StrictHasher<H> {
+    #[inline]
+    fn finish(&self) -> T {
+        self.hasher.finish()
+    }
+
+    #[inline]
+    fn finish_once(self) -> T {
+        self.hasher.finish_once()
+    }
+}
+
+impl<H: HasherWrite> HasherWrite for StrictHasher<H> {
+    #[track_caller]
+    fn write(&mut self, bytes: &[u8]) {
+        let framed = self.expected_len.take() == Some(bytes.len());
+        if self.unframed && !framed {
+            panic!(
+                "ambiguous write to `StrictHasher`: adjacent variable-length writes without \
+                 a length prefix or terminator"
+            );
+        }
+        self.unframed = !framed;
+        self.hasher.write(bytes);
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        // 0xff after an unframed write terminates it like `write_str` does
+        self.fixed();
+        self.hasher.write_u8(i);
+    }
+
+    impl_strict_writes! {
+        u16: write_u16,
+        u32: write_u32,
+        u64: write_u64,
+        u128: write_u128,
+        i8: write_i8,
+        i16: write_i16,
+        i32: write_i32,
+        i64: write_i64,
+        i128: write_i128,
+        isize: write_isize,
+        f32: write_f32,
+        f64: write_f64,
+        &str: write_str,
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        // `core::hash::Hasher::write_length_prefix` is a `write_usize` on stable
+        self.fixed();
+        self.hasher.write_usize(i);
+        self.expected_len = Some(i);
+    }
+
+    #[inline]
+    fn write_length_prefix(&mut self, len: usize) {
+        self.fixed();
+        self.hasher.write_length_prefix(len);
+        self.expected_len = Some(len);
+    }
+}
+
+impl<H: Debug> Debug for StrictHasher<H> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("StrictHasher").field(&self.hasher).finish()
+    }
+}
+
+impl<H: Clone> Clone for StrictHasher<H> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            hasher: self.hasher.clone(),
+            expected_len: self.expected_len,
+            unframed: self.unframed,
+        }
+    }
+}
+
+impl<H: Default> Default for StrictHasher<H> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(H::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{impl_hash, tests::RawBytes, Hash};
+
+    #[derive(Default)]
+    struct Null;
+
+    impl HasherWrite for Null {
+        fn write(&mut self, _: &[u8]) {}
+    }
+
+    struct Unframed<'a>(&'a [u8]);
+
+    impl Hash for Unframed<'_> {
+        fn hash<H: HasherWrite>(&self, state: &mut H) {
+            state.write(self.0);
+        }
+    }
+
+    #[derive(core::hash::Hash)]
+    struct CoreHash<'a>(&'a [u8], &'a str);
+
+    impl_hash!(CoreHash<'_>);
+
+    #[test]
+    fn framed() {
+        let mut h = StrictHasher::new(Null);
+        ("ab", "c", [1_u8, 2], RawBytes(b"xyz")).hash(&mut h);
+        CoreHash(b"ab", "c").hash(&mut h);
+        CoreHash(b"a", "bc").hash(&mut h);
+        Unframed(b"tail").hash(&mut h);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unframed() {
+        (Unframed(b"ab"), Unframed(b"c")).hash(&mut StrictHasher::new(Null));
+    }
+}
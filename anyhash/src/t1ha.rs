@@ -0,0 +1,699 @@
+//! Hasher and collections using the t1ha2 ("Fast Positive Hash") algorithm.
+
+// based on t1ha2 from the reference implementation at https://github.com/erthink/t1ha
+
+use crate::{
+    impl_core_build_hasher, impl_core_hasher, BuildHasher, BuildHasherDefault,
+    EndianIndependentAlgorithm, Hasher, HasherWrite, SeedableBuildHasher,
+};
+
+impl_core_build_hasher!(T1ha2BuildHasher);
+impl_core_hasher!(T1ha2);
+
+const PRIME_0: u64 = 0xec99bf0d8372caab;
+const PRIME_1: u64 = 0x82434fe90edcef39;
+const PRIME_2: u64 = 0xd4f06db99d67be4b;
+const PRIME_3: u64 = 0xbd9cacc22c6e9571;
+const PRIME_4: u64 = 0x9c06faf4d023e3ab;
+const PRIME_5: u64 = 0xc060724a8424f345;
+const PRIME_6: u64 = 0xcb5af53ae3aaac31;
+
+#[inline(always)]
+fn r8(bytes: &[u8], i: usize) -> u64 {
+    u64::from_le_bytes(bytes[i..i + 8].try_into().unwrap())
+}
+
+/// Read 1 to 8 bytes, padded with zeroes.
+#[inline(always)]
+fn tail64(bytes: &[u8]) -> u64 {
+    let mut buf = [0; 8];
+    buf[..bytes.len()].copy_from_slice(bytes);
+    u64::from_le_bytes(buf)
+}
+
+#[inline(always)]
+const fn mul_64x64_128(a: u64, b: u64) -> (u64, u64) {
+    let r = a as u128 * b as u128;
+    (r as u64, (r >> 64) as u64)
+}
+
+#[inline(always)]
+const fn mux64(v: u64, prime: u64) -> u64 {
+    let (l, h) = mul_64x64_128(v, prime);
+    l ^ h
+}
+
+#[inline(always)]
+fn mixup64(a: &mut u64, b: &mut u64, v: u64, prime: u64) {
+    let (l, h) = mul_64x64_128(b.wrapping_add(v), prime);
+    *a ^= l;
+    *b = b.wrapping_add(h);
+}
+
+#[inline(always)]
+const fn final64(a: u64, b: u64) -> u64 {
+    let x = a.wrapping_add(b.rotate_right(41)).wrapping_mul(PRIME_0);
+    let y = a.rotate_right(23).wrapping_add(b).wrapping_mul(PRIME_6);
+    mux64(x ^ y, PRIME_5)
+}
+
+#[derive(Clone)]
+struct State {
+    a: u64,
+    b: u64,
+    c: u64,
+    d: u64,
+}
+
+impl State {
+    #[inline(always)]
+    const fn new(x: u64, y: u64) -> Self {
+        Self {
+            a: x,
+            b: y,
+            c: y.rotate_right(23).wrapping_add(!x),
+            d: (!y).wrapping_add(x.rotate_right(19)),
+        }
+    }
+
+    #[inline(always)]
+    fn update(&mut self, block: &[u8]) {
+        let w0 = r8(block, 0);
+        let w1 = r8(block, 8);
+        let w2 = r8(block, 16);
+        let w3 = r8(block, 24);
+
+        let d02 = w0.wrapping_add(w2.wrapping_add(self.d).rotate_right(56));
+        let c13 = w1.wrapping_add(w3.wrapping_add(self.c).rotate_right(19));
+        self.d ^= self.b.wrapping_add(w1.rotate_right(38));
+        self.c ^= self.a.wrapping_add(w0.rotate_right(57));
+        self.b ^= PRIME_6.wrapping_mul(c13.wrapping_add(w2));
+        self.a ^= PRIME_5.wrapping_mul(d02.wrapping_add(w3));
+    }
+
+    #[inline(always)]
+    fn squash(&mut self) {
+        self.a ^= PRIME_6.wrapping_mul(self.c.wrapping_add(self.d.rotate_right(23)));
+        self.b ^= PRIME_5.wrapping_mul(self.c.rotate_right(19).wrapping_add(self.d));
+    }
+
+    /// Finish with up to 32 bytes of remaining data for the 64-bit hash.
+    #[inline(always)]
+    fn tail_ab(mut self, mut v: &[u8]) -> u64 {
+        let len = v.len();
+        if len > 24 {
+            mixup64(&mut self.a, &mut self.b, r8(v, 0), PRIME_4);
+            v = &v[8..];
+        }
+        if len > 16 {
+            mixup64(&mut self.b, &mut self.a, r8(v, 0), PRIME_3);
+            v = &v[8..];
+        }
+        if len > 8 {
+            mixup64(&mut self.a, &mut self.b, r8(v, 0), PRIME_2);
+            v = &v[8..];
+        }
+        if len > 0 {
+            mixup64(&mut self.b, &mut self.a, tail64(v), PRIME_1);
+        }
+        final64(self.a, self.b)
+    }
+
+    /// Finish with up to 32 bytes of remaining data for the 128-bit hash.
+    #[inline(always)]
+    fn tail_abcd(mut self, mut v: &[u8]) -> u128 {
+        let len = v.len();
+        if len > 24 {
+            mixup64(&mut self.a, &mut self.d, r8(v, 0), PRIME_4);
+            v = &v[8..];
+        }
+        if len > 16 {
+            mixup64(&mut self.b, &mut self.a, r8(v, 0), PRIME_3);
+            v = &v[8..];
+        }
+        if len > 8 {
+            mixup64(&mut self.c, &mut self.b, r8(v, 0), PRIME_2);
+            v = &v[8..];
+        }
+        if len > 0 {
+            mixup64(&mut self.d, &mut self.c, tail64(v), PRIME_1);
+        }
+
+        let Self {
+            mut a,
+            mut b,
+            mut c,
+            mut d,
+        } = self;
+        mixup64(&mut a, &mut b, c.rotate_right(41) ^ d, PRIME_0);
+        mixup64(&mut b, &mut c, d.rotate_right(23) ^ a, PRIME_6);
+        mixup64(&mut c, &mut d, a.rotate_right(19) ^ b, PRIME_5);
+        mixup64(&mut d, &mut a, b.rotate_right(31) ^ c, PRIME_4);
+        (a ^ b) as u128 | (c.wrapping_add(d) as u128) << 64
+    }
+}
+
+/// Calculate the 64-bit t1ha2 hash of `bytes` in a single pass.
+///
+/// This is compatible with `t1ha2_atonce` from the reference implementation. It gives a
+/// different result than hashing the same bytes with [`T1ha2`].
+#[inline]
+pub fn t1ha2_atonce(bytes: &[u8], seed: u64) -> u64 {
+    let mut state = State::new(seed, bytes.len() as u64);
+    let mut v = bytes;
+    if v.len() > 32 {
+        while v.len() >= 32 {
+            state.update(&v[..32]);
+            v = &v[32..];
+        }
+        state.squash();
+    }
+    state.tail_ab(v)
+}
+
+/// Calculate the 128-bit t1ha2 hash of `bytes` in a single pass.
+///
+/// This is compatible with `t1ha2_atonce128` from the reference implementation, with the
+/// extra result in the upper 64 bits. It gives a different result than hashing the same
+/// bytes with [`T1ha2`].
+#[inline]
+pub fn t1ha2_atonce128(bytes: &[u8], seed: u64) -> u128 {
+    let mut state = State::new(seed, bytes.len() as u64);
+    let mut v = bytes;
+    if v.len() > 32 {
+        while v.len() >= 32 {
+            state.update(&v[..32]);
+            v = &v[32..];
+        }
+    }
+    state.tail_abcd(v)
+}
+
+/// [`BuildHasher`] implementation for the [`T1ha2`] hasher.
+/// If you don't need support for using custom seeds, use the zero sized
+/// [`T1ha2BuildHasherDefault`] instead.
+#[derive(Clone, Debug)]
+pub struct T1ha2BuildHasher([u64; 2]);
+
+impl T1ha2BuildHasher {
+    /// Create a [`BuildHasher`] for [`T1ha2`] using the default seeds (zero).
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seeds(0, 0)
+    }
+
+    /// Create a [`BuildHasher`] for [`T1ha2`] with a custom seed.
+    /// This is the same as `with_seeds(seed, 0)`.
+    #[inline]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self::with_seeds(seed, 0)
+    }
+
+    /// Create a [`BuildHasher`] for [`T1ha2`] with two custom seeds.
+    #[inline]
+    pub const fn with_seeds(seed_x: u64, seed_y: u64) -> Self {
+        Self([seed_x, seed_y])
+    }
+}
+
+impl BuildHasher<u64> for T1ha2BuildHasher {
+    type Hasher = T1ha2;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seeds(self.0[0], self.0[1])
+    }
+}
+
+impl BuildHasher<u128> for T1ha2BuildHasher {
+    type Hasher = T1ha2;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seeds(self.0[0], self.0[1])
+    }
+}
+
+impl SeedableBuildHasher for T1ha2BuildHasher {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seeds(seed as u64, (seed >> 64) as u64)
+    }
+}
+
+impl Default for T1ha2BuildHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for T1ha2BuildHasher {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for T1ha2BuildHasher {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for T1ha2BuildHasher {}
+
+/// [`BuildHasher`] implementation for the [`T1ha2`] hasher using the default seed (zero sized).
+pub type T1ha2BuildHasherDefault = BuildHasherDefault<T1ha2>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`T1ha2`] hasher.
+pub type T1ha2HashMap<K, V> = std::collections::HashMap<K, V, T1ha2BuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`T1ha2`] hasher with the default seed.
+pub type T1ha2HashMapDefault<K, V> = std::collections::HashMap<K, V, T1ha2BuildHasherDefault>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`T1ha2`] hasher.
+pub type T1ha2HashSet<T> = std::collections::HashSet<T, T1ha2BuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`T1ha2`] hasher with the default seed.
+pub type T1ha2HashSetDefault<T> = std::collections::HashSet<T, T1ha2BuildHasherDefault>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`T1ha2`] hasher.
+pub type T1ha2HeaplessIndexMap<K, V, const N: usize> =
+    heapless::IndexMap<K, V, T1ha2BuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`T1ha2`] hasher with the default seed.
+pub type T1ha2HeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, T1ha2BuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`T1ha2`] hasher.
+pub type T1ha2HeaplessIndexSet<T, const N: usize> = heapless::IndexSet<T, T1ha2BuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`T1ha2`] hasher with the default seed.
+pub type T1ha2HeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, T1ha2BuildHasherDefault, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`T1ha2`] hasher.
+pub type T1ha2HashBrownMap<K, V> = hashbrown::HashMap<K, V, T1ha2BuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`T1ha2`] hasher with the default seed.
+pub type T1ha2HashBrownMapDefault<K, V> = hashbrown::HashMap<K, V, T1ha2BuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`T1ha2`] hasher.
+pub type T1ha2HashBrownSet<T> = hashbrown::HashSet<T, T1ha2BuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`T1ha2`] hasher with the default seed.
+pub type T1ha2HashBrownSetDefault<T> = hashbrown::HashSet<T, T1ha2BuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`T1ha2`] hasher.
+pub type T1ha2IndexMap<K, V> = indexmap::IndexMap<K, V, T1ha2BuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`T1ha2`] hasher with the default seed.
+pub type T1ha2IndexMapDefault<K, V> = indexmap::IndexMap<K, V, T1ha2BuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`T1ha2`] hasher.
+pub type T1ha2IndexSet<T> = indexmap::IndexSet<T, T1ha2BuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`T1ha2`] hasher with the default seed.
+pub type T1ha2IndexSetDefault<T> = indexmap::IndexSet<T, T1ha2BuildHasherDefault>;
+
+/// Hasher using the streaming t1ha2 algorithm, with 64-bit and 128-bit output.
+///
+/// This is compatible with `t1ha2_init`, `t1ha2_update` and `t1ha2_final` from the reference
+/// implementation. The streaming variant pads the data with its length, so it gives different
+/// results than [`t1ha2_atonce`] and [`t1ha2_atonce128`]. The 128-bit hash has the extra
+/// result in the upper 64 bits.
+#[derive(Clone)]
+pub struct T1ha2 {
+    state: State,
+    buffer: [u8; 32],
+    buffer_len: usize,
+    total: u64,
+}
+
+impl T1ha2 {
+    /// Create a new `T1ha2` hasher using the default seeds (zero).
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seeds(0, 0)
+    }
+
+    /// Create a new `T1ha2` hasher with a custom seed.
+    /// This is the same as `with_seeds(seed, 0)`.
+    #[inline]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self::with_seeds(seed, 0)
+    }
+
+    /// Create a new `T1ha2` hasher with two custom seeds.
+    #[inline]
+    pub const fn with_seeds(seed_x: u64, seed_y: u64) -> Self {
+        Self {
+            state: State::new(seed_x, seed_y),
+            buffer: [0; 32],
+            buffer_len: 0,
+            total: 0,
+        }
+    }
+
+    fn finish_state(&self) -> Self {
+        let mut hasher = self.clone();
+        let bits = (self.total << 3) ^ (1 << 63);
+        hasher.write(&bits.to_le_bytes());
+        hasher
+    }
+}
+
+impl EndianIndependentAlgorithm for T1ha2 {}
+
+impl Default for T1ha2 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for T1ha2 {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.state.a.zeroize();
+        self.state.b.zeroize();
+        self.state.c.zeroize();
+        self.state.d.zeroize();
+        self.buffer.zeroize();
+        self.buffer_len.zeroize();
+        self.total.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for T1ha2 {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for T1ha2 {}
+
+impl HasherWrite for T1ha2 {
+    fn write(&mut self, mut bytes: &[u8]) {
+        self.total = self.total.wrapping_add(bytes.len() as u64);
+
+        if self.buffer_len != 0 {
+            let n = (32 - self.buffer_len).min(bytes.len());
+            self.buffer[self.buffer_len..self.buffer_len + n].copy_from_slice(&bytes[..n]);
+            self.buffer_len += n;
+            if self.buffer_len < 32 {
+                return;
+            }
+            self.buffer_len = 0;
+            bytes = &bytes[n..];
+            self.state.update(&self.buffer);
+        }
+
+        while bytes.len() >= 32 {
+            self.state.update(&bytes[..32]);
+            bytes = &bytes[32..];
+        }
+
+        self.buffer[..bytes.len()].copy_from_slice(bytes);
+        self.buffer_len = bytes.len();
+    }
+}
+
+impl Hasher<u64> for T1ha2 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        let hasher = self.finish_state();
+        let mut state = hasher.state.clone();
+        state.squash();
+        state.tail_ab(&hasher.buffer[..hasher.buffer_len])
+    }
+}
+
+impl Hasher<u128> for T1ha2 {
+    #[inline]
+    fn finish(&self) -> u128 {
+        let hasher = self.finish_state();
+        hasher
+            .state
+            .clone()
+            .tail_abcd(&hasher.buffer[..hasher.buffer_len])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Hash;
+
+    fn default_seed<T: Hash>(x: T) -> u64 {
+        T1ha2BuildHasherDefault::new().hash_one(x)
+    }
+
+    fn default_seed_128<T: Hash>(x: T) -> u128 {
+        T1ha2BuildHasherDefault::new().hash_one(x)
+    }
+
+    fn data() -> [u8; 300] {
+        let mut data = [0; 300];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = (i * 7 + 1) as u8;
+        }
+        data
+    }
+
+    fn stream<T>(bytes: &[u8], seed: u64) -> T
+    where
+        T1ha2: Hasher<T>,
+    {
+        let mut hasher = T1ha2::with_seeds(seed, seed);
+        hasher.write(bytes);
+        hasher.finish()
+    }
+
+    // the first test vectors from the self check of the reference implementation
+    #[test]
+    fn reference() {
+        const PATTERN: [u8; 64] =
+            *b"\x00\x01\x02\x03\x04\x05\x06\x07\xff\x7f\x3f\x1f\x0f\x08\x10\x20\
+            \x40\x80\xfe\xfc\xf8\xf0\xe0\xc0\xfd\xfb\xf7\xef\xdf\xbf\x55\xaa\
+            \x0b\x11\x13\x17\x1d\x25\x2a\x2babcdefghijklmnopqrstuvwx";
+
+        assert_eq!(t1ha2_atonce(&[], 0), 0);
+        assert_eq!(t1ha2_atonce(&[], !0), 0x772c7311be32ff42);
+        assert_eq!(t1ha2_atonce(&PATTERN, 0), 0x444753d23f207e03);
+        assert_eq!(t1ha2_atonce(&PATTERN[..1], 1), 0x71f6df5da3b4f532);
+        assert_eq!(t1ha2_atonce(&PATTERN[..2], 2), 0x555859635365f660);
+
+        assert_eq!(t1ha2_atonce128(&[], 0) as u64, 0x4ec7f6a48e33b00a);
+        assert_eq!(t1ha2_atonce128(&[], !0) as u64, 0xb7b7faa5bd7d8c1e);
+
+        assert_eq!(stream::<u64>(&[], 0), 0x3c8426e33cb41606);
+        assert_eq!(stream::<u64>(&[], !0), 0xfd74be70ee73e617);
+
+        assert_eq!(stream::<u128>(&[], 0) as u64, 0xcd2801d3b92237d6);
+        assert_eq!(stream::<u128>(&[], !0) as u64, 0x10e4d47bd821546d);
+    }
+
+    #[test]
+    fn lengths() {
+        let data = data();
+        for (len, atonce, atonce128, stream, stream128) in [
+            (
+                0,
+                0x788f072c5f62357f,
+                0xcaec1289c9519eb9c46e6997d626a2b1,
+                0x18d37feb5386b47c,
+                0xf858db58d79ca4ad7d80a01e237ef0de,
+            ),
+            (
+                1,
+                0xa1eef121e110b5f6,
+                0x6f65afe6531c33e629ae5bd848230d70,
+                0xa77c902287ff2c66,
+                0xdac7cc2d78ff2e6d209995213a9b44b1,
+            ),
+            (
+                8,
+                0x56816b354c40f0c3,
+                0x732d584d338951b71bbfec1b0e552b7a,
+                0x63c8c4b52639d92b,
+                0xd164dd1560021dfa281e4ec939900c40,
+            ),
+            (
+                24,
+                0x044196a5cf0e76b0,
+                0xdccfef28e3d1b0089aa3770f4e513ddd,
+                0xd4eb5411a3f68dcb,
+                0xcb27fd03128bba24bf6077855b683114,
+            ),
+            (
+                31,
+                0xccb37af9d4c46452,
+                0x81ffbfedd0aed11bac99127016fd1a61,
+                0x735f9572d18e2208,
+                0xdc18f4161a4e5a29c80f7fc1578fb08f,
+            ),
+            (
+                32,
+                0x6898fdc034c0f8ff,
+                0xe6771db34b1320c523c6e6b2a0cde103,
+                0xc1ad719182d80985,
+                0xeb127f74527fa938a6af19b0ee73d724,
+            ),
+            (
+                33,
+                0x48279cd9a9bcbcae,
+                0xc7a3039926f1952e650e995981614b6e,
+                0xbec23cbdb459e2e8,
+                0x8a2612ba107cf0e0a2ad17d15715c977,
+            ),
+            (
+                64,
+                0x7d86d8eda899e0ca,
+                0x7bbd90ee8f5d0cdb88886ee01f787853,
+                0x2b27c768becbce3e,
+                0x29bc0ad2d9af2660244d6fbb590e9a83,
+            ),
+            (
+                100,
+                0x7f41393135d4b79d,
+                0x968aecb6d958f5e31760623093d8a184,
+                0x76b29e4389c7f1af,
+                0xf4e0d9b1e7775d51b4b5411de8cdd877,
+            ),
+            (
+                300,
+                0xf940990d2799f021,
+                0xd05359a966c495caf323cb917f62d4f0,
+                0xd88fc4382b820600,
+                0x938ecd321cf072574eb81cf0ff4889b9,
+            ),
+        ] {
+            let data = &data[..len];
+            assert_eq!(t1ha2_atonce(data, 0x1234), atonce, "len {len}");
+            assert_eq!(t1ha2_atonce128(data, 0x1234), atonce128, "len {len}");
+            let mut hasher = T1ha2::with_seeds(0x1234, 0x5678);
+            hasher.write(data);
+            assert_eq!(Hasher::<u64>::finish(&hasher), stream, "len {len}");
+            assert_eq!(Hasher::<u128>::finish(&hasher), stream128, "len {len}");
+        }
+    }
+
+    #[test]
+    fn streaming() {
+        let data = data();
+        let mut expected = T1ha2::new();
+        expected.write(&data);
+        let expected: u128 = expected.finish();
+        for split in [1, 7, 31, 32, 33, 100] {
+            let mut hasher = T1ha2::new();
+            for chunk in data.chunks(split) {
+                hasher.write(chunk);
+            }
+            assert_eq!(Hasher::<u128>::finish(&hasher), expected, "split {split}");
+        }
+    }
+
+    hash_test_vectors! {
+        [default_seed]
+        a: 0x6a4720e834efec69,
+        ab: 0xe60d5d3dad7e8390,
+        abc: 0xd03ef8122bc05edd,
+        abcd: 0x81154682f7a665d,
+        abcde: 0x4c282b44a51eb961,
+        abcdef: 0x368d021f6c817225,
+        abcdefg: 0x19c0b83b1641e5b8,
+        abcdefgh: 0xf7d5e06fd2c7f7e0,
+        abcdefghi: 0xe0b3f203011d09cd,
+        abcdefghij: 0x7a2b59194cb01bf8,
+        abcdefghijk: 0x7915d7d6c1ba54fd,
+        abcdefghijkl: 0xe651c063c086d4a2,
+        abcdefghijklm: 0xeddf874a9ec1c7a1,
+        abcdefghijklmn: 0xe1dc01c54bbed9b1,
+        abcdefghijklmno: 0xd3456552298ca892,
+        abcdefghijklmnop: 0xa9b64546e62d4e15,
+        abcdefghijklmnopq: 0xca5991be2d9dfdf3,
+        abcdefghijklmnopqr: 0x865da9d65621ae0e,
+        abcdefghijklmnopqrs: 0x713f63c500b88d16,
+        abcdefghijklmnopqrst: 0x1810644c320785c8,
+        abcdefghijklmnopqrstu: 0x9b49bd0f18026e94,
+        abcdefghijklmnopqrstuv: 0xa376f205b05e58d4,
+        abcdefghijklmnopqrstuvw: 0xf2c5203dc68c54eb,
+        abcdefghijklmnopqrstuvwx: 0xf70991827e86639e,
+        abcdefghijklmnopqrstuvwxy: 0x5b65631b041afb4a,
+        abcdefghijklmnopqrstuvwxyz: 0xc53e01ed4e5bd797,
+        abcdefghijklmnopqrstuvwxyz0: 0x681ab22d207d52bc,
+        abcdefghijklmnopqrstuvwxyz01: 0xba659564fee3ee3f,
+        abcdefghijklmnopqrstuvwxyz012: 0x6ff64c74bb125576,
+        abcdefghijklmnopqrstuvwxyz0123: 0x65c4aa92cebe35c2,
+        abcdefghijklmnopqrstuvwxyz01234: 0x7179e1a7a17aa020,
+        abcdefghijklmnopqrstuvwxyz012345: 0x3eac43dabd32e2a9,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x2d7d212fa40a2fb,
+        abcdefghijklmnopqrstuvwxyz01234567: 0xc597ee17934cde12,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x627b70ae0df11d1b,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x66f1fd7d5946e796,
+
+        [default_seed_128]
+        a: 0xbcb35e7aa9b2f679a8b1e8c345a84fe1,
+        ab: 0x1923ebe9f7aa865056d4ea1ddc33c116,
+        abc: 0x89968569442c760aa217e53c902c29f3,
+        abcd: 0xc9e834691e3e78bc44b050bb7e6382b,
+        abcde: 0xe6fd6c1512a0d596096c9440071c7902,
+        abcdef: 0x2e021cb89ea704bdb8c6709ab3f85e35,
+        abcdefg: 0xd76340d34fd156a7d28c02ce4359d421,
+        abcdefgh: 0x978747e0c4ecb1c4147c8caa25877d87,
+        abcdefghi: 0xa5f360f2df72b16b06190c783bddab88,
+        abcdefghij: 0x3402c9ed743522b3a9d1246145b3b700,
+        abcdefghijk: 0x38a63dfe93044d26da3f82307db44aaf,
+        abcdefghijkl: 0x601ce6ff164c3a01888df44eaa542318,
+        abcdefghijklm: 0xd64c83f6d6e5c7a95ce6b7317768612c,
+        abcdefghijklmn: 0x373cdf1a7b9041b2ff2ec9df4aa5b652,
+        abcdefghijklmno: 0x7f16688a01020a6becce0166e0e6f797,
+        abcdefghijklmnop: 0x7da66831443882300362460456bb8294,
+        abcdefghijklmnopq: 0x771748898b77b38eff5a970fc2c591e8,
+        abcdefghijklmnopqr: 0x6d664798e8602bc5c057ce9c80aef70b,
+        abcdefghijklmnopqrs: 0x745f4f2765faaa794029335bf7a6955d,
+        abcdefghijklmnopqrst: 0x11fc9043637aa364fc2524de1d42f18,
+        abcdefghijklmnopqrstu: 0xbce0ecc2a62976cc6e44a4b3c767652c,
+        abcdefghijklmnopqrstuv: 0x8f0fcd01269ed6cd83afdc94d9d35c8c,
+        abcdefghijklmnopqrstuvw: 0x2548dd241df5ab1152fcf1eba53874e3,
+        abcdefghijklmnopqrstuvwx: 0x67c577074c484c389b8958a9926e4d60,
+        abcdefghijklmnopqrstuvwxy: 0x68f8071a1feca11bdd118bca54df06b5,
+        abcdefghijklmnopqrstuvwxyz: 0x9d28c81c39e4f6226ba8f37541cac961,
+        abcdefghijklmnopqrstuvwxyz0: 0xb669e834e51332cae18b465e9bc383f8,
+        abcdefghijklmnopqrstuvwxyz01: 0xb016d2303bb6520558c84a84c566c5a8,
+        abcdefghijklmnopqrstuvwxyz012: 0xe372f6ca7c357c9c38ff58c6a07e6cac,
+        abcdefghijklmnopqrstuvwxyz0123: 0xb6b9dd02df5a7d3e53c4890ee5ab9276,
+        abcdefghijklmnopqrstuvwxyz01234: 0x53d877fedf39a9e0ab2e6140f9d78cc2,
+        abcdefghijklmnopqrstuvwxyz012345: 0xa3a51dbd7c6576ffe0748a5e77b2fc01,
+        abcdefghijklmnopqrstuvwxyz0123456: 0xef409eb8db245166c5658a37b4d8f5ea,
+        abcdefghijklmnopqrstuvwxyz01234567: 0x245371be1d7358cf86fe194eeb14e924,
+        abcdefghijklmnopqrstuvwxyz012345678: 0xf942c02b085dc14c5a9f8a5c3a573c95,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0xeb6d8b6e66a20e09d26bebc92a18529c,
+    }
+}
@@ -0,0 +1,331 @@
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display, LowerHex, UpperHex},
+    marker::PhantomData,
+};
+
+use crate::{BuildHasher, Hash, Hasher, HasherWrite};
+
+/// Hash value tagged with the algorithm that made it.
+///
+/// The tag `A` is a marker type that identifies the algorithm, and optionally the seed, that
+/// calculated the hash. Hash values with different tags have different types, so they can't be
+/// compared with each other by mistake. [`TypedBuildHasher`] makes hash values of this type.
+///
+/// `Display` formats the hash as zero padded lowercase hexadecimal.
+#[repr(transparent)]
+pub struct HashValue<T, A> {
+    value: T,
+    _pd: PhantomData<fn() -> A>,
+}
+
+impl<T, A> HashValue<T, A> {
+    /// Tag the hash `value` with the algorithm `A`.
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Get a reference to the hash value.
+    #[inline]
+    pub const fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Unwrap the hash value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: Clone, A> Clone for HashValue<T, A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<T: Copy, A> Copy for HashValue<T, A> {}
+
+impl<T: PartialEq, A> PartialEq for HashValue<T, A> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq, A> Eq for HashValue<T, A> {}
+
+impl<T: PartialOrd, A> PartialOrd for HashValue<T, A> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Ord, A> Ord for HashValue<T, A> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T: Hash, A> Hash for HashValue<T, A> {
+    #[inline]
+    fn hash<H: HasherWrite>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<T: core::hash::Hash, A> core::hash::Hash for HashValue<T, A> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<T: Debug, A> Debug for HashValue<T, A> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("HashValue").field(&self.value).finish()
+    }
+}
+
+impl<T: LowerHex, A> Display for HashValue<T, A> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = core::mem::size_of::<T>() * 2;
+        write!(f, "{:0width$x}", self.value)
+    }
+}
+
+impl<T: LowerHex, A> LowerHex for HashValue<T, A> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        LowerHex::fmt(&self.value, f)
+    }
+}
+
+impl<T: UpperHex, A> UpperHex for HashValue<T, A> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        UpperHex::fmt(&self.value, f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, A> serde::Serialize for HashValue<T, A> {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, A> serde::Deserialize<'de> for HashValue<T, A> {
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+macro_rules! impl_typed_writes {
+    ($($t:ty: $fn:ident),* $(,)?) => { $(
+        #[inline]
+        fn $fn(&mut self, i: $t) {
+            self.hasher.$fn(i);
+        }
+    )* };
+}
+
+/// Hasher made by [`TypedBuildHasher`] that finishes to a [`HashValue`].
+pub struct TypedHasher<H, A> {
+    hasher: H,
+    _pd: PhantomData<fn() -> A>,
+}
+
+impl<H, A> TypedHasher<H, A> {
+    /// Create a new `TypedHasher` wrapping `hasher`.
+    #[inline]
+    pub const fn new(hasher: H) -> Self {
+        Self {
+            hasher,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Get a reference to the wrapped hasher.
+    #[inline]
+    pub const fn get_ref(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Unwrap the wrapped hasher.
+    #[inline]
+    pub fn into_inner(self) -> H {
+        self.hasher
+    }
+}
+
+impl<T, H: Hasher<T>, A> Hasher<HashValue<T, A>> for TypedHasher<H, A> {
+    #[inline]
+    fn finish(&self) -> HashValue<T, A> {
+        HashValue::new(self.hasher.finish())
+    }
+
+    #[inline]
+    fn finish_once(self) -> HashValue<T, A> {
+        HashValue::new(self.hasher.finish_once())
+    }
+}
+
+impl<H: HasherWrite, A> HasherWrite for TypedHasher<H, A> {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.hasher.write(bytes);
+    }
+
+    impl_typed_writes! {
+        u8: write_u8,
+        u16: write_u16,
+        u32: write_u32,
+        u64: write_u64,
+        u128: write_u128,
+        usize: write_usize,
+        i8: write_i8,
+        i16: write_i16,
+        i32: write_i32,
+        i64: write_i64,
+        i128: write_i128,
+        isize: write_isize,
+        f32: write_f32,
+        f64: write_f64,
+        &str: write_str,
+    }
+
+    #[inline]
+    fn write_length_prefix(&mut self, len: usize) {
+        self.hasher.write_length_prefix(len);
+    }
+}
+
+impl<H: Debug, A> Debug for TypedHasher<H, A> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TypedHasher").field(&self.hasher).finish()
+    }
+}
+
+impl<H: Clone, A> Clone for TypedHasher<H, A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.hasher.clone())
+    }
+}
+
+impl<H: Default, A> Default for TypedHasher<H, A> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(H::default())
+    }
+}
+
+/// `BuildHasher` wrapper that makes hashes of type [`HashValue<T, A>`] instead of `T`.
+///
+/// The tag `A` defaults to the type of the wrapped build hasher. Use a custom marker type to
+/// also tell apart hashes made with different seeds.
+///
+/// ```
+/// # use anyhash::{BuildHasher, BuildHasherDefault, HashValue, Hasher, HasherWrite};
+/// # use anyhash::TypedBuildHasher;
+/// # #[derive(Default)]
+/// # struct MyHasher(u64);
+/// # impl HasherWrite for MyHasher {
+/// #     fn write(&mut self, bytes: &[u8]) {
+/// #         for &b in bytes { self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3); }
+/// #     }
+/// # }
+/// # impl Hasher<u64> for MyHasher {
+/// #     fn finish(&self) -> u64 { self.0 }
+/// # }
+/// struct SessionKeys;
+///
+/// let bh = TypedBuildHasher::<_, SessionKeys>::new(BuildHasherDefault::<MyHasher>::new());
+/// let hash: HashValue<u64, SessionKeys> = bh.hash_one("session");
+/// assert_eq!(hash.to_string().len(), 16);
+/// ```
+pub struct TypedBuildHasher<BH, A = BH> {
+    build_hasher: BH,
+    _pd: PhantomData<fn() -> A>,
+}
+
+impl<BH, A> TypedBuildHasher<BH, A> {
+    /// Create a new `TypedBuildHasher` wrapping `build_hasher`.
+    #[inline]
+    pub const fn new(build_hasher: BH) -> Self {
+        Self {
+            build_hasher,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Get a reference to the wrapped `BuildHasher`.
+    #[inline]
+    pub const fn get_ref(&self) -> &BH {
+        &self.build_hasher
+    }
+}
+
+impl<T, BH: BuildHasher<T>, A> BuildHasher<HashValue<T, A>> for TypedBuildHasher<BH, A> {
+    type Hasher = TypedHasher<BH::Hasher, A>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        TypedHasher::new(self.build_hasher.build_hasher())
+    }
+}
+
+impl<BH: Debug, A> Debug for TypedBuildHasher<BH, A> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TypedBuildHasher")
+            .field(&self.build_hasher)
+            .finish()
+    }
+}
+
+impl<BH: Clone, A> Clone for TypedBuildHasher<BH, A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.build_hasher.clone())
+    }
+}
+
+impl<BH: Default, A> Default for TypedBuildHasher<BH, A> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(BH::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Fnv;
+
+    #[test]
+    fn display() {
+        extern crate std;
+        use std::string::ToString;
+
+        let hash = HashValue::<u32, Fnv>::new(0xabc);
+        assert_eq!(hash.to_string(), "00000abc");
+        assert_eq!(std::format!("{hash:#X}"), "0xABC");
+        assert!(hash < HashValue::new(0xabd));
+    }
+}
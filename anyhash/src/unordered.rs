@@ -0,0 +1,248 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{internal::fmix64, BuildHasher, Hash, HasherWrite};
+#[cfg(feature = "alloc")]
+use crate::{RecordedWrite, RecordingHasher};
+
+/// Combines hashes in a way that doesn't depend on their order.
+///
+/// Each hash is mixed and then accumulated both with wrapping addition and with xor, so equal
+/// hashes don't cancel each other out. The combiner implements [`Hash`], which writes the number
+/// of hashes and the accumulated values, so it can be used to hash unordered collections like
+/// sets and maps. See also [`hash_unordered_with`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnorderedCombiner {
+    sum: u64,
+    xor: u64,
+    len: usize,
+}
+
+impl UnorderedCombiner {
+    /// Create a new, empty `UnorderedCombiner`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            sum: 0,
+            xor: 0,
+            len: 0,
+        }
+    }
+
+    /// Add a hash to the combiner.
+    #[inline]
+    pub fn add(&mut self, hash: u64) {
+        self.sum = self.sum.wrapping_add(fmix64(hash));
+        self.xor ^= fmix64(hash ^ 0x9e3779b97f4a7c15);
+        self.len += 1;
+    }
+
+    /// Get the number of hashes that have been added.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no hashes have been added.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the combined hash of the hashes added so far.
+    #[inline]
+    pub const fn finish(&self) -> u64 {
+        fmix64(self.sum ^ fmix64(self.xor ^ self.len as u64).rotate_left(32))
+    }
+}
+
+impl Hash for UnorderedCombiner {
+    #[inline]
+    fn hash<H: HasherWrite>(&self, state: &mut H) {
+        state.write_length_prefix(self.len);
+        state.write_u64(self.sum);
+        state.write_u64(self.xor);
+    }
+}
+
+impl Extend<u64> for UnorderedCombiner {
+    #[inline]
+    fn extend<I: IntoIterator<Item = u64>>(&mut self, iter: I) {
+        for hash in iter {
+            self.add(hash);
+        }
+    }
+}
+
+impl FromIterator<u64> for UnorderedCombiner {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = u64>>(iter: I) -> Self {
+        let mut combiner = Self::new();
+        combiner.extend(iter);
+        combiner
+    }
+}
+
+/// Hash the items of `iter` into `state` in a way that doesn't depend on the order of the
+/// items.
+///
+/// The write calls each item makes are recorded, and then the number of items and the recorded
+/// writes of each item are written to `state`, with the items sorted by their writes. Everything
+/// is hashed by `state` itself, so this is as strong as the hasher: keyed hashers keep their
+/// HashDoS resistance and wide hashers keep their full output size. This allocates for every
+/// item. [`hash_unordered_with`] doesn't, but it's limited by the hasher used for the items.
+///
+/// ```
+/// # use anyhash::{hash_unordered, Hash, HasherWrite};
+/// struct Tags(Vec<&'static str>);
+///
+/// impl Hash for Tags {
+///     fn hash<H: HasherWrite>(&self, state: &mut H) {
+///         hash_unordered(&self.0, state);
+///     }
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+pub fn hash_unordered<I, H>(iter: I, state: &mut H)
+where
+    I: IntoIterator,
+    I::Item: Hash,
+    H: ?Sized + HasherWrite,
+{
+    let mut items: Vec<Vec<RecordedWrite>> = iter
+        .into_iter()
+        .map(|item| {
+            let mut recorder = RecordingHasher::new(Discard);
+            item.hash(&mut recorder);
+            recorder.into_parts().1
+        })
+        .collect();
+    items.sort_unstable();
+    state.write_length_prefix(items.len());
+    for writes in &items {
+        for write in writes {
+            write.replay(state);
+        }
+    }
+}
+
+/// Hash the items of `iter` into `state` in a way that doesn't depend on the order of the
+/// items, using hashers from `build_hasher` to hash each item.
+///
+/// The `u64` hashes of the items are combined with [`UnorderedCombiner`], and only the combined
+/// value is written to `state`. Because of that, the result can't be stronger than the hasher
+/// for the items and a 64-bit hash, no matter which hasher `state` is. In particular, an unkeyed
+/// `build_hasher` makes it easy to find collisions even if `state` is keyed. Use
+/// `hash_unordered` (with the `alloc` feature) if that matters.
+#[inline]
+pub fn hash_unordered_with<BH, I, H>(build_hasher: &BH, iter: I, state: &mut H)
+where
+    BH: ?Sized + BuildHasher<u64>,
+    I: IntoIterator,
+    I::Item: Hash,
+    H: ?Sized + HasherWrite,
+{
+    let combiner: UnorderedCombiner = iter
+        .into_iter()
+        .map(|item| build_hasher.hash_one(item))
+        .collect();
+    combiner.hash(&mut &mut *state);
+}
+
+// hasher that ignores everything written to it, for recording writes
+#[cfg(feature = "alloc")]
+struct Discard;
+
+#[cfg(feature = "alloc")]
+impl HasherWrite for Discard {
+    #[inline]
+    fn write(&mut self, _: &[u8]) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Hasher;
+
+    #[derive(Default)]
+    struct Fnv(u64);
+
+    impl HasherWrite for Fnv {
+        fn write(&mut self, bytes: &[u8]) {
+            for &b in bytes {
+                self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3);
+            }
+        }
+    }
+
+    impl Hasher<u64> for Fnv {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn hash(items: &[&str]) -> u64 {
+        hash_keyed(0xcbf29ce484222325, items)
+    }
+
+    // `key` is the initial state of the hasher, so it works like a key
+    #[cfg(feature = "alloc")]
+    fn hash_keyed(key: u64, items: &[&str]) -> u64 {
+        let mut hasher = Fnv(key);
+        hash_unordered(items, &mut hasher);
+        hasher.finish()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn unordered() {
+        assert_eq!(hash(&["a", "b", "c"]), hash(&["c", "a", "b"]));
+        assert_ne!(hash(&["a", "b", "c"]), hash(&["a", "b"]));
+        assert_ne!(hash(&["a", "a"]), hash(&["b", "b"]));
+        assert_ne!(hash(&["a", "a"]), hash(&[]));
+        assert_ne!(hash(&["ab", "c"]), hash(&["a", "bc"]));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn writes_go_to_outer_hasher() {
+        let mut hasher = RecordingHasher::new(Fnv::default());
+        hash_unordered([(2_u32, "b"), (1, "a")], &mut hasher);
+        assert_eq!(
+            hasher.records(),
+            [
+                RecordedWrite::LengthPrefix(2),
+                RecordedWrite::WriteU32(1),
+                RecordedWrite::Str("a".into()),
+                RecordedWrite::WriteU32(2),
+                RecordedWrite::Str("b".into()),
+            ]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn depends_on_key() {
+        let (a, b) = (["x", "y", "z"], ["z", "x", "y"]);
+        for key in [1, 2, 3] {
+            assert_eq!(hash_keyed(key, &a), hash_keyed(key, &b));
+        }
+        assert_ne!(hash_keyed(1, &a), hash_keyed(2, &a));
+        assert_ne!(hash_keyed(1, &a), hash_keyed(3, &a));
+    }
+
+    #[test]
+    fn combiner() {
+        let a: UnorderedCombiner = [1, 2, 3].into_iter().collect();
+        let b: UnorderedCombiner = [3, 1, 2].into_iter().collect();
+        assert_eq!(a, b);
+        assert_eq!(a.finish(), b.finish());
+        assert_eq!(a.len(), 3);
+
+        let (mut ha, mut hb) = (Fnv::default(), Fnv::default());
+        a.hash(&mut ha);
+        b.hash(&mut hb);
+        assert_eq!(ha.finish(), hb.finish());
+    }
+}
@@ -0,0 +1,572 @@
+//! Hasher and collections using the wyhash algorithm.
+
+// based on the final version 4 of the reference implementation at https://github.com/wangyi-fudan/wyhash
+
+use crate::{
+    impl_core_build_hasher, impl_core_hasher, BuildHasher, BuildHasherDefault,
+    EndianIndependentAlgorithm, Hasher, HasherWrite, SeedableBuildHasher,
+};
+
+impl_core_build_hasher!(WyHashBuildHasher);
+impl_core_hasher!(WyHash);
+
+/// The default secret used by wyhash.
+pub const DEFAULT_SECRET: [u64; 4] = [
+    0x2d358dccaa6c78a5,
+    0x8bb84b93962eacc9,
+    0x4b33a62ed433d4a3,
+    0x4d5a2da51de1aa47,
+];
+
+#[inline(always)]
+const fn wymum(a: u64, b: u64) -> (u64, u64) {
+    let r = a as u128 * b as u128;
+    (r as u64, (r >> 64) as u64)
+}
+
+#[inline(always)]
+const fn wymix(a: u64, b: u64) -> u64 {
+    let (a, b) = wymum(a, b);
+    a ^ b
+}
+
+#[inline(always)]
+const fn wyrand(seed: u64) -> (u64, u64) {
+    let seed = seed.wrapping_add(0x2d358dccaa6c78a5);
+    (seed, wymix(seed, seed ^ 0x8bb84b93962eacc9))
+}
+
+#[inline(always)]
+fn r8(bytes: &[u8], i: usize) -> u64 {
+    u64::from_le_bytes(bytes[i..i + 8].try_into().unwrap())
+}
+
+#[inline(always)]
+fn r4(bytes: &[u8], i: usize) -> u64 {
+    u32::from_le_bytes(bytes[i..i + 4].try_into().unwrap()) as u64
+}
+
+/// Generate a secret for wyhash from `seed`, using the same algorithm as the reference
+/// implementation's `make_secret`.
+///
+/// Each word of the secret is odd, consists of bytes with four bits set, and differs from
+/// the other words in exactly 32 bits.
+pub const fn make_secret(mut seed: u64) -> [u64; 4] {
+    // all bytes with exactly four bits set
+    const C: [u8; 70] = [
+        15, 23, 27, 29, 30, 39, 43, 45, 46, 51, 53, 54, 57, 58, 60, 71, 75, 77, 78, 83, 85, 86, 89,
+        90, 92, 99, 101, 102, 105, 106, 108, 113, 114, 116, 120, 135, 139, 141, 142, 147, 149, 150,
+        153, 154, 156, 163, 165, 166, 169, 170, 172, 177, 178, 180, 184, 195, 197, 198, 201, 202,
+        204, 209, 210, 212, 216, 225, 226, 228, 232, 240,
+    ];
+
+    let mut secret = [0; 4];
+    let mut i = 0;
+    while i < 4 {
+        'retry: loop {
+            let mut s = 0;
+            let mut j = 0;
+            while j < 64 {
+                let r;
+                (seed, r) = wyrand(seed);
+                s |= (C[(r % C.len() as u64) as usize] as u64) << j;
+                j += 8;
+            }
+            if s % 2 == 0 {
+                continue;
+            }
+            let mut k = 0;
+            while k < i {
+                if (secret[k] ^ s).count_ones() != 32 {
+                    continue 'retry;
+                }
+                k += 1;
+            }
+            secret[i] = s;
+            break;
+        }
+        i += 1;
+    }
+    secret
+}
+
+/// [`BuildHasher`] implementation for the [`WyHash`] hasher.
+/// If you don't need support for using custom seeds, use the zero sized
+/// [`WyHashBuildHasherDefault`] instead.
+///
+/// [`SeedableBuildHasher::from_seed`] uses the low 64 bits of the seed as the seed and
+/// generates the secret from the high 64 bits with [`make_secret`].
+#[derive(Clone, Debug)]
+pub struct WyHashBuildHasher {
+    seed: u64,
+    secret: [u64; 4],
+}
+
+impl WyHashBuildHasher {
+    /// Create a [`BuildHasher`] for [`WyHash`] using the default seed and secret.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Create a [`BuildHasher`] for [`WyHash`] with a custom seed and the default secret.
+    #[inline]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self::with_seed_and_secret(seed, DEFAULT_SECRET)
+    }
+
+    /// Create a [`BuildHasher`] for [`WyHash`] with a custom seed and secret.
+    /// The secret should be generated with [`make_secret`].
+    #[inline]
+    pub const fn with_seed_and_secret(seed: u64, secret: [u64; 4]) -> Self {
+        Self { seed, secret }
+    }
+}
+
+impl BuildHasher<u64> for WyHashBuildHasher {
+    type Hasher = WyHash;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seed_and_secret(self.seed, self.secret)
+    }
+}
+
+impl SeedableBuildHasher for WyHashBuildHasher {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seed_and_secret(seed as u64, make_secret((seed >> 64) as u64))
+    }
+}
+
+impl Default for WyHashBuildHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for WyHashBuildHasher {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.seed.zeroize();
+        self.secret.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for WyHashBuildHasher {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for WyHashBuildHasher {}
+
+/// [`BuildHasher`] implementation for the [`WyHash`] hasher using the default seed (zero sized).
+pub type WyHashBuildHasherDefault = BuildHasherDefault<WyHash>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`WyHash`] hasher.
+pub type WyHashHashMap<K, V> = std::collections::HashMap<K, V, WyHashBuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`WyHash`] hasher with the default seed.
+pub type WyHashHashMapDefault<K, V> = std::collections::HashMap<K, V, WyHashBuildHasherDefault>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`WyHash`] hasher.
+pub type WyHashHashSet<T> = std::collections::HashSet<T, WyHashBuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`WyHash`] hasher with the default seed.
+pub type WyHashHashSetDefault<T> = std::collections::HashSet<T, WyHashBuildHasherDefault>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`WyHash`] hasher.
+pub type WyHashHeaplessIndexMap<K, V, const N: usize> =
+    heapless::IndexMap<K, V, WyHashBuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`WyHash`] hasher with the default seed.
+pub type WyHashHeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, WyHashBuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`WyHash`] hasher.
+pub type WyHashHeaplessIndexSet<T, const N: usize> = heapless::IndexSet<T, WyHashBuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`WyHash`] hasher with the default seed.
+pub type WyHashHeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, WyHashBuildHasherDefault, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`WyHash`] hasher.
+pub type WyHashHashBrownMap<K, V> = hashbrown::HashMap<K, V, WyHashBuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`WyHash`] hasher with the default seed.
+pub type WyHashHashBrownMapDefault<K, V> = hashbrown::HashMap<K, V, WyHashBuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`WyHash`] hasher.
+pub type WyHashHashBrownSet<T> = hashbrown::HashSet<T, WyHashBuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`WyHash`] hasher with the default seed.
+pub type WyHashHashBrownSetDefault<T> = hashbrown::HashSet<T, WyHashBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`WyHash`] hasher.
+pub type WyHashIndexMap<K, V> = indexmap::IndexMap<K, V, WyHashBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`WyHash`] hasher with the default seed.
+pub type WyHashIndexMapDefault<K, V> = indexmap::IndexMap<K, V, WyHashBuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`WyHash`] hasher.
+pub type WyHashIndexSet<T> = indexmap::IndexSet<T, WyHashBuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`WyHash`] hasher with the default seed.
+pub type WyHashIndexSetDefault<T> = indexmap::IndexSet<T, WyHashBuildHasherDefault>;
+
+/// Hasher using the wyhash algorithm (final version 4).
+#[derive(Clone)]
+pub struct WyHash {
+    secret: [u64; 4],
+    seed: u64,
+    see1: u64,
+    see2: u64,
+    buffer: [u8; 48],
+    buffer_len: usize,
+    prev: [u8; 16],
+    length: u64,
+}
+
+impl WyHash {
+    /// Create a new `WyHash` hasher using the default seed and secret.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Create a new `WyHash` hasher with a custom seed and the default secret.
+    #[inline]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self::with_seed_and_secret(seed, DEFAULT_SECRET)
+    }
+
+    /// Create a new `WyHash` hasher with a custom seed and secret.
+    /// The secret should be generated with [`make_secret`].
+    #[inline]
+    pub const fn with_seed_and_secret(seed: u64, secret: [u64; 4]) -> Self {
+        let seed = seed ^ wymix(seed ^ secret[0], secret[1]);
+        Self {
+            secret,
+            seed,
+            see1: seed,
+            see2: seed,
+            buffer: [0; 48],
+            buffer_len: 0,
+            prev: [0; 16],
+            length: 0,
+        }
+    }
+
+    #[inline(always)]
+    fn block(secret: &[u64; 4], seeds: &mut [u64; 3], block: &[u8]) {
+        seeds[0] = wymix(r8(block, 0) ^ secret[1], r8(block, 8) ^ seeds[0]);
+        seeds[1] = wymix(r8(block, 16) ^ secret[2], r8(block, 24) ^ seeds[1]);
+        seeds[2] = wymix(r8(block, 32) ^ secret[3], r8(block, 40) ^ seeds[2]);
+    }
+
+    #[inline]
+    fn process(&mut self, block: &[u8]) {
+        let mut seeds = [self.seed, self.see1, self.see2];
+        Self::block(&self.secret, &mut seeds, block);
+        [self.seed, self.see1, self.see2] = seeds;
+        self.prev.copy_from_slice(&block[32..]);
+    }
+}
+
+impl EndianIndependentAlgorithm for WyHash {}
+
+impl Default for WyHash {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for WyHash {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.secret.zeroize();
+        self.seed.zeroize();
+        self.see1.zeroize();
+        self.see2.zeroize();
+        self.buffer.zeroize();
+        self.buffer_len.zeroize();
+        self.prev.zeroize();
+        self.length.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for WyHash {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for WyHash {}
+
+impl HasherWrite for WyHash {
+    fn write(&mut self, mut bytes: &[u8]) {
+        self.length = self.length.wrapping_add(bytes.len() as u64);
+
+        // blocks are only processed once it's known that more data follows them,
+        // because the last block is handled differently when the hash is finished
+        if self.buffer_len + bytes.len() <= 48 {
+            self.buffer[self.buffer_len..self.buffer_len + bytes.len()].copy_from_slice(bytes);
+            self.buffer_len += bytes.len();
+            return;
+        }
+
+        if self.buffer_len != 0 {
+            let n = 48 - self.buffer_len;
+            self.buffer[self.buffer_len..].copy_from_slice(&bytes[..n]);
+            bytes = &bytes[n..];
+            let buffer = self.buffer;
+            self.process(&buffer);
+        }
+
+        while bytes.len() > 48 {
+            self.process(&bytes[..48]);
+            bytes = &bytes[48..];
+        }
+
+        self.buffer[..bytes.len()].copy_from_slice(bytes);
+        self.buffer_len = bytes.len();
+    }
+}
+
+impl Hasher<u64> for WyHash {
+    fn finish(&self) -> u64 {
+        let secret = &self.secret;
+        let len = self.length;
+        let buffer = &self.buffer[..self.buffer_len];
+        let mut seed = self.seed;
+        let (a, b);
+
+        if len <= 16 {
+            let len = buffer.len();
+            if len >= 4 {
+                let q = (len >> 3) << 2;
+                a = r4(buffer, 0) << 32 | r4(buffer, q);
+                b = r4(buffer, len - 4) << 32 | r4(buffer, len - 4 - q);
+            } else if len > 0 {
+                a = (buffer[0] as u64) << 16
+                    | (buffer[len >> 1] as u64) << 8
+                    | buffer[len - 1] as u64;
+                b = 0;
+            } else {
+                a = 0;
+                b = 0;
+            }
+        } else {
+            let mut i = 0;
+            if len >= 48 {
+                let mut seeds = [seed, self.see1, self.see2];
+                if buffer.len() == 48 {
+                    Self::block(secret, &mut seeds, buffer);
+                    i = 48;
+                }
+                seed = seeds[0] ^ seeds[1] ^ seeds[2];
+            }
+            while buffer.len() - i > 16 {
+                seed = wymix(r8(buffer, i) ^ secret[1], r8(buffer, i + 8) ^ seed);
+                i += 16;
+            }
+
+            // the last 16 bytes may start in the previous block
+            let mut last = [0; 64];
+            last[..16].copy_from_slice(&self.prev);
+            last[16..16 + buffer.len()].copy_from_slice(buffer);
+            a = r8(&last, buffer.len());
+            b = r8(&last, buffer.len() + 8);
+        }
+
+        let (a, b) = wymum(a ^ secret[1], b ^ seed);
+        wymix(a ^ secret[0] ^ len, b ^ secret[1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::RawBytes, Hash};
+
+    fn default_seed<T: Hash>(x: T) -> u64 {
+        WyHashBuildHasherDefault::new().hash_one(x)
+    }
+
+    fn data() -> [u8; 255] {
+        let mut data = [0; 255];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = (i * 7 + 1) as u8;
+        }
+        data
+    }
+
+    // test vectors from the reference implementation, using the index as the seed
+    #[test]
+    fn reference() {
+        for (seed, expected, msg) in [
+            (0, 0x93228a4de0eec5a2, ""),
+            (1, 0xc5bac3db178713c4, "a"),
+            (2, 0xa97f2f7b1d9b3314, "abc"),
+            (3, 0x786d1f1df3801df4, "message digest"),
+            (4, 0xdca5a8138ad37c87, "abcdefghijklmnopqrstuvwxyz"),
+            (
+                5,
+                0xb9e734f117cfaf70,
+                "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+            ),
+            (
+                6,
+                0x6cc5eab49a92d617,
+                "12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+            ),
+        ] {
+            assert_eq!(
+                WyHashBuildHasher::with_seed(seed).hash_one(RawBytes(msg.as_bytes())),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn secret() {
+        let secret = make_secret(0x55555555);
+        assert_eq!(
+            secret,
+            [
+                0xb136654de18b66a9,
+                0x8b3c391739969a93,
+                0x662b3c53b23ae44b,
+                0x954b63a6e21eb1d1
+            ]
+        );
+        for (i, a) in secret.iter().enumerate() {
+            assert_eq!(a % 2, 1);
+            for b in &secret[i + 1..] {
+                assert_eq!((a ^ b).count_ones(), 32);
+            }
+        }
+    }
+
+    #[test]
+    fn lengths() {
+        let data = data();
+        let secret = make_secret(0x55555555);
+        for (len, default_secret, custom_secret) in [
+            (0, 0x7c4acdcbeed59b2a, 0x6ce8dce924567591),
+            (3, 0xe1168452851f08ac, 0x3812fb4c18e023eb),
+            (4, 0xd6c6478d54d682cb, 0xd393d75294d2f1b),
+            (8, 0x1b443ef489f741fe, 0x2fe2e3e122bf9a80),
+            (16, 0xd3b7633e856e1d8, 0x516f117dc07fcfcd),
+            (17, 0x2423bb3cb2c8c109, 0xcb652c08e134c117),
+            (32, 0xee612c9e80525df6, 0x2b6fa362f3ac6ea7),
+            (47, 0xdac1b4e970d652d1, 0xea8e924c237ed3a7),
+            (48, 0x57ed9efa30a2efdd, 0x43f4a966c34fa26c),
+            (49, 0x50bd40aefe817f3c, 0x410e3a93c11df17e),
+            (64, 0x65e148d4cf25c742, 0x52064de8dd0d10f0),
+            (95, 0x8adf0f7a8f052bfa, 0xded74eff3a23a6b4),
+            (96, 0xea16c5252f4c948b, 0xc21ccc2d8301d797),
+            (97, 0x4aef007bbdcd5c64, 0x6742bb53ca792df3),
+            (100, 0xb8b7260e06020d2c, 0x6208be705feeef5a),
+            (255, 0x41ed319e7f3f1787, 0xcbd3e281b7e66b9e),
+        ] {
+            assert_eq!(
+                WyHashBuildHasher::with_seed(0x1234).hash_one(RawBytes(&data[..len])),
+                default_secret,
+                "len {len}"
+            );
+            assert_eq!(
+                WyHashBuildHasher::with_seed_and_secret(0x1234, secret)
+                    .hash_one(RawBytes(&data[..len])),
+                custom_secret,
+                "len {len}"
+            );
+        }
+    }
+
+    #[test]
+    fn streaming() {
+        let data = data();
+        for len in 0..=data.len() {
+            let expected = default_seed(RawBytes(&data[..len]));
+            for chunk_size in [1, 5, 16, 47, 48, 49] {
+                let mut hasher = WyHash::new();
+                for chunk in data[..len].chunks(chunk_size) {
+                    hasher.write(chunk);
+                }
+                assert_eq!(
+                    hasher.finish(),
+                    expected,
+                    "len {len}, chunk size {chunk_size}"
+                );
+            }
+        }
+    }
+
+    hash_test_vectors! {
+        [default_seed]
+        a: 0xaced12527fe5bff8,
+        ab: 0xe9c28c2968258c7d,
+        abc: 0x989b4a209c1011c9,
+        abcd: 0x6d9a9834037410eb,
+        abcde: 0x66e6c1ed15f1f9ea,
+        abcdef: 0x90c9ed91822a3bf0,
+        abcdefg: 0x5b183f3d4acb6fd8,
+        abcdefgh: 0xb9a4994f5b68615c,
+        abcdefghi: 0xd91779aa91afa226,
+        abcdefghij: 0x11c2cd68f070aa36,
+        abcdefghijk: 0x684cd8821add2bc7,
+        abcdefghijkl: 0x87f227113c1b6972,
+        abcdefghijklm: 0x512a4f9acd6540e6,
+        abcdefghijklmn: 0xfccf01b1ad8cee81,
+        abcdefghijklmno: 0x80880309007ebf1a,
+        abcdefghijklmnop: 0x35309de45dc92e4a,
+        abcdefghijklmnopq: 0x9e0aa4c61a2da95d,
+        abcdefghijklmnopqr: 0x4951478af66f20c8,
+        abcdefghijklmnopqrs: 0x1a2bc31653ec2d57,
+        abcdefghijklmnopqrst: 0x3a21907281306e8,
+        abcdefghijklmnopqrstu: 0x4ccc9b616da179f6,
+        abcdefghijklmnopqrstuv: 0x9303875f44dbd63b,
+        abcdefghijklmnopqrstuvw: 0x3876bbfa2fa8c5b4,
+        abcdefghijklmnopqrstuvwx: 0x3266ec720a884102,
+        abcdefghijklmnopqrstuvwxy: 0x749b4c3951955de8,
+        abcdefghijklmnopqrstuvwxyz: 0xccaeadc12a061176,
+        abcdefghijklmnopqrstuvwxyz0: 0xa93ab1ff676a3574,
+        abcdefghijklmnopqrstuvwxyz01: 0x519ccfa78d231f5b,
+        abcdefghijklmnopqrstuvwxyz012: 0xd9383784c96ae4d1,
+        abcdefghijklmnopqrstuvwxyz0123: 0x3fcbf62ba7b4a27a,
+        abcdefghijklmnopqrstuvwxyz01234: 0x8dca46e26584054d,
+        abcdefghijklmnopqrstuvwxyz012345: 0x688a7e60770927c5,
+        abcdefghijklmnopqrstuvwxyz0123456: 0x1232d7899bf5e843,
+        abcdefghijklmnopqrstuvwxyz01234567: 0x1077f064e6b04963,
+        abcdefghijklmnopqrstuvwxyz012345678: 0xc43ba9ae8b6cb1a6,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x904174e72c0951ef,
+    }
+}
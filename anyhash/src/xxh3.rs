@@ -0,0 +1,943 @@
+//! Hasher and collections using the XXH3 hashing algorithm.
+
+// based on the spec at https://github.com/Cyan4973/xxHash/blob/dev/doc/xxhash_spec.md
+
+use crate::{
+    impl_core_build_hasher, impl_core_hasher, BuildHasher, BuildHasherDefault,
+    EndianIndependentAlgorithm, Hasher, HasherWrite, SeedableBuildHasher,
+};
+
+impl_core_build_hasher!(Xxh3BuildHasher);
+impl_core_hasher!(Xxh3);
+
+/// [`BuildHasher`] implementation for the [`Xxh3`] hasher.
+/// If you don't need support for using custom seeds, use the zero sized
+/// [`Xxh3BuildHasherDefault`] instead.
+#[derive(Clone, Debug)]
+pub struct Xxh3BuildHasher(u64);
+
+impl Xxh3BuildHasher {
+    /// Create a [`BuildHasher`] for [`Xxh3`] using the default seed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Create a [`BuildHasher`] for [`Xxh3`] with a custom seed.
+    #[inline]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self(seed)
+    }
+}
+
+impl BuildHasher<u64> for Xxh3BuildHasher {
+    type Hasher = Xxh3;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seed(self.0)
+    }
+}
+
+impl BuildHasher<u128> for Xxh3BuildHasher {
+    type Hasher = Xxh3;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Self::Hasher::with_seed(self.0)
+    }
+}
+
+impl SeedableBuildHasher for Xxh3BuildHasher {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seed(seed as u64)
+    }
+}
+
+impl Default for Xxh3BuildHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Xxh3BuildHasher {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Xxh3BuildHasher {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Xxh3BuildHasher {}
+
+/// [`BuildHasher`] implementation for the [`Xxh3`] hasher using the default seed (zero sized).
+pub type Xxh3BuildHasherDefault = BuildHasherDefault<Xxh3>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`Xxh3`] hasher.
+pub type Xxh3HashMap<K, V> = std::collections::HashMap<K, V, Xxh3BuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashMap` from `std` configured to use the [`Xxh3`] hasher with the default seed.
+pub type Xxh3HashMapDefault<K, V> = std::collections::HashMap<K, V, Xxh3BuildHasherDefault>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`Xxh3`] hasher.
+pub type Xxh3HashSet<T> = std::collections::HashSet<T, Xxh3BuildHasher>;
+
+#[cfg(feature = "std")]
+/// `HashSet` from `std` configured to use the [`Xxh3`] hasher with the default seed.
+pub type Xxh3HashSetDefault<T> = std::collections::HashSet<T, Xxh3BuildHasherDefault>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`Xxh3`] hasher.
+pub type Xxh3HeaplessIndexMap<K, V, const N: usize> = heapless::IndexMap<K, V, Xxh3BuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`Xxh3`] hasher with the default seed.
+pub type Xxh3HeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, Xxh3BuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`Xxh3`] hasher.
+pub type Xxh3HeaplessIndexSet<T, const N: usize> = heapless::IndexSet<T, Xxh3BuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`Xxh3`] hasher with the default seed.
+pub type Xxh3HeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, Xxh3BuildHasherDefault, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`Xxh3`] hasher.
+pub type Xxh3HashBrownMap<K, V> = hashbrown::HashMap<K, V, Xxh3BuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`Xxh3`] hasher with the default seed.
+pub type Xxh3HashBrownMapDefault<K, V> = hashbrown::HashMap<K, V, Xxh3BuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`Xxh3`] hasher.
+pub type Xxh3HashBrownSet<T> = hashbrown::HashSet<T, Xxh3BuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`Xxh3`] hasher with the default seed.
+pub type Xxh3HashBrownSetDefault<T> = hashbrown::HashSet<T, Xxh3BuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`Xxh3`] hasher.
+pub type Xxh3IndexMap<K, V> = indexmap::IndexMap<K, V, Xxh3BuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`Xxh3`] hasher with the default seed.
+pub type Xxh3IndexMapDefault<K, V> = indexmap::IndexMap<K, V, Xxh3BuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`Xxh3`] hasher.
+pub type Xxh3IndexSet<T> = indexmap::IndexSet<T, Xxh3BuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`Xxh3`] hasher with the default seed.
+pub type Xxh3IndexSetDefault<T> = indexmap::IndexSet<T, Xxh3BuildHasherDefault>;
+
+const PRIME32_1: u64 = 0x9e3779b1;
+const PRIME32_2: u64 = 0x85ebca77;
+const PRIME32_3: u64 = 0xc2b2ae3d;
+const PRIME64_1: u64 = 0x9e3779b185ebca87;
+const PRIME64_2: u64 = 0xc2b2ae3d27d4eb4f;
+const PRIME64_3: u64 = 0x165667b19e3779f9;
+const PRIME64_4: u64 = 0x85ebca77c2b2ae63;
+const PRIME64_5: u64 = 0x27d4eb2f165667c5;
+
+const STRIPE_LEN: usize = 64;
+const SECRET_LEN: usize = 192;
+const SECRET_CONSUME_RATE: usize = 8;
+const STRIPES_PER_BLOCK: usize = (SECRET_LEN - STRIPE_LEN) / SECRET_CONSUME_RATE;
+const SECRET_MERGEACCS_START: usize = 11;
+const SECRET_LASTACC_START: usize = 7;
+const MID_SIZE_MAX: usize = 240;
+const BUFFER_LEN: usize = 256;
+
+const INITIAL_ACC: [u64; 8] = [
+    PRIME32_3, PRIME64_1, PRIME64_2, PRIME64_3, PRIME64_4, PRIME32_2, PRIME64_5, PRIME32_1,
+];
+
+const DEFAULT_SECRET: [u8; SECRET_LEN] = [
+    0xb8, 0xfe, 0x6c, 0x39, 0x23, 0xa4, 0x4b, 0xbe, 0x7c, 0x01, 0x81, 0x2c, 0xf7, 0x21, 0xad, 0x1c,
+    0xde, 0xd4, 0x6d, 0xe9, 0x83, 0x90, 0x97, 0xdb, 0x72, 0x40, 0xa4, 0xa4, 0xb7, 0xb3, 0x67, 0x1f,
+    0xcb, 0x79, 0xe6, 0x4e, 0xcc, 0xc0, 0xe5, 0x78, 0x82, 0x5a, 0xd0, 0x7d, 0xcc, 0xff, 0x72, 0x21,
+    0xb8, 0x08, 0x46, 0x74, 0xf7, 0x43, 0x24, 0x8e, 0xe0, 0x35, 0x90, 0xe6, 0x81, 0x3a, 0x26, 0x4c,
+    0x3c, 0x28, 0x52, 0xbb, 0x91, 0xc3, 0x00, 0xcb, 0x88, 0xd0, 0x65, 0x8b, 0x1b, 0x53, 0x2e, 0xa3,
+    0x71, 0x64, 0x48, 0x97, 0xa2, 0x0d, 0xf9, 0x4e, 0x38, 0x19, 0xef, 0x46, 0xa9, 0xde, 0xac, 0xd8,
+    0xa8, 0xfa, 0x76, 0x3f, 0xe3, 0x9c, 0x34, 0x3f, 0xf9, 0xdc, 0xbb, 0xc7, 0xc7, 0x0b, 0x4f, 0x1d,
+    0x8a, 0x51, 0xe0, 0x4b, 0xcd, 0xb4, 0x59, 0x31, 0xc8, 0x9f, 0x7e, 0xc9, 0xd9, 0x78, 0x73, 0x64,
+    0xea, 0xc5, 0xac, 0x83, 0x34, 0xd3, 0xeb, 0xc3, 0xc5, 0x81, 0xa0, 0xff, 0xfa, 0x13, 0x63, 0xeb,
+    0x17, 0x0d, 0xdd, 0x51, 0xb7, 0xf0, 0xda, 0x49, 0xd3, 0x16, 0x55, 0x26, 0x29, 0xd4, 0x68, 0x9e,
+    0x2b, 0x16, 0xbe, 0x58, 0x7d, 0x47, 0xa1, 0xfc, 0x8f, 0xf8, 0xb8, 0xd1, 0x7a, 0xd0, 0x31, 0xce,
+    0x45, 0xcb, 0x3a, 0x8f, 0x95, 0x16, 0x04, 0x28, 0xaf, 0xd7, 0xfb, 0xca, 0xbb, 0x4b, 0x40, 0x7e,
+];
+
+#[inline(always)]
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+#[inline(always)]
+fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap())
+}
+
+#[inline(always)]
+const fn mul128_fold64(a: u64, b: u64) -> u64 {
+    let r = a as u128 * b as u128;
+    r as u64 ^ (r >> 64) as u64
+}
+
+#[inline(always)]
+const fn xxh64_avalanche(mut x: u64) -> u64 {
+    x ^= x >> 33;
+    x = x.wrapping_mul(PRIME64_2);
+    x ^= x >> 29;
+    x = x.wrapping_mul(PRIME64_3);
+    x ^ (x >> 32)
+}
+
+#[inline(always)]
+const fn avalanche(mut x: u64) -> u64 {
+    x ^= x >> 37;
+    x = x.wrapping_mul(0x165667919e3779f9);
+    x ^ (x >> 32)
+}
+
+#[inline(always)]
+const fn rrmxmx(mut x: u64, len: u64) -> u64 {
+    x ^= x.rotate_left(49) ^ x.rotate_left(24);
+    x = x.wrapping_mul(0x9fb21c651e98df25);
+    x ^= (x >> 35).wrapping_add(len);
+    x = x.wrapping_mul(0x9fb21c651e98df25);
+    x ^ (x >> 28)
+}
+
+#[inline(always)]
+fn mix16(bytes: &[u8], secret: &[u8], seed: u64) -> u64 {
+    mul128_fold64(
+        read_u64(bytes, 0) ^ read_u64(secret, 0).wrapping_add(seed),
+        read_u64(bytes, 8) ^ read_u64(secret, 8).wrapping_sub(seed),
+    )
+}
+
+fn hash_64_0_to_16(bytes: &[u8], secret: &[u8], seed: u64) -> u64 {
+    let len = bytes.len();
+    if len > 8 {
+        let flip1 = (read_u64(secret, 24) ^ read_u64(secret, 32)).wrapping_add(seed);
+        let flip2 = (read_u64(secret, 40) ^ read_u64(secret, 48)).wrapping_sub(seed);
+        let lo = read_u64(bytes, 0) ^ flip1;
+        let hi = read_u64(bytes, len - 8) ^ flip2;
+        avalanche(
+            (len as u64)
+                .wrapping_add(lo.swap_bytes())
+                .wrapping_add(hi)
+                .wrapping_add(mul128_fold64(lo, hi)),
+        )
+    } else if len >= 4 {
+        let seed = seed ^ ((seed as u32).swap_bytes() as u64) << 32;
+        let input =
+            (read_u32(bytes, len - 4) as u64).wrapping_add((read_u32(bytes, 0) as u64) << 32);
+        let flip = (read_u64(secret, 8) ^ read_u64(secret, 16)).wrapping_sub(seed);
+        rrmxmx(input ^ flip, len as u64)
+    } else if len > 0 {
+        let combined = (bytes[0] as u64) << 16
+            | (bytes[len >> 1] as u64) << 24
+            | bytes[len - 1] as u64
+            | (len as u64) << 8;
+        let flip = ((read_u32(secret, 0) ^ read_u32(secret, 4)) as u64).wrapping_add(seed);
+        xxh64_avalanche(combined ^ flip)
+    } else {
+        xxh64_avalanche(seed ^ read_u64(secret, 56) ^ read_u64(secret, 64))
+    }
+}
+
+fn hash_64_17_to_128(bytes: &[u8], secret: &[u8], seed: u64) -> u64 {
+    let len = bytes.len();
+    let mut acc = (len as u64).wrapping_mul(PRIME64_1);
+    let rounds = (len - 1) / 32;
+    for i in (0..=rounds).rev() {
+        acc = acc
+            .wrapping_add(mix16(&bytes[16 * i..], &secret[32 * i..], seed))
+            .wrapping_add(mix16(
+                &bytes[len - 16 * (i + 1)..],
+                &secret[32 * i + 16..],
+                seed,
+            ));
+    }
+    avalanche(acc)
+}
+
+fn hash_64_129_to_240(bytes: &[u8], secret: &[u8], seed: u64) -> u64 {
+    let len = bytes.len();
+    let mut acc = (len as u64).wrapping_mul(PRIME64_1);
+    for i in 0..8 {
+        acc = acc.wrapping_add(mix16(&bytes[16 * i..], &secret[16 * i..], seed));
+    }
+    acc = avalanche(acc);
+    for i in 8..len / 16 {
+        acc = acc.wrapping_add(mix16(&bytes[16 * i..], &secret[16 * (i - 8) + 3..], seed));
+    }
+    acc = acc.wrapping_add(mix16(&bytes[len - 16..], &secret[136 - 17..], seed));
+    avalanche(acc)
+}
+
+fn hash_64_short(bytes: &[u8], secret: &[u8], seed: u64) -> u64 {
+    if bytes.len() <= 16 {
+        hash_64_0_to_16(bytes, secret, seed)
+    } else if bytes.len() <= 128 {
+        hash_64_17_to_128(bytes, secret, seed)
+    } else {
+        hash_64_129_to_240(bytes, secret, seed)
+    }
+}
+
+#[inline(always)]
+fn mix32(acc: (u64, u64), a: &[u8], b: &[u8], secret: &[u8], seed: u64) -> (u64, u64) {
+    (
+        acc.0.wrapping_add(mix16(a, secret, seed)) ^ read_u64(b, 0).wrapping_add(read_u64(b, 8)),
+        acc.1.wrapping_add(mix16(b, &secret[16..], seed))
+            ^ read_u64(a, 0).wrapping_add(read_u64(a, 8)),
+    )
+}
+
+#[inline(always)]
+fn finish_128(acc: (u64, u64), len: usize, seed: u64) -> u128 {
+    let lo = acc.0.wrapping_add(acc.1);
+    let hi = acc
+        .0
+        .wrapping_mul(PRIME64_1)
+        .wrapping_add(acc.1.wrapping_mul(PRIME64_4))
+        .wrapping_add((len as u64).wrapping_sub(seed).wrapping_mul(PRIME64_2));
+    avalanche(lo) as u128 | (avalanche(hi).wrapping_neg() as u128) << 64
+}
+
+fn hash_128_0_to_16(bytes: &[u8], secret: &[u8], seed: u64) -> u128 {
+    let len = bytes.len();
+    if len > 8 {
+        let flip_lo = (read_u64(secret, 32) ^ read_u64(secret, 40)).wrapping_sub(seed);
+        let flip_hi = (read_u64(secret, 48) ^ read_u64(secret, 56)).wrapping_add(seed);
+        let input_lo = read_u64(bytes, 0);
+        let input_hi = read_u64(bytes, len - 8);
+        let m = (input_lo ^ input_hi ^ flip_lo) as u128 * PRIME64_1 as u128;
+        let input_hi = input_hi ^ flip_hi;
+        let m_lo = (m as u64).wrapping_add((len as u64 - 1) << 54);
+        let m_hi = ((m >> 64) as u64)
+            .wrapping_add(input_hi)
+            .wrapping_add((input_hi & 0xffffffff).wrapping_mul(PRIME32_2 - 1));
+        let m_lo = m_lo ^ m_hi.swap_bytes();
+        let h = m_lo as u128 * PRIME64_2 as u128;
+        let h_lo = h as u64;
+        let h_hi = ((h >> 64) as u64).wrapping_add(m_hi.wrapping_mul(PRIME64_2));
+        avalanche(h_lo) as u128 | (avalanche(h_hi) as u128) << 64
+    } else if len >= 4 {
+        let seed = seed ^ ((seed as u32).swap_bytes() as u64) << 32;
+        let input =
+            (read_u32(bytes, 0) as u64).wrapping_add((read_u32(bytes, len - 4) as u64) << 32);
+        let flip = (read_u64(secret, 16) ^ read_u64(secret, 24)).wrapping_add(seed);
+        let m = (input ^ flip) as u128 * PRIME64_1.wrapping_add((len as u64) << 2) as u128;
+        let mut lo = m as u64;
+        let mut hi = (m >> 64) as u64;
+        hi = hi.wrapping_add(lo << 1);
+        lo ^= hi >> 3;
+        lo = (lo ^ (lo >> 35)).wrapping_mul(0x9fb21c651e98df25);
+        lo ^= lo >> 28;
+        lo as u128 | (avalanche(hi) as u128) << 64
+    } else if len > 0 {
+        let lo = (bytes[0] as u32) << 16
+            | (bytes[len >> 1] as u32) << 24
+            | bytes[len - 1] as u32
+            | (len as u32) << 8;
+        let hi = lo.swap_bytes().rotate_left(13);
+        let flip_lo = ((read_u32(secret, 0) ^ read_u32(secret, 4)) as u64).wrapping_add(seed);
+        let flip_hi = ((read_u32(secret, 8) ^ read_u32(secret, 12)) as u64).wrapping_sub(seed);
+        xxh64_avalanche(lo as u64 ^ flip_lo) as u128
+            | (xxh64_avalanche(hi as u64 ^ flip_hi) as u128) << 64
+    } else {
+        let flip_lo = read_u64(secret, 64) ^ read_u64(secret, 72);
+        let flip_hi = read_u64(secret, 80) ^ read_u64(secret, 88);
+        xxh64_avalanche(seed ^ flip_lo) as u128 | (xxh64_avalanche(seed ^ flip_hi) as u128) << 64
+    }
+}
+
+fn hash_128_17_to_128(bytes: &[u8], secret: &[u8], seed: u64) -> u128 {
+    let len = bytes.len();
+    let mut acc = ((len as u64).wrapping_mul(PRIME64_1), 0);
+    let rounds = (len - 1) / 32;
+    for i in (0..=rounds).rev() {
+        acc = mix32(
+            acc,
+            &bytes[16 * i..],
+            &bytes[len - 16 * (i + 1)..],
+            &secret[32 * i..],
+            seed,
+        );
+    }
+    finish_128(acc, len, seed)
+}
+
+fn hash_128_129_to_240(bytes: &[u8], secret: &[u8], seed: u64) -> u128 {
+    let len = bytes.len();
+    let mut acc = ((len as u64).wrapping_mul(PRIME64_1), 0);
+    for i in 0..4 {
+        acc = mix32(
+            acc,
+            &bytes[32 * i..],
+            &bytes[32 * i + 16..],
+            &secret[32 * i..],
+            seed,
+        );
+    }
+    acc = (avalanche(acc.0), avalanche(acc.1));
+    for i in 4..len / 32 {
+        acc = mix32(
+            acc,
+            &bytes[32 * i..],
+            &bytes[32 * i + 16..],
+            &secret[32 * (i - 4) + 3..],
+            seed,
+        );
+    }
+    acc = mix32(
+        acc,
+        &bytes[len - 16..],
+        &bytes[len - 32..],
+        &secret[136 - 17 - 16..],
+        seed.wrapping_neg(),
+    );
+    finish_128(acc, len, seed)
+}
+
+fn hash_128_short(bytes: &[u8], secret: &[u8], seed: u64) -> u128 {
+    if bytes.len() <= 16 {
+        hash_128_0_to_16(bytes, secret, seed)
+    } else if bytes.len() <= 128 {
+        hash_128_17_to_128(bytes, secret, seed)
+    } else {
+        hash_128_129_to_240(bytes, secret, seed)
+    }
+}
+
+#[inline(always)]
+fn accumulate_512(acc: &mut [u64; 8], stripe: &[u8], secret: &[u8]) {
+    for i in 0..8 {
+        let data = read_u64(stripe, 8 * i);
+        let key = data ^ read_u64(secret, 8 * i);
+        acc[i ^ 1] = acc[i ^ 1].wrapping_add(data);
+        acc[i] = acc[i].wrapping_add((key & 0xffffffff).wrapping_mul(key >> 32));
+    }
+}
+
+#[inline(always)]
+fn scramble(acc: &mut [u64; 8], secret: &[u8]) {
+    for (i, acc) in acc.iter_mut().enumerate() {
+        *acc = (*acc ^ (*acc >> 47) ^ read_u64(secret, 8 * i)).wrapping_mul(PRIME32_1);
+    }
+}
+
+fn merge_accs(acc: &[u64; 8], secret: &[u8], start: u64) -> u64 {
+    let mut result = start;
+    for i in 0..4 {
+        result = result.wrapping_add(mul128_fold64(
+            acc[2 * i] ^ read_u64(secret, 16 * i),
+            acc[2 * i + 1] ^ read_u64(secret, 16 * i + 8),
+        ));
+    }
+    avalanche(result)
+}
+
+/// Hasher using the XXH3 algorithm.
+///
+/// This implements both [`Hasher<u64>`] and [`Hasher<u128>`], for XXH3-64 and XXH3-128.
+/// Both can be finished from the same hasher state.
+///
+/// This hasher buffers up to 256 bytes of input, so it's fairly large. The hash is the same
+/// as XXH3's one-shot hash of all the written data, regardless of how it was split up into writes.
+#[derive(Clone)]
+pub struct Xxh3 {
+    acc: [u64; 8],
+    secret: [u8; SECRET_LEN],
+    buffer: [u8; BUFFER_LEN],
+    buffer_len: usize,
+    stripes: usize,
+    total_len: u64,
+    seed: u64,
+}
+
+impl Xxh3 {
+    /// Create a new `Xxh3` hasher using the default seed.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Create a new `Xxh3` hasher with a custom `seed`.
+    pub fn with_seed(seed: u64) -> Self {
+        let mut secret = DEFAULT_SECRET;
+        if seed != 0 {
+            for (i, chunk) in secret.chunks_exact_mut(16).enumerate() {
+                let lo = read_u64(&DEFAULT_SECRET, 16 * i).wrapping_add(seed);
+                let hi = read_u64(&DEFAULT_SECRET, 16 * i + 8).wrapping_sub(seed);
+                chunk[..8].copy_from_slice(&lo.to_le_bytes());
+                chunk[8..].copy_from_slice(&hi.to_le_bytes());
+            }
+        }
+        Self {
+            acc: INITIAL_ACC,
+            secret,
+            buffer: [0; BUFFER_LEN],
+            buffer_len: 0,
+            stripes: 0,
+            total_len: 0,
+            seed,
+        }
+    }
+
+    fn consume_stripes(acc: &mut [u64; 8], stripes: &mut usize, bytes: &[u8], secret: &[u8]) {
+        let n = bytes.len() / STRIPE_LEN;
+        let to_end = STRIPES_PER_BLOCK - *stripes;
+        if n >= to_end {
+            for (i, stripe) in bytes.chunks_exact(STRIPE_LEN).enumerate() {
+                if i < to_end {
+                    accumulate_512(acc, stripe, &secret[(*stripes + i) * SECRET_CONSUME_RATE..]);
+                } else {
+                    if i == to_end {
+                        scramble(acc, &secret[SECRET_LEN - STRIPE_LEN..]);
+                    }
+                    accumulate_512(acc, stripe, &secret[(i - to_end) * SECRET_CONSUME_RATE..]);
+                }
+            }
+            if n == to_end {
+                scramble(acc, &secret[SECRET_LEN - STRIPE_LEN..]);
+            }
+            *stripes = n - to_end;
+        } else {
+            for (i, stripe) in bytes.chunks_exact(STRIPE_LEN).enumerate() {
+                accumulate_512(acc, stripe, &secret[(*stripes + i) * SECRET_CONSUME_RATE..]);
+            }
+            *stripes += n;
+        }
+    }
+
+    fn long_acc(&self) -> [u64; 8] {
+        let mut acc = self.acc;
+        let buffer = &self.buffer[..self.buffer_len];
+        let last_secret = &self.secret[SECRET_LEN - STRIPE_LEN - SECRET_LASTACC_START..];
+        if buffer.len() >= STRIPE_LEN {
+            let n = (buffer.len() - 1) / STRIPE_LEN;
+            let mut stripes = self.stripes;
+            Self::consume_stripes(
+                &mut acc,
+                &mut stripes,
+                &buffer[..n * STRIPE_LEN],
+                &self.secret,
+            );
+            accumulate_512(&mut acc, &buffer[buffer.len() - STRIPE_LEN..], last_secret);
+        } else {
+            // the last stripe overlaps the end of the previous buffer
+            let mut stripe = [0; STRIPE_LEN];
+            let catchup = STRIPE_LEN - buffer.len();
+            stripe[..catchup].copy_from_slice(&self.buffer[BUFFER_LEN - catchup..]);
+            stripe[catchup..].copy_from_slice(buffer);
+            accumulate_512(&mut acc, &stripe, last_secret);
+        }
+        acc
+    }
+}
+
+impl EndianIndependentAlgorithm for Xxh3 {}
+
+impl Default for Xxh3 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Xxh3 {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.acc.zeroize();
+        self.secret.zeroize();
+        self.buffer.zeroize();
+        self.buffer_len.zeroize();
+        self.stripes.zeroize();
+        self.total_len.zeroize();
+        self.seed.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Xxh3 {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Xxh3 {}
+
+impl HasherWrite for Xxh3 {
+    fn write(&mut self, mut bytes: &[u8]) {
+        self.total_len += bytes.len() as u64;
+
+        if self.buffer_len + bytes.len() <= BUFFER_LEN {
+            self.buffer[self.buffer_len..self.buffer_len + bytes.len()].copy_from_slice(bytes);
+            self.buffer_len += bytes.len();
+            return;
+        }
+
+        if self.buffer_len != 0 {
+            let take;
+            (take, bytes) = bytes.split_at(BUFFER_LEN - self.buffer_len);
+            self.buffer[self.buffer_len..].copy_from_slice(take);
+            Self::consume_stripes(&mut self.acc, &mut self.stripes, &self.buffer, &self.secret);
+            self.buffer_len = 0;
+        }
+
+        // always keep at least one byte in the buffer for finish
+        if bytes.len() > BUFFER_LEN {
+            let n = (bytes.len() - 1) / BUFFER_LEN * BUFFER_LEN;
+            let consume;
+            (consume, bytes) = bytes.split_at(n);
+            for block in consume.chunks_exact(BUFFER_LEN) {
+                Self::consume_stripes(&mut self.acc, &mut self.stripes, block, &self.secret);
+            }
+            self.buffer[BUFFER_LEN - STRIPE_LEN..].copy_from_slice(&consume[n - STRIPE_LEN..]);
+        }
+
+        self.buffer[..bytes.len()].copy_from_slice(bytes);
+        self.buffer_len = bytes.len();
+    }
+}
+
+impl Hasher<u64> for Xxh3 {
+    fn finish(&self) -> u64 {
+        if self.total_len <= MID_SIZE_MAX as u64 {
+            hash_64_short(&self.buffer[..self.buffer_len], &DEFAULT_SECRET, self.seed)
+        } else {
+            merge_accs(
+                &self.long_acc(),
+                &self.secret[SECRET_MERGEACCS_START..],
+                self.total_len.wrapping_mul(PRIME64_1),
+            )
+        }
+    }
+}
+
+impl Hasher<u128> for Xxh3 {
+    fn finish(&self) -> u128 {
+        if self.total_len <= MID_SIZE_MAX as u64 {
+            hash_128_short(&self.buffer[..self.buffer_len], &DEFAULT_SECRET, self.seed)
+        } else {
+            let acc = self.long_acc();
+            let lo = merge_accs(
+                &acc,
+                &self.secret[SECRET_MERGEACCS_START..],
+                self.total_len.wrapping_mul(PRIME64_1),
+            );
+            let hi = merge_accs(
+                &acc,
+                &self.secret[SECRET_LEN - STRIPE_LEN - SECRET_MERGEACCS_START..],
+                !self.total_len.wrapping_mul(PRIME64_2),
+            );
+            lo as u128 | (hi as u128) << 64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::RawBytes, Hash};
+
+    fn default_seed<T: Hash>(x: T) -> u64 {
+        Xxh3BuildHasherDefault::new().hash_one(x)
+    }
+
+    fn custom_seed<T: Hash>(x: T) -> u64 {
+        Xxh3BuildHasher::with_seed(0x55555555_55555555).hash_one(x)
+    }
+
+    fn default_seed_128<T: Hash>(x: T) -> u128 {
+        Xxh3BuildHasherDefault::new().hash_one(x)
+    }
+
+    fn custom_seed_128<T: Hash>(x: T) -> u128 {
+        Xxh3BuildHasher::with_seed(0x55555555_55555555).hash_one(x)
+    }
+
+    fn data() -> [u8; 4096] {
+        let mut data = [0; 4096];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = ((i as u32).wrapping_mul(2654435761) >> 13) as u8;
+        }
+        data
+    }
+
+    #[test]
+    fn empty_default_seed() {
+        assert_eq!(default_seed(()), 0x2d06800538d394c2);
+    }
+
+    #[test]
+    fn empty_custom_seed() {
+        assert_eq!(custom_seed(()), 0xdb5d3c54c82962d6);
+    }
+
+    #[test]
+    fn empty_128() {
+        assert_eq!(default_seed_128(()), 0x99aa06d3014798d86001c324468d497f);
+        assert_eq!(custom_seed_128(()), 0xfef5da86ea2ed160dac556fd2a088ad1);
+    }
+
+    #[test]
+    fn lengths() {
+        let data = data();
+        for (len, default, custom) in [
+            (1, 0xc44bdff4074eecdb, 0x191130833c008dda),
+            (3, 0xa1c4a8259b827291, 0xaa2bf6b88aa121f0),
+            (4, 0xbb4e3d89ee0b271d, 0xafcbe29848863ef5),
+            (8, 0x79d02238b80e37b1, 0xa29cfc9851d7fdcd),
+            (9, 0xf64cecc4271ff461, 0xc7a7230f1cdd8b44),
+            (16, 0x222e9aead6bddd51, 0x2851e8ee4faf108),
+            (17, 0x47aad6b375eb4bba, 0xa5d70b502fe40f2f),
+            (128, 0x421a9c905c6e66ba, 0x2916bbb15b0f5944),
+            (129, 0x9e2414800f83768a, 0xf00d4d2f3cce2a88),
+            (240, 0xb714c5fd22744964, 0xabec1b00dfe5781c),
+            (241, 0xbc424a2c480dd281, 0xdf263d7c80347d6e),
+            (255, 0x155baa5891f7606f, 0xd2c0bb260b586e9f),
+            (256, 0x2d040b1ab40f0d78, 0xd097ba3c1533e3eb),
+            (257, 0xd0515fbec69efb50, 0x90cc6fe58d9ca4a6),
+            (1023, 0x3071307bafa2f8f4, 0x5ec2ae6c7bcd77a4),
+            (1024, 0x1fd15e7d36f5e1bc, 0xf5d4a73c8387ee2),
+            (1025, 0xfe08e5a874d23fd2, 0x109810a728910bc9),
+            (4096, 0x84d9e7ce664c8217, 0x5475aff58dc80924),
+        ] {
+            let bytes = RawBytes(&data[..len]);
+            assert_eq!(default_seed(&bytes), default, "len {len}");
+            assert_eq!(custom_seed(&bytes), custom, "len {len}");
+        }
+    }
+
+    #[test]
+    fn lengths_128() {
+        let data = data();
+        for (len, default, custom) in [
+            (
+                1,
+                0xa6cd5e9392000f6ac44bdff4074eecdb,
+                0x249cda080ee9236c191130833c008dda,
+            ),
+            (
+                3,
+                0x95c705060a313bf8a1c4a8259b827291,
+                0x5af8f8d4d7c0502faa2bf6b88aa121f0,
+            ),
+            (
+                4,
+                0xafbf64f9281b8de2fde8d93ae8794d8e,
+                0x3fcfe08337ab6f140d8d807f75b912d,
+            ),
+            (
+                8,
+                0x2761698c33953c430234362aaf47b71a,
+                0x68dff7ca33a3bd2f8632b5f9921b905c,
+            ),
+            (
+                9,
+                0xd39db6431d37a74895c8a562da51412,
+                0xe6bfbb49edf89ccc947334f1e0ae2387,
+            ),
+            (
+                16,
+                0x29be75b0bbbb5284aafffcec5df2cb27,
+                0xc57ffd8ffc1b4c7f0980848b932de920,
+            ),
+            (
+                17,
+                0xdb7e8f77961e47fd878751509ecfdb8b,
+                0x57521d55984404817f8a2ad483a5f667,
+            ),
+            (
+                128,
+                0xba44fd018231af4cbbe087d879edcc78,
+                0x71ecb00fb6baf692fbc856f3438be77a,
+            ),
+            (
+                129,
+                0x522c922743fd67f1b8075934107218e5,
+                0x62870ce6dea1882e7fae562bcc220763,
+            ),
+            (
+                240,
+                0x4f49ccc8526aa7ad407883ea5ef95b9a,
+                0x2b18985e2d206f17f760985dd29fcfe0,
+            ),
+            (
+                241,
+                0x50b62ee1ee6455a7bc424a2c480dd281,
+                0x9a6a751f08817c23df263d7c80347d6e,
+            ),
+            (
+                255,
+                0x13c4fb2eb194824f155baa5891f7606f,
+                0x23f7f5a132e274a3d2c0bb260b586e9f,
+            ),
+            (
+                256,
+                0x20d618055259b36c2d040b1ab40f0d78,
+                0xe6908df0f08cc511d097ba3c1533e3eb,
+            ),
+            (
+                257,
+                0x6d2f2dce7891e45d0515fbec69efb50,
+                0xc6361c5ef7c138fd90cc6fe58d9ca4a6,
+            ),
+            (
+                1023,
+                0xd34ef4f411b9a93e3071307bafa2f8f4,
+                0xbdcc4fad44c517025ec2ae6c7bcd77a4,
+            ),
+            (
+                1024,
+                0x53bd178b75ab292e1fd15e7d36f5e1bc,
+                0x93449f46c8d8eff00f5d4a73c8387ee2,
+            ),
+            (
+                1025,
+                0xd1ad5f4a3cce4374fe08e5a874d23fd2,
+                0x4b6775847c49b765109810a728910bc9,
+            ),
+            (
+                4096,
+                0xc263f435125451084d9e7ce664c8217,
+                0xa82d7a049f3c165a5475aff58dc80924,
+            ),
+        ] {
+            let bytes = RawBytes(&data[..len]);
+            assert_eq!(default_seed_128(&bytes), default, "len {len}");
+            assert_eq!(custom_seed_128(&bytes), custom, "len {len}");
+        }
+    }
+
+    #[test]
+    fn streaming() {
+        let data = data();
+        for len in [0, 100, 241, 256, 257, 600, 1025, 4096] {
+            let expected = custom_seed(RawBytes(&data[..len]));
+            let expected_128 = custom_seed_128(RawBytes(&data[..len]));
+            for chunk_size in [1, 7, 64, 100, 255, 256, 257, 1000] {
+                let mut hasher = Xxh3::with_seed(0x55555555_55555555);
+                for chunk in data[..len].chunks(chunk_size) {
+                    hasher.write(chunk);
+                }
+                assert_eq!(
+                    Hasher::<u64>::finish(&hasher),
+                    expected,
+                    "len {len}, chunk size {chunk_size}"
+                );
+                assert_eq!(
+                    Hasher::<u128>::finish(&hasher),
+                    expected_128,
+                    "len {len}, chunk size {chunk_size}"
+                );
+            }
+        }
+    }
+
+    hash_test_vectors! {
+        [default_seed]
+        a: 0xe6c632b61e964e1f,
+        ab: 0xa873719c24d5735c,
+        abc: 0x78af5f94892f3950,
+        abcd: 0x6497a96f53a89890,
+        abcde: 0x55c65158ee9e652d,
+        abcdef: 0xda87bd32d3c47db6,
+        abcdefg: 0x5a40dc3fd44c052f,
+        abcdefgh: 0x6f45a76842a96483,
+        abcdefghi: 0xe0dde4fc174590a0,
+        abcdefghij: 0xc85ff0a489414010,
+        abcdefghijk: 0x386cb4f266186f62,
+        abcdefghijkl: 0x52beba2086c3f6d7,
+        abcdefghijklm: 0xd7b6fd946b75df4b,
+        abcdefghijklmn: 0xaa26cab2afa36140,
+        abcdefghijklmno: 0xa8edaf6dc2724d85,
+        abcdefghijklmnop: 0x3d3ccac9af14d8a8,
+        abcdefghijklmnopq: 0xca7f3571df47cacf,
+        abcdefghijklmnopqr: 0xe94c06b8a056e3a2,
+        abcdefghijklmnopqrs: 0x5214bfc42e79fe1e,
+        abcdefghijklmnopqrst: 0x67cb7b2afe010bb6,
+        abcdefghijklmnopqrstu: 0x9128e88a04be2ef9,
+        abcdefghijklmnopqrstuv: 0x23fca6fcd206c15c,
+        abcdefghijklmnopqrstuvw: 0x736608a667ac1a4f,
+        abcdefghijklmnopqrstuvwx: 0x88b5cc21276c04a,
+        abcdefghijklmnopqrstuvwxy: 0x41a0e749e177faf1,
+        abcdefghijklmnopqrstuvwxyz: 0x810f9ca067fbb90c,
+        abcdefghijklmnopqrstuvwxyz0: 0x7cfe360d307e2287,
+        abcdefghijklmnopqrstuvwxyz01: 0x8ee10d7a7148d79f,
+        abcdefghijklmnopqrstuvwxyz012: 0xdefa24ce996c37ce,
+        abcdefghijklmnopqrstuvwxyz0123: 0xa7f83ee5892f3f65,
+        abcdefghijklmnopqrstuvwxyz01234: 0xa8126711d036c549,
+        abcdefghijklmnopqrstuvwxyz012345: 0x5a4ee6ee5f6dadcf,
+        abcdefghijklmnopqrstuvwxyz0123456: 0xb8787ddc6103ba40,
+        abcdefghijklmnopqrstuvwxyz01234567: 0xfaf96d17bb582a5a,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x5cabad5521cee741,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x1173f34a884ae63c,
+
+        [default_seed_128]
+        a: 0xa96faf705af16834e6c632b61e964e1f,
+        ab: 0x89c65ebc828eebaca873719c24d5735c,
+        abc: 0x6b05ab6733a618578af5f94892f3950,
+        abcd: 0x8d6b60383dfa90c21be79eecd1b1353d,
+        abcde: 0x3043c78169f25c3f97d5a48ef320eec2,
+        abcdef: 0x389197a55db2b2e4da35a6714d34f8a2,
+        abcdefg: 0x2aafd83869a59c313fe798c0edaa6dc6,
+        abcdefgh: 0xdac23237af37353342b702b313880f12,
+        abcdefghi: 0xb43ff5bc5ff2e0adc0646b2d7986db98,
+        abcdefghij: 0x9e814df2752571c7b0a8c058e69ff5a7,
+        abcdefghijk: 0xf63802ddeb8a84810c30617e220bd2c5,
+        abcdefghijkl: 0xd5c1c71e1ef3a2b6ca41a0e8a26ef9e2,
+        abcdefghijklm: 0xb3f3c61b89a9d1224c633bfeef25de5b,
+        abcdefghijklmn: 0x4d15f6daa22c156bcb0743e0c58a8d23,
+        abcdefghijklmno: 0x5e190a0fa5ad0836d35dc9eaab32b9a0,
+        abcdefghijklmnop: 0x1f58fc809b1b8c4b3e8e153ff12f6330,
+        abcdefghijklmnopq: 0x11078c38a5ca3a8dc3acc9940596efab,
+        abcdefghijklmnopqr: 0x1bd856b993bd246a1b568682235c0ed7,
+        abcdefghijklmnopqrs: 0x5d56d092a3e61bca36f22c21d9921870,
+        abcdefghijklmnopqrst: 0xc27b3c5de29ebd49f8ceb1adbb3ff045,
+        abcdefghijklmnopqrstu: 0x69a38b32575a281e004637c61b234c5f,
+        abcdefghijklmnopqrstuv: 0x991c93515f4a1a77cd067b0ff70f80ab,
+        abcdefghijklmnopqrstuvw: 0x1068a70597186c00fc3aa9abcbdd5c4,
+        abcdefghijklmnopqrstuvwx: 0xf2a66fdce22d2c6c5a65199cdab9bdd,
+        abcdefghijklmnopqrstuvwxy: 0xafc46f15feda737ee6da132cd9cebfd9,
+        abcdefghijklmnopqrstuvwxyz: 0xdb7ca44e84843d67ebe162220154e1e6,
+        abcdefghijklmnopqrstuvwxyz0: 0x51f1f3a1c935d0f7eb28a6da5ab30144,
+        abcdefghijklmnopqrstuvwxyz01: 0xa764ffea6bb9ef2b41749458b4c129d2,
+        abcdefghijklmnopqrstuvwxyz012: 0x8a594edfaafaf398ba0f4293c10bf3d4,
+        abcdefghijklmnopqrstuvwxyz0123: 0xca0bef178dbfc4b46bba634dbaad3873,
+        abcdefghijklmnopqrstuvwxyz01234: 0xb1699673ce8410fdd29eab53026270d0,
+        abcdefghijklmnopqrstuvwxyz012345: 0x427c421757a8079329514a480c01d224,
+        abcdefghijklmnopqrstuvwxyz0123456: 0xca1d90fbf2da6d15f6c34ba556823047,
+        abcdefghijklmnopqrstuvwxyz01234567: 0xacfe479fed191cea5961ab6452026adc,
+        abcdefghijklmnopqrstuvwxyz012345678: 0x14c148582cfe4939ef1add87ae12433f,
+        abcdefghijklmnopqrstuvwxyz0123456789: 0x6d54bb4e6bb9d97a10f98e19d9422276,
+    }
+}
@@ -5,7 +5,8 @@
 use crate::{
     impl_core_build_hasher, impl_core_hasher,
     internal::{Buffer, N4},
-    BuildHasher, BuildHasherDefault, EndianIndependentAlgorithm, Hasher, HasherWrite,
+    BuildHasher, BuildHasherDefault, EndianIndependentAlgorithm, Hasher, HasherCheckpoint,
+    HasherWrite, SeedableBuildHasher,
 };
 
 impl_core_build_hasher!(Xxh64BuildHasher);
@@ -40,6 +41,13 @@ impl BuildHasher<u64> for Xxh64BuildHasher {
     }
 }
 
+impl SeedableBuildHasher for Xxh64BuildHasher {
+    #[inline]
+    fn from_seed(seed: u128) -> Self {
+        Self::with_seed(seed as u64)
+    }
+}
+
 impl Default for Xxh64BuildHasher {
     #[inline]
     fn default() -> Self {
@@ -47,6 +55,25 @@ impl Default for Xxh64BuildHasher {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Xxh64BuildHasher {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Xxh64BuildHasher {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Xxh64BuildHasher {}
+
 /// [`BuildHasher`] implementation for the [`Xxh64`] hasher using the default seed (zero sized).
 pub type Xxh64BuildHasherDefault = BuildHasherDefault<Xxh64>;
 
@@ -66,6 +93,153 @@ pub type XXh64HashSet<T> = std::collections::HashSet<T, Xxh64BuildHasher>;
 /// `HashSet` from `std` configured to use the [`Xxh64`] hasher with the default seed.
 pub type XXh64HashSetDefault<T> = std::collections::HashSet<T, Xxh64BuildHasherDefault>;
 
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`Xxh64`] hasher.
+pub type Xxh64HeaplessIndexMap<K, V, const N: usize> =
+    heapless::IndexMap<K, V, Xxh64BuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexMap` from `heapless` configured to use the [`Xxh64`] hasher with the default seed.
+pub type Xxh64HeaplessIndexMapDefault<K, V, const N: usize> =
+    heapless::IndexMap<K, V, Xxh64BuildHasherDefault, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`Xxh64`] hasher.
+pub type Xxh64HeaplessIndexSet<T, const N: usize> = heapless::IndexSet<T, Xxh64BuildHasher, N>;
+
+#[cfg(feature = "heapless")]
+/// `IndexSet` from `heapless` configured to use the [`Xxh64`] hasher with the default seed.
+pub type Xxh64HeaplessIndexSetDefault<T, const N: usize> =
+    heapless::IndexSet<T, Xxh64BuildHasherDefault, N>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`Xxh64`] hasher.
+pub type Xxh64HashBrownMap<K, V> = hashbrown::HashMap<K, V, Xxh64BuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashMap` from `hashbrown` configured to use the [`Xxh64`] hasher with the default seed.
+pub type Xxh64HashBrownMapDefault<K, V> = hashbrown::HashMap<K, V, Xxh64BuildHasherDefault>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`Xxh64`] hasher.
+pub type Xxh64HashBrownSet<T> = hashbrown::HashSet<T, Xxh64BuildHasher>;
+
+#[cfg(feature = "hashbrown")]
+/// `HashSet` from `hashbrown` configured to use the [`Xxh64`] hasher with the default seed.
+pub type Xxh64HashBrownSetDefault<T> = hashbrown::HashSet<T, Xxh64BuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`Xxh64`] hasher.
+pub type Xxh64IndexMap<K, V> = indexmap::IndexMap<K, V, Xxh64BuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexMap` from `indexmap` configured to use the [`Xxh64`] hasher with the default seed.
+pub type Xxh64IndexMapDefault<K, V> = indexmap::IndexMap<K, V, Xxh64BuildHasherDefault>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`Xxh64`] hasher.
+pub type Xxh64IndexSet<T> = indexmap::IndexSet<T, Xxh64BuildHasher>;
+
+#[cfg(feature = "indexmap")]
+/// `IndexSet` from `indexmap` configured to use the [`Xxh64`] hasher with the default seed.
+pub type Xxh64IndexSetDefault<T> = indexmap::IndexSet<T, Xxh64BuildHasherDefault>;
+
+/// Calculate the Xxh64 hash of `bytes` with the given `seed` at compile time.
+///
+/// This gives the same result as writing `bytes` to an [`Xxh64`] hasher with the same seed.
+/// Use it to make constants, e.g. for hashes of strings used as `match` keys.
+///
+/// ```
+/// # use anyhash::xxh64::xxh64;
+/// const GET: u64 = xxh64(b"GET", 0);
+/// const PUT: u64 = xxh64(b"PUT", 0);
+///
+/// fn method(hash: u64) -> Option<&'static str> {
+///     match hash {
+///         GET => Some("get"),
+///         PUT => Some("put"),
+///         _ => None,
+///     }
+/// }
+///
+/// assert_eq!(method(xxh64("PUT".as_bytes(), 0)), Some("put"));
+/// ```
+pub const fn xxh64(bytes: &[u8], seed: u64) -> u64 {
+    const fn read_u64(bytes: &[u8], i: usize) -> u64 {
+        u64::from_le_bytes([
+            bytes[i],
+            bytes[i + 1],
+            bytes[i + 2],
+            bytes[i + 3],
+            bytes[i + 4],
+            bytes[i + 5],
+            bytes[i + 6],
+            bytes[i + 7],
+        ])
+    }
+
+    let len = bytes.len();
+    let mut i = 0;
+
+    let mut acc = if len < 32 {
+        seed.wrapping_add(Xxh64::PRIME64_5)
+    } else {
+        let mut acc0 = seed
+            .wrapping_add(Xxh64::PRIME64_1)
+            .wrapping_add(Xxh64::PRIME64_2);
+        let mut acc1 = seed.wrapping_add(Xxh64::PRIME64_2);
+        let mut acc2 = seed;
+        let mut acc3 = seed.wrapping_sub(Xxh64::PRIME64_1);
+        while len - i >= 32 {
+            acc0 = Xxh64::round(acc0, read_u64(bytes, i));
+            acc1 = Xxh64::round(acc1, read_u64(bytes, i + 8));
+            acc2 = Xxh64::round(acc2, read_u64(bytes, i + 16));
+            acc3 = Xxh64::round(acc3, read_u64(bytes, i + 24));
+            i += 32;
+        }
+        let mut acc = acc0
+            .rotate_left(1)
+            .wrapping_add(acc1.rotate_left(7))
+            .wrapping_add(acc2.rotate_left(12))
+            .wrapping_add(acc3.rotate_left(18));
+        acc = Xxh64::merge_accumulator(acc, acc0);
+        acc = Xxh64::merge_accumulator(acc, acc1);
+        acc = Xxh64::merge_accumulator(acc, acc2);
+        Xxh64::merge_accumulator(acc, acc3)
+    };
+
+    acc = acc.wrapping_add(len as u64);
+
+    while len - i >= 8 {
+        acc = (acc ^ Xxh64::round(0, read_u64(bytes, i)))
+            .rotate_left(27)
+            .wrapping_mul(Xxh64::PRIME64_1)
+            .wrapping_add(Xxh64::PRIME64_4);
+        i += 8;
+    }
+
+    if len - i >= 4 {
+        let lane = u32::from_le_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]]) as u64;
+        acc = (acc ^ lane.wrapping_mul(Xxh64::PRIME64_1))
+            .rotate_left(23)
+            .wrapping_mul(Xxh64::PRIME64_2)
+            .wrapping_add(Xxh64::PRIME64_3);
+        i += 4;
+    }
+
+    while i < len {
+        let lane = bytes[i] as u64;
+        acc = (acc ^ lane.wrapping_mul(Xxh64::PRIME64_5))
+            .rotate_left(11)
+            .wrapping_mul(Xxh64::PRIME64_1);
+        i += 1;
+    }
+
+    acc = (acc ^ (acc >> 33)).wrapping_mul(Xxh64::PRIME64_2);
+    acc = (acc ^ (acc >> 29)).wrapping_mul(Xxh64::PRIME64_3);
+    acc ^ (acc >> 32)
+}
+
 /// Hasher using the Xxh64 algorithm.
 #[derive(Clone)]
 pub struct Xxh64 {
@@ -137,6 +311,43 @@ impl Default for Xxh64 {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Xxh64 {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.acc.zeroize();
+        self.buffer.zeroize();
+        self.buffer_len.zeroize();
+        self.total_len.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Xxh64 {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Xxh64 {}
+
+/// The snapshot is a copy of the hasher, including its buffered input.
+impl HasherCheckpoint for Xxh64 {
+    type Snapshot = Self;
+
+    #[inline]
+    fn checkpoint(&self) -> Self {
+        self.clone()
+    }
+
+    #[inline]
+    fn restore(&mut self, snapshot: Self) {
+        *self = snapshot;
+    }
+}
+
 impl HasherWrite for Xxh64 {
     fn write(&mut self, mut bytes: &[u8]) {
         self.total_len += bytes.len() as u64;
@@ -202,6 +413,52 @@ impl Hasher<u64> for Xxh64 {
     }
 }
 
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+
+    impl Serialize for Xxh64BuildHasher {
+        #[inline]
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.0.serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Xxh64BuildHasher {
+        #[inline]
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            u64::deserialize(deserializer).map(Self::with_seed)
+        }
+    }
+
+    /// Serializes as a tuple of the accumulators, the buffer as little endian `u64`s, the
+    /// number of bytes in the buffer and the total length.
+    impl Serialize for Xxh64 {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let buffer = self.buffer.as_u64s().map(u64::from_le);
+            (self.acc, buffer, self.buffer_len as u8, self.total_len).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Xxh64 {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let (acc, buffer, buffer_len, total_len) =
+                <([u64; 4], [u64; 4], u8, u64)>::deserialize(deserializer)?;
+            if buffer_len >= 32 {
+                return Err(D::Error::custom("invalid buffer length"));
+            }
+            let mut hasher = Self::new();
+            hasher.acc = acc;
+            *hasher.buffer.as_u64s_mut() = buffer.map(u64::to_le);
+            hasher.buffer_len = buffer_len as usize;
+            hasher.total_len = total_len;
+            Ok(hasher)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,7 +482,7 @@ mod tests {
         assert_eq!(custom_seed(()), 0x28e7a0126181c619);
     }
 
-    test_bytes_hash! {
+    hash_test_vectors! {
         [default_seed]
         a: 0xd24ec4f1a98c6e5b,
         ab: 0x65f708ca92d04a61,
@@ -302,4 +559,42 @@ mod tests {
         abcdefghijklmnopqrstuvwxyz012345678: 0xfcff767d554c3aca,
         abcdefghijklmnopqrstuvwxyz0123456789: 0x1913cbdad3ae2e20,
     }
+
+    #[test]
+    fn xxh64_const() {
+        const HASH: u64 = xxh64(b"abcdef", 0);
+        assert_eq!(HASH, 0xfa8afd82c423144d);
+        let buf: [u8; 100] = core::array::from_fn(|i| (i * 3 + 7) as u8);
+        for len in [0, 3, 4, 8, 13, 31, 32, 33, 64, 100] {
+            for seed in [0, 0x55555555_55555555] {
+                let mut hasher = Xxh64::with_seed(seed);
+                hasher.write(&buf[..len]);
+                assert_eq!(
+                    xxh64(&buf[..len], seed),
+                    hasher.finish(),
+                    "wrong value at {len}"
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use crate::tests::RawBytes;
+
+        let buf: [u8; 100] = core::array::from_fn(|i| (i * 3 + 7) as u8);
+        let bh: Xxh64BuildHasher =
+            serde_json::from_str(&serde_json::to_string(&Xxh64BuildHasher::with_seed(5)).unwrap())
+                .unwrap();
+        let hash = bh.hash_one(RawBytes(&buf));
+        for split in [0, 10, 31, 32, 50] {
+            let mut hasher = Xxh64::with_seed(5);
+            hasher.write(&buf[..split]);
+            let json = serde_json::to_string(&hasher).unwrap();
+            let mut hasher: Xxh64 = serde_json::from_str(&json).unwrap();
+            hasher.write(&buf[split..]);
+            assert_eq!(hasher.finish(), hash, "wrong value at {split}");
+        }
+    }
 }
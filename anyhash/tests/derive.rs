@@ -0,0 +1,290 @@
+#![cfg(feature = "alloc")]
+
+use anyhash::{Hash, Hasher, HasherWrite, RecordedWrite, RecordingHasher};
+use RecordedWrite::*;
+
+#[derive(Default)]
+struct Fnv(u64);
+
+impl HasherWrite for Fnv {
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+impl Hasher<u64> for Fnv {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+fn records<T: Hash + ?Sized>(value: &T) -> Vec<RecordedWrite> {
+    let mut hasher = RecordingHasher::new(Fnv::default());
+    value.hash(&mut hasher);
+    hasher.records().to_vec()
+}
+
+fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = Fnv::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn tag_fields() {
+    #[derive(Hash)]
+    #[hash(tag_fields)]
+    struct Size {
+        width: u32,
+        height: u8,
+    }
+
+    assert_eq!(
+        records(&Size {
+            width: 1,
+            height: 2
+        }),
+        [
+            Str("width".into()),
+            WriteU32(1),
+            Str("height".into()),
+            WriteU8(2)
+        ]
+    );
+}
+
+#[test]
+fn version() {
+    #[derive(Hash)]
+    #[hash(version = 3)]
+    struct Point(u32, u8);
+
+    assert_eq!(
+        records(&Point(1, 2)),
+        [WriteU64(3), WriteU32(1), WriteU8(2)]
+    );
+}
+
+#[test]
+fn skip() {
+    #[derive(Hash)]
+    struct Cached {
+        value: u32,
+        #[hash(skip)]
+        _cache: u8,
+    }
+
+    assert_eq!(
+        records(&Cached {
+            value: 1,
+            _cache: 2
+        }),
+        [WriteU32(1)]
+    );
+}
+
+#[test]
+fn bound() {
+    use core::marker::PhantomData;
+
+    struct NotHash;
+
+    #[derive(Hash)]
+    #[hash(bound = "")]
+    struct Id<T> {
+        id: u32,
+        _marker: PhantomData<T>,
+    }
+
+    assert_eq!(
+        records(&Id::<NotHash> {
+            id: 1,
+            _marker: PhantomData
+        }),
+        [WriteU32(1)]
+    );
+}
+
+#[test]
+fn stable_discriminant() {
+    #[derive(Hash)]
+    #[hash(stable_discriminant)]
+    enum Shape {
+        Empty,
+        Square(u8),
+    }
+
+    assert_eq!(records(&Shape::Empty), [WriteU32(0)]);
+    assert_eq!(records(&Shape::Square(5)), [WriteU32(1), WriteU8(5)]);
+}
+
+#[test]
+fn use_discriminant() {
+    #[derive(Hash)]
+    #[hash(use_discriminant)]
+    #[repr(u8)]
+    enum Status {
+        Active = 1,
+        Suspended = 4,
+        Deleted,
+    }
+
+    #[derive(Hash)]
+    #[hash(use_discriminant)]
+    enum Level {
+        Low = -1,
+        High,
+    }
+
+    assert_eq!(records(&Status::Active), [WriteU8(1)]);
+    assert_eq!(records(&Status::Suspended), [WriteU8(4)]);
+    assert_eq!(records(&Status::Deleted), [WriteU8(5)]);
+    assert_eq!(records(&Level::Low), [WriteIsize(-1)]);
+    assert_eq!(records(&Level::High), [WriteIsize(0)]);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn pod() {
+    #[derive(Clone, Copy, Hash)]
+    #[hash(pod)]
+    #[repr(C)]
+    struct Pixel {
+        value: u16,
+        alpha: [u8; 2],
+    }
+
+    unsafe impl bytemuck::NoUninit for Pixel {}
+
+    #[derive(Clone, Copy, Hash)]
+    #[hash(pod)]
+    #[repr(C)]
+    union Word {
+        int: u32,
+        bytes: [u8; 4],
+    }
+
+    unsafe impl bytemuck::NoUninit for Word {}
+
+    let pixel = Pixel {
+        value: 0x0102,
+        alpha: [3, 4],
+    };
+    let [a, b] = 0x0102_u16.to_ne_bytes();
+    assert_eq!(records(&pixel), [Write(vec![a, b, 3, 4])]);
+    assert_eq!(
+        records(&[pixel, pixel].as_slice()),
+        [LengthPrefix(2), Write(vec![a, b, 3, 4, a, b, 3, 4])]
+    );
+
+    let word = Word { int: 0x01020304 };
+    assert_eq!(
+        records(&word),
+        [Write(0x01020304_u32.to_ne_bytes().to_vec())]
+    );
+    assert_eq!(
+        records(&word),
+        records(&Word {
+            bytes: unsafe { word.bytes }
+        })
+    );
+}
+
+#[test]
+fn tag() {
+    #[derive(Hash)]
+    #[hash(tag = "pt")]
+    struct Point(u32);
+
+    #[derive(Hash)]
+    #[hash(tag = "pt", version = 2)]
+    struct PointV2(u32);
+
+    assert_eq!(
+        records(&Point(1)),
+        [WriteU64(2), Write(b"pt".to_vec()), WriteU32(1)]
+    );
+    assert_eq!(
+        records(&PointV2(1)),
+        [WriteU64(2), Write(b"pt".to_vec()), WriteU64(2), WriteU32(1)]
+    );
+}
+
+#[test]
+fn order() {
+    #[derive(Hash)]
+    #[hash(order(1, 0))]
+    struct Pair(u8, u32);
+
+    #[derive(Hash)]
+    #[hash(order(id, flags))]
+    struct Entry {
+        flags: u8,
+        id: u32,
+    }
+
+    assert_eq!(records(&Pair(1, 2)), [WriteU32(2), WriteU8(1)]);
+    assert_eq!(
+        records(&Entry { flags: 1, id: 2 }),
+        [WriteU32(2), WriteU8(1)]
+    );
+}
+
+#[test]
+fn sort_fields() {
+    #[derive(Hash)]
+    #[hash(sort_fields)]
+    struct Before {
+        b: u8,
+        a: u32,
+        c: u16,
+    }
+
+    #[derive(Hash)]
+    #[hash(sort_fields)]
+    struct After {
+        c: u16,
+        a: u32,
+        b: u8,
+    }
+
+    let before = Before { b: 1, a: 2, c: 3 };
+    let after = After { c: 3, a: 2, b: 1 };
+    assert_eq!(records(&before), [WriteU32(2), WriteU8(1), WriteU16(3)]);
+    assert_eq!(records(&before), records(&after));
+    assert_eq!(hash(&before), hash(&after));
+}
+
+#[test]
+fn packed() {
+    #[derive(Hash)]
+    #[repr(C, packed)]
+    struct Packed {
+        kind: u8,
+        len: u32,
+        name: &'static str,
+    }
+
+    #[derive(Hash)]
+    struct Unpacked {
+        kind: u8,
+        len: u32,
+        name: &'static str,
+    }
+
+    let packed = Packed {
+        kind: 1,
+        len: 2,
+        name: "x",
+    };
+    let unpacked = Unpacked {
+        kind: 1,
+        len: 2,
+        name: "x",
+    };
+    assert_eq!(records(&packed), [WriteU8(1), WriteU32(2), Str("x".into())]);
+    assert_eq!(records(&packed), records(&unpacked));
+    assert_eq!(hash(&packed), hash(&unpacked));
+}
@@ -0,0 +1,34 @@
+use anyhash::{BuildHasher, Hasher, HasherWrite};
+
+struct KeyedHasher(u64);
+
+impl KeyedHasher {
+    fn with_seed(key: [u8; 48]) -> Self {
+        Self(key.iter().fold(0, |h, &b| h.rotate_left(5) ^ b as u64))
+    }
+}
+
+impl HasherWrite for KeyedHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+impl Hasher<u64> for KeyedHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+// `[u8; 48]` doesn't implement `Default`, so this only works without `new`
+#[derive(BuildHasher, Clone)]
+#[build_hasher(hasher = "KeyedHasher")]
+struct KeyedBuildHasher {
+    key: [u8; 48],
+}
+
+fn main() {
+    let _: u64 = KeyedBuildHasher::with_seed([1; 48]).hash_one("hello");
+}
@@ -0,0 +1,45 @@
+use anyhash::{Hash, HasherWrite};
+
+#[derive(Hash)]
+struct Array<T, const N: usize = 4>([T; N]);
+
+#[derive(Hash)]
+struct Interleaved<const N: usize, T>([T; N])
+where
+    T: Copy;
+
+#[derive(Hash)]
+#[hash(core)]
+struct Defaults<'a, T = u8, const N: usize = 2, U = ()>
+where
+    U: Default,
+{
+    values: &'a [T; N],
+    extra: U,
+}
+
+#[derive(Hash)]
+enum Matrix<const R: usize, T, const C: usize = R> {
+    Dense([[T; C]; R]),
+    Zero,
+}
+
+fn hash<T: Hash>(value: &T) {
+    struct Sink;
+    impl HasherWrite for Sink {
+        fn write(&mut self, _: &[u8]) {}
+    }
+    value.hash(&mut Sink);
+}
+
+fn main() {
+    hash(&Array([1_u8; 4]));
+    hash(&Array::<u8, 2>([1, 2]));
+    hash(&Interleaved::<3, u16>([1, 2, 3]));
+    hash(&Defaults {
+        values: &[1_u8, 2],
+        extra: (),
+    });
+    hash(&Matrix::<2, u8>::Dense([[1, 2], [3, 4]]));
+    hash(&Matrix::<2, u8, 3>::Zero);
+}
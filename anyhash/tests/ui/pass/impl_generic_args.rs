@@ -0,0 +1,36 @@
+use anyhash::{impl_core_hash, impl_hash, Hash, HasherWrite};
+
+#[derive(Hash)]
+struct Foo<T>(T);
+
+mod nested {
+    #[derive(anyhash::Hash)]
+    pub struct Bar<T, const N: usize>(pub [T; N]);
+}
+
+#[derive(core::hash::Hash)]
+struct Legacy<T>(T);
+
+impl_core_hash! {
+    impl<T: Iterator + Hash> Foo<(T, Option<T::Item>)> where T::Item: Hash;
+    impl<T: Iterator + Hash> Foo<(T, [<T as Iterator>::Item; 2])>
+    where
+        <T as Iterator>::Item: Hash;
+    Foo::<u32>;
+    impl<T: Hash, const N: usize> nested::Bar<T, N>;
+    Foo<nested::Bar<u8, 4>>;
+    Foo<fn(u32) -> u32>;
+    Foo<&'static dyn anyhash::DynHash>;
+}
+
+impl_hash! {
+    impl<T> Legacy<T> where T: core::hash::Hash + Clone;
+}
+
+fn main() {
+    struct Sink;
+    impl HasherWrite for Sink {
+        fn write(&mut self, _: &[u8]) {}
+    }
+    Legacy(1_u32).hash(&mut Sink);
+}
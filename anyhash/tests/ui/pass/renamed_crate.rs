@@ -0,0 +1,45 @@
+mod facade {
+    pub mod hashing {
+        pub use ::anyhash::*;
+    }
+}
+
+use facade::hashing::{BuildHasher, Hash, Hasher, HasherWrite};
+
+#[derive(Default)]
+struct Inner(u64);
+
+impl Inner {
+    fn with_seed(seed: u64) -> Self {
+        Self(seed)
+    }
+}
+
+impl HasherWrite for Inner {
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+impl Hasher<u64> for Inner {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[derive(facade::hashing::HasherForward, Default)]
+#[hasher_forward(crate = "facade::hashing")]
+struct Forward(Inner);
+
+#[derive(facade::hashing::BuildHasher, Clone)]
+#[build_hasher(crate = "facade::hashing", hasher = "Inner")]
+struct BuildInner(u64);
+
+fn main() {
+    let mut hasher = Forward::default();
+    "hello".hash(&mut hasher);
+    let _: u64 = hasher.finish();
+    let _: u64 = BuildInner::with_seed(1).hash_one("hello");
+}